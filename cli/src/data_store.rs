@@ -0,0 +1,399 @@
+use async_trait::async_trait;
+use initiative_core::{DataStore, MemoryCampaign, MemoryDataStore, Thing, Uuid, DEFAULT_CAMPAIGN};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const FILE_NAME: &str = "journal.json";
+
+/// A data store that persists the journal as a JSON file in a directory on disk.
+///
+/// The contents of the file are read into memory when the store is opened, and all queries are
+/// answered from memory. Every write rewrites the file in full, first writing to a temporary file
+/// and then moving it into place so that a crash can't leave a half-written journal behind.
+pub struct FileDataStore {
+    path: PathBuf,
+    memory: MemoryDataStore,
+}
+
+#[derive(Deserialize, Serialize)]
+struct FileContents {
+    campaign: String,
+    campaigns: BTreeMap<String, CampaignContents>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct CampaignContents {
+    things: Vec<Thing>,
+
+    #[serde(rename = "keyValues")]
+    key_values: HashMap<String, String>,
+}
+
+impl Default for FileContents {
+    fn default() -> Self {
+        Self {
+            campaign: DEFAULT_CAMPAIGN.to_string(),
+            campaigns: BTreeMap::default(),
+        }
+    }
+}
+
+impl From<CampaignContents> for MemoryCampaign {
+    fn from(input: CampaignContents) -> Self {
+        Self {
+            things: input
+                .things
+                .into_iter()
+                .filter_map(|thing| thing.uuid().copied().map(|uuid| (uuid, thing)))
+                .collect(),
+            key_values: input.key_values,
+        }
+    }
+}
+
+impl From<&MemoryCampaign> for CampaignContents {
+    fn from(input: &MemoryCampaign) -> Self {
+        let mut things: Vec<Thing> = input.things.values().cloned().collect();
+        things.sort_by_key(|thing| thing.uuid().copied());
+
+        Self {
+            things,
+            key_values: input.key_values.clone(),
+        }
+    }
+}
+
+impl FileDataStore {
+    /// Opens the journal stored in `data_dir`, creating the directory and an empty journal if
+    /// necessary. Fails if the directory isn't writable or an existing journal can't be read.
+    pub fn open(data_dir: impl AsRef<Path>) -> io::Result<Self> {
+        let data_dir = data_dir.as_ref();
+        fs::create_dir_all(data_dir)?;
+
+        let path = data_dir.join(FILE_NAME);
+        let mut contents: FileContents = match fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => FileContents::default(),
+            Err(e) => return Err(e),
+        };
+
+        let memory = MemoryDataStore::default();
+        let active: MemoryCampaign = contents
+            .campaigns
+            .remove(&contents.campaign)
+            .unwrap_or_default()
+            .into();
+        memory.things.replace(active.things);
+        memory.key_values.replace(active.key_values);
+        memory.campaign.replace(contents.campaign);
+        memory.campaigns.replace(
+            contents
+                .campaigns
+                .into_iter()
+                .map(|(name, campaign)| (name, campaign.into()))
+                .collect(),
+        );
+
+        let data_store = Self { path, memory };
+
+        // Writing the file straight away ensures that we find out about permission problems now
+        // rather than the first time the user tries to save something.
+        data_store.persist()?;
+
+        Ok(data_store)
+    }
+
+    fn persist(&self) -> io::Result<()> {
+        let json = {
+            let campaign = self.memory.campaign.borrow().clone();
+            let mut campaigns: BTreeMap<String, CampaignContents> = self
+                .memory
+                .campaigns
+                .borrow()
+                .iter()
+                .map(|(name, campaign)| (name.clone(), campaign.into()))
+                .collect();
+
+            campaigns.insert(
+                campaign.clone(),
+                (&MemoryCampaign {
+                    things: self.memory.things.borrow().clone(),
+                    key_values: self.memory.key_values.borrow().clone(),
+                })
+                    .into(),
+            );
+
+            serde_json::to_string(&FileContents {
+                campaign,
+                campaigns,
+            })
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+        };
+
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, json)?;
+        fs::rename(&temp_path, &self.path)
+    }
+
+    fn persist_result(&self, result: Result<(), ()>) -> Result<(), ()> {
+        result.and_then(|()| self.persist().map_err(|_| ()))
+    }
+}
+
+#[async_trait(?Send)]
+impl DataStore for FileDataStore {
+    async fn health_check(&self) -> Result<(), ()> {
+        self.persist().map_err(|_| ())
+    }
+
+    async fn delete_thing_by_uuid(&mut self, uuid: &Uuid) -> Result<(), ()> {
+        let result = self.memory.delete_thing_by_uuid(uuid).await;
+        self.persist_result(result)
+    }
+
+    async fn edit_thing(&mut self, thing: &Thing) -> Result<(), ()> {
+        let result = self.memory.edit_thing(thing).await;
+        self.persist_result(result)
+    }
+
+    async fn get_all_the_things(&self) -> Result<Vec<Thing>, ()> {
+        self.memory.get_all_the_things().await
+    }
+
+    async fn get_thing_by_uuid(&self, uuid: &Uuid) -> Result<Option<Thing>, ()> {
+        self.memory.get_thing_by_uuid(uuid).await
+    }
+
+    async fn get_thing_by_name(&self, name: &str) -> Result<Option<Thing>, ()> {
+        self.memory.get_thing_by_name(name).await
+    }
+
+    async fn get_things_by_name_start(
+        &self,
+        name: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<Thing>, ()> {
+        self.memory.get_things_by_name_start(name, limit).await
+    }
+
+    async fn search_things(&self, query: &str, limit: Option<usize>) -> Result<Vec<Thing>, ()> {
+        self.memory.search_things(query, limit).await
+    }
+
+    async fn get_npcs_by_place(&self, place_uuid: &Uuid) -> Result<Vec<Thing>, ()> {
+        self.memory.get_npcs_by_place(place_uuid).await
+    }
+
+    async fn save_thing(&mut self, thing: &Thing) -> Result<(), ()> {
+        let result = self.memory.save_thing(thing).await;
+        self.persist_result(result)
+    }
+
+    async fn set_value(&mut self, key: &str, value: &str) -> Result<(), ()> {
+        let result = self.memory.set_value(key, value).await;
+        self.persist_result(result)
+    }
+
+    async fn get_value(&self, key: &str) -> Result<Option<String>, ()> {
+        self.memory.get_value(key).await
+    }
+
+    async fn delete_value(&mut self, key: &str) -> Result<(), ()> {
+        let result = self.memory.delete_value(key).await;
+        self.persist_result(result)
+    }
+
+    async fn get_campaign(&self) -> Result<String, ()> {
+        self.memory.get_campaign().await
+    }
+
+    async fn get_campaigns(&self) -> Result<Vec<String>, ()> {
+        self.memory.get_campaigns().await
+    }
+
+    async fn set_campaign(&mut self, name: &str) -> Result<(), ()> {
+        let result = self.memory.set_campaign(name).await;
+        self.persist_result(result)
+    }
+
+    async fn delete_campaign(&mut self, name: &str) -> Result<(), ()> {
+        let result = self.memory.delete_campaign(name).await;
+        self.persist_result(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const GANDALF_UUID: &str = "00000000-0000-0000-0000-000000000001";
+
+    #[tokio::test]
+    async fn save_thing_test() {
+        let data_dir = TestDir::new();
+
+        {
+            let mut ds = FileDataStore::open(&data_dir.0).unwrap();
+            assert_eq!(Ok(()), ds.save_thing(&gandalf()).await);
+            assert_eq!(Err(()), ds.save_thing(&gandalf()).await);
+        }
+
+        let ds = FileDataStore::open(&data_dir.0).unwrap();
+        assert_eq!(Ok(vec![gandalf()]), ds.get_all_the_things().await);
+        assert_eq!(
+            Ok(Some(gandalf())),
+            ds.get_thing_by_uuid(&gandalf_uuid()).await,
+        );
+        assert_eq!(
+            Ok(Some(gandalf())),
+            ds.get_thing_by_name("gANDALF THE gREY").await,
+        );
+        assert_eq!(
+            Ok(vec![gandalf()]),
+            ds.get_things_by_name_start("gan", Some(1)).await,
+        );
+    }
+
+    #[tokio::test]
+    async fn edit_delete_thing_test() {
+        let data_dir = TestDir::new();
+
+        {
+            let mut ds = FileDataStore::open(&data_dir.0).unwrap();
+            ds.save_thing(&gandalf()).await.unwrap();
+        }
+
+        {
+            let mut ds = FileDataStore::open(&data_dir.0).unwrap();
+            assert_eq!(Ok(()), ds.edit_thing(&npc("Gandalf the White")).await);
+        }
+
+        {
+            let mut ds = FileDataStore::open(&data_dir.0).unwrap();
+            assert_eq!(
+                Ok(Some(npc("Gandalf the White"))),
+                ds.get_thing_by_uuid(&gandalf_uuid()).await,
+            );
+            assert_eq!(Ok(()), ds.delete_thing_by_uuid(&gandalf_uuid()).await);
+            assert_eq!(Err(()), ds.delete_thing_by_uuid(&gandalf_uuid()).await);
+        }
+
+        let ds = FileDataStore::open(&data_dir.0).unwrap();
+        assert_eq!(Ok(Vec::new()), ds.get_all_the_things().await);
+    }
+
+    #[tokio::test]
+    async fn key_value_test() {
+        let data_dir = TestDir::new();
+
+        {
+            let mut ds = FileDataStore::open(&data_dir.0).unwrap();
+            assert_eq!(Ok(None), ds.get_value("time").await);
+            assert_eq!(Ok(()), ds.set_value("time", "1:2:03:04:05").await);
+            assert_eq!(Ok(()), ds.set_value("other", "value").await);
+        }
+
+        {
+            let mut ds = FileDataStore::open(&data_dir.0).unwrap();
+            assert_eq!(
+                Ok(Some("1:2:03:04:05".to_string())),
+                ds.get_value("time").await,
+            );
+            assert_eq!(Ok(()), ds.delete_value("other").await);
+        }
+
+        let ds = FileDataStore::open(&data_dir.0).unwrap();
+        assert_eq!(Ok(None), ds.get_value("other").await);
+    }
+
+    #[tokio::test]
+    async fn campaign_test() {
+        let data_dir = TestDir::new();
+
+        {
+            let mut ds = FileDataStore::open(&data_dir.0).unwrap();
+            ds.save_thing(&gandalf()).await.unwrap();
+            assert_eq!(Ok(()), ds.set_campaign("Curse of Strahd").await);
+            ds.set_value("time", "1:2:03:04:05").await.unwrap();
+        }
+
+        {
+            let mut ds = FileDataStore::open(&data_dir.0).unwrap();
+            assert_eq!(Ok("Curse of Strahd".to_string()), ds.get_campaign().await);
+            assert_eq!(Ok(Vec::new()), ds.get_all_the_things().await);
+            assert_eq!(
+                Ok(Some("1:2:03:04:05".to_string())),
+                ds.get_value("time").await,
+            );
+
+            assert_eq!(Ok(()), ds.set_campaign(DEFAULT_CAMPAIGN).await);
+            assert_eq!(Ok(vec![gandalf()]), ds.get_all_the_things().await);
+            assert_eq!(Ok(None), ds.get_value("time").await);
+            assert_eq!(Ok(()), ds.delete_campaign("Curse of Strahd").await);
+        }
+
+        let ds = FileDataStore::open(&data_dir.0).unwrap();
+        assert_eq!(
+            Ok(vec![DEFAULT_CAMPAIGN.to_string()]),
+            ds.get_campaigns().await,
+        );
+    }
+
+    #[test]
+    fn open_test_corrupt() {
+        let data_dir = TestDir::new();
+        fs::create_dir_all(&data_dir.0).unwrap();
+        fs::write(data_dir.0.join(FILE_NAME), "not json").unwrap();
+
+        assert_eq!(
+            io::ErrorKind::InvalidData,
+            FileDataStore::open(&data_dir.0).err().unwrap().kind(),
+        );
+    }
+
+    #[test]
+    fn open_test_not_a_directory() {
+        let data_dir = TestDir::new();
+        fs::write(&data_dir.0, "").unwrap();
+
+        assert!(FileDataStore::open(&data_dir.0).is_err());
+    }
+
+    fn gandalf_uuid() -> Uuid {
+        Uuid::parse_str(GANDALF_UUID).unwrap()
+    }
+
+    fn gandalf() -> Thing {
+        npc("Gandalf the Grey")
+    }
+
+    fn npc(name: &str) -> Thing {
+        serde_json::from_str(&format!(
+            r#"{{"type":"Npc","uuid":"{}","name":"{}","gender":null,"age":null,"age_years":null,"size":null,"species":null,"ethnicity":null,"location_uuid":null}}"#,
+            GANDALF_UUID, name,
+        ))
+        .unwrap()
+    }
+
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!("initiative-test-{}", Uuid::new_v4())))
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            if self.0.is_dir() {
+                let _ = fs::remove_dir_all(&self.0);
+            } else {
+                let _ = fs::remove_file(&self.0);
+            }
+        }
+    }
+}
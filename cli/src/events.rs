@@ -0,0 +1,137 @@
+//! The core app raises events, such as a file being ready to export, through a plain function
+//! pointer. The terminal has no download prompt or file picker, so events are queued here and
+//! handled once the command that raised them has finished, with the results added to its output.
+
+use crate::data_dir;
+use initiative_core::{App, Event, ExportFile};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+static EVENTS: Mutex<Vec<Event>> = Mutex::new(Vec::new());
+
+pub fn event_dispatcher(event: Event) {
+    EVENTS.lock().unwrap().push(event);
+}
+
+/// Run a command, then handle any events that it raised.
+pub async fn command(app: &mut App, input: &str) -> Result<String, String> {
+    let mut result = app.command(input).await;
+    let events = mem::take(&mut *EVENTS.lock().unwrap());
+
+    for event in events {
+        let message = match event {
+            Event::Export(file) => export(&file),
+            Event::Import => {
+                // The app's response only makes sense in a browser, so replace it outright.
+                result = import(app).await;
+                continue;
+            }
+        };
+
+        match (&mut result, message) {
+            (Ok(output), Ok(message)) => {
+                output.push_str("\n\n");
+                output.push_str(&message);
+            }
+            (Ok(_), Err(e)) => result = Err(e),
+            (Err(_), _) => {}
+        }
+    }
+
+    result
+}
+
+fn export(file: &ExportFile) -> Result<String, String> {
+    let dir = data_dir().ok_or("Unable to find a data directory to export to.")?;
+
+    fs::create_dir_all(&dir)
+        .and_then(|_| write_new(&dir, file.file_name(), &file.contents()))
+        .map(|path| format!("_The export has been saved to {}._", path.display()))
+        .map_err(|e| format!("Unable to export to {}: {}", dir.display(), e))
+}
+
+/// Without a file picker, `import` reads `import.json` or `import.csv` from the data directory.
+async fn import(app: &mut App) -> Result<String, String> {
+    let dir = data_dir().ok_or("Unable to find a data directory to import from.")?;
+
+    for (file_name, is_csv) in [("import.json", false), ("import.csv", true)] {
+        let path = dir.join(file_name);
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(format!("Unable to read {}: {}", path.display(), e)),
+        };
+
+        return if is_csv {
+            app.bulk_import_csv(&contents).await
+        } else {
+            let data = serde_json::from_str(&contents)
+                .map_err(|e| format!("{} is not a valid backup: {}", path.display(), e))?;
+            app.bulk_import(data).await
+        };
+    }
+
+    Err(format!(
+        "To import, save a backup as import.json or a spreadsheet as import.csv in {}, then run `import` again.",
+        dir.display(),
+    ))
+}
+
+/// Write a file into a directory without replacing anything already there. If the name is taken,
+/// a number is added to it, eg. `initiative_export-2.json`.
+fn write_new(dir: &Path, file_name: &str, contents: &str) -> io::Result<PathBuf> {
+    let (stem, extension) = file_name.rsplit_once('.').unwrap_or((file_name, ""));
+    let mut i = 1;
+
+    loop {
+        let path = if i == 1 {
+            dir.join(file_name)
+        } else {
+            dir.join(format!("{}-{}.{}", stem, i, extension))
+        };
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                file.write_all(contents.as_bytes())?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => i += 1,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use initiative_core::Uuid;
+
+    #[test]
+    fn write_new_test() {
+        let dir = std::env::temp_dir().join(format!("initiative-test-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            dir.join("export.json"),
+            write_new(&dir, "export.json", "first").unwrap(),
+        );
+        assert_eq!(
+            dir.join("export-2.json"),
+            write_new(&dir, "export.json", "second").unwrap(),
+        );
+        assert_eq!(
+            "first",
+            fs::read_to_string(dir.join("export.json")).unwrap(),
+        );
+        assert_eq!(
+            "second",
+            fs::read_to_string(dir.join("export-2.json")).unwrap(),
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -6,19 +6,42 @@
 //! * No autocomplete suggestions are displayed
 //! * Markdown output is displayed literally rather than being formatted
 //! * No scrolling is present in the rich interface
-//! * No data storage is available
-//! * Import/export don't work
+//! * Files to import can't be chosen interactively
+//!
+//! Journal entries are stored in a JSON file in the platform's user data directory (eg.
+//! `~/.local/share/initiative` on Linux), which can be overridden with the `INITIATIVE_DATA_DIR`
+//! environment variable. If that directory can't be written, the app falls back to in-memory
+//! storage that is discarded on exit. Exports are saved to the same directory, and `import`
+//! reads a backup named `import.json` or a spreadsheet named `import.csv` from it.
 
+mod data_store;
+mod events;
 mod light;
 mod rich;
 
+pub use data_store::FileDataStore;
+pub use events::event_dispatcher;
+
 use initiative_core::App;
+use std::env;
 use std::io;
+use std::path::PathBuf;
 
-pub async fn run(app: App) -> io::Result<()> {
+/// Run the interface until the user exits, starting with the message of the day returned by
+/// `App::init`.
+pub async fn run(app: App, motd: &str) -> io::Result<()> {
     if termion::is_tty(&io::stdin()) {
-        rich::run(app).await
+        rich::run(app, motd).await
     } else {
-        light::run(app).await
+        light::run(app, motd).await
     }
 }
+
+/// The directory in which the journal, exports and any custom `cultures.json` are stored, either
+/// from the `INITIATIVE_DATA_DIR` environment variable or the platform's default user data
+/// directory.
+pub fn data_dir() -> Option<PathBuf> {
+    env::var_os("INITIATIVE_DATA_DIR")
+        .map(PathBuf::from)
+        .or_else(|| dirs::data_dir().map(|dir| dir.join("initiative")))
+}
@@ -1,14 +1,17 @@
+use crate::events;
 use initiative_core::App;
 use std::io;
 
-pub async fn run(mut app: App) -> io::Result<()> {
+pub async fn run(mut app: App, motd: &str) -> io::Result<()> {
     let mut buffer = String::new();
     let stdin = io::stdin();
 
+    println!("{}\n", motd);
+
     loop {
         match stdin.read_line(&mut buffer) {
             Ok(0) => return Ok(()),
-            Ok(_) => match app.command(&buffer).await {
+            Ok(_) => match events::command(&mut app, buffer.trim_end()).await {
                 Ok(s) => println!("\n{}\n", s),
                 Err(e) => eprintln!("\n{}\n", e),
             },
@@ -1,14 +1,35 @@
 use initiative_cli as cli;
 use initiative_core as core;
 use std::error::Error;
+use std::fs;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    let data_store = core::NullDataStore;
-    cli::run(core::app(data_store, &event_dispatcher)).await?;
-    Ok(())
-}
+    let mut app = match cli::data_dir().map(cli::FileDataStore::open) {
+        Some(Ok(data_store)) => core::app(data_store, &cli::event_dispatcher),
+        Some(Err(e)) => {
+            eprintln!(
+                "Unable to open the journal, changes will not be saved: {}",
+                e,
+            );
+            core::app(core::MemoryDataStore::default(), &cli::event_dispatcher)
+        }
+        None => {
+            eprintln!("Unable to find a data directory, changes will not be saved.");
+            core::app(core::MemoryDataStore::default(), &cli::event_dispatcher)
+        }
+    };
+
+    let motd = app.init().await;
 
-fn event_dispatcher(event: core::Event) {
-    println!("Dispatched event: {:?}", event);
+    if let Some(path) = cli::data_dir().map(|dir| dir.join("cultures.json")) {
+        if let Ok(cultures) = fs::read_to_string(&path) {
+            if let Err(e) = app.load_cultures(&cultures) {
+                eprintln!("Unable to load {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    cli::run(app, motd).await?;
+    Ok(())
 }
@@ -1,5 +1,6 @@
 mod wrap;
 
+use crate::events;
 use initiative_core::app::AutocompleteSuggestion;
 use initiative_core::App;
 use std::fmt;
@@ -176,7 +177,7 @@ impl Autocomplete {
     }
 }
 
-pub async fn run(mut app: App) -> io::Result<()> {
+pub async fn run(mut app: App, motd: &str) -> io::Result<()> {
     let mut screen = termion::screen::AlternateScreen::from(io::stdout())
         .into_raw_mode()
         .unwrap();
@@ -193,7 +194,7 @@ pub async fn run(mut app: App) -> io::Result<()> {
     });
 
     let mut input = Input::default();
-    let mut output = String::new();
+    let mut output = motd.to_string();
     let mut autocomplete: Option<Autocomplete> = None;
 
     draw_output(&mut screen, &output)?;
@@ -304,16 +305,18 @@ pub async fn run(mut app: App) -> io::Result<()> {
             }
         };
 
-        output = app.command(&command).await.unwrap_or_else(|e| {
-            format!(
-                "{}{}{}{}{}",
-                color::Fg(color::Black),
-                color::Bg(color::Red),
-                e,
-                color::Fg(color::Reset),
-                color::Bg(color::Reset),
-            )
-        });
+        output = events::command(&mut app, &command)
+            .await
+            .unwrap_or_else(|e| {
+                format!(
+                    "{}{}{}{}{}",
+                    color::Fg(color::Black),
+                    color::Bg(color::Red),
+                    e,
+                    color::Fg(color::Reset),
+                    color::Bg(color::Reset),
+                )
+            });
 
         draw_output(&mut screen, &output)?;
         draw_autocomplete(&mut screen, autocomplete.as_ref())?;
@@ -1,7 +1,9 @@
 use crate::app::{
     AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
 };
+use crate::storage::{Change, KeyValue};
 use crate::utils::CaseInsensitiveStr;
+use crate::world::npc::Units;
 use async_trait::async_trait;
 use caith::Roller;
 use initiative_macros::changelog;
@@ -13,7 +15,9 @@ pub enum AppCommand {
     Changelog,
     Debug,
     Help,
+    HistoryLength(Option<usize>),
     Roll(String),
+    Units(Option<Units>),
 }
 
 #[async_trait(?Send)]
@@ -32,6 +36,29 @@ impl Runnable for AppCommand {
             Self::Help => include_str!("../../../../data/help.md")
                 .trim_end()
                 .to_string(),
+            Self::HistoryLength(None) => {
+                let len = app_meta.repository.undo_history_len();
+                format!(
+                    "Up to {} change{} {} kept in your `history`. Use `history length [number]` to change this.",
+                    len,
+                    if len == 1 { "" } else { "s" },
+                    if len == 1 { "is" } else { "are" },
+                )
+            }
+            Self::HistoryLength(Some(len)) => app_meta
+                .repository
+                .modify(Change::SetKeyValue {
+                    key_value: KeyValue::UndoHistoryLen(Some(len)),
+                })
+                .await
+                .map(|_| {
+                    format!(
+                        "Up to {} change{} will now be kept in your `history`. Use `undo` to reverse this.",
+                        len,
+                        if len == 1 { "" } else { "s" },
+                    )
+                })
+                .map_err(|_| "Unable to change the history length.".to_string())?,
             Self::Roll(s) => Roller::new(&s)
                 .ok()
                 .and_then(|r| r.roll_with(&mut app_meta.rng).ok())
@@ -48,6 +75,23 @@ impl Runnable for AppCommand {
                         s
                     )
                 })?,
+            Self::Units(None) => format!(
+                "Heights and weights are shown in {} units. Use `units metric` or `units imperial` to change this.",
+                app_meta.repository.units().await,
+            ),
+            Self::Units(Some(units)) => app_meta
+                .repository
+                .modify(Change::SetKeyValue {
+                    key_value: KeyValue::Units(Some(units)),
+                })
+                .await
+                .map(|_| {
+                    format!(
+                        "Heights and weights will now be shown in {} units. Use `undo` to reverse this.",
+                        units,
+                    )
+                })
+                .map_err(|_| "Unable to change the units.".to_string())?,
         })
     }
 }
@@ -63,6 +107,19 @@ impl ContextAwareParse for AppCommand {
             CommandMatches::new_canonical(Self::Debug)
         } else if input.eq_ci("help") {
             CommandMatches::new_canonical(Self::Help)
+        } else if input.eq_ci("units") {
+            CommandMatches::new_canonical(Self::Units(None))
+        } else if let Some(Ok(units)) = input.strip_prefix_ci("units ").map(|s| s.trim().parse()) {
+            CommandMatches::new_canonical(Self::Units(Some(units)))
+        } else if input.eq_ci("history length") {
+            CommandMatches::new_canonical(Self::HistoryLength(None))
+        } else if let Some(len) = input
+            .strip_prefix_ci("history length ")
+            .and_then(|s| s.trim().parse::<usize>().ok())
+            // Keeping no history at all would leave even this change impossible to undo.
+            .filter(|&len| len > 0)
+        {
+            CommandMatches::new_canonical(Self::HistoryLength(Some(len)))
         } else if input.starts_with_ci("roll ") {
             CommandMatches::new_canonical(Self::Roll(input[5..].to_string()))
         } else if !input.chars().all(|c| c.is_ascii_digit())
@@ -86,6 +143,9 @@ impl Autocomplete for AppCommand {
             AutocompleteSuggestion::new("about", "about initiative.sh"),
             AutocompleteSuggestion::new("changelog", "show latest updates"),
             AutocompleteSuggestion::new("help", "how to use initiative.sh"),
+            AutocompleteSuggestion::new("history length", "how many changes to keep"),
+            AutocompleteSuggestion::new("units imperial", "show feet and pounds"),
+            AutocompleteSuggestion::new("units metric", "show centimeters and kilograms"),
         ]
         .into_iter()
         .filter(|suggestion| suggestion.term.starts_with_ci(input))
@@ -106,7 +166,11 @@ impl fmt::Display for AppCommand {
             Self::Changelog => write!(f, "changelog"),
             Self::Debug => write!(f, "debug"),
             Self::Help => write!(f, "help"),
+            Self::HistoryLength(None) => write!(f, "history length"),
+            Self::HistoryLength(Some(len)) => write!(f, "history length {}", len),
             Self::Roll(s) => write!(f, "roll {}", s),
+            Self::Units(None) => write!(f, "units"),
+            Self::Units(Some(units)) => write!(f, "units {}", units),
         }
     }
 }
@@ -138,6 +202,26 @@ mod test {
             block_on(AppCommand::parse_input("d20", &app_meta)),
         );
 
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Units(Some(Units::Imperial))),
+            block_on(AppCommand::parse_input("units imperial", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(AppCommand::parse_input("units furlongs", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::HistoryLength(Some(20))),
+            block_on(AppCommand::parse_input("history length 20", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(AppCommand::parse_input("history length 0", &app_meta)),
+        );
+
         assert_eq!(
             CommandMatches::default(),
             block_on(AppCommand::parse_input("potato", &app_meta)),
@@ -152,6 +236,7 @@ mod test {
             ("about", "about initiative.sh"),
             ("changelog", "show latest updates"),
             ("help", "how to use initiative.sh"),
+            ("units metric", "show centimeters and kilograms"),
         ]
         .into_iter()
         .for_each(|(term, summary)| {
@@ -197,6 +282,10 @@ mod test {
             AppCommand::Changelog,
             AppCommand::Debug,
             AppCommand::Help,
+            AppCommand::HistoryLength(None),
+            AppCommand::HistoryLength(Some(20)),
+            AppCommand::Units(None),
+            AppCommand::Units(Some(Units::Metric)),
         ]
         .into_iter()
         .for_each(|command| {
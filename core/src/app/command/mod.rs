@@ -179,6 +179,7 @@ impl Autocomplete for Command {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CommandType {
     Alias(CommandAlias),
@@ -8,7 +8,8 @@ use crate::storage::{Change, StorageCommand};
 use crate::time::TimeCommand;
 use crate::utils::CaseInsensitiveStr;
 use crate::world::npc::{Age, Ethnicity, Gender, Npc, Species};
-use crate::world::{ParsedThing, Thing, WorldCommand};
+use crate::world::{ParsedThing, PlaceRelations, Thing, WorldCommand};
+use crate::Uuid;
 use async_trait::async_trait;
 use std::fmt;
 
@@ -453,6 +454,8 @@ impl Runnable for TutorialCommand {
         )) = input_command.get_type()
         {
             if let Some(inn_name) = inn_name {
+                delete_patrons(app_meta, inn_name).await;
+
                 app_meta
                     .repository
                     .modify(Change::Delete {
@@ -626,6 +629,8 @@ impl Runnable for TutorialCommand {
                 Self::AdvancingTime {
                     inn_name, npc_name, ..
                 } => {
+                    delete_patrons(app_meta, &inn_name).await;
+
                     let next = Self::CheckingTheTime { inn_name, npc_name };
 
                     (
@@ -642,6 +647,8 @@ impl Runnable for TutorialCommand {
                     )
                 }
                 Self::Conclusion { inn_name, npc_name } => {
+                    delete_patrons(app_meta, &inn_name).await;
+
                     app_meta
                         .repository
                         .modify(Change::Delete {
@@ -766,6 +773,33 @@ impl Autocomplete for TutorialCommand {
     }
 }
 
+/// The inn is saved along with a few patrons, who must be deleted before it so that the tutorial
+/// doesn't leave them behind in the journal.
+async fn delete_patrons(app_meta: &mut AppMeta, inn_name: &str) {
+    let inn = match app_meta.repository.get_by_name(inn_name).await {
+        Ok(inn) => inn,
+        Err(_) => return,
+    };
+
+    let relations: PlaceRelations = app_meta
+        .repository
+        .load_relations(&inn)
+        .await
+        .unwrap_or_default()
+        .into();
+
+    for npc in relations.occupants {
+        app_meta
+            .repository
+            .modify(Change::Delete {
+                name: npc.name.to_string(),
+                uuid: npc.uuid.map(Uuid::from),
+            })
+            .await
+            .ok();
+    }
+}
+
 impl fmt::Display for TutorialCommand {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
@@ -1,14 +1,22 @@
 use super::{CommandAlias, Event};
-use crate::storage::{DataStore, Repository};
+use crate::storage::backup::ImportOptions;
+use crate::storage::{DataStore, KeyValue, Repository};
 use crate::world;
+use crate::world::npc::Culture;
 use rand::prelude::*;
 use std::collections::HashSet;
 use std::fmt;
 
 pub struct AppMeta {
     pub command_aliases: HashSet<CommandAlias>,
+
+    /// Custom cultures loaded for the session, eg. from a `cultures.json` file, as opposed to
+    /// those stored with the current campaign.
+    pub cultures: Vec<Culture>,
+
     pub demographics: world::Demographics,
     pub event_dispatcher: &'static dyn Fn(Event),
+    pub import_options: ImportOptions,
     pub rng: SmallRng,
     pub repository: Repository,
 }
@@ -20,12 +28,35 @@ impl AppMeta {
     ) -> Self {
         Self {
             command_aliases: HashSet::default(),
+            cultures: Vec::new(),
             demographics: world::Demographics::default(),
             event_dispatcher,
+            import_options: ImportOptions::default(),
             repository: Repository::new(data_store),
             rng: SmallRng::from_entropy(),
         }
     }
+
+    /// Make the session's custom cultures and those stored with the current campaign, eg. by
+    /// importing a backup, available when generating characters. This replaces any cultures
+    /// loaded previously, so it is run again whenever the campaign changes.
+    pub async fn load_cultures(&mut self) {
+        let stored_cultures = self
+            .repository
+            .get_key_value(&KeyValue::Cultures(None))
+            .await
+            .ok()
+            .and_then(KeyValue::cultures)
+            .unwrap_or_default();
+
+        self.demographics.set_cultures(
+            self.cultures
+                .iter()
+                .cloned()
+                .chain(stored_cultures)
+                .collect(),
+        );
+    }
 }
 
 impl fmt::Debug for AppMeta {
@@ -51,7 +82,7 @@ mod test {
         app_meta.demographics = Demographics::new(HashMap::new().into());
 
         assert_eq!(
-            "AppMeta { command_aliases: {}, demographics: Demographics { groups: GroupMapWrapper({}) }, repository: Repository { data_store_enabled: false, recent: [] } }",
+            "AppMeta { command_aliases: {}, demographics: Demographics { groups: GroupMapWrapper({}), cultures: [] }, repository: Repository { data_store_enabled: false, recent: [] } }",
             format!("{:?}", app_meta),
         );
     }
@@ -10,9 +10,12 @@ pub use command::assert_autocomplete;
 mod command;
 mod meta;
 
-use crate::storage::backup::{import, BackupData};
+use crate::storage::backup::{import, BackupData, ImportOptions, ImportStats};
+use crate::storage::import_csv;
 use crate::utils::CaseInsensitiveStr;
+use crate::world::npc::Culture;
 use initiative_macros::motd;
+use std::mem;
 
 /// The application wrapper. Its inner [`AppMeta`] object holds metadata associated with the
 /// application, including ephemeral storage of journal entries and the object representing the
@@ -28,13 +31,52 @@ pub struct App {
 /// An event that can occur while the app is running that may require special handling by the UI.
 #[derive(Debug)]
 pub enum Event {
-    /// The user typed the `export` command and the journal backup is ready to download.
-    Export(BackupData),
+    /// The user typed the `export` command and the file is ready to download.
+    Export(ExportFile),
 
     /// The user typed the `import` command and should be prompted to select a file to import.
     Import,
 }
 
+/// A file produced by the `export` command.
+#[derive(Debug)]
+pub enum ExportFile {
+    /// A JSON backup of the journal, which can be restored with `import`.
+    Backup(BackupData),
+
+    /// A Markdown handbook of the journal contents.
+    Markdown(String),
+
+    /// An HTML handbook of the journal contents.
+    Html(String),
+}
+
+impl ExportFile {
+    /// The name under which the file should be saved.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Backup(_) => "initiative_export.json",
+            Self::Markdown(_) => "initiative_handbook.md",
+            Self::Html(_) => "initiative_handbook.html",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Backup(_) => "application/json",
+            Self::Markdown(_) => "text/markdown",
+            Self::Html(_) => "text/html",
+        }
+    }
+
+    pub fn contents(&self) -> String {
+        match self {
+            Self::Backup(data) => serde_json::to_string(data).unwrap(),
+            Self::Markdown(s) | Self::Html(s) => s.clone(),
+        }
+    }
+}
+
 impl App {
     pub fn new(meta: AppMeta) -> App {
         App { meta }
@@ -45,6 +87,8 @@ impl App {
     /// store to initialize, which may involve opening a database connection.
     pub async fn init(&mut self) -> &'static str {
         self.meta.repository.init().await;
+        self.meta.load_cultures().await;
+
         let (motd, motd_len) = motd!("! Local storage is not available in your browser. You will be able to use initiative.sh, but anything you save will not persist beyond this session.");
 
         if self.meta.repository.data_store_enabled() {
@@ -54,6 +98,18 @@ impl App {
         }
     }
 
+    /// Load custom cultures from a JSON list of culture definitions, such as the contents of a
+    /// `cultures.json` file. Characters drawn from these cultures are generated alongside the
+    /// built-in species for the rest of the session, whichever campaign is in use. Returns the
+    /// number of cultures loaded.
+    pub fn load_cultures(&mut self, input: &str) -> Result<usize, String> {
+        let cultures = Culture::parse_list(input)?;
+        let count = cultures.len();
+        self.meta.cultures.extend(cultures.iter().cloned());
+        self.meta.demographics.add_cultures(cultures);
+        Ok(count)
+    }
+
     /// The user typed an input and pressed Enter. What happens?
     ///
     /// On success or failure, returns a String that can be displayed back to the user.
@@ -78,12 +134,47 @@ impl App {
         suggestions
     }
 
+    /// Set the maximum number of changes that the user can `undo`. Defaults to
+    /// [`DEFAULT_UNDO_HISTORY_LEN`](crate::DEFAULT_UNDO_HISTORY_LEN).
+    pub async fn set_undo_history_len(&mut self, len: usize) {
+        self.meta.repository.set_undo_history_len(len).await;
+    }
+
     /// The part of the import flow that occurs after the user selects a file in response to the
-    /// [`Event::Import`].
+    /// [`Event::Import`]. Conflicts are resolved using the options given to the `import` command.
     pub async fn bulk_import(&mut self, data: BackupData) -> Result<String, String> {
-        import(&mut self.meta.repository, data)
+        let options = mem::take(&mut self.meta.import_options);
+
+        let result = import(&mut self.meta.repository, data, options)
             .await
-            .map(|stats| stats.to_string())
-            .map_err(|_| "Failed to import.".to_string())
+            .map(|stats| display_import_stats(stats, options))
+            .map_err(|_| "Failed to import.".to_string());
+
+        if !options.dry_run {
+            self.meta.load_cultures().await;
+        }
+
+        result
+    }
+
+    /// As [`App::bulk_import`], but for a spreadsheet of characters and places in CSV format
+    /// rather than a backup.
+    pub async fn bulk_import_csv(&mut self, input: &str) -> Result<String, String> {
+        let options = mem::take(&mut self.meta.import_options);
+
+        import_csv(&mut self.meta.repository, input, options)
+            .await
+            .map(|stats| display_import_stats(stats, options))
+    }
+}
+
+fn display_import_stats(stats: ImportStats, options: ImportOptions) -> String {
+    if options.dry_run {
+        format!(
+            "{}\n\n*This was a dry run, so your journal has not been changed. To import for real, use `import` without `--dry-run`.*",
+            stats,
+        )
+    } else {
+        stats.to_string()
     }
 }
@@ -8,9 +8,12 @@
 
 pub mod app;
 
-pub use app::{App, Event};
+pub use app::{App, Event, ExportFile};
 pub use storage::backup::BackupData;
-pub use storage::{DataStore, MemoryDataStore, NullDataStore};
+pub use storage::{
+    DataStore, MemoryCampaign, MemoryDataStore, NullDataStore, DEFAULT_CAMPAIGN,
+    DEFAULT_UNDO_HISTORY_LEN,
+};
 pub use uuid::Uuid;
 pub use world::Thing;
 
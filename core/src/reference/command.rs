@@ -1,4 +1,4 @@
-use super::{Condition, Item, ItemCategory, MagicItem, Spell, Trait};
+use super::{Condition, Item, ItemCategory, Language, MagicItem, Spell, Trait};
 use crate::app::{
     AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
 };
@@ -13,6 +13,7 @@ pub enum ReferenceCommand {
     Condition(Condition),
     Item(Item),
     ItemCategory(ItemCategory),
+    Language(Language),
     MagicItem(MagicItem),
     OpenGameLicense,
     Spell(Spell),
@@ -27,6 +28,7 @@ impl Runnable for ReferenceCommand {
             Self::Condition(condition) => (format!("{}", condition), condition.get_name()),
             Self::Item(item) => (format!("{}", item), item.get_name()),
             Self::ItemCategory(category) => (format!("{}", category), "This listing"),
+            Self::Language(language) => (format!("{}", language), language.get_name()),
             Self::MagicItem(magic_item) => (format!("{}", magic_item), magic_item.get_name()),
             Self::OpenGameLicense => {
                 return Ok(include_str!("../../../data/ogl-1.0a.md")
@@ -68,6 +70,13 @@ impl ContextAwareParse for ReferenceCommand {
             .and_then(|s| s.parse().ok())
         {
             CommandMatches::new_canonical(Self::Item(item))
+        } else if let Some(language) = input
+            .strip_prefix_ci("srd language ")
+            .and_then(|s| s.parse().ok())
+        {
+            // Language names overlap with ethnicities ("Elvish", "Halfling"), which generate
+            // characters, so languages are only looked up with the "srd language" prefix.
+            CommandMatches::new_canonical(Self::Language(language))
         } else if let Some(magic_item) = input
             .strip_prefix_ci("srd magic item ")
             .and_then(|s| s.parse().ok())
@@ -127,6 +136,11 @@ impl Autocomplete for ReferenceCommand {
         .chain(ItemCategory::get_words().zip(repeat("SRD item category")))
         .chain(MagicItem::get_words().zip(repeat("SRD magic item")))
         .chain(Trait::get_words().zip(repeat("SRD trait")))
+        .map(|(term, summary)| (term.to_string(), summary))
+        .chain(
+            // Bare language names generate characters, so the full command is suggested.
+            Language::get_words().map(|word| (format!("srd language {}", word), "SRD language")),
+        )
         .filter(|(term, _)| term.starts_with_ci(input))
         .take(10)
         .map(|(term, summary)| AutocompleteSuggestion::new(term, summary))
@@ -140,6 +154,7 @@ impl fmt::Display for ReferenceCommand {
             Self::Condition(condition) => write!(f, "srd condition {}", condition.get_name()),
             Self::Item(item) => write!(f, "srd item {}", item.get_name()),
             Self::ItemCategory(category) => write!(f, "srd item category {}", category.get_name()),
+            Self::Language(language) => write!(f, "srd language {}", language.get_name()),
             Self::MagicItem(item) => write!(f, "srd magic item {}", item.get_name()),
             Self::OpenGameLicense => write!(f, "Open Game License"),
             Self::Spell(spell) => write!(f, "srd spell {}", spell.get_name()),
@@ -149,7 +164,8 @@ impl fmt::Display for ReferenceCommand {
     }
 }
 
-fn linkify_dice(input: &str) -> String {
+/// Wrap any dice formulas in the input in backticks so they become clickable rolls.
+pub fn linkify_dice(input: &str) -> String {
     let mut result = String::with_capacity(input.len());
     let mut input_offset = 0;
 
@@ -224,6 +240,7 @@ mod test {
             ReferenceCommand::Spells,
             ReferenceCommand::Item(Item::Shield),
             ReferenceCommand::ItemCategory(ItemCategory::Shields),
+            ReferenceCommand::Language(Language::Elvish),
             ReferenceCommand::MagicItem(MagicItem::DeckOfManyThings),
             ReferenceCommand::OpenGameLicense,
         ]
@@ -251,6 +268,29 @@ mod test {
         });
     }
 
+    #[test]
+    fn parse_input_test_language() {
+        let app_meta = app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(ReferenceCommand::Language(Language::DeepSpeech)),
+            block_on(ReferenceCommand::parse_input(
+                "srd language deep speech",
+                &app_meta
+            )),
+        );
+
+        // Bare names are left to the ethnicities that share them, however they're capitalized.
+        for input in ["elvish", "Elvish", "Halfling", "Deep Speech"] {
+            assert_eq!(
+                CommandMatches::default(),
+                block_on(ReferenceCommand::parse_input(input, &app_meta)),
+                "{:?}",
+                input,
+            );
+        }
+    }
+
     fn event_dispatcher(_event: Event) {}
 
     fn app_meta() -> AppMeta {
@@ -1,4 +1,4 @@
-pub use command::ReferenceCommand;
+pub use command::{linkify_dice, ReferenceCommand};
 
 mod command;
 
@@ -10,6 +10,8 @@ reference_enum!(Item);
 
 reference_enum!(ItemCategory);
 
+reference_enum!(Language);
+
 reference_enum!(MagicItem);
 
 reference_enum!(Spell);
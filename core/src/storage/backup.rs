@@ -1,23 +1,89 @@
+use super::filter::{by_uuid, locations, ThingFilter, ThingKind};
 use super::repository::{Change, Error as RepositoryError, KeyValue, Repository};
+use crate::utils::CaseInsensitiveStr;
+use crate::world::npc::Culture;
 use crate::world::Thing;
 use futures::join;
+use schemars::schema::RootSchema;
+use schemars::{schema_for, JsonSchema};
+use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
 
-#[derive(Debug, Deserialize, Serialize)]
+/// The current version of the backup format. Version 1 (places stored their location as
+/// `parent_uuid`) and version 2 predate the `version` field, which is inferred on import.
+pub const BACKUP_VERSION: u32 = 3;
+
+const BACKUP_COMMENT: &str = "This document is exported from initiative.sh. Its format is described by the JSON Schema published at https://github.com/initiative-sh/initiative.sh/blob/main/data/backup-schema.json. Backups produced by older versions of initiative.sh are upgraded automatically when imported.";
+
+/// A backup of the active campaign's journal and key/value data. Older backup formats are
+/// upgraded to the current version when deserialized.
+#[derive(Debug, JsonSchema, Serialize)]
+#[schemars(title = "initiative.sh backup")]
 pub struct BackupData {
-    #[serde(rename(serialize = "_"), skip_deserializing)]
+    /// A human-readable note identifying the document. Ignored on import.
+    #[serde(rename = "_")]
     pub comment: &'static str,
 
+    /// The version of the backup format.
+    pub version: u32,
+
+    /// The things saved to the journal.
     pub things: Vec<Thing>,
 
     #[serde(rename = "keyValue")]
     pub key_value: KeyValueBackup,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+/// Miscellaneous values associated with the campaign.
+#[derive(Debug, Deserialize, JsonSchema, Serialize)]
 pub struct KeyValueBackup {
+    /// The current time, formatted as `days:hours:minutes:seconds`.
     pub time: Option<String>,
+
+    /// Homebrew cultures, in the same format as a `cultures.json` file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cultures: Option<Vec<Culture>>,
+}
+
+/// The current backup format as it appears on disk, once any migrations have been applied.
+#[derive(Deserialize)]
+struct CurrentBackupData {
+    things: Vec<Thing>,
+
+    #[serde(rename = "keyValue")]
+    key_value: KeyValueBackup,
+}
+
+/// Determines what happens when an imported thing shares a UUID or name with a thing that is
+/// already in the journal.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ImportStrategy {
+    /// Update the thing with the same UUID, keeping any fields that are empty in the backup.
+    /// Things whose name belongs to a different thing are skipped.
+    #[default]
+    MergeByUuid,
+
+    /// Replace the thing with the same UUID or name with the imported version.
+    Overwrite,
+
+    /// Import conflicting things alongside the existing ones, under a new UUID and name.
+    Rename,
+
+    /// Leave the existing thing alone.
+    Skip,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ImportOptions {
+    pub strategy: ImportStrategy,
+
+    /// Report what would be imported without changing anything.
+    pub dry_run: bool,
 }
 
 #[derive(Default)]
@@ -25,82 +91,509 @@ pub struct ImportStats {
     npc_stats: ImportStat,
     place_stats: ImportStat,
     key_value_stats: ImportStat,
+    errors: Vec<String>,
 }
 
 #[derive(Default)]
 struct ImportStat {
     created: usize,
     updated: usize,
+    skipped: usize,
     failed: usize,
 }
 
-pub async fn export(repo: &Repository) -> BackupData {
-    let (things, time) = join!(repo.journal(), repo.get_key_value(&KeyValue::Time(None)));
+enum ImportAction {
+    Create(Thing),
+    Update {
+        name: String,
+        uuid: Option<Uuid>,
+        diff: Thing,
+    },
+    Skip(Thing),
+    Fail(Thing),
+}
 
-    BackupData {
-        comment: "This document is exported from initiative.sh. Please note that this format is currently undocumented and no guarantees of forward compatibility are provided, although a reasonable effort will be made to ensure that older backups can be safely imported.",
-        things: things.unwrap_or_default(),
-        key_value: KeyValueBackup {
-            time: time.ok().and_then(|t| t.time()).map(|t| t.display_short().to_string()),
-        },
+impl BackupData {
+    pub fn new(things: Vec<Thing>, key_value: KeyValueBackup) -> Self {
+        Self {
+            comment: BACKUP_COMMENT,
+            version: BACKUP_VERSION,
+            things,
+            key_value,
+        }
+    }
+
+    /// The JSON Schema describing the current backup format, published as
+    /// `data/backup-schema.json`.
+    pub fn json_schema() -> RootSchema {
+        schema_for!(BackupData)
+    }
+}
+
+impl<'de> Deserialize<'de> for BackupData {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = migrate(Value::deserialize(deserializer)?).map_err(de::Error::custom)?;
+        let data = CurrentBackupData::deserialize(&value).map_err(de::Error::custom)?;
+        Ok(Self::new(data.things, data.key_value))
+    }
+}
+
+/// Upgrades a backup of any known version to the current format, one version at a time.
+fn migrate(mut value: Value) -> Result<Value, String> {
+    let mut version = backup_version(&value)?;
+
+    if version > BACKUP_VERSION {
+        return Err(format!(
+            "Backup version {} is newer than the latest supported version ({}).",
+            version, BACKUP_VERSION,
+        ));
+    }
+
+    while version < BACKUP_VERSION {
+        match version {
+            1 => migrate_v1(&mut value),
+            2 => migrate_v2(&mut value),
+            _ => return Err(format!("Unrecognized backup version {}.", version)),
+        }
+        version += 1;
+    }
+
+    Ok(value)
+}
+
+/// Reads the version of a backup. Versions 1 and 2 had no `version` field, but can be told apart
+/// by the name of the place location field.
+fn backup_version(value: &Value) -> Result<u32, String> {
+    match value.get("version") {
+        Some(version) => version
+            .as_u64()
+            .and_then(|v| v.try_into().ok())
+            .ok_or_else(|| format!("Invalid backup version: {}", version)),
+        None => {
+            let is_v1 = value
+                .get("things")
+                .and_then(Value::as_array)
+                .is_some_and(|things| {
+                    things
+                        .iter()
+                        .any(|thing| thing.get("parent_uuid").is_some())
+                });
+
+            Ok(if is_v1 { 1 } else { 2 })
+        }
+    }
+}
+
+/// Version 1 to 2: places' `parent_uuid` was renamed to `location_uuid`.
+fn migrate_v1(value: &mut Value) {
+    if let Some(things) = value.get_mut("things").and_then(Value::as_array_mut) {
+        for thing in things.iter_mut().filter_map(Value::as_object_mut) {
+            if let Some(parent_uuid) = thing.remove("parent_uuid") {
+                thing.insert("location_uuid".to_string(), parent_uuid);
+            }
+        }
+    }
+}
+
+/// Version 2 to 3: the `version` field was added.
+fn migrate_v2(value: &mut Value) {
+    if let Some(object) = value.as_object_mut() {
+        object.insert("version".to_string(), 3.into());
     }
 }
 
+/// Export the journal, or the part of it matching a filter. A partial export also includes the
+/// places containing the matching things so that it is self-contained, but leaves out the time
+/// and cultures.
+pub async fn export(repo: &Repository, filter: &ThingFilter) -> BackupData {
+    let (things, time, cultures) = join!(
+        repo.journal(),
+        repo.get_key_value(&KeyValue::Time(None)),
+        repo.get_key_value(&KeyValue::Cultures(None)),
+    );
+    let things = things.unwrap_or_default();
+
+    if filter.is_empty() {
+        return BackupData::new(
+            things,
+            KeyValueBackup {
+                time: time
+                    .ok()
+                    .and_then(|t| t.time())
+                    .map(|t| t.display_short().to_string()),
+                cultures: cultures.ok().and_then(KeyValue::cultures),
+            },
+        );
+    }
+
+    let included: HashSet<Uuid> = {
+        let things_by_uuid = by_uuid(&things);
+
+        filter
+            .apply(&things)
+            .into_iter()
+            .flat_map(|thing| {
+                let mut related = locations(thing, &things_by_uuid);
+                related.push(thing);
+                related
+            })
+            .filter_map(|thing| thing.uuid().copied())
+            .collect()
+    };
+
+    BackupData::new(
+        things
+            .into_iter()
+            .filter(|thing| thing.uuid().is_some_and(|uuid| included.contains(uuid)))
+            .collect(),
+        KeyValueBackup {
+            time: None,
+            cultures: None,
+        },
+    )
+}
+
 pub async fn import(
     repo: &mut Repository,
-    mut data: BackupData,
+    data: BackupData,
+    options: ImportOptions,
 ) -> Result<ImportStats, RepositoryError> {
+    let BackupData {
+        things,
+        mut key_value,
+        ..
+    } = data;
     let mut stats = ImportStats::default();
+    let mut uuid_map = HashMap::new();
+    let mut claimed_names = HashSet::new();
+    let mut actions = Vec::with_capacity(things.len());
 
-    for thing in data.things.into_iter() {
-        match (
-            match thing {
-                Thing::Npc(_) => &mut stats.npc_stats,
-                Thing::Place(_) => &mut stats.place_stats,
-            },
-            repo.modify_without_undo(Change::CreateAndSave { thing })
-                .await,
-        ) {
-            (stat, Ok(_)) => stat.created += 1,
-            (stat, Err((Change::CreateAndSave { thing }, RepositoryError::NameAlreadyExists))) => {
-                let name = thing.name().to_string();
-                match repo
-                    .modify_without_undo(Change::Edit {
-                        name,
-                        uuid: None,
-                        diff: thing,
-                    })
-                    .await
+    // Every action is planned before any are carried out, since things may refer to the UUIDs of
+    // things that appear later in the backup and are reassigned in the process.
+    for thing in things {
+        actions.push(
+            plan_import(
+                repo,
+                thing,
+                options.strategy,
+                &mut uuid_map,
+                &mut claimed_names,
+            )
+            .await,
+        );
+    }
+
+    for action in actions {
+        let stat = match action.thing() {
+            Thing::Npc(_) => &mut stats.npc_stats,
+            Thing::Place(_) => &mut stats.place_stats,
+        };
+
+        match action {
+            ImportAction::Create(mut thing) => {
+                remap_uuids(&mut thing, &uuid_map);
+
+                if options.dry_run
+                    || repo
+                        .modify_without_undo(Change::CreateAndSave { thing })
+                        .await
+                        .is_ok()
                 {
-                    Ok(_) => stat.updated += 1,
-                    Err(_) => stat.failed += 1,
+                    stat.created += 1;
+                } else {
+                    stat.failed += 1;
                 }
             }
-            (stat, Err(_)) => stat.failed += 1,
+            ImportAction::Update {
+                name,
+                uuid,
+                mut diff,
+            } => {
+                remap_uuids(&mut diff, &uuid_map);
+
+                if options.dry_run
+                    || repo
+                        .modify_without_undo(Change::Edit { name, uuid, diff })
+                        .await
+                        .is_ok()
+                {
+                    stat.updated += 1;
+                } else {
+                    stat.failed += 1;
+                }
+            }
+            ImportAction::Skip(_) => stat.skipped += 1,
+            ImportAction::Fail(_) => stat.failed += 1,
         }
     }
 
-    if let Some(time) = data.key_value.time.take().and_then(|s| s.parse().ok()) {
-        match repo
-            .modify_without_undo(Change::SetKeyValue {
-                key_value: KeyValue::Time(Some(time)),
-            })
+    if let Some(time) = key_value.time.take().and_then(|s| s.parse().ok()) {
+        let current_time = repo
+            .get_key_value(&KeyValue::Time(None))
             .await
-        {
-            Ok(Change::SetKeyValue {
-                key_value: KeyValue::Time(None),
-            }) => stats.key_value_stats.created += 1,
-            Ok(Change::SetKeyValue {
-                key_value: KeyValue::Time(Some(_)),
-            }) => stats.key_value_stats.updated += 1,
-            Ok(_) => unreachable!(),
-            Err(_) => stats.key_value_stats.failed += 1,
+            .ok()
+            .and_then(|key_value| key_value.time());
+
+        match (options.strategy, current_time, options.dry_run) {
+            (ImportStrategy::Skip, Some(_), _) => stats.key_value_stats.skipped += 1,
+            (_, None, true) => stats.key_value_stats.created += 1,
+            (_, Some(_), true) => stats.key_value_stats.updated += 1,
+            (_, _, false) => match repo
+                .modify_without_undo(Change::SetKeyValue {
+                    key_value: KeyValue::Time(Some(time)),
+                })
+                .await
+            {
+                Ok(Change::SetKeyValue {
+                    key_value: KeyValue::Time(None),
+                }) => stats.key_value_stats.created += 1,
+                Ok(Change::SetKeyValue {
+                    key_value: KeyValue::Time(Some(_)),
+                }) => stats.key_value_stats.updated += 1,
+                Ok(_) => unreachable!(),
+                Err(_) => stats.key_value_stats.failed += 1,
+            },
         }
     }
 
+    if let Some(cultures) = key_value.cultures.take() {
+        import_cultures(repo, cultures, options, &mut stats).await;
+    }
+
     Ok(stats)
 }
 
+/// Imported cultures are added to those already stored for the campaign, replacing any with the
+/// same name.
+async fn import_cultures(
+    repo: &mut Repository,
+    cultures: Vec<Culture>,
+    options: ImportOptions,
+    stats: &mut ImportStats,
+) {
+    if let Err(e) = Culture::validate_list(&cultures) {
+        stats.key_value_stats.failed += 1;
+        stats.errors.push(e);
+        return;
+    }
+
+    let current_cultures = repo
+        .get_key_value(&KeyValue::Cultures(None))
+        .await
+        .ok()
+        .and_then(KeyValue::cultures);
+
+    match (options.strategy, current_cultures, options.dry_run) {
+        (ImportStrategy::Skip, Some(_), _) => stats.key_value_stats.skipped += 1,
+        (_, None, true) => stats.key_value_stats.created += 1,
+        (_, Some(_), true) => stats.key_value_stats.updated += 1,
+        (_, current_cultures, false) => {
+            let is_update = current_cultures.is_some();
+            let mut merged = current_cultures.unwrap_or_default();
+
+            for culture in cultures {
+                merged.retain(|c| !c.name.eq_ci(&culture.name));
+                merged.push(culture);
+            }
+
+            match repo
+                .modify_without_undo(Change::SetKeyValue {
+                    key_value: KeyValue::Cultures(Some(merged)),
+                })
+                .await
+            {
+                Ok(_) if is_update => stats.key_value_stats.updated += 1,
+                Ok(_) => stats.key_value_stats.created += 1,
+                Err(_) => stats.key_value_stats.failed += 1,
+            }
+        }
+    }
+}
+
+/// Decide what to do with a single imported thing, based on the things already in the journal
+/// and those claimed earlier in the same import.
+async fn plan_import(
+    repo: &Repository,
+    mut thing: Thing,
+    strategy: ImportStrategy,
+    uuid_map: &mut HashMap<Uuid, Uuid>,
+    claimed_names: &mut HashSet<String>,
+) -> ImportAction {
+    let name = if let Some(name) = thing.name().value() {
+        name.to_string()
+    } else {
+        return ImportAction::Fail(thing);
+    };
+
+    let same_uuid = if let Some(uuid) = thing.uuid() {
+        repo.get_by_uuid(uuid).await.ok()
+    } else {
+        None
+    };
+
+    let same_name = repo.get_by_name(&name).await.ok();
+    let other_name = same_name
+        .as_ref()
+        .filter(|other| same_uuid.is_none() || other.uuid() != thing.uuid());
+
+    if matches!(&same_uuid, Some(existing) if existing.as_str() != thing.as_str()) {
+        return ImportAction::Fail(thing);
+    }
+
+    let action = match strategy {
+        ImportStrategy::MergeByUuid => match (&same_uuid, other_name) {
+            (_, Some(_)) => ImportAction::Skip(thing),
+            (Some(existing), None) => {
+                thing.unlock_empty();
+                ImportAction::Update {
+                    name: existing.name().to_string(),
+                    uuid: existing.uuid().copied(),
+                    diff: thing,
+                }
+            }
+            (None, None) => ImportAction::Create(thing),
+        },
+        ImportStrategy::Overwrite => match (&same_uuid, other_name) {
+            (Some(_), Some(_)) => ImportAction::Fail(thing),
+            (Some(existing), None) | (None, Some(existing)) => {
+                if let (Some(&from), Some(&to)) = (thing.uuid(), existing.uuid()) {
+                    uuid_map.insert(from, to);
+                }
+
+                ImportAction::Update {
+                    name: existing.name().to_string(),
+                    uuid: existing.uuid().copied(),
+                    diff: thing,
+                }
+            }
+            (None, None) => ImportAction::Create(thing),
+        },
+        ImportStrategy::Rename => {
+            if let Some(&from) = thing.uuid().filter(|_| same_uuid.is_some()) {
+                let to = Uuid::new_v4();
+                thing.clear_uuid();
+                thing.set_uuid(to);
+                uuid_map.insert(from, to);
+            }
+
+            if same_name.is_some() || claimed_names.contains(&name.to_lowercase()) {
+                let mut i = 2;
+                let new_name = loop {
+                    let new_name = format!("{} ({})", name, i);
+                    if !claimed_names.contains(&new_name.to_lowercase())
+                        && repo.get_by_name(&new_name).await.is_err()
+                    {
+                        break new_name;
+                    }
+                    i += 1;
+                };
+
+                match &mut thing {
+                    Thing::Npc(npc) => npc.name = new_name.into(),
+                    Thing::Place(place) => place.name = new_name.into(),
+                }
+            }
+
+            ImportAction::Create(thing)
+        }
+        ImportStrategy::Skip => {
+            if same_uuid.is_some() || same_name.is_some() {
+                ImportAction::Skip(thing)
+            } else {
+                ImportAction::Create(thing)
+            }
+        }
+    };
+
+    match action {
+        ImportAction::Create(thing) | ImportAction::Update { diff: thing, .. }
+            if !claimed_names.insert(thing.name().to_string().to_lowercase()) =>
+        {
+            // Another thing in the backup has already claimed the name.
+            ImportAction::Fail(thing)
+        }
+        action => action,
+    }
+}
+
+/// Point any references to things that were reassigned a UUID during the import at their new
+/// UUIDs.
+fn remap_uuids(thing: &mut Thing, uuid_map: &HashMap<Uuid, Uuid>) {
+    let location_uuid = match thing {
+        Thing::Npc(npc) => &mut npc.location_uuid,
+        Thing::Place(place) => &mut place.location_uuid,
+    };
+
+    if let Some(location_uuid) = location_uuid.value_mut() {
+        if let Some(&uuid) = uuid_map.get(location_uuid.as_ref()) {
+            *location_uuid = uuid.into();
+        }
+    }
+
+    if let Thing::Place(place) = thing {
+        if let Some(owner) = place.owner.value_mut() {
+            if let Some(&uuid) = uuid_map.get(owner.as_ref()) {
+                *owner = uuid.into();
+            }
+        }
+
+        for employee in place.staff.value_mut().into_iter().flatten() {
+            if let Some(&uuid) = uuid_map.get(employee.as_ref()) {
+                *employee = uuid.into();
+            }
+        }
+    }
+
+    if let Thing::Npc(npc) = thing {
+        if let Some(home_uuid) = npc.home_uuid.value_mut() {
+            if let Some(&uuid) = uuid_map.get(home_uuid.as_ref()) {
+                *home_uuid = uuid.into();
+            }
+        }
+
+        for relatives in [
+            &mut npc.parents,
+            &mut npc.spouses,
+            &mut npc.siblings,
+            &mut npc.children,
+        ] {
+            for relative_uuid in relatives.value_mut().into_iter().flatten() {
+                if let Some(&uuid) = uuid_map.get(relative_uuid.as_ref()) {
+                    *relative_uuid = uuid.into();
+                }
+            }
+        }
+    }
+}
+
+impl ImportAction {
+    fn thing(&self) -> &Thing {
+        match self {
+            Self::Create(thing)
+            | Self::Update { diff: thing, .. }
+            | Self::Skip(thing)
+            | Self::Fail(thing) => thing,
+        }
+    }
+}
+
+impl ImportStats {
+    /// Record an entry that couldn't be read from the imported file, and so never made it as far
+    /// as the journal.
+    pub fn add_error(&mut self, kind: ThingKind, error: String) {
+        match kind {
+            ThingKind::Npc => self.npc_stats.failed += 1,
+            ThingKind::Place => self.place_stats.failed += 1,
+            ThingKind::Any => {}
+        }
+
+        self.errors.push(error);
+    }
+}
+
 impl fmt::Display for ImportStats {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         let mut first = true;
@@ -126,17 +619,26 @@ impl fmt::Display for ImportStats {
             first = false;
         }
 
-        if first {
+        if first && self.errors.is_empty() {
             write!(f, "Nothing to import.")?;
         }
 
+        for (i, error) in self.errors.iter().enumerate() {
+            if i == 0 && !first {
+                write!(f, "\n\n")?;
+            } else if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "* {}", error)?;
+        }
+
         Ok(())
     }
 }
 
 impl ImportStat {
     fn is_empty(&self) -> bool {
-        self.created == 0 && self.updated == 0 && self.failed == 0
+        self.created == 0 && self.updated == 0 && self.skipped == 0 && self.failed == 0
     }
 }
 
@@ -157,6 +659,14 @@ impl fmt::Display for ImportStat {
             first = false;
         }
 
+        if self.skipped != 0 {
+            if !first {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} skipped", self.skipped)?;
+            first = false;
+        }
+
         if self.failed != 0 {
             if !first {
                 write!(f, ", ")?;
@@ -167,3 +677,78 @@ impl fmt::Display for ImportStat {
         Ok(())
     }
 }
+
+impl ImportStrategy {
+    const fn flag(&self) -> &'static str {
+        match self {
+            Self::MergeByUuid => "--merge-by-uuid",
+            Self::Overwrite => "--overwrite",
+            Self::Rename => "--rename",
+            Self::Skip => "--skip",
+        }
+    }
+}
+
+impl ImportOptions {
+    pub const FLAGS: [(&'static str, &'static str); 5] = [
+        ("--dry-run", "preview the import"),
+        ("--merge-by-uuid", "update entries with the same ID"),
+        ("--overwrite", "replace conflicting entries"),
+        ("--rename", "rename conflicting entries"),
+        ("--skip", "ignore conflicting entries"),
+    ];
+}
+
+impl FromStr for ImportOptions {
+    type Err = ();
+
+    /// Parse a whitespace-separated list of flags, eg. `--skip --dry-run`. At most one strategy
+    /// may be given.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut options = Self::default();
+        let mut has_strategy = false;
+
+        for flag in input.split_whitespace() {
+            let strategy = match flag.to_lowercase().as_str() {
+                "--dry-run" if !options.dry_run => {
+                    options.dry_run = true;
+                    continue;
+                }
+                "--merge-by-uuid" => ImportStrategy::MergeByUuid,
+                "--overwrite" => ImportStrategy::Overwrite,
+                "--rename" => ImportStrategy::Rename,
+                "--skip" => ImportStrategy::Skip,
+                _ => return Err(()),
+            };
+
+            if has_strategy {
+                return Err(());
+            }
+
+            options.strategy = strategy;
+            has_strategy = true;
+        }
+
+        Ok(options)
+    }
+}
+
+impl fmt::Display for ImportOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let mut first = true;
+
+        if self.strategy != ImportStrategy::default() {
+            write!(f, "{}", self.strategy.flag())?;
+            first = false;
+        }
+
+        if self.dry_run {
+            if !first {
+                write!(f, " ")?;
+            }
+            write!(f, "--dry-run")?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,12 @@
-use super::backup::export;
-use super::{Change, RepositoryError};
+use super::backup::{export, ImportOptions};
+use super::handbook::{self, HandbookFormat};
+use super::{Change, RepositoryError, ThingFilter};
 use crate::app::{
     AppMeta, Autocomplete, AutocompleteSuggestion, CommandAlias, CommandMatches, ContextAwareParse,
-    Event, Runnable,
+    Event, ExportFile, Runnable,
 };
 use crate::utils::CaseInsensitiveStr;
-use crate::world::Thing;
+use crate::world::{display_patrons, with_patrons, Thing};
 use async_trait::async_trait;
 use futures::join;
 use std::cmp::Ordering;
@@ -14,13 +15,21 @@ use std::iter::repeat;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum StorageCommand {
+    CampaignDelete { name: String },
+    CampaignList,
+    CampaignNew { name: String },
+    CampaignSwitch { name: String },
     Delete { name: String },
-    Export,
-    Import,
+    Export { filter: ThingFilter },
+    ExportHandbook { format: HandbookFormat },
+    History,
+    Import { options: ImportOptions },
     Journal,
+    List { filter: ThingFilter },
     Load { name: String },
     Redo,
     Save { name: String },
+    Search { query: String },
     Undo,
 }
 
@@ -28,57 +37,167 @@ pub enum StorageCommand {
 impl Runnable for StorageCommand {
     async fn run(self, _input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
         match self {
-            Self::Journal => {
-                let mut output = "# Journal".to_string();
-                let [mut npcs, mut places] = [Vec::new(), Vec::new()];
+            Self::CampaignDelete { name } => {
+                if matches!(
+                    app_meta.repository.get_campaign().await,
+                    Ok(campaign) if campaign.eq_ci(&name),
+                ) {
+                    return Err("You can't delete the campaign you are currently using. Switch to another campaign with `campaign switch [name]` first.".to_string());
+                }
 
-                let record_count = app_meta
+                app_meta
                     .repository
-                    .journal()
+                    .delete_campaign(&name)
                     .await
-                    .map_err(|_| "Couldn't access the journal.".to_string())?
-                    .into_iter()
-                    .map(|thing| match thing {
-                        Thing::Npc(_) => npcs.push(thing),
-                        Thing::Place(_) => places.push(thing),
+                    .map(|name| format!("The campaign \"{}\" was permanently deleted.", name))
+                    .map_err(|e| match e {
+                        RepositoryError::NotFound => {
+                            format!("There is no campaign named \"{}\".", name)
+                        }
+                        RepositoryError::DataStoreFailed
+                        | RepositoryError::MissingName
+                        | RepositoryError::NameAlreadyExists => {
+                            format!("Couldn't delete campaign `{}`.", name)
+                        }
                     })
-                    .count();
+            }
+            Self::CampaignList => {
+                let (campaign, campaigns) = join!(
+                    app_meta.repository.get_campaign(),
+                    app_meta.repository.get_campaigns(),
+                );
+                let campaigns =
+                    campaigns.map_err(|_| "Couldn't access the campaign list.".to_string())?;
+
+                let mut output = "# Campaigns\n".to_string();
+
+                for name in campaigns {
+                    if campaign.as_ref() == Ok(&name) {
+                        output.push_str(&format!("\n* **{}** (active)", name));
+                    } else {
+                        output.push_str(&format!("\n* {} (`campaign switch {}`)", name, name));
+                    }
+                }
 
-                let mut add_section = |title: &str, mut things: Vec<Thing>| {
-                    if !things.is_empty() {
-                        output.push_str("\n\n## ");
-                        output.push_str(title);
+                output.push_str("\n\n*Each campaign has its own journal, time, and undo history. To start another one, use `campaign new [name]`.*");
 
-                        things.sort_unstable_by(|a, b| {
-                            if let (Some(a), Some(b)) = (a.name().value(), b.name().value()) {
-                                a.cmp_ci(b)
-                            } else {
-                                // This shouldn't happen.
-                                Ordering::Equal
-                            }
-                        });
+                Ok(output)
+            }
+            Self::CampaignNew { name } => {
+                let result = app_meta.repository.create_campaign(&name).await;
 
-                        things.into_iter().enumerate().for_each(|(i, thing)| {
-                            if i > 0 {
-                                output.push('\\');
-                            }
+                if result.is_ok() {
+                    app_meta.load_cultures().await;
+                }
 
-                            output.push_str(&format!("\n{}", thing.display_summary()));
-                        });
-                    }
-                };
+                result
+                    .map(|name| {
+                        format!(
+                            "Switched to the new campaign \"{}\". Use `campaign list` to see all of your campaigns.",
+                            name,
+                        )
+                    })
+                    .map_err(|e| match e {
+                        RepositoryError::NameAlreadyExists => {
+                            format!("There is already a campaign named \"{}\".", name)
+                        }
+                        RepositoryError::MissingName => {
+                            "Please provide a name for the campaign.".to_string()
+                        }
+                        RepositoryError::DataStoreFailed | RepositoryError::NotFound => {
+                            format!("Couldn't create campaign `{}`.", name)
+                        }
+                    })
+            }
+            Self::CampaignSwitch { name } => {
+                let result = app_meta.repository.switch_campaign(&name).await;
+
+                if result.is_ok() {
+                    app_meta.load_cultures().await;
+                }
+
+                result
+                    .map(|name| format!("Switched to the campaign \"{}\".", name))
+                    .map_err(|e| match e {
+                        RepositoryError::NotFound => {
+                            format!("There is no campaign named \"{}\".", name)
+                        }
+                        RepositoryError::DataStoreFailed
+                        | RepositoryError::MissingName
+                        | RepositoryError::NameAlreadyExists => {
+                            format!("Couldn't switch to campaign `{}`.", name)
+                        }
+                    })
+            }
+            Self::History => {
+                let mut output = "# History".to_string();
+                let mut undo_history = app_meta.repository.undo_history().peekable();
+                let mut redo_history = app_meta.repository.redo_history().peekable();
+
+                if undo_history.peek().is_none() && redo_history.peek().is_none() {
+                    output.push_str("\n\n*Nothing to undo.*");
+                    return Ok(output);
+                }
+
+                if undo_history.peek().is_some() {
+                    output.push_str("\n\n## Changes\n");
+                    undo_history.for_each(|change| {
+                        output.push_str(&format!("\n* {}", change.display_undo()))
+                    });
+                }
+
+                if redo_history.peek().is_some() {
+                    output.push_str("\n\n## Undone changes\n");
+                    redo_history.for_each(|change| {
+                        output.push_str(&format!("\n* {}", change.display_redo()))
+                    });
+                }
+
+                output.push_str("\n\n*Most recent changes are listed first. Use `undo` and `redo` to step backward and forward through your history.*");
+
+                Ok(output)
+            }
+            Self::Journal => {
+                let mut output = "# Journal".to_string();
 
-                add_section("NPCs", npcs);
-                add_section("Places", places);
+                let things = app_meta
+                    .repository
+                    .journal()
+                    .await
+                    .map_err(|_| "Couldn't access the journal.".to_string())?;
 
-                if record_count == 0 {
+                if things.is_empty() {
                     output.push_str("\n\n*Your journal is currently empty.*");
                 } else {
+                    push_journal_sections(&mut output, things);
                     output.push_str("\n\n*To export the contents of your journal, use `export`.*");
                 }
 
                 Ok(output)
             }
+            Self::List { filter } => {
+                let journal = app_meta
+                    .repository
+                    .journal()
+                    .await
+                    .map_err(|_| "Couldn't access the journal.".to_string())?;
+
+                if let Some(location) = &filter.location {
+                    if !journal.iter().any(|thing| filter.is_location(thing)) {
+                        return Err(format!("There is no place named \"{}\" in your journal.", location));
+                    }
+                }
+
+                let things: Vec<Thing> = filter.apply(&journal).into_iter().cloned().collect();
+
+                if things.is_empty() {
+                    return Err(format!("Nothing in your journal matches `{}`.", filter));
+                }
+
+                let mut output = format!("# Journal: {}", filter);
+                push_journal_sections(&mut output, things);
+                Ok(output)
+            }
             Self::Delete { name } => {
                 let name = app_meta
                         .repository
@@ -113,11 +232,20 @@ impl Runnable for StorageCommand {
                     .unwrap_or(None)
                     .unwrap_or(name);
 
-                 app_meta
+                let (change, patrons) =
+                    with_patrons(app_meta, Change::Save { name: name.clone() }).await?;
+
+                app_meta
                     .repository
-                    .modify(Change::Save { name: name.clone() })
+                    .modify(change)
                     .await
-                    .map(|_| format!("{} was successfully saved. Use `undo` to reverse this.", name))
+                    .map(|_| {
+                        format!(
+                            "{} was successfully saved. Use `undo` to reverse this.{}",
+                            name,
+                            display_patrons(&patrons),
+                        )
+                    })
                     .map_err(|(_, e)| match e {
                         RepositoryError::NotFound => {
                             format!("There is no entity named \"{}\".", name)
@@ -129,13 +257,85 @@ impl Runnable for StorageCommand {
                         }
                     })
             }
-            Self::Export => {
-                (app_meta.event_dispatcher)(Event::Export(export(&app_meta.repository).await));
-                Ok("The journal is exporting. Your download should begin shortly.".to_string())
+            Self::Export { filter } => {
+                if filter.is_empty() {
+                    (app_meta.event_dispatcher)(Event::Export(ExportFile::Backup(
+                        export(&app_meta.repository, &filter).await,
+                    )));
+                    return Ok("The journal is exporting. Your download should begin shortly.".to_string());
+                }
+
+                if let Some(location) = &filter.location {
+                    if !matches!(
+                        app_meta.repository.get_by_name(location).await,
+                        Ok(Thing::Place(place)) if place.uuid.is_some(),
+                    ) {
+                        return Err(format!("There is no place named \"{}\" in your journal.", location));
+                    }
+                }
+
+                let data = export(&app_meta.repository, &filter).await;
+                let count = data.things.len();
+
+                if count == 0 {
+                    return Err(format!("Nothing in your journal matches `{}`.", filter));
+                }
+
+                (app_meta.event_dispatcher)(Event::Export(ExportFile::Backup(data)));
+                Ok(format!(
+                    "{} journal {} exporting, including the places that contain them. Your download should begin shortly.",
+                    count,
+                    if count == 1 { "entry is" } else { "entries are" },
+                ))
+            }
+            Self::ExportHandbook { format } => {
+                if app_meta
+                    .repository
+                    .journal()
+                    .await
+                    .unwrap_or_default()
+                    .is_empty()
+                {
+                    return Err("There is nothing in your journal to export. Save some characters or places first.".to_string());
+                }
+
+                let markdown = handbook::markdown(&app_meta.repository)
+                    .await
+                    .map_err(|_| "Couldn't export the handbook.".to_string())?;
+
+                (app_meta.event_dispatcher)(Event::Export(match format {
+                    HandbookFormat::Markdown => ExportFile::Markdown(markdown),
+                    HandbookFormat::Html => ExportFile::Html(handbook::html(&markdown)),
+                }));
+                Ok("The handbook is exporting. Your download should begin shortly.".to_string())
             }
-            Self::Import => {
+            Self::Import { options } => {
+                app_meta.import_options = options;
                 (app_meta.event_dispatcher)(Event::Import);
-                Ok("The file upload popup should appear momentarily. Please select a compatible JSON file, such as that produced by the `export` command.".to_string())
+                Ok("The file upload popup should appear momentarily. Please select a compatible JSON file, such as that produced by the `export` command, or a CSV spreadsheet of characters and places.".to_string())
+            }
+            Self::Search { query } => {
+                let things = app_meta
+                    .repository
+                    .search(&query, None)
+                    .await
+                    .map_err(|_| "Couldn't access the journal.".to_string())?;
+
+                if things.is_empty() {
+                    return Err(format!("Nothing in your journal matches \"{}\".", query));
+                }
+
+                let mut output = format!("# Search results for \"{}\"\n", query);
+
+                for (i, thing) in things.iter().enumerate() {
+                    if i > 0 {
+                        output.push('\\');
+                    }
+
+                    output.push_str(&format!("\n{}", thing.display_summary()));
+                }
+
+                Ok(output)
             }
             Self::Load { name } => {
                 let thing = app_meta.repository.get_by_name(&name).await;
@@ -150,12 +350,12 @@ impl Runnable for StorageCommand {
 
                         Ok(format!(
                             "{}\n\n_{} has not yet been saved. Use ~save~ to save {} to your `journal`._",
-                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
+                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default(), app_meta.repository.units().await),
                             thing.name(),
                             thing.gender().them(),
                         ))
                     } else {
-                        Ok(format!("{}", thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default())))
+                        Ok(format!("{}", thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default(), app_meta.repository.units().await)))
                     }
                 } else {
                     Err(format!("No matches for \"{}\"", name))
@@ -179,7 +379,7 @@ impl Runnable for StorageCommand {
                     if let Some(thing) = thing {
                         Ok(format!(
                             "{}\n\n_Successfully redid {}. Use `undo` to reverse this._",
-                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
+                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default(), app_meta.repository.units().await),
                             action,
                         ))
                     } else {
@@ -199,7 +399,7 @@ impl Runnable for StorageCommand {
                     if let Some(thing) = thing {
                         Ok(format!(
                             "{}\n\n_Successfully undid {}. Use `redo` to reverse this._",
-                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
+                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default(), app_meta.repository.units().await),
                             action,
                         ))
                     } else {
@@ -233,7 +433,23 @@ impl ContextAwareParse for StorageCommand {
             });
         }
 
-        if let Some(name) = input.strip_prefix_ci("delete ") {
+        if let Some(name) = input.strip_prefix_ci("campaign delete ") {
+            matches.push_canonical(Self::CampaignDelete {
+                name: name.to_string(),
+            });
+        } else if let Some(name) = input.strip_prefix_ci("campaign new ") {
+            matches.push_canonical(Self::CampaignNew {
+                name: name.to_string(),
+            });
+        } else if let Some(name) = input.strip_prefix_ci("campaign switch ") {
+            matches.push_canonical(Self::CampaignSwitch {
+                name: name.to_string(),
+            });
+        } else if input.eq_ci("campaign list") {
+            matches.push_canonical(Self::CampaignList);
+        } else if input.eq_ci("campaigns") {
+            matches.push_fuzzy(Self::CampaignList);
+        } else if let Some(name) = input.strip_prefix_ci("delete ") {
             matches.push_canonical(Self::Delete {
                 name: name.to_string(),
             });
@@ -245,16 +461,47 @@ impl ContextAwareParse for StorageCommand {
             matches.push_canonical(Self::Save {
                 name: name.to_string(),
             });
+        } else if let Some(query) = input
+            .strip_prefix_ci("search ")
+            .filter(|query| !query.trim().is_empty())
+        {
+            matches.push_canonical(Self::Search {
+                query: query.trim().to_string(),
+            });
         } else if input.eq_ci("journal") {
             matches.push_canonical(Self::Journal);
+        } else if let Some(Ok(filter)) = input
+            .strip_prefix_ci("list ")
+            .or_else(|| input.strip_prefix_ci("journal "))
+            .map(str::parse)
+        {
+            matches.push_canonical(Self::List { filter });
+        } else if input.eq_ci("history") {
+            matches.push_canonical(Self::History);
         } else if input.eq_ci("undo") {
             matches.push_canonical(Self::Undo);
         } else if input.eq_ci("redo") {
             matches.push_canonical(Self::Redo);
         } else if input.eq_ci("export") {
-            matches.push_canonical(Self::Export);
+            matches.push_canonical(Self::Export {
+                filter: ThingFilter::default(),
+            });
+        } else if input.eq_ci("export markdown") {
+            matches.push_canonical(Self::ExportHandbook {
+                format: HandbookFormat::Markdown,
+            });
+        } else if input.eq_ci("export html") {
+            matches.push_canonical(Self::ExportHandbook {
+                format: HandbookFormat::Html,
+            });
+        } else if let Some(Ok(filter)) = input.strip_prefix_ci("export ").map(str::parse) {
+            matches.push_canonical(Self::Export { filter });
         } else if input.eq_ci("import") {
-            matches.push_canonical(Self::Import);
+            matches.push_canonical(Self::Import {
+                options: ImportOptions::default(),
+            });
+        } else if let Some(Ok(options)) = input.strip_prefix_ci("import ").map(str::parse) {
+            matches.push_canonical(Self::Import { options });
         }
 
         matches
@@ -265,12 +512,35 @@ impl ContextAwareParse for StorageCommand {
 impl Autocomplete for StorageCommand {
     async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
         let mut suggestions: Vec<AutocompleteSuggestion> = [
+            (
+                "campaign delete",
+                "campaign delete [name]",
+                "delete a campaign",
+            ),
+            ("campaign list", "campaign list", "list your campaigns"),
+            (
+                "campaign new",
+                "campaign new [name]",
+                "start a new campaign",
+            ),
+            (
+                "campaign switch",
+                "campaign switch [name]",
+                "switch to another campaign",
+            ),
             ("delete", "delete [name]", "remove an entry from journal"),
             ("export", "export", "export the journal contents"),
+            ("history", "history", "list recent changes"),
             ("import", "import", "import a journal backup"),
             ("journal", "journal", "list journal contents"),
+            (
+                "list",
+                "list [description]",
+                "list matching journal entries",
+            ),
             ("load", "load [name]", "load an entry"),
             ("save", "save [name]", "save an entry to journal"),
+            ("search", "search [text]", "search the journal"),
         ]
         .into_iter()
         .filter(|(s, _, _)| s.starts_with_ci(input))
@@ -301,6 +571,99 @@ impl Autocomplete for StorageCommand {
         )
         .collect();
 
+        if let Some(query) = input.strip_prefix_ci("export ") {
+            if let Some(i) = query
+                .char_indices()
+                .filter(|&(i, c)| {
+                    let tail = &query[i..];
+                    c == ' ' && tail.starts_with_ci(" in ")
+                })
+                .map(|(i, _)| i)
+                .next_back()
+            {
+                let (head, location) = query.split_at(i + 4);
+
+                for thing in app_meta
+                    .repository
+                    .get_by_name_start(location, Some(10))
+                    .await
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|thing| matches!(thing, Thing::Place(place) if place.uuid.is_some()))
+                {
+                    suggestions.push(AutocompleteSuggestion::new(
+                        format!("export {}{}", head, thing.name()),
+                        format!("export {} contents", thing.display_description()),
+                    ));
+                }
+            } else {
+                for (term, summary) in [
+                    ("everything in [place]", "export a place and its contents"),
+                    ("html", "export the journal as a web page"),
+                    ("markdown", "export the journal as a Markdown document"),
+                    ("npcs named [name]", "export matching characters"),
+                    ("places named [name]", "export matching places"),
+                ] {
+                    if term.starts_with_ci(query) {
+                        suggestions.push(AutocompleteSuggestion::new(
+                            format!("export {}", term),
+                            summary,
+                        ));
+                    }
+                }
+            }
+
+            return suggestions;
+        }
+
+        if let Some(flags) = input.strip_prefix_ci("import ") {
+            let (given, partial) = match flags.rfind(' ') {
+                Some(i) => flags.split_at(i + 1),
+                None => ("", flags),
+            };
+
+            for (flag, summary) in ImportOptions::FLAGS {
+                let options = format!("{}{}", given, flag);
+
+                // Parsing rejects repeated flags and conflicting strategies.
+                if flag.starts_with_ci(partial) && options.parse::<ImportOptions>().is_ok() {
+                    suggestions.push(AutocompleteSuggestion::new(
+                        format!("import {}", options),
+                        summary,
+                    ));
+                }
+            }
+
+            return suggestions;
+        }
+
+        if let Some((prefix, name)) = ["campaign delete ", "campaign switch "]
+            .iter()
+            .find_map(|prefix| input.strip_prefix_ci(prefix).map(|name| (*prefix, name)))
+        {
+            let campaign = app_meta.repository.get_campaign().await.ok();
+
+            for campaign_name in app_meta
+                .repository
+                .get_campaigns()
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|s| s.starts_with_ci(name) && Some(s) != campaign.as_ref())
+            {
+                suggestions.push(AutocompleteSuggestion::new(
+                    format!("{}{}", prefix, campaign_name),
+                    if prefix == "campaign delete " {
+                        "delete campaign"
+                    } else {
+                        "switch to campaign"
+                    },
+                ));
+            }
+
+            return suggestions;
+        }
+
         let ((full_matches, partial_matches), prefix) = if let Some((prefix, name)) =
             ["delete ", "load ", "save "]
                 .iter()
@@ -367,16 +730,73 @@ impl Autocomplete for StorageCommand {
     }
 }
 
+/// Append the given things to the output as NPC and place sections, sorted by name.
+fn push_journal_sections(output: &mut String, things: Vec<Thing>) {
+    let [mut npcs, mut places] = [Vec::new(), Vec::new()];
+
+    things.into_iter().for_each(|thing| match thing {
+        Thing::Npc(_) => npcs.push(thing),
+        Thing::Place(_) => places.push(thing),
+    });
+
+    let mut add_section = |title: &str, mut things: Vec<Thing>| {
+        if !things.is_empty() {
+            output.push_str("\n\n## ");
+            output.push_str(title);
+
+            things.sort_unstable_by(|a, b| {
+                if let (Some(a), Some(b)) = (a.name().value(), b.name().value()) {
+                    a.cmp_ci(b)
+                } else {
+                    // This shouldn't happen.
+                    Ordering::Equal
+                }
+            });
+
+            things.into_iter().enumerate().for_each(|(i, thing)| {
+                if i > 0 {
+                    output.push('\\');
+                }
+
+                output.push_str(&format!("\n{}", thing.display_summary()));
+            });
+        }
+    };
+
+    add_section("NPCs", npcs);
+    add_section("Places", places);
+}
+
 impl fmt::Display for StorageCommand {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
+            Self::CampaignDelete { name } => write!(f, "campaign delete {}", name),
+            Self::CampaignList => write!(f, "campaign list"),
+            Self::CampaignNew { name } => write!(f, "campaign new {}", name),
+            Self::CampaignSwitch { name } => write!(f, "campaign switch {}", name),
             Self::Delete { name } => write!(f, "delete {}", name),
-            Self::Export => write!(f, "export"),
-            Self::Import => write!(f, "import"),
+            Self::Export { filter } => {
+                if filter.is_empty() {
+                    write!(f, "export")
+                } else {
+                    write!(f, "export {}", filter)
+                }
+            }
+            Self::ExportHandbook { format } => write!(f, "export {}", format),
+            Self::History => write!(f, "history"),
+            Self::Import { options } => {
+                if options == &ImportOptions::default() {
+                    write!(f, "import")
+                } else {
+                    write!(f, "import {}", options)
+                }
+            }
             Self::Journal => write!(f, "journal"),
+            Self::List { filter } => write!(f, "list {}", filter),
             Self::Load { name } => write!(f, "load {}", name),
             Self::Redo => write!(f, "redo"),
             Self::Save { name } => write!(f, "save {}", name),
+            Self::Search { query } => write!(f, "search {}", query),
             Self::Undo => write!(f, "undo"),
         }
     }
@@ -386,6 +806,7 @@ impl fmt::Display for StorageCommand {
 mod test {
     use super::*;
     use crate::app::assert_autocomplete;
+    use crate::storage::backup::ImportStrategy;
     use crate::storage::MemoryDataStore;
     use crate::world::npc::{Age, Gender, Npc, Species};
     use crate::world::place::{Place, PlaceType};
@@ -464,6 +885,21 @@ mod test {
             )),
         );
 
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Search {
+                query: "dwarf in Thornwick".to_string(),
+            }),
+            block_on(StorageCommand::parse_input(
+                "SEARCH  dwarf in Thornwick ",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(StorageCommand::parse_input("search ", &app_meta)),
+        );
+
         assert_eq!(
             CommandMatches::new_canonical(StorageCommand::Journal),
             block_on(StorageCommand::parse_input("journal", &app_meta)),
@@ -474,10 +910,47 @@ mod test {
             block_on(StorageCommand::parse_input("JOURNAL", &app_meta)),
         );
 
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::List {
+                filter: "elves in Thornwick".parse().unwrap(),
+            }),
+            block_on(StorageCommand::parse_input(
+                "list all elves in Thornwick",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::List {
+                filter: "inns".parse().unwrap(),
+            }),
+            block_on(StorageCommand::parse_input("Journal inns", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(StorageCommand::parse_input("list potatoes", &app_meta)),
+        );
+
         assert_eq!(
             CommandMatches::default(),
             block_on(StorageCommand::parse_input("potato", &app_meta)),
         );
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::CampaignNew {
+                name: "Curse of Strahd".to_string(),
+            }),
+            block_on(StorageCommand::parse_input(
+                "CAMPAIGN NEW Curse of Strahd",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(StorageCommand::CampaignList),
+            block_on(StorageCommand::parse_input("campaigns", &app_meta)),
+        );
     }
 
     #[test]
@@ -572,15 +1045,26 @@ mod test {
         );
 
         assert_autocomplete(
-            &[("save [name]", "save an entry to journal")][..],
+            &[
+                ("save [name]", "save an entry to journal"),
+                ("search [text]", "search the journal"),
+            ][..],
             block_on(StorageCommand::autocomplete("s", &app_meta)),
         );
 
         assert_autocomplete(
-            &[("save [name]", "save an entry to journal")][..],
+            &[
+                ("save [name]", "save an entry to journal"),
+                ("search [text]", "search the journal"),
+            ][..],
             block_on(StorageCommand::autocomplete("S", &app_meta)),
         );
 
+        assert_autocomplete(
+            &[("search [text]", "search the journal")][..],
+            block_on(StorageCommand::autocomplete("sea", &app_meta)),
+        );
+
         assert_autocomplete(
             &[("journal", "list journal contents")][..],
             block_on(StorageCommand::autocomplete("j", &app_meta)),
@@ -659,6 +1143,321 @@ mod test {
                 &AppMeta::new(MemoryDataStore::default(), &event_dispatcher),
             )),
         );
+
+        assert_autocomplete(
+            &[
+                ("campaign delete [name]", "delete a campaign"),
+                ("campaign list", "list your campaigns"),
+                ("campaign new [name]", "start a new campaign"),
+                ("campaign switch [name]", "switch to another campaign"),
+            ][..],
+            block_on(StorageCommand::autocomplete("camp", &app_meta)),
+        );
+
+        block_on(app_meta.repository.create_campaign("Curse of Strahd")).unwrap();
+        block_on(app_meta.repository.create_campaign("Candlekeep")).unwrap();
+
+        assert_autocomplete(
+            &[
+                ("campaign switch Curse of Strahd", "switch to campaign"),
+                ("campaign switch Default", "switch to campaign"),
+            ][..],
+            block_on(StorageCommand::autocomplete("campaign switch ", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("campaign delete Curse of Strahd", "delete campaign")][..],
+            block_on(StorageCommand::autocomplete("Campaign Delete c", &app_meta)),
+        );
+    }
+
+    #[test]
+    fn history_test() {
+        let mut app_meta = app_meta();
+        block_on(app_meta.repository.init());
+
+        assert_eq!(
+            Ok("# History\n\n*Nothing to undo.*".to_string()),
+            block_on(StorageCommand::History.run("", &mut app_meta)),
+        );
+
+        for name in ["Potato Johnson", "Potato & Meat", "Potato Salad"] {
+            block_on(
+                app_meta.repository.modify(Change::Create {
+                    thing: Npc {
+                        name: name.into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                }),
+            )
+            .unwrap();
+        }
+
+        block_on(app_meta.repository.undo()).unwrap().unwrap();
+
+        assert_eq!(
+            Ok("# History
+
+## Changes
+
+* creating Potato & Meat
+* creating Potato Johnson
+
+## Undone changes
+
+* creating Potato Salad
+
+*Most recent changes are listed first. Use `undo` and `redo` to step backward and forward through your history.*".to_string()),
+            block_on(StorageCommand::History.run("", &mut app_meta)),
+        );
+    }
+
+    #[test]
+    fn campaign_test() {
+        let mut app_meta = app_meta();
+        block_on(app_meta.repository.init());
+
+        block_on(
+            app_meta.repository.modify(Change::CreateAndSave {
+                thing: Npc {
+                    name: "Potato Johnson".into(),
+                    ..Default::default()
+                }
+                .into(),
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Ok("Switched to the new campaign \"Curse of Strahd\". Use `campaign list` to see all of your campaigns.".to_string()),
+            block_on(
+                StorageCommand::CampaignNew {
+                    name: "Curse of Strahd".to_string()
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+
+        assert!(block_on(app_meta.repository.get_by_name("Potato Johnson")).is_err());
+
+        assert_eq!(
+            Err("There is already a campaign named \"curse of strahd\".".to_string()),
+            block_on(
+                StorageCommand::CampaignNew {
+                    name: "curse of strahd".to_string()
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+
+        assert_eq!(
+            Ok("# Campaigns\n\n* **Curse of Strahd** (active)\n* Default (`campaign switch Default`)\n\n*Each campaign has its own journal, time, and undo history. To start another one, use `campaign new [name]`.*".to_string()),
+            block_on(StorageCommand::CampaignList.run("", &mut app_meta)),
+        );
+
+        assert!(block_on(
+            StorageCommand::CampaignDelete {
+                name: "CURSE OF STRAHD".to_string()
+            }
+            .run("", &mut app_meta)
+        )
+        .is_err());
+
+        assert_eq!(
+            Ok("Switched to the campaign \"Default\".".to_string()),
+            block_on(
+                StorageCommand::CampaignSwitch {
+                    name: "default".to_string()
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+
+        assert!(block_on(app_meta.repository.get_by_name("Potato Johnson")).is_ok());
+
+        assert_eq!(
+            Ok("The campaign \"Curse of Strahd\" was permanently deleted.".to_string()),
+            block_on(
+                StorageCommand::CampaignDelete {
+                    name: "curse of strahd".to_string()
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+
+        assert_eq!(
+            Err("There is no campaign named \"Curse of Strahd\".".to_string()),
+            block_on(
+                StorageCommand::CampaignSwitch {
+                    name: "Curse of Strahd".to_string()
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+    }
+
+    #[test]
+    fn export_test() {
+        let mut app_meta = app_meta();
+
+        block_on(
+            app_meta.repository.modify(Change::CreateAndSave {
+                thing: Place {
+                    name: "Thornwick".into(),
+                    subtype: "town".parse::<PlaceType>().ok().into(),
+                    ..Default::default()
+                }
+                .into(),
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Export {
+                filter: "npcs named *Vance".parse().unwrap(),
+            }),
+            block_on(StorageCommand::parse_input(
+                "export npcs named *Vance",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(StorageCommand::parse_input("export potatoes", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[
+                (
+                    "export everything in [place]",
+                    "export a place and its contents",
+                ),
+                ("export html", "export the journal as a web page"),
+                (
+                    "export markdown",
+                    "export the journal as a Markdown document",
+                ),
+                ("export npcs named [name]", "export matching characters"),
+                ("export places named [name]", "export matching places"),
+            ][..],
+            block_on(StorageCommand::autocomplete("export ", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("export npcs in Thornwick", "export town contents")][..],
+            block_on(StorageCommand::autocomplete("export npcs in th", &app_meta)),
+        );
+
+        assert_eq!(
+            Err("There is no place named \"Ironhold\" in your journal.".to_string()),
+            block_on(
+                StorageCommand::Export {
+                    filter: "everything in Ironhold".parse().unwrap(),
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+    }
+
+    #[test]
+    fn export_handbook_test() {
+        let mut app_meta = app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::ExportHandbook {
+                format: HandbookFormat::Markdown,
+            }),
+            block_on(StorageCommand::parse_input("export Markdown", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::ExportHandbook {
+                format: HandbookFormat::Html,
+            }),
+            block_on(StorageCommand::parse_input("EXPORT HTML", &app_meta)),
+        );
+
+        assert_eq!(
+            Err(
+                "There is nothing in your journal to export. Save some characters or places first."
+                    .to_string()
+            ),
+            block_on(
+                StorageCommand::ExportHandbook {
+                    format: HandbookFormat::Html,
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+    }
+
+    #[test]
+    fn import_test() {
+        let mut app_meta = app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Import {
+                options: ImportOptions {
+                    strategy: ImportStrategy::Skip,
+                    dry_run: true,
+                },
+            }),
+            block_on(StorageCommand::parse_input(
+                "IMPORT --skip --Dry-Run",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(StorageCommand::parse_input(
+                "import --skip --rename",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(StorageCommand::parse_input("import --potato", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[
+                ("import --dry-run", "preview the import"),
+                ("import --merge-by-uuid", "update entries with the same ID"),
+                ("import --overwrite", "replace conflicting entries"),
+                ("import --rename", "rename conflicting entries"),
+                ("import --skip", "ignore conflicting entries"),
+            ][..],
+            block_on(StorageCommand::autocomplete("import ", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("import --skip --dry-run", "preview the import")][..],
+            block_on(StorageCommand::autocomplete("import --skip --", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("import --dry-run --rename", "rename conflicting entries")][..],
+            block_on(StorageCommand::autocomplete(
+                "import --dry-run --r",
+                &app_meta,
+            )),
+        );
+
+        let options = ImportOptions {
+            strategy: ImportStrategy::Overwrite,
+            dry_run: false,
+        };
+        block_on(app_meta.repository.init());
+
+        assert_eq!(
+            Ok("The file upload popup should appear momentarily. Please select a compatible JSON file, such as that produced by the `export` command, or a CSV spreadsheet of characters and places.".to_string()),
+            block_on(StorageCommand::Import { options }.run("", &mut app_meta)),
+        );
+        assert_eq!(options, app_meta.import_options);
     }
 
     #[test]
@@ -666,18 +1465,54 @@ mod test {
         let app_meta = app_meta();
 
         [
+            StorageCommand::CampaignDelete {
+                name: "Curse of Strahd".to_string(),
+            },
+            StorageCommand::CampaignList,
+            StorageCommand::CampaignNew {
+                name: "Curse of Strahd".to_string(),
+            },
+            StorageCommand::CampaignSwitch {
+                name: "Curse of Strahd".to_string(),
+            },
             StorageCommand::Delete {
                 name: "Potato Johnson".to_string(),
             },
             StorageCommand::Save {
                 name: "Potato Johnson".to_string(),
             },
-            StorageCommand::Export,
-            StorageCommand::Import,
+            StorageCommand::Export {
+                filter: ThingFilter::default(),
+            },
+            StorageCommand::Export {
+                filter: "npcs named *Vance in Thornwick".parse().unwrap(),
+            },
+            StorageCommand::ExportHandbook {
+                format: HandbookFormat::Markdown,
+            },
+            StorageCommand::ExportHandbook {
+                format: HandbookFormat::Html,
+            },
+            StorageCommand::History,
+            StorageCommand::Import {
+                options: ImportOptions::default(),
+            },
+            StorageCommand::Import {
+                options: ImportOptions {
+                    strategy: ImportStrategy::Rename,
+                    dry_run: true,
+                },
+            },
             StorageCommand::Journal,
+            StorageCommand::List {
+                filter: "dwarvish npcs in the Duchy of Vell".parse().unwrap(),
+            },
             StorageCommand::Load {
                 name: "Potato Johnson".to_string(),
             },
+            StorageCommand::Search {
+                query: "Potato Johnson".to_string(),
+            },
         ]
         .into_iter()
         .for_each(|command| {
@@ -1,15 +1,41 @@
+use super::search;
 use crate::utils::CaseInsensitiveStr;
 use crate::{Thing, Uuid};
 use async_trait::async_trait;
 use std::collections::HashMap;
 
+/// The campaign that is active when none has been chosen, and to which any data created before
+/// the introduction of campaigns belongs.
+pub const DEFAULT_CAMPAIGN: &str = "Default";
+
 #[derive(Default)]
 pub struct NullDataStore;
 
-#[derive(Clone, Default)]
+/// The `things` and `key_values` fields contain the data of the active campaign; data belonging to
+/// inactive campaigns is moved into `campaigns` until the campaign is switched back.
+#[derive(Clone)]
 pub struct MemoryDataStore {
     pub things: std::rc::Rc<std::cell::RefCell<HashMap<Uuid, Thing>>>,
     pub key_values: std::rc::Rc<std::cell::RefCell<std::collections::HashMap<String, String>>>,
+    pub campaign: std::rc::Rc<std::cell::RefCell<String>>,
+    pub campaigns: std::rc::Rc<std::cell::RefCell<HashMap<String, MemoryCampaign>>>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MemoryCampaign {
+    pub things: HashMap<Uuid, Thing>,
+    pub key_values: HashMap<String, String>,
+}
+
+impl Default for MemoryDataStore {
+    fn default() -> Self {
+        Self {
+            things: Default::default(),
+            key_values: Default::default(),
+            campaign: std::rc::Rc::new(std::cell::RefCell::new(DEFAULT_CAMPAIGN.to_string())),
+            campaigns: Default::default(),
+        }
+    }
 }
 
 #[async_trait(?Send)]
@@ -61,6 +87,22 @@ impl DataStore for NullDataStore {
     async fn delete_value(&mut self, _key: &str) -> Result<(), ()> {
         Err(())
     }
+
+    async fn get_campaign(&self) -> Result<String, ()> {
+        Err(())
+    }
+
+    async fn get_campaigns(&self) -> Result<Vec<String>, ()> {
+        Err(())
+    }
+
+    async fn set_campaign(&mut self, _name: &str) -> Result<(), ()> {
+        Err(())
+    }
+
+    async fn delete_campaign(&mut self, _name: &str) -> Result<(), ()> {
+        Err(())
+    }
 }
 
 #[async_trait(?Send)]
@@ -123,6 +165,24 @@ impl DataStore for MemoryDataStore {
             .collect())
     }
 
+    async fn search_things(&self, query: &str, limit: Option<usize>) -> Result<Vec<Thing>, ()> {
+        Ok(search::rank(self.things.borrow().values(), query)
+            .into_iter()
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_npcs_by_place(&self, place_uuid: &Uuid) -> Result<Vec<Thing>, ()> {
+        Ok(self
+            .things
+            .borrow()
+            .values()
+            .filter(|thing| is_in_place(thing, place_uuid))
+            .cloned()
+            .collect())
+    }
+
     async fn save_thing(&mut self, thing: &Thing) -> Result<(), ()> {
         if let Some(uuid) = thing.uuid() {
             let mut things = self.things.borrow_mut();
@@ -154,6 +214,43 @@ impl DataStore for MemoryDataStore {
         key_values.remove(key);
         Ok(())
     }
+
+    async fn get_campaign(&self) -> Result<String, ()> {
+        Ok(self.campaign.borrow().clone())
+    }
+
+    async fn get_campaigns(&self) -> Result<Vec<String>, ()> {
+        Ok(std::iter::once(self.campaign.borrow().clone())
+            .chain(self.campaigns.borrow().keys().cloned())
+            .collect())
+    }
+
+    async fn set_campaign(&mut self, name: &str) -> Result<(), ()> {
+        let mut campaign = self.campaign.borrow_mut();
+
+        if *campaign != name {
+            let mut campaigns = self.campaigns.borrow_mut();
+            let next = campaigns.remove(name).unwrap_or_default();
+
+            campaigns.insert(
+                std::mem::replace(&mut *campaign, name.to_string()),
+                MemoryCampaign {
+                    things: self.things.replace(next.things),
+                    key_values: self.key_values.replace(next.key_values),
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn delete_campaign(&mut self, name: &str) -> Result<(), ()> {
+        self.campaigns
+            .borrow_mut()
+            .remove(name)
+            .map(|_| ())
+            .ok_or(())
+    }
 }
 
 #[async_trait(?Send)]
@@ -176,6 +273,31 @@ pub trait DataStore {
         limit: Option<usize>,
     ) -> Result<Vec<Thing>, ()>;
 
+    /// Get the things matching a search query, best matches first. See the `search` command for
+    /// the fields that are searched. The default implementation ranks the whole journal in
+    /// memory; backends that can answer the query more efficiently should override it.
+    async fn search_things(&self, query: &str, limit: Option<usize>) -> Result<Vec<Thing>, ()> {
+        let things = self.get_all_the_things().await?;
+
+        Ok(search::rank(&things, query)
+            .into_iter()
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+
+    /// Get the characters who live in or are currently at the place with the given UUID. The
+    /// default implementation filters the whole journal; backends that index characters by place
+    /// should override it.
+    async fn get_npcs_by_place(&self, place_uuid: &Uuid) -> Result<Vec<Thing>, ()> {
+        Ok(self
+            .get_all_the_things()
+            .await?
+            .into_iter()
+            .filter(|thing| is_in_place(thing, place_uuid))
+            .collect())
+    }
+
     async fn save_thing(&mut self, thing: &Thing) -> Result<(), ()>;
 
     async fn set_value(&mut self, key: &str, value: &str) -> Result<(), ()>;
@@ -183,12 +305,36 @@ pub trait DataStore {
     async fn get_value(&self, key: &str) -> Result<Option<String>, ()>;
 
     async fn delete_value(&mut self, key: &str) -> Result<(), ()>;
+
+    /// Get the name of the active campaign. All other methods operate only on the things and
+    /// values belonging to the active campaign.
+    async fn get_campaign(&self) -> Result<String, ()>;
+
+    /// Get the names of all campaigns, including the active one.
+    async fn get_campaigns(&self) -> Result<Vec<String>, ()>;
+
+    /// Make the named campaign active, creating it if it doesn't already exist. The choice should
+    /// persist between sessions where the data store supports it.
+    async fn set_campaign(&mut self, name: &str) -> Result<(), ()>;
+
+    /// Delete an inactive campaign and all of its contents. Fails if the campaign is active or
+    /// doesn't exist.
+    async fn delete_campaign(&mut self, name: &str) -> Result<(), ()>;
+}
+
+fn is_in_place(thing: &Thing, place_uuid: &Uuid) -> bool {
+    match thing {
+        Thing::Npc(npc) => [&npc.location_uuid, &npc.home_uuid]
+            .into_iter()
+            .any(|uuid| uuid.value().map(|uuid| uuid.as_ref()) == Some(place_uuid)),
+        Thing::Place(_) => false,
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::world::{Npc, Place};
+    use crate::world::{Npc, Place, PlaceUuid};
     use tokio_test::block_on;
 
     const TEST_UUID: Uuid = Uuid::from_u128(u128::MAX);
@@ -327,6 +473,47 @@ mod test {
             .for_each(|(t, i)| assert_eq!(Some(&Uuid::from_u128(i)), t.uuid()));
     }
 
+    #[test]
+    fn memory_get_npcs_by_place_test() {
+        let mut ds = MemoryDataStore::default();
+        let place_uuid = Uuid::from_u128(1);
+
+        for thing in [
+            Npc {
+                uuid: Some(Uuid::from_u128(2).into()),
+                location_uuid: PlaceUuid::from(place_uuid).into(),
+                ..Default::default()
+            }
+            .into(),
+            Npc {
+                uuid: Some(Uuid::from_u128(3).into()),
+                home_uuid: PlaceUuid::from(place_uuid).into(),
+                ..Default::default()
+            }
+            .into(),
+            Npc {
+                uuid: Some(Uuid::from_u128(4).into()),
+                ..Default::default()
+            }
+            .into(),
+            Place {
+                uuid: Some(Uuid::from_u128(5).into()),
+                location_uuid: PlaceUuid::from(place_uuid).into(),
+                ..Default::default()
+            }
+            .into(),
+        ] {
+            block_on(ds.save_thing(&thing)).unwrap();
+        }
+
+        let mut npcs = block_on(ds.get_npcs_by_place(&place_uuid)).unwrap();
+        npcs.sort_by(|a, b| a.uuid().cmp(&b.uuid()));
+        assert_eq!(
+            vec![Some(&Uuid::from_u128(2)), Some(&Uuid::from_u128(3))],
+            npcs.iter().map(|thing| thing.uuid()).collect::<Vec<_>>(),
+        );
+    }
+
     #[test]
     fn memory_save_thing_test() {
         let mut ds = MemoryDataStore::default();
@@ -353,6 +540,49 @@ mod test {
         assert_eq!(Ok(None), block_on(ds.get_value("somekey")));
     }
 
+    #[test]
+    fn memory_campaign_test() {
+        let mut ds = MemoryDataStore::default();
+
+        assert_eq!(
+            Ok(DEFAULT_CAMPAIGN.to_string()),
+            block_on(ds.get_campaign())
+        );
+        assert_eq!(Ok(()), block_on(ds.save_thing(&person(TEST_UUID))));
+        assert_eq!(Ok(()), block_on(ds.set_value("somekey", "abc")));
+
+        assert_eq!(Ok(()), block_on(ds.set_campaign("Curse of Strahd")));
+        assert_eq!(
+            Ok("Curse of Strahd".to_string()),
+            block_on(ds.get_campaign()),
+        );
+        assert_eq!(Ok(None), block_on(ds.get_thing_by_uuid(&TEST_UUID)));
+        assert_eq!(Ok(None), block_on(ds.get_value("somekey")));
+        assert_eq!(Ok(()), block_on(ds.save_thing(&place(TEST_UUID))));
+        assert_eq!(Err(()), block_on(ds.delete_campaign("Curse of Strahd")));
+
+        let mut campaigns = block_on(ds.get_campaigns()).unwrap();
+        campaigns.sort();
+        assert_eq!(vec!["Curse of Strahd", DEFAULT_CAMPAIGN], campaigns);
+
+        assert_eq!(Ok(()), block_on(ds.set_campaign(DEFAULT_CAMPAIGN)));
+        assert_eq!(
+            Ok(Some(person(TEST_UUID))),
+            block_on(ds.get_thing_by_uuid(&TEST_UUID)),
+        );
+        assert_eq!(
+            Ok(Some("abc".to_string())),
+            block_on(ds.get_value("somekey")),
+        );
+
+        assert_eq!(Ok(()), block_on(ds.delete_campaign("Curse of Strahd")));
+        assert_eq!(Err(()), block_on(ds.delete_campaign("Curse of Strahd")));
+        assert_eq!(
+            Ok(vec![DEFAULT_CAMPAIGN.to_string()]),
+            block_on(ds.get_campaigns()),
+        );
+    }
+
     fn person(uuid: Uuid) -> Thing {
         Npc {
             uuid: Some(uuid.into()),
@@ -0,0 +1,583 @@
+use crate::utils::CaseInsensitiveStr;
+use crate::world::npc::{Age, Ethnicity, Gender, Species};
+use crate::world::place::PlaceType;
+use crate::world::Thing;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// A query selecting part of the journal, eg. `npcs named *Vance in Thornwick` or
+/// `dwarvish characters in the Duchy of Vell`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ThingFilter {
+    pub kind: ThingKind,
+
+    /// Descriptive words that matching things must fit, eg. "elves" or "dwarvish", as entered.
+    pub traits: Vec<String>,
+
+    /// A name pattern, in which `*` matches any sequence of characters.
+    pub name: Option<String>,
+
+    /// The name of a place that matching things are located in, directly or indirectly.
+    pub location: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ThingKind {
+    #[default]
+    Any,
+    Npc,
+    Place,
+}
+
+impl ThingFilter {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Select the things matching the filter from a list, typically the journal. Locations are
+    /// resolved against the same list.
+    pub fn apply<'a>(&self, things: &'a [Thing]) -> Vec<&'a Thing> {
+        let things_by_uuid = by_uuid(things);
+
+        things
+            .iter()
+            .filter(|thing| self.matches(thing, &things_by_uuid))
+            .collect()
+    }
+
+    /// Whether a thing is the place named by the filter's location. "The Duchy of Vell" and
+    /// "Duchy of Vell" both refer to the same place.
+    pub fn is_location(&self, thing: &Thing) -> bool {
+        matches!(
+            (&self.location, thing),
+            (Some(location), Thing::Place(place)) if place.name.value().is_some_and(|name| {
+                name.eq_ci(location)
+                    || location.strip_prefix_ci("the ").is_some_and(|l| name.eq_ci(l))
+            }),
+        )
+    }
+
+    fn matches(&self, thing: &Thing, things_by_uuid: &HashMap<Uuid, &Thing>) -> bool {
+        match (self.kind, thing) {
+            (ThingKind::Any, _) | (ThingKind::Npc, Thing::Npc(_)) => {}
+            (ThingKind::Place, Thing::Place(_)) => {}
+            _ => return false,
+        }
+
+        if !self.traits.iter().all(|word| has_trait(thing, word)) {
+            return false;
+        }
+
+        if let Some(pattern) = &self.name {
+            if !thing
+                .name()
+                .value()
+                .is_some_and(|name| glob_match_ci(pattern, name))
+            {
+                return false;
+            }
+        }
+
+        if self.location.is_some()
+            && !locations(thing, things_by_uuid)
+                .into_iter()
+                .any(|place| self.is_location(place))
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// Whether a thing fits a descriptive word, eg. an elvish woman fits "elves", "elvish" and
+/// "women", and an inn fits "inns", "businesses" and "buildings".
+fn has_trait(thing: &Thing, word: &str) -> bool {
+    match thing {
+        Thing::Npc(npc) => {
+            let (gender, age) = (parse_plural::<Gender>(word), parse_plural::<Age>(word));
+
+            if gender.is_some() || age.is_some() {
+                // Words like "boys" imply both gender and age.
+                (gender.is_none() || npc.gender.value() == gender.as_ref())
+                    && (age.is_none() || npc.age.value() == age.as_ref())
+            } else {
+                parse_plural::<Species>(word)
+                    .is_some_and(|species| npc.species.value() == Some(&species))
+                    || parse_plural::<Ethnicity>(word)
+                        .is_some_and(|ethnicity| npc.ethnicity.value() == Some(&ethnicity))
+            }
+        }
+        Thing::Place(place) => parse_plural::<PlaceType>(word).is_some_and(|place_type| {
+            place
+                .subtype
+                .value()
+                .is_some_and(|subtype| place_type.includes(subtype))
+        }),
+    }
+}
+
+/// Which kind of thing a descriptive word applies to, if it's recognized at all.
+fn trait_kind(word: &str) -> Option<ThingKind> {
+    if parse_plural::<Gender>(word).is_some()
+        || parse_plural::<Age>(word).is_some()
+        || parse_plural::<Species>(word).is_some()
+        || parse_plural::<Ethnicity>(word).is_some()
+    {
+        Some(ThingKind::Npc)
+    } else if parse_plural::<PlaceType>(word).is_some() {
+        Some(ThingKind::Place)
+    } else {
+        None
+    }
+}
+
+/// Parse a word that may be plural, eg. "elves" or "duchies", by trying likely singular forms.
+fn parse_plural<T: FromStr>(word: &str) -> Option<T> {
+    let word = word.to_lowercase();
+
+    if let Ok(value) = word.parse() {
+        return Some(value);
+    }
+
+    if let Some((_, singular)) = [
+        ("people", "person"),
+        ("men", "man"),
+        ("women", "woman"),
+        ("children", "child"),
+    ]
+    .into_iter()
+    .find(|(plural, _)| plural == &word)
+    {
+        return singular.parse().ok();
+    }
+
+    [("ies", "y"), ("ves", "f"), ("s", ""), ("es", "")]
+        .into_iter()
+        .filter_map(|(suffix, replacement)| {
+            word.strip_suffix(suffix)
+                .map(|stem| format!("{}{}", stem, replacement))
+        })
+        .find_map(|singular| singular.parse().ok())
+}
+
+/// Index a list of things by UUID, skipping any that haven't been saved.
+pub fn by_uuid(things: &[Thing]) -> HashMap<Uuid, &Thing> {
+    things
+        .iter()
+        .filter_map(|thing| thing.uuid().map(|&uuid| (uuid, thing)))
+        .collect()
+}
+
+/// List the places containing a thing, from its immediate location outward.
+pub fn locations<'a>(thing: &Thing, things_by_uuid: &HashMap<Uuid, &'a Thing>) -> Vec<&'a Thing> {
+    let mut result = Vec::new();
+    let mut visited = HashSet::new();
+    let mut next = location_uuid(thing);
+
+    // The visited set guards against cycles, which the data model doesn't prevent.
+    while let Some(place) = next
+        .filter(|uuid| visited.insert(*uuid))
+        .and_then(|uuid| things_by_uuid.get(&uuid))
+    {
+        result.push(*place);
+        next = location_uuid(place);
+    }
+
+    result
+}
+
+pub fn location_uuid(thing: &Thing) -> Option<Uuid> {
+    match thing {
+        Thing::Npc(npc) => npc.location_uuid.value(),
+        Thing::Place(place) => place.location_uuid.value(),
+    }
+    .map(|uuid| *uuid.as_ref())
+}
+
+/// Match a string against a case-insensitive pattern, in which `*` matches any sequence of
+/// characters.
+fn glob_match_ci(pattern: &str, input: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let input: Vec<char> = input.to_lowercase().chars().collect();
+    let (mut p, mut i) = (0, 0);
+    let mut backtrack = None;
+
+    while i < input.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, i));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == input[i] {
+            p += 1;
+            i += 1;
+        } else if let Some((star_p, star_i)) = backtrack {
+            // Let the most recent `*` swallow one more character and try again.
+            backtrack = Some((star_p, star_i + 1));
+            p = star_p + 1;
+            i = star_i + 1;
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+impl FromStr for ThingFilter {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        let mut rest = if input.eq_ci("all") {
+            "everything"
+        } else {
+            input.strip_prefix_ci("all ").unwrap_or(input)
+        };
+
+        let mut filter = Self::default();
+
+        if let Some(i) = rest
+            .char_indices()
+            .filter(|&(i, c)| {
+                let tail = &rest[i..];
+                c == ' ' && tail.starts_with_ci(" in ")
+            })
+            .map(|(i, _)| i)
+            .next_back()
+        {
+            filter.location = Some(rest[i + 4..].trim().to_string());
+            rest = &rest[..i];
+        }
+
+        if let Some(i) = rest
+            .char_indices()
+            .find(|&(i, c)| {
+                let tail = &rest[i..];
+                c == ' ' && tail.starts_with_ci(" named ")
+            })
+            .map(|(i, _)| i)
+        {
+            filter.name = Some(rest[i + 7..].trim().to_string());
+            rest = &rest[..i];
+        }
+
+        let mut words: Vec<&str> = rest.split_whitespace().collect();
+
+        filter.kind = [
+            ("everything", ThingKind::Any),
+            ("npcs", ThingKind::Npc),
+            ("characters", ThingKind::Npc),
+            ("people", ThingKind::Npc),
+            ("places", ThingKind::Place),
+        ]
+        .into_iter()
+        .find(|(word, _)| words.last().is_some_and(|last| last.eq_ci(word)))
+        .map(|(_, kind)| {
+            words.pop();
+            kind
+        })
+        .or_else(|| {
+            // Plain descriptive words like "elves" or "inns" imply the kind of thing they describe.
+            words
+                .last()
+                .and_then(|word| trait_kind(word))
+                .map(|_| ThingKind::Any)
+        })
+        .ok_or(())?;
+
+        for word in words {
+            match (trait_kind(word), filter.kind) {
+                (Some(ThingKind::Npc), ThingKind::Place)
+                | (Some(ThingKind::Place), ThingKind::Npc)
+                | (None, _) => return Err(()),
+                _ => filter.traits.push(word.to_string()),
+            }
+        }
+
+        // A place can't be an elf, nor a character an inn.
+        if filter
+            .traits
+            .iter()
+            .any(|word| trait_kind(word) == Some(ThingKind::Npc))
+            && filter
+                .traits
+                .iter()
+                .any(|word| trait_kind(word) == Some(ThingKind::Place))
+        {
+            return Err(());
+        }
+
+        if matches!(&filter.name, Some(s) if s.is_empty())
+            || matches!(&filter.location, Some(s) if s.is_empty())
+        {
+            return Err(());
+        }
+
+        Ok(filter)
+    }
+}
+
+impl fmt::Display for ThingFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        for (i, word) in self.traits.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", word)?;
+        }
+
+        match (self.kind, self.traits.is_empty()) {
+            (ThingKind::Any, false) => {}
+            (ThingKind::Any, true) => write!(f, "everything")?,
+            (ThingKind::Npc, empty) => write!(f, "{}npcs", if empty { "" } else { " " })?,
+            (ThingKind::Place, empty) => write!(f, "{}places", if empty { "" } else { " " })?,
+        }
+
+        if let Some(name) = &self.name {
+            write!(f, " named {}", name)?;
+        }
+
+        if let Some(location) = &self.location {
+            write!(f, " in {}", location)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::world::npc::Npc;
+    use crate::world::place::Place;
+    use crate::world::PlaceUuid;
+
+    #[test]
+    fn apply_test() {
+        let things = things();
+        let names = |filter: &str| -> Vec<String> {
+            filter
+                .parse::<ThingFilter>()
+                .unwrap()
+                .apply(&things)
+                .into_iter()
+                .map(|thing| thing.name().to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            vec![
+                "Thornwick",
+                "Prancing Pony",
+                "Ellis Vance",
+                "Mira Vance",
+                "Drunk Vance",
+                "Duchy of Vell",
+            ],
+            names("everything"),
+        );
+        assert_eq!(
+            vec!["Ellis Vance", "Mira Vance"],
+            names("npcs in thornwick")
+        );
+        assert_eq!(vec!["Prancing Pony"], names("places in Thornwick"));
+        assert_eq!(
+            vec!["Ellis Vance", "Mira Vance", "Drunk Vance"],
+            names("npcs named *vance"),
+        );
+        assert_eq!(
+            vec!["Mira Vance"],
+            names("everything named M*a V* in Thornwick")
+        );
+        assert!(names("everything in Ellis Vance").is_empty());
+
+        assert_eq!(vec!["Ellis Vance"], names("dwarves"));
+        assert_eq!(
+            vec!["Ellis Vance"],
+            names("dwarvish npcs in the Duchy of Vell")
+        );
+        assert_eq!(vec!["Mira Vance"], names("all women"));
+        assert_eq!(vec!["Prancing Pony"], names("inns"));
+        assert_eq!(vec!["Prancing Pony"], names("businesses in Thornwick"));
+        assert_eq!(
+            vec!["Thornwick", "Prancing Pony"],
+            names("places in the duchy of vell")
+        );
+        assert!(names("children").is_empty());
+        assert_eq!(vec!["Duchy of Vell"], names("duchies"));
+    }
+
+    #[test]
+    fn parse_plural_test() {
+        assert_eq!(Some(Species::Elf), parse_plural("Elves"));
+        assert_eq!(Some(Species::Dwarf), parse_plural("dwarves"));
+        assert_eq!(Some(Species::Halfling), parse_plural("halflings"));
+        assert_eq!(Some(Age::Child), parse_plural("children"));
+        assert_eq!(Some(Gender::Feminine), parse_plural("women"));
+        assert_eq!(Some(Ethnicity::Dwarvish), parse_plural("dwarvish"));
+        assert_eq!("duchy".parse::<PlaceType>().ok(), parse_plural("duchies"));
+        assert_eq!(
+            "business".parse::<PlaceType>().ok(),
+            parse_plural("businesses")
+        );
+        assert_eq!(None, parse_plural::<Species>("potatoes"));
+    }
+
+    #[test]
+    fn locations_test() {
+        let things = things();
+        let things_by_uuid = by_uuid(&things);
+
+        assert_eq!(
+            vec!["Prancing Pony", "Thornwick", "Duchy of Vell"],
+            locations(&things[3], &things_by_uuid)
+                .into_iter()
+                .map(|thing| thing.name().to_string())
+                .collect::<Vec<_>>(),
+        );
+        assert!(locations(&things[5], &things_by_uuid).is_empty());
+    }
+
+    #[test]
+    fn glob_match_ci_test() {
+        assert!(glob_match_ci("*Vance", "Ellis Vance"));
+        assert!(glob_match_ci("*vance", "ELLIS VANCE"));
+        assert!(glob_match_ci("*", ""));
+        assert!(glob_match_ci("E*s*e", "Ellis Vance"));
+        assert!(glob_match_ci("Ellis Vance", "ellis vance"));
+        assert!(!glob_match_ci("*Vance", "Vance Ellis"));
+        assert!(!glob_match_ci("Ellis", "Ellis Vance"));
+        assert!(!glob_match_ci("", "Ellis"));
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(ThingFilter::default()), "everything".parse());
+        assert_eq!(
+            Ok(ThingFilter {
+                kind: ThingKind::Npc,
+                name: Some("*Vance".to_string()),
+                ..Default::default()
+            }),
+            "NPCs named *Vance".parse(),
+        );
+        assert_eq!(
+            Ok(ThingFilter {
+                location: Some("Thornwick".to_string()),
+                ..Default::default()
+            }),
+            "everything in Thornwick".parse(),
+        );
+        assert_eq!(
+            Ok(ThingFilter {
+                kind: ThingKind::Place,
+                name: Some("The Inn in the Hills".to_string()),
+                location: Some("Thornwick".to_string()),
+                ..Default::default()
+            }),
+            "places named The Inn in the Hills IN Thornwick".parse(),
+        );
+        assert_eq!(
+            Ok(ThingFilter {
+                traits: vec!["elves".to_string()],
+                location: Some("Thornwick".to_string()),
+                ..Default::default()
+            }),
+            "all elves in Thornwick".parse(),
+        );
+        assert_eq!(
+            Ok(ThingFilter {
+                kind: ThingKind::Npc,
+                traits: vec!["Dwarvish".to_string()],
+                ..Default::default()
+            }),
+            "Dwarvish characters".parse(),
+        );
+        assert_eq!(Ok(ThingFilter::default()), "all".parse());
+
+        assert_eq!(Err(()), "".parse::<ThingFilter>());
+        assert_eq!(Err(()), "potatoes".parse::<ThingFilter>());
+        assert_eq!(Err(()), "npcs potato".parse::<ThingFilter>());
+        assert_eq!(Err(()), "npcs named ".parse::<ThingFilter>());
+        assert_eq!(Err(()), "everything in".parse::<ThingFilter>());
+        assert_eq!(Err(()), "elvish places".parse::<ThingFilter>());
+        assert_eq!(Err(()), "elvish inns".parse::<ThingFilter>());
+        assert_eq!(Err(()), "potato elves".parse::<ThingFilter>());
+    }
+
+    #[test]
+    fn display_test() {
+        [
+            "everything",
+            "npcs named *Vance",
+            "places in Thornwick",
+            "npcs named A in B",
+            "elves in Thornwick",
+            "dwarvish npcs",
+            "old women named *Vance",
+        ]
+        .into_iter()
+        .for_each(|s| assert_eq!(s, s.parse::<ThingFilter>().unwrap().to_string()));
+    }
+
+    fn things() -> Vec<Thing> {
+        let vell = Uuid::new_v4();
+        let thornwick = Uuid::new_v4();
+        let pony = Uuid::new_v4();
+
+        vec![
+            Place {
+                uuid: Some(thornwick.into()),
+                location_uuid: PlaceUuid::from(vell).into(),
+                name: "Thornwick".into(),
+                subtype: "town".parse::<PlaceType>().ok().into(),
+                ..Default::default()
+            }
+            .into(),
+            Place {
+                uuid: Some(pony.into()),
+                location_uuid: PlaceUuid::from(thornwick).into(),
+                name: "Prancing Pony".into(),
+                subtype: "inn".parse::<PlaceType>().ok().into(),
+                ..Default::default()
+            }
+            .into(),
+            Npc {
+                uuid: Some(Uuid::new_v4().into()),
+                location_uuid: PlaceUuid::from(thornwick).into(),
+                name: "Ellis Vance".into(),
+                species: Species::Dwarf.into(),
+                ethnicity: Ethnicity::Dwarvish.into(),
+                gender: Gender::Masculine.into(),
+                age: Age::Adult.into(),
+                ..Default::default()
+            }
+            .into(),
+            Npc {
+                uuid: Some(Uuid::new_v4().into()),
+                location_uuid: PlaceUuid::from(pony).into(),
+                name: "Mira Vance".into(),
+                species: Species::Human.into(),
+                gender: Gender::Feminine.into(),
+                age: Age::Adult.into(),
+                ..Default::default()
+            }
+            .into(),
+            Npc {
+                uuid: Some(Uuid::new_v4().into()),
+                name: "Drunk Vance".into(),
+                ..Default::default()
+            }
+            .into(),
+            Place {
+                uuid: Some(vell.into()),
+                name: "Duchy of Vell".into(),
+                subtype: "duchy".parse::<PlaceType>().ok().into(),
+                ..Default::default()
+            }
+            .into(),
+        ]
+    }
+}
@@ -0,0 +1,309 @@
+use super::filter::{by_uuid, location_uuid};
+use super::{Repository, DEFAULT_CAMPAIGN};
+use crate::utils::CaseInsensitiveStr;
+use crate::world::Thing;
+use pulldown_cmark::{escape::escape_html, html, Options, Parser};
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HandbookFormat {
+    Markdown,
+    Html,
+}
+
+/// Render the journal as a Markdown document. Entries are grouped by location, each place being
+/// followed by the characters in it and then the places inside it, and names are linked to their
+/// entries.
+pub async fn markdown(repo: &Repository) -> Result<String, ()> {
+    let (campaign, journal) = (repo.get_campaign().await, repo.journal().await);
+    let journal = journal.map_err(|_| ())?;
+    let entries = arrange(&journal);
+
+    let mut anchors = HashMap::new();
+    for (_, thing) in &entries {
+        let name = thing.name().to_string();
+        let slug = slugify(&name);
+        let mut anchor = slug.clone();
+        let mut i = 2;
+
+        while anchors.values().any(|a: &String| a == &anchor) {
+            anchor = format!("{}-{}", slug, i);
+            i += 1;
+        }
+
+        anchors.insert(name, anchor);
+    }
+
+    let mut output = match campaign {
+        Ok(campaign) if !campaign.eq_ci(DEFAULT_CAMPAIGN) => format!("# {} Handbook", campaign),
+        _ => "# Campaign Handbook".to_string(),
+    };
+
+    if entries.is_empty() {
+        output.push_str("\n\n*The journal is empty.*");
+        return Ok(output);
+    }
+
+    output.push_str("\n\n## Contents\n");
+
+    for (depth, thing) in &entries {
+        let name = thing.name().to_string();
+        output.push_str(&format!(
+            "\n{}* [{}](#{}) ({})",
+            "  ".repeat(*depth),
+            name,
+            anchors[&name],
+            thing.display_description(),
+        ));
+    }
+
+    let units = repo.units().await;
+
+    for (_, thing) in &entries {
+        let details = thing
+            .display_details(repo.load_relations(thing).await.unwrap_or_default(), units)
+            .to_string();
+
+        output.push_str(&format!(
+            "\n\n<a id=\"{}\"></a>\n\n{}",
+            anchors[&thing.name().to_string()],
+            link_names(&details, &anchors),
+        ));
+    }
+
+    Ok(output)
+}
+
+/// Wrap a Markdown handbook in a standalone HTML document.
+pub fn html(markdown: &str) -> String {
+    let title = markdown
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("# "))
+        .unwrap_or("Campaign Handbook");
+
+    let mut output =
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>".to_string();
+    escape_html(&mut output, title).unwrap();
+    output.push_str("</title>\n<style>.thing-box { border: 1px solid #999; margin: 1em 0; padding: 0 1em; }</style>\n</head>\n<body>\n");
+    html::push_html(&mut output, Parser::new_ext(markdown, Options::empty()));
+    output.push_str("</body>\n</html>\n");
+    output
+}
+
+/// Order the journal for display, pairing each thing with its depth in the place hierarchy.
+/// Things whose location isn't in the journal are treated as top-level.
+fn arrange(journal: &[Thing]) -> Vec<(usize, &Thing)> {
+    let things_by_uuid = by_uuid(journal);
+    let mut children: HashMap<Option<Uuid>, Vec<&Thing>> = HashMap::new();
+
+    for thing in journal {
+        let parent = location_uuid(thing)
+            .filter(|uuid| things_by_uuid.contains_key(uuid) && Some(uuid) != thing.uuid());
+
+        children.entry(parent).or_default().push(thing);
+    }
+
+    for (parent, things) in children.iter_mut() {
+        // Characters come before the places nested inside the same place, but characters without
+        // a location are left until the end.
+        let places_first = parent.is_none();
+        things.sort_by(|a, b| {
+            (matches!(a, Thing::Place(_)) == places_first)
+                .cmp(&(matches!(b, Thing::Place(_)) == places_first))
+                .reverse()
+                .then_with(|| a.name().to_string().cmp_ci(b.name().to_string()))
+        });
+    }
+
+    let mut result = Vec::with_capacity(journal.len());
+    let mut stack: Vec<(usize, &Thing)> = children
+        .remove(&None)
+        .unwrap_or_default()
+        .into_iter()
+        .rev()
+        .map(|thing| (0, thing))
+        .collect();
+
+    while let Some((depth, thing)) = stack.pop() {
+        result.push((depth, thing));
+
+        if let Some(things) = thing.uuid().and_then(|&uuid| children.remove(&Some(uuid))) {
+            stack.extend(things.into_iter().rev().map(|thing| (depth + 1, thing)));
+        }
+    }
+
+    // Anything left over is part of a location cycle, which can't be reached from the top level.
+    let mut orphans: Vec<&Thing> = children.into_values().flatten().collect();
+    orphans.sort_by(|a, b| a.name().to_string().cmp_ci(b.name().to_string()));
+    result.extend(orphans.into_iter().map(|thing| (0, thing)));
+
+    result
+}
+
+/// Replace the backtick-quoted names of journal entries with links to those entries.
+fn link_names(text: &str, anchors: &HashMap<String, String>) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for (i, part) in text.split('`').enumerate() {
+        if i % 2 == 0 {
+            result.push_str(part);
+        } else if let Some(anchor) = anchors.get(part) {
+            result.push_str(&format!("[{}](#{})", part, anchor));
+        } else {
+            result.push('`');
+            result.push_str(part);
+            result.push('`');
+        }
+    }
+
+    result
+}
+
+fn slugify(name: &str) -> String {
+    let mut slug = String::with_capacity(name.len());
+
+    for c in name.to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if (c.is_whitespace() || c == '-') && !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+
+    if slug.is_empty() {
+        "entry".to_string()
+    } else {
+        slug
+    }
+}
+
+impl fmt::Display for HandbookFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Markdown => write!(f, "markdown"),
+            Self::Html => write!(f, "html"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::storage::{Change, MemoryDataStore};
+    use crate::world::npc::Npc;
+    use crate::world::place::{Place, PlaceType};
+    use crate::world::PlaceUuid;
+    use tokio_test::block_on;
+
+    #[test]
+    fn markdown_test() {
+        let mut repo = Repository::new(MemoryDataStore::default());
+        let town = Uuid::new_v4();
+        let inn = Uuid::new_v4();
+
+        [
+            Thing::from(Place {
+                uuid: Some(town.into()),
+                name: "Thornwick".into(),
+                subtype: "town".parse::<PlaceType>().ok().into(),
+                ..Default::default()
+            }),
+            Place {
+                uuid: Some(inn.into()),
+                location_uuid: PlaceUuid::from(town).into(),
+                name: "Prancing Pony".into(),
+                subtype: "inn".parse::<PlaceType>().ok().into(),
+                ..Default::default()
+            }
+            .into(),
+            Npc {
+                location_uuid: PlaceUuid::from(inn).into(),
+                name: "Mira Vance".into(),
+                ..Default::default()
+            }
+            .into(),
+            Npc {
+                location_uuid: PlaceUuid::from(town).into(),
+                name: "Ellis Vance".into(),
+                ..Default::default()
+            }
+            .into(),
+            Npc {
+                name: "Drifter".into(),
+                ..Default::default()
+            }
+            .into(),
+        ]
+        .into_iter()
+        .for_each(|thing| {
+            block_on(repo.modify(Change::CreateAndSave { thing })).unwrap();
+        });
+
+        let output = block_on(markdown(&repo)).unwrap();
+
+        assert!(
+            output.starts_with(
+                "# Campaign Handbook
+
+## Contents
+
+* [Thornwick](#thornwick) (town)
+  * [Ellis Vance](#ellis-vance) (person)
+  * [Prancing Pony](#prancing-pony) (inn)
+    * [Mira Vance](#mira-vance) (person)
+* [Drifter](#drifter) (person)
+
+<a id=\"thornwick\"></a>
+
+<div class=\"thing-box place\">
+
+# Thornwick"
+            ),
+            "{}",
+            output,
+        );
+
+        assert!(
+            output.contains(
+                "**Location:** 🏨 [Prancing Pony](#prancing-pony), 🏘 [Thornwick](#thornwick)"
+            ),
+            "{}",
+            output,
+        );
+    }
+
+    #[test]
+    fn html_test() {
+        let output = html("# Tomb & Co Handbook\n\n<a id=\"foo\"></a>\n\n* [Foo](#foo)");
+
+        assert!(
+            output.contains("<title>Tomb &amp; Co Handbook</title>"),
+            "{}",
+            output,
+        );
+        assert!(
+            output.contains("<h1>Tomb &amp; Co Handbook</h1>"),
+            "{}",
+            output
+        );
+        assert!(
+            output.contains("<li><a href=\"#foo\">Foo</a></li>"),
+            "{}",
+            output,
+        );
+    }
+
+    #[test]
+    fn slugify_test() {
+        assert_eq!("the-prancing-pony", slugify("The Prancing  Pony!"));
+        assert_eq!("élan-vital", slugify("Élan-Vital"));
+        assert_eq!("entry", slugify("???"));
+    }
+}
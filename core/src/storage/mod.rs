@@ -1,9 +1,17 @@
 pub mod backup;
 
 pub use command::StorageCommand;
-pub use data_store::{DataStore, MemoryDataStore, NullDataStore};
-pub use repository::{Change, Error as RepositoryError, KeyValue, Repository};
+pub use data_store::{DataStore, MemoryCampaign, MemoryDataStore, NullDataStore, DEFAULT_CAMPAIGN};
+pub use filter::ThingFilter;
+pub use repository::{
+    Change, Error as RepositoryError, KeyValue, Repository, DEFAULT_UNDO_HISTORY_LEN,
+};
+pub use spreadsheet::import as import_csv;
 
 mod command;
 mod data_store;
+mod filter;
+mod handbook;
 mod repository;
+mod search;
+mod spreadsheet;
@@ -1,34 +1,55 @@
-use crate::storage::{DataStore, MemoryDataStore};
+use crate::storage::{search, DataStore, MemoryDataStore};
 use crate::time::Time;
 use crate::utils::CaseInsensitiveStr;
-use crate::world::{Npc, NpcRelations, Place, PlaceRelations, Thing, ThingRelations};
+use crate::world::npc::{Culture, Relationship, Units};
+use crate::world::{Field, Npc, NpcRelations, Place, PlaceRelations, Thing, ThingRelations};
 use crate::Uuid;
 use futures::join;
-use std::collections::VecDeque;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
 use std::fmt;
+use std::mem;
 
 const RECENT_MAX_LEN: usize = 100;
-const UNDO_HISTORY_LEN: usize = 10;
+pub const DEFAULT_UNDO_HISTORY_LEN: usize = 100;
+
+/// The undo and redo stacks are persisted as JSON in the key-value store, and as such are
+/// specific to the active campaign.
+const UNDO_HISTORY_KEY: &str = "undoHistory";
+const REDO_HISTORY_KEY: &str = "redoHistory";
 
 pub struct Repository {
     data_store: Box<dyn DataStore>,
     data_store_enabled: bool,
     recent: VecDeque<Thing>,
-    redo_change: Option<Change>,
+    redo_history: VecDeque<Change>,
     undo_history: VecDeque<Change>,
+    undo_history_len: usize,
+
+    /// Recent things belonging to campaigns that aren't currently active, held until the user
+    /// switches back to that campaign.
+    inactive_recent: HashMap<String, VecDeque<Thing>>,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "type")]
 pub enum Change {
     /// Create a new thing and store it in recent entries.
     ///
     /// Reverse: Delete { uuid: None, .. }
-    Create { thing: Thing },
+    Create {
+        #[serde(with = "thing_with_locks")]
+        thing: Thing,
+    },
 
     /// Create a new thing and store it in the journal.
     ///
     /// Reverse: Delete { uuid: Some(_), .. }
-    CreateAndSave { thing: Thing },
+    CreateAndSave {
+        #[serde(with = "thing_with_locks")]
+        thing: Thing,
+    },
 
     /// Delete a thing from recent or journal.
     ///
@@ -41,6 +62,7 @@ pub enum Change {
     Edit {
         name: String,
         uuid: Option<Uuid>,
+        #[serde(with = "thing_with_locks")]
         diff: Thing,
     },
 
@@ -50,6 +72,7 @@ pub enum Change {
     EditAndUnsave {
         name: String,
         uuid: Uuid,
+        #[serde(with = "thing_with_locks")]
         diff: Thing,
     },
 
@@ -67,6 +90,52 @@ pub enum Change {
     ///
     /// Reverse: SetKeyValue
     SetKeyValue { key_value: KeyValue },
+
+    /// Apply several changes in order as a single step, eg. populating a settlement. If one of
+    /// them fails, those already applied are reversed. The description is used for both undo and
+    /// redo, eg. "populating Thornwick".
+    ///
+    /// Reverse: Batch (with each change reversed, in reverse order)
+    Batch {
+        description: String,
+        changes: Vec<Change>,
+    },
+}
+
+/// Changes are serialized to persist the undo history. A diff relies on its fields' lock state to
+/// know which ones to apply, but that state is lost when serializing a thing, so the things in a
+/// change are stored along with the names of their locked fields.
+mod thing_with_locks {
+    use super::Thing;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    struct ThingWithLocksRef<'a> {
+        thing: &'a Thing,
+        locked: Vec<&'static str>,
+    }
+
+    #[derive(Deserialize)]
+    struct ThingWithLocks {
+        thing: Thing,
+        locked: Option<Vec<String>>,
+    }
+
+    pub fn serialize<S: Serializer>(thing: &Thing, serializer: S) -> Result<S::Ok, S::Error> {
+        ThingWithLocksRef {
+            thing,
+            locked: thing.locked_fields(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Thing, D::Error> {
+        let ThingWithLocks { mut thing, locked } = ThingWithLocks::deserialize(deserializer)?;
+        if let Some(locked) = locked {
+            thing.set_locked_fields(&locked);
+        }
+        Ok(thing)
+    }
 }
 
 pub struct DisplayUndo<'a>(&'a Change);
@@ -81,9 +150,25 @@ pub enum Error {
     NotFound,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(into = "RawKeyValue", try_from = "RawKeyValue")]
 pub enum KeyValue {
     Time(Option<Time>),
+
+    /// Custom cultures, stored as a JSON list in the same format as a `cultures.json` file.
+    Cultures(Option<Vec<Culture>>),
+
+    /// The preferred units for displaying heights and weights.
+    Units(Option<Units>),
+
+    /// The maximum number of changes that can be undone.
+    UndoHistoryLen(Option<usize>),
+}
+
+#[derive(Deserialize, Serialize)]
+struct RawKeyValue {
+    key: String,
+    value: Option<String>,
 }
 
 impl Repository {
@@ -92,8 +177,10 @@ impl Repository {
             data_store: Box::new(data_store),
             data_store_enabled: false,
             recent: VecDeque::default(),
-            redo_change: None,
+            redo_history: VecDeque::default(),
             undo_history: VecDeque::default(),
+            undo_history_len: DEFAULT_UNDO_HISTORY_LEN,
+            inactive_recent: HashMap::default(),
         }
     }
 
@@ -103,6 +190,24 @@ impl Repository {
         } else {
             self.data_store = Box::<MemoryDataStore>::default();
         }
+
+        self.load_history().await;
+    }
+
+    /// The maximum number of changes that can be undone (and redone).
+    pub fn undo_history_len(&self) -> usize {
+        self.undo_history_len
+    }
+
+    /// Set the maximum number of changes that can be undone (and redone). Older changes are
+    /// discarded.
+    pub async fn set_undo_history_len(&mut self, len: usize) {
+        self.undo_history_len = len;
+
+        if self.undo_history.len() > len || self.redo_history.len() > len {
+            self.truncate_history();
+            self.save_history().await;
+        }
     }
 
     pub async fn get_by_change(&self, change: &Change) -> Result<Thing, Error> {
@@ -124,7 +229,7 @@ impl Repository {
             Change::Delete { name, .. } | Change::Edit { name, .. } | Change::Save { name } => {
                 (Some(name), None)
             }
-            Change::SetKeyValue { .. } => (None, None),
+            Change::SetKeyValue { .. } | Change::Batch { .. } => (None, None),
         };
 
         if let Some(uuid) = uuid {
@@ -183,17 +288,137 @@ impl Repository {
         };
 
         match thing {
-            Thing::Npc(Npc { .. }) => Ok(NpcRelations {
-                location: locations,
+            Thing::Npc(npc) => {
+                let home = match npc.home_uuid.value() {
+                    Some(uuid) => match self
+                        .get_by_uuid(&uuid.to_owned().into())
+                        .await
+                        .and_then(|thing| thing.into_place().map_err(|_| Error::NotFound))
+                    {
+                        Ok(home) => Some(home),
+                        Err(Error::NotFound) => None,
+                        Err(e) => return Err(e),
+                    },
+                    None => None,
+                };
+
+                let mut relations = NpcRelations {
+                    location: locations,
+                    home,
+                    ..Default::default()
+                };
+                self.load_family(npc, &mut relations).await?;
+                Ok(relations.into())
             }
-            .into()),
-            Thing::Place(Place { .. }) => Ok(PlaceRelations {
-                location: locations,
+            Thing::Place(place) => {
+                let mut relations = PlaceRelations {
+                    location: locations,
+                    ..Default::default()
+                };
+                self.load_inhabitants(place, &mut relations).await?;
+                self.load_staff(place, &mut relations).await?;
+                Ok(relations.into())
             }
-            .into()),
         }
     }
 
+    /// Characters record where they live and where they are, so the people in a place are looked
+    /// up by the place's UUID among both the saved and recent characters.
+    async fn load_inhabitants(
+        &self,
+        place: &Place,
+        relations: &mut PlaceRelations,
+    ) -> Result<(), Error> {
+        let uuid = match &place.uuid {
+            Some(uuid) => uuid,
+            None => return Ok(()),
+        };
+
+        let saved = self
+            .data_store
+            .get_npcs_by_place(uuid.as_ref())
+            .await
+            .map_err(|_| Error::DataStoreFailed)?;
+
+        saved
+            .iter()
+            .chain(self.recent())
+            .filter_map(|thing| match thing {
+                Thing::Npc(npc) => Some(npc),
+                Thing::Place(_) => None,
+            })
+            .for_each(|npc| {
+                if npc.home_uuid.value() == Some(uuid) {
+                    relations.residents.push(npc.clone());
+                }
+                if npc.location_uuid.value() == Some(uuid) {
+                    relations.occupants.push(npc.clone());
+                }
+            });
+
+        Ok(())
+    }
+
+    /// Owners and staff are recorded on the place itself, so unlike residents they can be looked
+    /// up directly. Anyone who has since been deleted is skipped.
+    async fn load_staff(&self, place: &Place, relations: &mut PlaceRelations) -> Result<(), Error> {
+        if let Some(uuid) = place.owner.value() {
+            match self.get_by_uuid(&uuid.to_owned().into()).await {
+                Ok(Thing::Npc(npc)) => relations.owner = Some(npc),
+                Ok(Thing::Place(_)) | Err(Error::NotFound) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        for uuid in place
+            .staff
+            .value()
+            .map_or(&[][..], |uuids| uuids.as_slice())
+        {
+            match self.get_by_uuid(&uuid.to_owned().into()).await {
+                Ok(Thing::Npc(npc)) => relations.staff.push(npc),
+                Ok(Thing::Place(_)) | Err(Error::NotFound) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Family links are recorded on both relatives once they're saved, so relatives in the
+    /// journal can be looked up directly. Unsaved relatives only record the link on their side, so
+    /// recent things are checked as well.
+    async fn load_family(&self, npc: &Npc, relations: &mut NpcRelations) -> Result<(), Error> {
+        for &relationship in Relationship::ALL.iter() {
+            for uuid in npc.relatives(relationship) {
+                match self.get_by_uuid(&uuid.to_owned().into()).await {
+                    Ok(Thing::Npc(relative)) => {
+                        relations.relatives_mut(relationship).push(relative)
+                    }
+                    Ok(Thing::Place(_)) | Err(Error::NotFound) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        if let Some(uuid) = &npc.uuid {
+            self.recent()
+                .filter_map(|thing| match thing {
+                    Thing::Npc(other) if other.uuid.is_none() => Some(other),
+                    _ => None,
+                })
+                .for_each(|other| {
+                    for &relationship in Relationship::ALL.iter() {
+                        if other.relatives(relationship.inverse()).contains(uuid) {
+                            relations.relatives_mut(relationship).push(other.clone());
+                        }
+                    }
+                });
+        }
+
+        Ok(())
+    }
+
     pub async fn get_by_name_start(
         &self,
         name: &str,
@@ -218,6 +443,23 @@ impl Repository {
         Ok(things)
     }
 
+    /// Search the journal and recently generated things, as described by the `search` command.
+    /// Recent things are listed after any matches from the journal.
+    pub async fn search(&self, query: &str, limit: Option<usize>) -> Result<Vec<Thing>, Error> {
+        let mut things = self
+            .data_store
+            .search_things(query, limit)
+            .await
+            .map_err(|_| Error::DataStoreFailed)?;
+
+        search::rank(self.recent(), query)
+            .into_iter()
+            .take(limit.unwrap_or(usize::MAX).saturating_sub(things.len()))
+            .for_each(|t| things.push(t.clone()));
+
+        Ok(things)
+    }
+
     pub fn recent(&self) -> impl Iterator<Item = &Thing> {
         let (a, b) = self.recent.as_slices();
         a.iter().chain(b.iter())
@@ -255,56 +497,67 @@ impl Repository {
         }
     }
 
+    /// Apply a change, recording its reverse in the undo history. Making a new change discards
+    /// any changes that were previously undone.
     pub async fn modify(&mut self, change: Change) -> Result<Option<Thing>, (Change, Error)> {
         let undo_change = self.modify_without_undo(change).await?;
         let thing = self.get_by_change(&undo_change).await.ok();
 
-        while self.undo_history.len() >= UNDO_HISTORY_LEN {
-            self.undo_history.pop_front();
-        }
+        self.redo_history.clear();
         self.undo_history.push_back(undo_change);
+        self.truncate_history();
+        self.save_history().await;
 
         Ok(thing)
     }
 
     pub async fn undo(&mut self) -> Option<Result<Option<Thing>, Error>> {
-        if let Some(change) = self.undo_history.pop_back() {
-            match self.modify_without_undo(change).await {
-                Ok(redo_change) => {
-                    let thing = self.get_by_change(&redo_change).await.ok();
-                    self.redo_change = Some(redo_change);
-                    Some(Ok(thing))
-                }
-                Err((undo_change, e)) => {
-                    self.undo_history.push_back(undo_change);
-                    Some(Err(e))
-                }
+        let change = self.undo_history.pop_back()?;
+
+        match self.modify_without_undo(change).await {
+            Ok(redo_change) => {
+                let thing = self.get_by_change(&redo_change).await.ok();
+                self.redo_history.push_back(redo_change);
+                self.save_history().await;
+                Some(Ok(thing))
+            }
+            Err((undo_change, e)) => {
+                self.undo_history.push_back(undo_change);
+                Some(Err(e))
             }
-        } else {
-            None
         }
     }
 
+    /// Changes that can be undone, most recent first.
     pub fn undo_history(&self) -> impl Iterator<Item = &Change> {
         self.undo_history.iter().rev()
     }
 
     pub async fn redo(&mut self) -> Option<Result<Option<Thing>, Error>> {
-        if let Some(change) = self.redo_change.take() {
-            match self.modify(change).await {
-                Ok(option_thing) => Some(Ok(option_thing)),
-                Err((redo_change, e)) => {
-                    self.redo_change = Some(redo_change);
-                    Some(Err(e))
-                }
+        let change = self.redo_history.pop_back()?;
+
+        match self.modify_without_undo(change).await {
+            Ok(undo_change) => {
+                let thing = self.get_by_change(&undo_change).await.ok();
+                self.undo_history.push_back(undo_change);
+                self.truncate_history();
+                self.save_history().await;
+                Some(Ok(thing))
+            }
+            Err((redo_change, e)) => {
+                self.redo_history.push_back(redo_change);
+                Some(Err(e))
             }
-        } else {
-            None
         }
     }
 
     pub fn get_redo(&self) -> Option<&Change> {
-        self.redo_change.as_ref()
+        self.redo_history.back()
+    }
+
+    /// Changes that have been undone and can be redone, most recently undone first.
+    pub fn redo_history(&self) -> impl Iterator<Item = &Change> {
+        self.redo_history.iter().rev()
     }
 
     pub async fn modify_without_undo(&mut self, change: Change) -> Result<Change, (Change, Error)> {
@@ -327,30 +580,31 @@ impl Repository {
             Change::Delete {
                 name,
                 uuid: Some(uuid),
-            } => self
-                .delete_thing_by_uuid(&uuid)
-                .await
-                .map(|thing| Change::CreateAndSave { thing })
-                .map_err(|(_, e)| {
-                    (
-                        Change::Delete {
-                            name,
-                            uuid: Some(uuid),
-                        },
-                        e,
-                    )
-                }),
-            Change::Delete { name, uuid: None } => self
-                .delete_thing_by_name(&name)
-                .await
-                .map(|thing| {
-                    if thing.uuid().is_some() {
+            } => match self.delete_thing_by_uuid(&uuid).await {
+                Ok((thing, restore_changes)) => Ok(Self::undo_delete(
+                    Change::CreateAndSave { thing },
+                    restore_changes,
+                )),
+                Err((_, e)) => Err((
+                    Change::Delete {
+                        name,
+                        uuid: Some(uuid),
+                    },
+                    e,
+                )),
+            },
+            Change::Delete { name, uuid: None } => match self.delete_thing_by_name(&name).await {
+                Ok((thing, restore_changes)) => {
+                    let undo_change = if thing.uuid().is_some() {
                         Change::CreateAndSave { thing }
                     } else {
                         Change::Create { thing }
-                    }
-                })
-                .map_err(|e| (Change::Delete { name, uuid: None }, e)),
+                    };
+
+                    Ok(Self::undo_delete(undo_change, restore_changes))
+                }
+                Err(e) => Err((Change::Delete { name, uuid: None }, e)),
+            },
             Change::Edit {
                 name,
                 uuid: Some(uuid),
@@ -441,9 +695,61 @@ impl Repository {
                 .await
                 .map(|old_kv| Change::SetKeyValue { key_value: old_kv })
                 .map_err(|e| (Change::SetKeyValue { key_value }, e)),
+            Change::Batch {
+                description,
+                changes,
+            } => {
+                let mut undo_changes = Vec::with_capacity(changes.len());
+                let mut changes = changes.into_iter();
+
+                while let Some(change) = changes.next() {
+                    match Box::pin(self.modify_without_undo(change)).await {
+                        Ok(undo_change) => undo_changes.push(undo_change),
+                        Err((change, e)) => {
+                            let mut redo_changes = Vec::with_capacity(undo_changes.len());
+
+                            while let Some(undo_change) = undo_changes.pop() {
+                                if let Ok(redo_change) =
+                                    Box::pin(self.modify_without_undo(undo_change)).await
+                                {
+                                    redo_changes.push(redo_change);
+                                }
+                            }
+
+                            redo_changes.reverse();
+                            redo_changes.push(change);
+                            redo_changes.extend(changes);
+
+                            return Err((
+                                Change::Batch {
+                                    description,
+                                    changes: redo_changes,
+                                },
+                                e,
+                            ));
+                        }
+                    }
+                }
+
+                undo_changes.reverse();
+                Ok(Change::Batch {
+                    description,
+                    changes: undo_changes,
+                })
+            }
         }
     }
 
+    /// The units in which heights and weights are shown, falling back to the default if none has
+    /// been chosen or the data store can't be read.
+    pub async fn units(&self) -> Units {
+        self.get_key_value(&KeyValue::Units(None))
+            .await
+            .ok()
+            .and_then(KeyValue::units)
+            .unwrap_or_default()
+    }
+
     pub async fn get_key_value(&self, key: &KeyValue) -> Result<KeyValue, Error> {
         let value_str = self.data_store.get_value(key.key_raw()).await;
 
@@ -451,6 +757,18 @@ impl Repository {
             KeyValue::Time(_) => value_str
                 .and_then(|o| o.map(|s| s.parse()).transpose())
                 .map(KeyValue::Time),
+            KeyValue::Cultures(_) => value_str
+                .and_then(|o| {
+                    o.map(|s| Culture::parse_list(&s).map_err(|_| ()))
+                        .transpose()
+                })
+                .map(KeyValue::Cultures),
+            KeyValue::Units(_) => value_str
+                .and_then(|o| o.map(|s| s.parse()).transpose())
+                .map(KeyValue::Units),
+            KeyValue::UndoHistoryLen(_) => value_str
+                .and_then(|o| o.map(|s| s.parse().map_err(|_| ())).transpose())
+                .map(KeyValue::UndoHistoryLen),
         }
         .map_err(|_| Error::DataStoreFailed)
     }
@@ -459,6 +777,136 @@ impl Repository {
         self.data_store_enabled
     }
 
+    pub async fn get_campaign(&self) -> Result<String, Error> {
+        self.data_store
+            .get_campaign()
+            .await
+            .map_err(|_| Error::DataStoreFailed)
+    }
+
+    /// Get the names of all campaigns, sorted alphabetically.
+    pub async fn get_campaigns(&self) -> Result<Vec<String>, Error> {
+        let mut campaigns = self
+            .data_store
+            .get_campaigns()
+            .await
+            .map_err(|_| Error::DataStoreFailed)?;
+        campaigns.sort_unstable_by(|a, b| a.cmp_ci(b));
+        Ok(campaigns)
+    }
+
+    /// Create a new campaign and make it active, returning its name.
+    pub async fn create_campaign(&mut self, name: &str) -> Result<String, Error> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            Err(Error::MissingName)
+        } else if self.find_campaign(name).await?.is_some() {
+            Err(Error::NameAlreadyExists)
+        } else {
+            self.activate_campaign(name).await?;
+            Ok(name.to_string())
+        }
+    }
+
+    /// Make an existing campaign active, returning its name as originally written. Recent things
+    /// and undo history are kept separately for each campaign.
+    pub async fn switch_campaign(&mut self, name: &str) -> Result<String, Error> {
+        let name = self.find_campaign(name).await?.ok_or(Error::NotFound)?;
+        self.activate_campaign(&name).await?;
+        Ok(name)
+    }
+
+    /// Delete an inactive campaign and everything in it, returning its name as originally
+    /// written. This can't be undone.
+    pub async fn delete_campaign(&mut self, name: &str) -> Result<String, Error> {
+        let name = self.find_campaign(name).await?.ok_or(Error::NotFound)?;
+
+        self.data_store
+            .delete_campaign(&name)
+            .await
+            .map_err(|_| Error::DataStoreFailed)?;
+        self.inactive_recent.remove(&name);
+
+        Ok(name)
+    }
+
+    async fn find_campaign(&self, name: &str) -> Result<Option<String>, Error> {
+        Ok(self
+            .get_campaigns()
+            .await?
+            .into_iter()
+            .find(|campaign| campaign.eq_ci(name)))
+    }
+
+    async fn activate_campaign(&mut self, name: &str) -> Result<(), Error> {
+        let current = self.get_campaign().await?;
+
+        if current != name {
+            self.data_store
+                .set_campaign(name)
+                .await
+                .map_err(|_| Error::DataStoreFailed)?;
+
+            let next = self.inactive_recent.remove(name).unwrap_or_default();
+            self.inactive_recent
+                .insert(current, mem::replace(&mut self.recent, next));
+            self.load_history().await;
+        }
+
+        Ok(())
+    }
+
+    /// Load the history length and the undo and redo stacks of the active campaign from the data
+    /// store. Failure to load is not fatal; it just means that older changes can't be undone.
+    async fn load_history(&mut self) {
+        self.undo_history_len = self
+            .get_key_value(&KeyValue::UndoHistoryLen(None))
+            .await
+            .ok()
+            .and_then(KeyValue::undo_history_len)
+            .unwrap_or(DEFAULT_UNDO_HISTORY_LEN);
+
+        let (undo_history, redo_history) = join!(
+            self.data_store.get_value(UNDO_HISTORY_KEY),
+            self.data_store.get_value(REDO_HISTORY_KEY),
+        );
+
+        let parse = |value: Result<Option<String>, ()>| -> VecDeque<Change> {
+            value
+                .ok()
+                .flatten()
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        };
+
+        self.undo_history = parse(undo_history);
+        self.redo_history = parse(redo_history);
+        self.truncate_history();
+    }
+
+    async fn save_history(&mut self) {
+        for (key, history) in [
+            (UNDO_HISTORY_KEY, &self.undo_history),
+            (REDO_HISTORY_KEY, &self.redo_history),
+        ] {
+            if let Ok(json) = serde_json::to_string(history) {
+                // As with loading, failure to persist the history shouldn't interrupt the user.
+                let _ = self.data_store.set_value(key, &json).await;
+            }
+        }
+    }
+
+    fn truncate_history(&mut self) {
+        while self.undo_history.len() > self.undo_history_len {
+            self.undo_history.pop_front();
+        }
+
+        while self.redo_history.len() > self.undo_history_len {
+            self.redo_history.pop_front();
+        }
+    }
+
     async fn set_key_value(&mut self, key_value: &KeyValue) -> Result<KeyValue, Error> {
         let old_key_value = self.get_key_value(key_value).await?;
 
@@ -466,7 +914,14 @@ impl Repository {
             (key, Some(value)) => self.data_store.set_value(key, &value).await,
             (key, None) => self.data_store.delete_value(key).await,
         }
-        .map(|_| old_key_value)
+        .map(|_| {
+            // The history itself is truncated once this change has been pushed onto it.
+            if let KeyValue::UndoHistoryLen(len) = key_value {
+                self.undo_history_len = len.unwrap_or(DEFAULT_UNDO_HISTORY_LEN);
+            }
+
+            old_key_value
+        })
         .map_err(|_| Error::DataStoreFailed)
     }
 
@@ -520,7 +975,25 @@ impl Repository {
         }
     }
 
-    async fn delete_thing_by_name(&mut self, name: &str) -> Result<Thing, Error> {
+    /// Reverse a deletion, along with any changes that it made to other things. Relatives are
+    /// linked again when the thing is created, but places must be edited to restore their owner
+    /// and staff. The places are restored first so that a `redo` deletes the thing before clearing
+    /// them again.
+    fn undo_delete(undo_change: Change, mut restore_changes: Vec<Change>) -> Change {
+        if restore_changes.is_empty() {
+            undo_change
+        } else {
+            let description = DisplayUndo(&undo_change).to_string();
+            restore_changes.push(undo_change);
+
+            Change::Batch {
+                description,
+                changes: restore_changes,
+            }
+        }
+    }
+
+    async fn delete_thing_by_name(&mut self, name: &str) -> Result<(Thing, Vec<Change>), Error> {
         if let Some(uuid) = self
             .get_by_name(name)
             .await
@@ -531,77 +1004,248 @@ impl Repository {
         } else if let Some(thing) =
             self.take_recent(|t| t.name().value().map_or(false, |s| s.eq_ci(name)))
         {
-            Ok(thing)
+            Ok((thing, Vec::new()))
         } else {
             Err(Error::NotFound)
         }
     }
 
-    async fn delete_thing_by_uuid(&mut self, uuid: &Uuid) -> Result<Thing, (Option<Thing>, Error)> {
-        match (
-            self.data_store.get_thing_by_uuid(uuid).await,
-            self.data_store.delete_thing_by_uuid(uuid).await,
-        ) {
-            (Ok(Some(thing)), Ok(())) => Ok(thing),
-            (Ok(Some(thing)), Err(())) => Err((Some(thing), Error::DataStoreFailed)),
-            (Ok(None), _) => Err((None, Error::NotFound)),
-            (Err(_), _) => Err((None, Error::DataStoreFailed)),
+    /// Delete a thing from the journal, returning it along with the changes needed to restore any
+    /// places that it owned or worked at.
+    async fn delete_thing_by_uuid(
+        &mut self,
+        uuid: &Uuid,
+    ) -> Result<(Thing, Vec<Change>), (Option<Thing>, Error)> {
+        let thing = match self.data_store.get_thing_by_uuid(uuid).await {
+            Ok(Some(thing)) => thing,
+            Ok(None) => return Err((None, Error::NotFound)),
+            Err(_) => return Err((None, Error::DataStoreFailed)),
+        };
+
+        if let Err(e) = self.unlink_relatives(&thing).await {
+            return Err((None, e));
         }
-    }
 
-    async fn save_thing_by_name(&mut self, name: &str) -> Result<Uuid, Error> {
-        if let Some(thing) = self.take_recent(|t| t.name().value().map_or(false, |s| s.eq_ci(name)))
-        {
-            self.save_thing(thing).await.map_err(|(thing, e)| {
-                self.push_recent(thing);
-                e
-            })
-        } else {
-            Err(Error::NotFound)
+        let restore_changes = match self.unlink_employers(&thing).await {
+            Ok(restore_changes) => restore_changes,
+            Err(e) => return Err((None, e)),
+        };
+
+        match self.data_store.delete_thing_by_uuid(uuid).await {
+            Ok(()) => Ok((thing, restore_changes)),
+            Err(()) => Err((Some(thing), Error::DataStoreFailed)),
         }
     }
 
-    async fn save_thing(&mut self, mut thing: Thing) -> Result<Uuid, (Thing, Error)> {
-        let uuid = if let Some(&uuid) = thing.uuid() {
-            uuid
-        } else {
-            let uuid = Uuid::new_v4();
-            thing.set_uuid(uuid);
-            uuid
+    /// Family links are recorded on both relatives, so saving an NPC adds a link back to it on any
+    /// relatives already in the journal.
+    async fn link_relatives(&mut self, thing: &Thing) -> Result<(), Error> {
+        let (npc, uuid) = match thing {
+            Thing::Npc(
+                npc @ Npc {
+                    uuid: Some(uuid), ..
+                },
+            ) => (npc, uuid),
+            _ => return Ok(()),
         };
 
-        match self.data_store.save_thing(&thing).await {
-            Ok(()) => Ok(uuid),
-            Err(()) => {
-                thing.clear_uuid();
-                Err((thing, Error::DataStoreFailed))
+        for &relationship in Relationship::ALL.iter() {
+            for relative_uuid in npc.relatives(relationship) {
+                match self
+                    .data_store
+                    .get_thing_by_uuid(&relative_uuid.to_owned().into())
+                    .await
+                {
+                    Ok(Some(Thing::Npc(mut relative)))
+                        if !relative.relatives(relationship.inverse()).contains(uuid) =>
+                    {
+                        relative.add_relative(relationship.inverse(), uuid.clone());
+                        self.data_store
+                            .edit_thing(&relative.into())
+                            .await
+                            .map_err(|()| Error::DataStoreFailed)?;
+                    }
+                    Ok(_) => {}
+                    Err(()) => return Err(Error::DataStoreFailed),
+                }
             }
         }
+
+        Ok(())
     }
 
-    async fn unsave_thing_by_uuid(
-        &mut self,
-        uuid: &Uuid,
-    ) -> Result<String, (Option<String>, Error)> {
-        let (mut thing, error) = match self.delete_thing_by_uuid(uuid).await {
-            Ok(thing) => (thing, None),
-            Err((Some(thing), e)) => (thing, Some(e)),
-            Err((None, e)) => return Err((None, e)),
+    /// Remove any links to a deleted NPC from its relatives. The deleted NPC keeps its own links,
+    /// so restoring it with `undo` also restores its family.
+    async fn unlink_relatives(&mut self, thing: &Thing) -> Result<(), Error> {
+        let (npc, uuid) = match thing {
+            Thing::Npc(
+                npc @ Npc {
+                    uuid: Some(uuid), ..
+                },
+            ) => (npc, uuid),
+            _ => return Ok(()),
         };
 
-        thing.clear_uuid();
-
-        match (self.create_thing(thing).await, error) {
-            (Ok(s), None) => Ok(s),
-            (Ok(s), Some(e)) => Err((Some(s), e)),
-            (Err((t, e)), None) | (Err((t, _)), Some(e)) => {
-                Err((t.name().value().map(|s| s.to_string()), e))
+        for relative_uuid in Relationship::ALL
+            .iter()
+            .flat_map(|&relationship| npc.relatives(relationship))
+        {
+            match self
+                .data_store
+                .get_thing_by_uuid(&relative_uuid.to_owned().into())
+                .await
+            {
+                Ok(Some(Thing::Npc(mut relative))) => {
+                    if !relative.remove_relative(uuid).is_empty() {
+                        self.data_store
+                            .edit_thing(&relative.into())
+                            .await
+                            .map_err(|()| Error::DataStoreFailed)?;
+                    }
+                }
+                Ok(_) => {}
+                Err(()) => return Err(Error::DataStoreFailed),
             }
         }
-    }
 
-    async fn edit_thing_by_uuid(
-        &mut self,
+        self.recent.iter_mut().for_each(|thing| {
+            if let Thing::Npc(relative) = thing {
+                relative.remove_relative(uuid);
+            }
+        });
+
+        Ok(())
+    }
+
+    /// A deleted character no longer owns or works at any place. Unlike relatives, these links are
+    /// only recorded on the place, so the edits needed to restore them are returned.
+    async fn unlink_employers(&mut self, thing: &Thing) -> Result<Vec<Change>, Error> {
+        let uuid = match thing {
+            Thing::Npc(Npc {
+                uuid: Some(uuid), ..
+            }) => uuid,
+            _ => return Ok(Vec::new()),
+        };
+
+        let mut restore_changes = Vec::new();
+        let things = self
+            .data_store
+            .get_all_the_things()
+            .await
+            .map_err(|()| Error::DataStoreFailed)?;
+
+        for thing in things {
+            if let Thing::Place(mut place) = thing {
+                let diff = Self::employees_diff(&place);
+
+                if place.remove_employee(uuid) {
+                    self.data_store
+                        .edit_thing(&place.clone().into())
+                        .await
+                        .map_err(|()| Error::DataStoreFailed)?;
+
+                    restore_changes.push(Change::Edit {
+                        name: place.name.to_string(),
+                        uuid: place.uuid.map(Uuid::from),
+                        diff,
+                    });
+                }
+            }
+        }
+
+        for thing in self.recent.iter_mut() {
+            if let Thing::Place(place) = thing {
+                let diff = Self::employees_diff(place);
+
+                if place.remove_employee(uuid) {
+                    restore_changes.push(Change::Edit {
+                        name: place.name.to_string(),
+                        uuid: None,
+                        diff,
+                    });
+                }
+            }
+        }
+
+        Ok(restore_changes)
+    }
+
+    /// A diff that sets a place's owner and staff back to their current values.
+    fn employees_diff(place: &Place) -> Thing {
+        Place {
+            owner: place
+                .owner
+                .value()
+                .cloned()
+                .map_or_else(Field::default, Field::from),
+            staff: place
+                .staff
+                .value()
+                .cloned()
+                .map_or_else(Field::default, Field::from),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    async fn save_thing_by_name(&mut self, name: &str) -> Result<Uuid, Error> {
+        if let Some(thing) = self.take_recent(|t| t.name().value().map_or(false, |s| s.eq_ci(name)))
+        {
+            self.save_thing(thing).await.map_err(|(thing, e)| {
+                self.push_recent(thing);
+                e
+            })
+        } else {
+            Err(Error::NotFound)
+        }
+    }
+
+    async fn save_thing(&mut self, mut thing: Thing) -> Result<Uuid, (Thing, Error)> {
+        let uuid = if let Some(&uuid) = thing.uuid() {
+            uuid
+        } else {
+            let uuid = Uuid::new_v4();
+            thing.set_uuid(uuid);
+            uuid
+        };
+
+        if let Err(e) = self.link_relatives(&thing).await {
+            thing.clear_uuid();
+            return Err((thing, e));
+        }
+
+        match self.data_store.save_thing(&thing).await {
+            Ok(()) => Ok(uuid),
+            Err(()) => {
+                thing.clear_uuid();
+                Err((thing, Error::DataStoreFailed))
+            }
+        }
+    }
+
+    async fn unsave_thing_by_uuid(
+        &mut self,
+        uuid: &Uuid,
+    ) -> Result<String, (Option<String>, Error)> {
+        let (mut thing, error) = match self.delete_thing_by_uuid(uuid).await {
+            Ok((thing, _)) => (thing, None),
+            Err((Some(thing), e)) => (thing, Some(e)),
+            Err((None, e)) => return Err((None, e)),
+        };
+
+        thing.clear_uuid();
+
+        match (self.create_thing(thing).await, error) {
+            (Ok(s), None) => Ok(s),
+            (Ok(s), Some(e)) => Err((Some(s), e)),
+            (Err((t, e)), None) | (Err((t, _)), Some(e)) => {
+                Err((t.name().value().map(|s| s.to_string()), e))
+            }
+        }
+    }
+
+    async fn edit_thing_by_uuid(
+        &mut self,
         uuid: &Uuid,
         mut diff: Thing,
     ) -> Result<Thing, (Thing, Error)> {
@@ -687,6 +1331,9 @@ impl KeyValue {
     pub const fn key_raw(&self) -> &'static str {
         match self {
             Self::Time(_) => "time",
+            Self::Cultures(_) => "cultures",
+            Self::Units(_) => "units",
+            Self::UndoHistoryLen(_) => "undoHistoryLen",
         }
     }
 
@@ -695,18 +1342,93 @@ impl KeyValue {
             self.key_raw(),
             match self {
                 Self::Time(time) => time.as_ref().map(|t| t.display_short().to_string()),
+                Self::Cultures(cultures) => {
+                    cultures.as_ref().map(|c| serde_json::to_string(c).unwrap())
+                }
+                Self::Units(units) => units.map(|u| u.as_str().to_string()),
+                Self::UndoHistoryLen(len) => len.map(|len| len.to_string()),
             },
         )
     }
 
-    pub const fn time(self) -> Option<Time> {
-        #[allow(irrefutable_let_patterns)]
+    pub fn time(self) -> Option<Time> {
         if let Self::Time(time) = self {
             time
         } else {
             None
         }
     }
+
+    pub fn cultures(self) -> Option<Vec<Culture>> {
+        if let Self::Cultures(cultures) = self {
+            cultures
+        } else {
+            None
+        }
+    }
+
+    pub fn units(self) -> Option<Units> {
+        if let Self::Units(units) = self {
+            units
+        } else {
+            None
+        }
+    }
+
+    pub fn undo_history_len(self) -> Option<usize> {
+        if let Self::UndoHistoryLen(len) = self {
+            len
+        } else {
+            None
+        }
+    }
+}
+
+impl From<KeyValue> for RawKeyValue {
+    fn from(input: KeyValue) -> Self {
+        let (key, value) = input.key_value_raw();
+        Self {
+            key: key.to_string(),
+            value,
+        }
+    }
+}
+
+impl TryFrom<RawKeyValue> for KeyValue {
+    type Error = String;
+
+    fn try_from(input: RawKeyValue) -> Result<Self, Self::Error> {
+        match input.key.as_str() {
+            "time" => input
+                .value
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map(Self::Time)
+                .map_err(|_| format!("Invalid time: {:?}", input.value)),
+            "cultures" => input
+                .value
+                .as_deref()
+                .map(Culture::parse_list)
+                .transpose()
+                .map(Self::Cultures),
+            "units" => input
+                .value
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map(Self::Units)
+                .map_err(|_| format!("Invalid units: {:?}", input.value)),
+            "undoHistoryLen" => input
+                .value
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map(Self::UndoHistoryLen)
+                .map_err(|_| format!("Invalid undo history length: {:?}", input.value)),
+            key => Err(format!("Unknown key: {}", key)),
+        }
+    }
 }
 
 impl Change {
@@ -727,6 +1449,7 @@ impl Change {
             | Self::Save { name }
             | Self::Unsave { name, .. } => name.to_owned(),
             Self::SetKeyValue { key_value } => key_value.key_raw().to_string(),
+            Self::Batch { description, .. } => description.to_owned(),
         }
     }
 }
@@ -746,9 +1469,10 @@ impl<'a> fmt::Display for DisplayUndo<'a> {
             Change::Unsave { name, .. } => write!(f, "saving {} to journal", name),
 
             // These changes are symmetric, so we can provide the same output in both cases.
-            Change::Edit { .. } | Change::EditAndUnsave { .. } | Change::SetKeyValue { .. } => {
-                write!(f, "{}", DisplayRedo(change))
-            }
+            Change::Edit { .. }
+            | Change::EditAndUnsave { .. }
+            | Change::SetKeyValue { .. }
+            | Change::Batch { .. } => write!(f, "{}", DisplayRedo(change)),
         }
     }
 }
@@ -768,7 +1492,11 @@ impl<'a> fmt::Display for DisplayRedo<'a> {
             Change::Unsave { name, .. } => write!(f, "removing {} from journal", name),
             Change::SetKeyValue { key_value } => match key_value {
                 KeyValue::Time(_) => write!(f, "changing the time"),
+                KeyValue::Cultures(_) => write!(f, "changing the cultures"),
+                KeyValue::Units(_) => write!(f, "changing the units"),
+                KeyValue::UndoHistoryLen(_) => write!(f, "changing the history length"),
             },
+            Change::Batch { description, .. } => write!(f, "{}", description),
         }
     }
 }
@@ -787,8 +1515,8 @@ impl fmt::Debug for Repository {
 mod test {
     use super::*;
     use crate::storage::data_store::{MemoryDataStore, NullDataStore};
-    use crate::world::npc::{Npc, Species};
-    use crate::world::{Place, PlaceUuid};
+    use crate::world::npc::{Npc, Species, Uuid as NpcUuid};
+    use crate::world::{Field, Place, PlaceUuid};
     use async_trait::async_trait;
     use std::cell::RefCell;
     use std::rc::Rc;
@@ -882,6 +1610,22 @@ mod test {
         assert_eq!(Err(Error::NotFound), block_on(repo().get_by_name("NOBODY")));
     }
 
+    #[test]
+    fn search_test() {
+        let names = |query: &str, limit: Option<usize>| -> Vec<String> {
+            block_on(repo().search(query, limit))
+                .unwrap()
+                .iter()
+                .map(|thing| thing.name().to_string())
+                .collect()
+        };
+
+        assert_eq!(vec!["Greece", "Olympus", "Thessaly"], names("greece", None));
+        assert_eq!(vec!["Olympus", "Odysseus"], names("o", None));
+        assert_eq!(vec!["Olympus"], names("o", Some(1)));
+        assert!(names("nobody", None).is_empty());
+    }
+
     #[test]
     fn get_by_uuid_test_from_journal() {
         assert_eq!(
@@ -986,7 +1730,7 @@ mod test {
                     name: "Odysseus".to_string(),
                     uuid: None,
                 }),
-                repo.redo_change,
+                repo.get_redo().cloned(),
             );
             assert!(block_on(repo.get_by_name("odysseus")).is_ok());
             assert_eq!(1, repo.recent().count());
@@ -1037,7 +1781,7 @@ mod test {
                     name: "Olympus".to_string(),
                     uuid: Some(OLYMPUS_UUID),
                 }),
-                repo.redo_change,
+                repo.get_redo().cloned(),
             );
             assert!(block_on(repo.get_by_uuid(&OLYMPUS_UUID)).is_ok());
             assert_eq!(4, block_on(repo.journal()).unwrap().len());
@@ -1691,7 +2435,9 @@ mod test {
 
     #[test]
     fn change_test_edit_and_unsave_data_store_failed() {
-        let mut repo = Repository::new(TimeBombDataStore::new(7));
+        // Three of the operations are spent by `init` loading the history length and the undo and
+        // redo history.
+        let mut repo = Repository::new(TimeBombDataStore::new(10));
         populate_repo(&mut repo);
 
         let change = Change::EditAndUnsave {
@@ -1718,6 +2464,157 @@ mod test {
         assert!(block_on(repo.get_by_name("Hades")).is_ok());
     }
 
+    #[test]
+    fn change_test_save_links_relatives() {
+        let (mut repo, data_store) = empty_repo_data_store();
+        let (penelope_uuid, telemachus_uuid) = (Uuid::from_u128(5), Uuid::from_u128(6));
+
+        block_on(
+            repo.modify(Change::CreateAndSave {
+                thing: Npc {
+                    uuid: Some(penelope_uuid.into()),
+                    name: "Penelope".into(),
+                    ..Default::default()
+                }
+                .into(),
+            }),
+        )
+        .unwrap();
+
+        let mut telemachus = Npc {
+            uuid: Some(telemachus_uuid.into()),
+            name: "Telemachus".into(),
+            ..Default::default()
+        };
+        telemachus.add_relative(Relationship::Parent, penelope_uuid.into());
+
+        block_on(repo.modify(Change::CreateAndSave {
+            thing: telemachus.into(),
+        }))
+        .unwrap();
+
+        let penelope = block_on(data_store.get_thing_by_uuid(&penelope_uuid))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            &[NpcUuid::from(telemachus_uuid)][..],
+            penelope.npc().unwrap().relatives(Relationship::Child),
+        );
+
+        block_on(repo.modify(Change::Delete {
+            name: "Telemachus".into(),
+            uuid: None,
+        }))
+        .unwrap();
+
+        let penelope = block_on(data_store.get_thing_by_uuid(&penelope_uuid))
+            .unwrap()
+            .unwrap();
+        assert!(penelope
+            .npc()
+            .unwrap()
+            .relatives(Relationship::Child)
+            .is_empty());
+    }
+
+    #[test]
+    fn change_test_delete_unlinks_employers() {
+        let (mut repo, data_store) = empty_repo_data_store();
+        let (barliman_uuid, nob_uuid) = (Uuid::from_u128(5), Uuid::from_u128(6));
+
+        for (uuid, name) in [(barliman_uuid, "Barliman"), (nob_uuid, "Nob")] {
+            block_on(
+                repo.modify(Change::CreateAndSave {
+                    thing: Npc {
+                        uuid: Some(uuid.into()),
+                        name: name.into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                }),
+            )
+            .unwrap();
+        }
+
+        block_on(
+            repo.modify(Change::CreateAndSave {
+                thing: Place {
+                    uuid: Some(OLYMPUS_UUID.into()),
+                    name: "The Prancing Pony".into(),
+                    owner: NpcUuid::from(barliman_uuid).into(),
+                    staff: vec![NpcUuid::from(barliman_uuid), NpcUuid::from(nob_uuid)].into(),
+                    ..Default::default()
+                }
+                .into(),
+            }),
+        )
+        .unwrap();
+
+        block_on(repo.modify(Change::Delete {
+            name: "Barliman".into(),
+            uuid: None,
+        }))
+        .unwrap();
+
+        let place = block_on(data_store.get_thing_by_uuid(&OLYMPUS_UUID))
+            .unwrap()
+            .unwrap();
+        let place = place.place().unwrap();
+        assert!(place.owner.is_none());
+        assert_eq!(Some(&vec![NpcUuid::from(nob_uuid)]), place.staff.value());
+
+        block_on(repo.modify(Change::Delete {
+            name: "Nob".into(),
+            uuid: None,
+        }))
+        .unwrap();
+
+        let place = block_on(data_store.get_thing_by_uuid(&OLYMPUS_UUID))
+            .unwrap()
+            .unwrap();
+        assert!(place.place().unwrap().staff.is_none());
+    }
+
+    #[test]
+    fn change_test_delete_relative_data_store_failed() {
+        let data_store = TimeBombDataStore::new(100);
+        let t_minus = data_store.t_minus.clone();
+        let mut repo = Repository::new(data_store);
+        let (penelope_uuid, telemachus_uuid) = (Uuid::from_u128(5), Uuid::from_u128(6));
+
+        let mut penelope = Npc {
+            uuid: Some(penelope_uuid.into()),
+            name: "Penelope".into(),
+            ..Default::default()
+        };
+        penelope.add_relative(Relationship::Child, telemachus_uuid.into());
+        block_on(repo.data_store.save_thing(&penelope.into())).unwrap();
+
+        let mut telemachus = Npc {
+            uuid: Some(telemachus_uuid.into()),
+            name: "Telemachus".into(),
+            ..Default::default()
+        };
+        telemachus.add_relative(Relationship::Parent, penelope_uuid.into());
+        block_on(repo.data_store.save_thing(&telemachus.into())).unwrap();
+
+        // Loading Telemachus and Penelope succeeds, but unlinking Penelope does not.
+        t_minus.replace(2);
+
+        let change = Change::Delete {
+            name: "Telemachus".into(),
+            uuid: Some(telemachus_uuid),
+        };
+
+        assert_eq!(
+            Err((change.clone(), Error::DataStoreFailed)),
+            block_on(repo.modify(change)),
+        );
+
+        t_minus.replace(100);
+        assert!(block_on(repo.get_by_uuid(&telemachus_uuid)).is_ok());
+    }
+
     #[test]
     fn change_test_create_success() {
         let mut repo = empty_repo();
@@ -1764,7 +2661,7 @@ mod test {
                     }
                     .into(),
                 }),
-                repo.redo_change,
+                repo.get_redo().cloned(),
             );
             assert_eq!(0, repo.recent().count());
         }
@@ -1854,7 +2751,7 @@ mod test {
                 Some(Change::Save {
                     name: "Odysseus".to_string(),
                 }),
-                repo.redo_change,
+                repo.get_redo().cloned(),
             );
             assert_eq!(4, block_on(repo.journal()).unwrap().len());
             assert_eq!(4, block_on(data_store.get_all_the_things()).unwrap().len());
@@ -1965,10 +2862,10 @@ mod test {
                 v => panic!("{:?}", v),
             }
 
-            if let Some(Change::Unsave { ref name, uuid }) = repo.redo_change {
+            if let Some(Change::Unsave { name, uuid }) = repo.get_redo() {
                 assert_eq!("Olympus", name);
-                assert_ne!(OLYMPUS_UUID, uuid);
-                assert!(block_on(repo.get_by_uuid(&uuid)).is_ok());
+                assert_ne!(&OLYMPUS_UUID, uuid);
+                assert!(block_on(repo.get_by_uuid(uuid)).is_ok());
             } else {
                 panic!();
             }
@@ -2034,7 +2931,7 @@ mod test {
                     }
                     .into(),
                 }),
-                repo.redo_change,
+                repo.get_redo().cloned(),
             );
             assert_eq!(0, block_on(repo.journal()).unwrap().len());
             assert_eq!(0, block_on(data_store.get_all_the_things()).unwrap().len());
@@ -2099,6 +2996,107 @@ mod test {
         );
     }
 
+    #[test]
+    fn change_test_batch_success() {
+        let (mut repo, data_store) = empty_repo_data_store();
+        let change = Change::Batch {
+            description: "populating Ithaca".to_string(),
+            changes: vec![
+                Change::CreateAndSave {
+                    thing: Place {
+                        uuid: Some(OLYMPUS_UUID.into()),
+                        name: "Ithaca".into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                },
+                Change::CreateAndSave {
+                    thing: Npc {
+                        name: "Penelope".into(),
+                        location_uuid: PlaceUuid::from(OLYMPUS_UUID).into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                },
+            ],
+        };
+        assert_eq!("populating Ithaca", change.display_redo().to_string());
+
+        assert_eq!(Ok(None), block_on(repo.modify(change)));
+        assert_eq!(2, block_on(data_store.get_all_the_things()).unwrap().len());
+
+        let penelope_uuid = *block_on(repo.get_by_name("Penelope"))
+            .unwrap()
+            .uuid()
+            .unwrap();
+
+        {
+            let result = repo.undo_history().next().unwrap();
+            assert_eq!(
+                &Change::Batch {
+                    description: "populating Ithaca".to_string(),
+                    changes: vec![
+                        Change::Delete {
+                            name: "Penelope".into(),
+                            uuid: Some(penelope_uuid),
+                        },
+                        Change::Delete {
+                            name: "Ithaca".into(),
+                            uuid: Some(OLYMPUS_UUID),
+                        },
+                    ],
+                },
+                result,
+            );
+            assert_eq!("populating Ithaca", result.display_undo().to_string());
+        }
+
+        assert_eq!(Some(Ok(None)), block_on(repo.undo()));
+        assert_eq!(0, block_on(data_store.get_all_the_things()).unwrap().len());
+
+        assert_eq!(Some(Ok(None)), block_on(repo.redo()));
+        assert_eq!(
+            Ok(penelope_uuid),
+            block_on(repo.get_by_name("Penelope")).map(|thing| *thing.uuid().unwrap()),
+        );
+        assert_eq!(
+            Ok(OLYMPUS_UUID),
+            block_on(repo.get_by_name("Ithaca")).map(|thing| *thing.uuid().unwrap()),
+        );
+    }
+
+    #[test]
+    fn change_test_batch_rolled_back() {
+        let (mut repo, data_store) = repo_data_store();
+        let change = Change::Batch {
+            description: "populating Ithaca".to_string(),
+            changes: vec![
+                Change::CreateAndSave {
+                    thing: Npc {
+                        name: "Penelope".into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                },
+                Change::CreateAndSave {
+                    thing: Place {
+                        name: "Olympus".into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                },
+            ],
+        };
+
+        assert!(matches!(
+            block_on(repo.modify(change)),
+            Err((Change::Batch { changes, .. }, Error::NameAlreadyExists)) if changes.len() == 2,
+        ));
+        assert_eq!(Err(Error::NotFound), block_on(repo.get_by_name("Penelope")));
+        assert_eq!(4, block_on(data_store.get_all_the_things()).unwrap().len());
+        assert!(repo.undo_history().next().is_none());
+    }
+
     #[test]
     fn change_test_set_key_value_success() {
         let mut repo = repo();
@@ -2188,6 +3186,7 @@ mod test {
         match block_on(repo.load_relations(&odysseus)) {
             Ok(ThingRelations::Npc(NpcRelations {
                 location: Some((parent, None)),
+                ..
             })) => {
                 assert_eq!("River Styx", parent.name.value().unwrap());
             }
@@ -2203,6 +3202,7 @@ mod test {
         match block_on(repo.load_relations(&olympus)) {
             Ok(ThingRelations::Place(PlaceRelations {
                 location: Some((parent, Some(grandparent))),
+                ..
             })) => {
                 assert_eq!("Thessaly", parent.name.value().unwrap());
                 assert_eq!("Greece", grandparent.name.value().unwrap());
@@ -2211,6 +3211,44 @@ mod test {
         }
     }
 
+    #[test]
+    fn load_relations_test_with_occupants() {
+        let repo = repo();
+        let styx = block_on(repo.get_by_uuid(&STYX_UUID)).unwrap();
+
+        match block_on(repo.load_relations(&styx)) {
+            Ok(ThingRelations::Place(PlaceRelations {
+                residents,
+                occupants,
+                ..
+            })) => {
+                assert!(residents.is_empty(), "{:?}", residents);
+                assert_eq!(1, occupants.len(), "{:?}", occupants);
+                assert_eq!("Odysseus", occupants[0].name.value().unwrap());
+            }
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn load_relations_test_with_home() {
+        let repo = repo();
+        let npc = Npc {
+            name: "Achilles".into(),
+            home_uuid: PlaceUuid::from(THESSALY_UUID).into(),
+            ..Default::default()
+        };
+
+        match block_on(repo.load_relations(&npc.into())) {
+            Ok(ThingRelations::Npc(NpcRelations {
+                location: None,
+                home: Some(home),
+                ..
+            })) => assert_eq!("Thessaly", home.name.value().unwrap()),
+            r => panic!("{:?}", r),
+        }
+    }
+
     #[test]
     fn debug_test() {
         assert_eq!(
@@ -2233,6 +3271,320 @@ mod test {
         assert_eq!(false, repo.data_store_enabled());
     }
 
+    #[test]
+    fn history_test_persisted() {
+        let data_store = MemoryDataStore::default();
+
+        {
+            let mut repo = Repository::new(data_store.clone());
+            block_on(repo.init());
+
+            for name in ["Odysseus", "Penelope", "Telemachus"] {
+                block_on(
+                    repo.modify(Change::CreateAndSave {
+                        thing: Npc {
+                            name: name.into(),
+                            ..Default::default()
+                        }
+                        .into(),
+                    }),
+                )
+                .unwrap();
+            }
+
+            block_on(repo.undo()).unwrap().unwrap();
+        }
+
+        let mut repo = Repository::new(data_store);
+        block_on(repo.init());
+
+        assert_eq!(
+            vec!["creating Penelope", "creating Odysseus"],
+            repo.undo_history()
+                .map(|change| change.display_undo().to_string())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            vec!["creating Telemachus"],
+            repo.redo_history()
+                .map(|change| change.display_redo().to_string())
+                .collect::<Vec<_>>(),
+        );
+
+        block_on(repo.undo()).unwrap().unwrap();
+        block_on(repo.undo()).unwrap().unwrap();
+        assert!(block_on(repo.undo()).is_none());
+        assert_eq!(0, block_on(repo.journal()).unwrap().len());
+
+        block_on(repo.redo()).unwrap().unwrap();
+        block_on(repo.redo()).unwrap().unwrap();
+        block_on(repo.redo()).unwrap().unwrap();
+        assert!(block_on(repo.redo()).is_none());
+        assert_eq!(3, block_on(repo.journal()).unwrap().len());
+    }
+
+    #[test]
+    fn history_test_edit_diff_persisted() {
+        let (mut repo, data_store) = repo_data_store();
+
+        block_on(
+            repo.modify(Change::Edit {
+                name: "Olympus".to_string(),
+                uuid: None,
+                diff: Place {
+                    description: "Home of the gods".into(),
+                    ..Default::default()
+                }
+                .into(),
+            }),
+        )
+        .unwrap();
+
+        let mut repo = Repository::new(data_store);
+        block_on(repo.init());
+        block_on(repo.undo()).unwrap().unwrap();
+
+        assert_eq!(
+            Ok(Place {
+                uuid: Some(OLYMPUS_UUID.into()),
+                location_uuid: PlaceUuid::from(THESSALY_UUID).into(),
+                name: "Olympus".into(),
+                description: Field::Locked(None),
+                ..Default::default()
+            }
+            .into()),
+            block_on(repo.get_by_uuid(&OLYMPUS_UUID)),
+        );
+    }
+
+    #[test]
+    fn history_test_locked_fields_persisted() {
+        let change = Change::Create {
+            thing: Place {
+                name: "Olympus".into(),
+                description: Field::new_generated("Home of the gods".to_string()),
+                ..Default::default()
+            }
+            .into(),
+        };
+
+        let json = serde_json::to_string(&change).unwrap();
+        assert_eq!(change, serde_json::from_str(&json).unwrap());
+
+        // Without the list of locked fields, every serialized field is locked as before.
+        assert_eq!(
+            Change::Create {
+                thing: Place {
+                    location_uuid: Field::Locked(None),
+                    subtype: Field::Locked(None),
+                    name: "Olympus".into(),
+                    description: "Home of the gods".into(),
+                    ..Default::default()
+                }
+                .into(),
+            },
+            serde_json::from_str(
+                r#"{"type":"Create","thing":{"thing":{"type":"Place","uuid":null,"location_uuid":null,"subtype":null,"name":"Olympus","description":"Home of the gods"}}}"#,
+            )
+            .unwrap(),
+        );
+    }
+
+    #[test]
+    fn history_test_new_change_clears_redo() {
+        let mut repo = repo();
+
+        block_on(repo.modify(Change::Delete {
+            name: "Olympus".to_string(),
+            uuid: None,
+        }))
+        .unwrap();
+        block_on(repo.undo()).unwrap().unwrap();
+        assert!(repo.get_redo().is_some());
+
+        block_on(repo.modify(Change::Delete {
+            name: "Thessaly".to_string(),
+            uuid: None,
+        }))
+        .unwrap();
+        assert!(repo.get_redo().is_none());
+    }
+
+    #[test]
+    fn history_test_len() {
+        let mut repo = empty_repo();
+        block_on(repo.set_undo_history_len(2));
+
+        for i in 0..5 {
+            block_on(
+                repo.modify(Change::Create {
+                    thing: Npc {
+                        name: format!("Thing {}", i).into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                }),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(2, repo.undo_history().count());
+
+        block_on(repo.set_undo_history_len(1));
+        assert_eq!(
+            vec!["creating Thing 4"],
+            repo.undo_history()
+                .map(|change| change.display_undo().to_string())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn history_test_len_key_value() {
+        let mut repo = empty_repo();
+
+        for i in 0..5 {
+            block_on(
+                repo.modify(Change::Create {
+                    thing: Npc {
+                        name: format!("Thing {}", i).into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                }),
+            )
+            .unwrap();
+        }
+
+        block_on(repo.modify(Change::SetKeyValue {
+            key_value: KeyValue::UndoHistoryLen(Some(3)),
+        }))
+        .unwrap();
+        assert_eq!(3, repo.undo_history_len());
+        assert_eq!(3, repo.undo_history().count());
+        assert_eq!(
+            Ok(KeyValue::UndoHistoryLen(Some(3))),
+            block_on(repo.get_key_value(&KeyValue::UndoHistoryLen(None))),
+        );
+
+        block_on(repo.undo()).unwrap().unwrap();
+        assert_eq!(DEFAULT_UNDO_HISTORY_LEN, repo.undo_history_len());
+    }
+
+    #[test]
+    fn key_value_serialize_test() {
+        let key_value = KeyValue::Time(Some(Time::try_new(1, 2, 3, 4).unwrap()));
+        let json = serde_json::to_string(&key_value).unwrap();
+
+        assert_eq!(r#"{"key":"time","value":"1:02:03:04"}"#, json);
+        assert_eq!(key_value, serde_json::from_str(&json).unwrap());
+        assert_eq!(
+            KeyValue::Time(None),
+            serde_json::from_str(r#"{"key":"time","value":null}"#).unwrap(),
+        );
+        assert_eq!(
+            KeyValue::Cultures(None),
+            serde_json::from_str(r#"{"key":"cultures","value":null}"#).unwrap(),
+        );
+        assert!(serde_json::from_str::<KeyValue>(r#"{"key":"cultures","value":"[{}]"}"#).is_err());
+        assert_eq!(
+            KeyValue::Units(Some(Units::Metric)),
+            serde_json::from_str(r#"{"key":"units","value":"metric"}"#).unwrap(),
+        );
+        assert_eq!(
+            r#"{"key":"units","value":"imperial"}"#,
+            serde_json::to_string(&KeyValue::Units(Some(Units::Imperial))).unwrap(),
+        );
+        assert!(serde_json::from_str::<KeyValue>(r#"{"key":"units","value":"furlongs"}"#).is_err());
+        assert_eq!(
+            KeyValue::UndoHistoryLen(Some(20)),
+            serde_json::from_str(r#"{"key":"undoHistoryLen","value":"20"}"#).unwrap(),
+        );
+        assert!(
+            serde_json::from_str::<KeyValue>(r#"{"key":"undoHistoryLen","value":"lots"}"#).is_err()
+        );
+        assert!(serde_json::from_str::<KeyValue>(r#"{"key":"potato","value":null}"#).is_err());
+    }
+
+    #[test]
+    fn campaign_test() {
+        let mut repo = repo();
+
+        block_on(
+            repo.modify(Change::Create {
+                thing: Npc {
+                    name: "Penelope".into(),
+                    ..Default::default()
+                }
+                .into(),
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Ok("Strahd".to_string()),
+            block_on(repo.create_campaign(" Strahd ")),
+        );
+        assert_eq!(Ok("Strahd".to_string()), block_on(repo.get_campaign()));
+        assert_eq!(0, block_on(repo.journal()).unwrap().len());
+        assert_eq!(0, repo.recent().count());
+        assert_eq!(None, repo.undo_history().next());
+        assert_eq!(Err(Error::NotFound), block_on(repo.get_by_name("Olympus")));
+
+        block_on(
+            repo.modify(Change::CreateAndSave {
+                thing: Npc {
+                    name: "Ireena".into(),
+                    ..Default::default()
+                }
+                .into(),
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Err(Error::NameAlreadyExists),
+            block_on(repo.create_campaign("STRAHD")),
+        );
+        assert_eq!(Err(Error::MissingName), block_on(repo.create_campaign(" ")));
+        assert_eq!(
+            Err(Error::NotFound),
+            block_on(repo.switch_campaign("Phandelver")),
+        );
+        assert_eq!(
+            Ok(vec!["Default".to_string(), "Strahd".to_string()]),
+            block_on(repo.get_campaigns()),
+        );
+
+        assert_eq!(
+            Ok("Default".to_string()),
+            block_on(repo.switch_campaign("default")),
+        );
+        assert_eq!(4, block_on(repo.journal()).unwrap().len());
+        assert_eq!(2, repo.recent().count());
+        assert_eq!(
+            Some(&Change::Delete {
+                name: "Penelope".to_string(),
+                uuid: None,
+            }),
+            repo.undo_history().next(),
+        );
+        assert_eq!(Err(Error::NotFound), block_on(repo.get_by_name("Ireena")));
+
+        assert_eq!(
+            Ok("Strahd".to_string()),
+            block_on(repo.delete_campaign("strahd")),
+        );
+        assert_eq!(
+            Err(Error::NotFound),
+            block_on(repo.delete_campaign("strahd")),
+        );
+        assert_eq!(
+            Ok(vec!["Default".to_string()]),
+            block_on(repo.get_campaigns()),
+        );
+    }
+
     fn repo() -> Repository {
         repo_data_store().0
     }
@@ -2385,6 +3737,16 @@ mod test {
             self.data_store.get_things_by_name_start(name, limit).await
         }
 
+        async fn search_things(&self, query: &str, limit: Option<usize>) -> Result<Vec<Thing>, ()> {
+            self.tick()?;
+            self.data_store.search_things(query, limit).await
+        }
+
+        async fn get_npcs_by_place(&self, place_uuid: &Uuid) -> Result<Vec<Thing>, ()> {
+            self.tick()?;
+            self.data_store.get_npcs_by_place(place_uuid).await
+        }
+
         async fn save_thing(&mut self, thing: &Thing) -> Result<(), ()> {
             self.tick()?;
             self.data_store.save_thing(thing).await
@@ -2404,5 +3766,25 @@ mod test {
             self.tick()?;
             self.data_store.delete_value(key).await
         }
+
+        async fn get_campaign(&self) -> Result<String, ()> {
+            self.tick()?;
+            self.data_store.get_campaign().await
+        }
+
+        async fn get_campaigns(&self) -> Result<Vec<String>, ()> {
+            self.tick()?;
+            self.data_store.get_campaigns().await
+        }
+
+        async fn set_campaign(&mut self, name: &str) -> Result<(), ()> {
+            self.tick()?;
+            self.data_store.set_campaign(name).await
+        }
+
+        async fn delete_campaign(&mut self, name: &str) -> Result<(), ()> {
+            self.tick()?;
+            self.data_store.delete_campaign(name).await
+        }
     }
 }
@@ -0,0 +1,173 @@
+use super::filter::locations;
+use crate::utils::CaseInsensitiveStr;
+use crate::world::Thing;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Rank a list of things, typically the journal, by how well they match a search query. Every
+/// word of the query must appear somewhere in a thing for it to match, whether in its name, its
+/// species, ethnicity, occupation, subtype or description, or the names of the places containing it.
+/// Locations are resolved against the same list.
+pub fn rank<'a>(things: impl IntoIterator<Item = &'a Thing>, query: &str) -> Vec<&'a Thing> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let things: Vec<&Thing> = things.into_iter().collect();
+    let things_by_uuid: HashMap<_, _> = things
+        .iter()
+        .filter_map(|&thing| thing.uuid().map(|&uuid| (uuid, thing)))
+        .collect();
+    let mut results: Vec<(u32, &Thing)> = things
+        .into_iter()
+        .filter_map(|thing| {
+            let location_names = locations(thing, &things_by_uuid)
+                .into_iter()
+                .map(|place| place.name().to_string())
+                .collect();
+
+            score(thing, query, &terms, location_names).map(|score| (score, thing))
+        })
+        .collect();
+
+    results.sort_by(|(a_score, a), (b_score, b)| {
+        Reverse(a_score)
+            .cmp(&Reverse(b_score))
+            .then_with(|| a.name().to_string().cmp_ci(b.name().to_string()))
+    });
+
+    results.into_iter().map(|(_, thing)| thing).collect()
+}
+
+/// Score a single thing against the query, or `None` if any of the terms can't be found.
+fn score(thing: &Thing, query: &str, terms: &[String], location_names: Vec<String>) -> Option<u32> {
+    let name = thing.name().to_string();
+    let mut fields = vec![(name.to_lowercase(), 8)];
+
+    match thing {
+        Thing::Npc(npc) => {
+            if let Some(species) = npc.species.value() {
+                fields.push((species.as_str().to_string(), 3));
+            }
+            if let Some(ethnicity) = npc.ethnicity.value() {
+                fields.push((ethnicity.as_str().to_string(), 3));
+            }
+            if let Some(occupation) = npc.occupation.value() {
+                fields.push((occupation.as_str().to_string(), 3));
+            }
+        }
+        Thing::Place(place) => {
+            if let Some(subtype) = place.subtype.value() {
+                fields.push((subtype.as_str().to_string(), 3));
+            }
+            if let Some(description) = place.description.value() {
+                fields.push((description.to_lowercase(), 1));
+            }
+        }
+    }
+
+    fields.extend(
+        location_names
+            .into_iter()
+            .map(|location_name| (location_name.to_lowercase(), 2)),
+    );
+
+    let mut total = if name.eq_ci(query.trim()) { 100 } else { 0 };
+
+    for term in terms {
+        total += fields
+            .iter()
+            .map(|(text, weight)| {
+                if text
+                    .split(|c: char| !c.is_alphanumeric())
+                    .any(|word| word == term)
+                {
+                    weight * 2
+                } else if text
+                    .split(|c: char| !c.is_alphanumeric())
+                    .any(|word| word.starts_with(term.as_str()))
+                {
+                    weight + weight / 2
+                } else if text.contains(term.as_str()) {
+                    *weight
+                } else {
+                    0
+                }
+            })
+            .max()
+            .filter(|&score| score > 0)?;
+    }
+
+    Some(total)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::world::npc::{Npc, Species};
+    use crate::world::place::{Place, PlaceType};
+    use crate::world::PlaceUuid;
+    use uuid::Uuid;
+
+    #[test]
+    fn rank_test() {
+        let things = things();
+        let names = |query: &str| -> Vec<String> {
+            rank(&things, query)
+                .into_iter()
+                .map(|thing| thing.name().to_string())
+                .collect()
+        };
+
+        assert_eq!(vec!["Ellis Vance", "Mira Vance"], names("vance"));
+        assert_eq!(vec!["Mira Vance"], names("Mira Vance"));
+        assert_eq!(vec!["Prancing Pony", "Mira Vance"], names("pony"));
+        assert_eq!(vec!["Ellis Vance"], names("dwarf thorn"));
+        assert_eq!(vec!["Thornwick"], names("QUIET"));
+        assert_eq!(vec!["Prancing Pony"], names("inn"));
+        assert!(names("elf").is_empty());
+        assert!(names("").is_empty());
+    }
+
+    fn things() -> Vec<Thing> {
+        let thornwick = Uuid::new_v4();
+        let pony = Uuid::new_v4();
+
+        vec![
+            Place {
+                uuid: Some(thornwick.into()),
+                name: "Thornwick".into(),
+                subtype: "town".parse::<PlaceType>().ok().into(),
+                description: "A quiet town".into(),
+                ..Default::default()
+            }
+            .into(),
+            Place {
+                uuid: Some(pony.into()),
+                location_uuid: PlaceUuid::from(thornwick).into(),
+                name: "Prancing Pony".into(),
+                subtype: "inn".parse::<PlaceType>().ok().into(),
+                ..Default::default()
+            }
+            .into(),
+            Npc {
+                uuid: Some(Uuid::new_v4().into()),
+                location_uuid: PlaceUuid::from(thornwick).into(),
+                name: "Ellis Vance".into(),
+                species: Species::Dwarf.into(),
+                ..Default::default()
+            }
+            .into(),
+            Npc {
+                uuid: Some(Uuid::new_v4().into()),
+                location_uuid: PlaceUuid::from(pony).into(),
+                name: "Mira Vance".into(),
+                species: Species::Human.into(),
+                ..Default::default()
+            }
+            .into(),
+        ]
+    }
+}
@@ -0,0 +1,496 @@
+use super::backup::{
+    import as import_backup, BackupData, ImportOptions, ImportStats, KeyValueBackup,
+};
+use super::filter::ThingKind;
+use super::Repository;
+use crate::utils::CaseInsensitiveStr;
+use crate::world::npc::Npc;
+use crate::world::place::Place;
+use crate::world::{Field, Thing};
+use csv::{ReaderBuilder, StringRecord, Trim};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Column {
+    Type,
+    Name,
+    Gender,
+    Age,
+    Species,
+    Ethnicity,
+    Location,
+    Subtype,
+    Description,
+}
+
+/// A row of the spreadsheet that has been read successfully, but whose location may not yet be
+/// resolved.
+struct Row {
+    line: u64,
+    thing: Thing,
+    location: Option<String>,
+
+    /// The UUID of the row's location if it's a place already in the journal, which takes
+    /// precedence over a place of the same name in the file.
+    journal_location: Option<Uuid>,
+}
+
+/// Import characters and places from a CSV file with a header row, eg.
+///
+/// ```csv
+/// type,name,gender,age,species,location
+/// place,Thornwick,,,,
+/// npc,Ellis Vance,female,adult,human,Thornwick
+/// ```
+///
+/// Columns are matched by name, and unrecognized columns are ignored. Each row is a character
+/// unless its `type` is `place` or it has a `subtype` or `description`. Locations are looked up
+/// by name, first in the journal and then among the places in the file. Rows that can't be read
+/// are reported in the returned stats, while the rest are imported as though from a backup.
+pub async fn import(
+    repo: &mut Repository,
+    input: &str,
+    options: ImportOptions,
+) -> Result<ImportStats, String> {
+    let mut reader = ReaderBuilder::new()
+        .flexible(true)
+        .trim(Trim::All)
+        .from_reader(input.as_bytes());
+
+    let columns: Vec<Option<Column>> = reader
+        .headers()
+        .map_err(|e| format!("The CSV file could not be read: {}", e))?
+        .iter()
+        .map(|header| header.parse().ok())
+        .collect();
+
+    if !columns.contains(&Some(Column::Name)) {
+        return Err("The CSV file must have a `name` column.".to_string());
+    }
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+
+    for record in reader.records() {
+        match record {
+            Ok(record) => {
+                let line = record.position().map_or(0, |position| position.line());
+                match parse_row(&columns, &record) {
+                    Ok((thing, location)) => rows.push(Row {
+                        line,
+                        thing,
+                        location,
+                        journal_location: None,
+                    }),
+                    Err((kind, error)) => errors.push((line, kind, error)),
+                }
+            }
+            Err(e) => errors.push((
+                e.position().map_or(0, |position| position.line()),
+                ThingKind::Any,
+                "the row could not be read.".to_string(),
+            )),
+        }
+    }
+
+    // Places in the file are given UUIDs up front so that other rows can refer to them.
+    let mut places_by_name = HashMap::new();
+    for row in rows.iter_mut() {
+        if let Thing::Place(place) = &mut row.thing {
+            let uuid = Uuid::new_v4();
+            place.uuid = Some(uuid.into());
+            places_by_name.insert(place.name.to_string().to_lowercase(), uuid);
+        }
+    }
+
+    for row in rows.iter_mut() {
+        if let Some(location) = &row.location {
+            if let Ok(Thing::Place(Place {
+                uuid: Some(uuid), ..
+            })) = repo.get_by_name(location).await
+            {
+                row.journal_location = Some(*uuid.as_ref());
+            }
+        }
+    }
+
+    // A row whose location can't be found is rejected. If that row is a place, rows inside it
+    // must then be rejected in turn, wherever they appear in the file, so keep going until
+    // nothing more changes.
+    loop {
+        let count = rows.len();
+
+        rows.retain(|row| {
+            let location = match (&row.location, row.location_uuid(&places_by_name)) {
+                (Some(location), None) => location,
+                _ => return true,
+            };
+
+            errors.push((
+                row.line,
+                kind(&row.thing),
+                format!("there is no place named \"{}\".", location),
+            ));
+
+            if let Thing::Place(place) = &row.thing {
+                let name = place.name.to_string().to_lowercase();
+                if places_by_name.get(&name) == place.uuid.as_ref().map(|uuid| uuid.as_ref()) {
+                    places_by_name.remove(&name);
+                }
+            }
+
+            false
+        });
+
+        if rows.len() == count {
+            break;
+        }
+    }
+
+    let things = rows
+        .into_iter()
+        .map(|row| {
+            let location_uuid = row.location_uuid(&places_by_name);
+            let mut thing = row.thing;
+
+            match (&mut thing, location_uuid) {
+                (Thing::Npc(npc), Some(uuid)) => npc.location_uuid = Field::new(uuid.into()),
+                (Thing::Place(place), Some(uuid)) => place.location_uuid = Field::new(uuid.into()),
+                (_, None) => {}
+            }
+
+            thing
+        })
+        .collect();
+
+    let mut stats = import_backup(
+        repo,
+        BackupData::new(
+            things,
+            KeyValueBackup {
+                time: None,
+                cultures: None,
+            },
+        ),
+        options,
+    )
+    .await
+    .map_err(|_| "Failed to import.".to_string())?;
+
+    errors.sort_by_key(|&(line, _, _)| line);
+    for (line, kind, error) in errors {
+        stats.add_error(kind, format!("Row {}: {}", line, error));
+    }
+
+    Ok(stats)
+}
+
+impl Row {
+    /// The UUID of the row's location, if it has one that can be found. A place can't be its own
+    /// location.
+    fn location_uuid(&self, places_by_name: &HashMap<String, Uuid>) -> Option<Uuid> {
+        let location = self.location.as_ref()?;
+
+        self.journal_location
+            .or_else(|| places_by_name.get(&location.to_lowercase()).copied())
+            .filter(|&uuid| Some(&uuid) != self.thing.uuid())
+    }
+}
+
+/// Read a single row into a thing and the name of its location, if any.
+fn parse_row(
+    columns: &[Option<Column>],
+    record: &StringRecord,
+) -> Result<(Thing, Option<String>), (ThingKind, String)> {
+    let cells: Vec<(Column, &str)> = columns
+        .iter()
+        .zip(record.iter())
+        .filter_map(|(column, value)| column.map(|column| (column, value)))
+        .filter(|(_, value)| !value.is_empty())
+        .collect();
+    let cell = |column: Column| {
+        cells
+            .iter()
+            .find(|(c, _)| *c == column)
+            .map(|(_, value)| *value)
+    };
+
+    let kind = match cell(Column::Type) {
+        Some(s) if s.eq_ci("npc") || s.eq_ci("character") || s.eq_ci("person") => ThingKind::Npc,
+        Some(s) if s.eq_ci("place") => ThingKind::Place,
+        Some(s) => {
+            return Err((
+                ThingKind::Any,
+                format!(
+                    "\"{}\" is not a valid type, which must be `npc` or `place`.",
+                    s
+                ),
+            ))
+        }
+        None if cell(Column::Subtype).is_some() || cell(Column::Description).is_some() => {
+            ThingKind::Place
+        }
+        None => ThingKind::Npc,
+    };
+
+    let fail = |error: String| Err((kind, error));
+
+    let name = if let Some(name) = cell(Column::Name) {
+        name
+    } else {
+        return fail("a name is required.".to_string());
+    };
+
+    let mut thing: Thing = match kind {
+        ThingKind::Place => Place {
+            name: name.into(),
+            ..Default::default()
+        }
+        .into(),
+        _ => Npc {
+            name: name.into(),
+            ..Default::default()
+        }
+        .into(),
+    };
+
+    for &(column, value) in &cells {
+        let result = match (&mut thing, column) {
+            (_, Column::Type) | (_, Column::Name) | (_, Column::Location) => Ok(()),
+            (Thing::Npc(npc), Column::Gender) => parse_field(&mut npc.gender, value, "gender"),
+            (Thing::Npc(npc), Column::Age) => parse_field(&mut npc.age, value, "age"),
+            (Thing::Npc(npc), Column::Species) => parse_field(&mut npc.species, value, "species"),
+            (Thing::Npc(npc), Column::Ethnicity) => {
+                parse_field(&mut npc.ethnicity, value, "ethnicity")
+            }
+            (Thing::Place(place), Column::Subtype) => {
+                parse_field(&mut place.subtype, value, "place type")
+            }
+            (Thing::Place(place), Column::Description) => {
+                place.description = value.into();
+                Ok(())
+            }
+            (Thing::Npc(_), column) => Err(format!("characters don't have a {}.", column)),
+            (Thing::Place(_), column) => Err(format!("places don't have a {}.", column)),
+        };
+
+        if let Err(error) = result {
+            return fail(error);
+        }
+    }
+
+    Ok((thing, cell(Column::Location).map(str::to_string)))
+}
+
+fn parse_field<T: FromStr>(field: &mut Field<T>, value: &str, label: &str) -> Result<(), String> {
+    value
+        .parse()
+        .map(|value| *field = Field::new(value))
+        .map_err(|_| format!("\"{}\" is not a recognized {}.", value, label))
+}
+
+fn kind(thing: &Thing) -> ThingKind {
+    match thing {
+        Thing::Npc(_) => ThingKind::Npc,
+        Thing::Place(_) => ThingKind::Place,
+    }
+}
+
+impl FromStr for Column {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().replace('_', " ").as_str() {
+            "type" => Ok(Self::Type),
+            "name" => Ok(Self::Name),
+            "gender" => Ok(Self::Gender),
+            "age" => Ok(Self::Age),
+            "species" => Ok(Self::Species),
+            "ethnicity" => Ok(Self::Ethnicity),
+            "location" | "location name" | "parent" | "parent location" => Ok(Self::Location),
+            "subtype" => Ok(Self::Subtype),
+            "description" => Ok(Self::Description),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Type => write!(f, "type"),
+            Self::Name => write!(f, "name"),
+            Self::Gender => write!(f, "gender"),
+            Self::Age => write!(f, "age"),
+            Self::Species => write!(f, "species"),
+            Self::Ethnicity => write!(f, "ethnicity"),
+            Self::Location => write!(f, "location"),
+            Self::Subtype => write!(f, "subtype"),
+            Self::Description => write!(f, "description"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::storage::MemoryDataStore;
+    use crate::world::npc::{Age, Gender, Species};
+    use crate::world::place::PlaceType;
+    use tokio_test::block_on;
+
+    #[test]
+    fn import_test() {
+        let mut repo = Repository::new(MemoryDataStore::default());
+
+        let stats = block_on(import(
+            &mut repo,
+            "Type,Name,Gender,Age,Species,Ethnicity,Location,Subtype,Description,Notes
+place,Thornwick,,,,,,town,A quiet town,
+NPC,Ellis Vance,female,adult,human,,Prancing Pony,,,Owes money
+place,Prancing Pony,,,,,thornwick,inn,,
+npc,Mira Vance,sometimes,,,,,,,
+,Drifter,,,,,Nowhere,,,
+place,Nameless Hill,male,,,,,,,
+pet,Rover,,,,,,,,
+",
+            ImportOptions::default(),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            "Places: 2 created, 1 failed \\
+Characters: 1 created, 2 failed
+
+* Row 5: \"sometimes\" is not a recognized gender.
+* Row 6: there is no place named \"Nowhere\".
+* Row 7: places don't have a gender.
+* Row 8: \"pet\" is not a valid type, which must be `npc` or `place`.",
+            stats.to_string(),
+        );
+
+        let ellis = match block_on(repo.get_by_name("Ellis Vance")) {
+            Ok(Thing::Npc(npc)) => npc,
+            result => panic!("{:?}", result),
+        };
+        let pony = match block_on(repo.get_by_name("Prancing Pony")) {
+            Ok(Thing::Place(place)) => place,
+            result => panic!("{:?}", result),
+        };
+        let thornwick = match block_on(repo.get_by_name("Thornwick")) {
+            Ok(Thing::Place(place)) => place,
+            result => panic!("{:?}", result),
+        };
+
+        assert_eq!(Some(&Gender::Feminine), ellis.gender.value());
+        assert_eq!(Some(&Age::Adult), ellis.age.value());
+        assert_eq!(Some(&Species::Human), ellis.species.value());
+        assert_eq!(pony.uuid.as_ref(), ellis.location_uuid.value());
+        assert_eq!(thornwick.uuid.as_ref(), pony.location_uuid.value());
+        assert_eq!(
+            Some(&"town".parse::<PlaceType>().unwrap()),
+            thornwick.subtype.value()
+        );
+        assert_eq!(
+            Some("A quiet town"),
+            thornwick.description.value().map(String::as_str)
+        );
+    }
+
+    #[test]
+    fn import_test_rejected_location() {
+        let mut repo = Repository::new(MemoryDataStore::default());
+
+        let stats = block_on(import(
+            &mut repo,
+            "type,name,location
+npc,Bob,Hamlet
+place,Cottage,Hamlet
+place,Hamlet,Nowhere
+npc,Jo,Cottage
+place,Thornwick,
+npc,Ellis Vance,Thornwick
+",
+            ImportOptions::default(),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            "Places: 1 created, 2 failed \\
+Characters: 1 created, 2 failed
+
+* Row 2: there is no place named \"Hamlet\".
+* Row 3: there is no place named \"Hamlet\".
+* Row 4: there is no place named \"Nowhere\".
+* Row 5: there is no place named \"Cottage\".",
+            stats.to_string(),
+        );
+
+        assert!(block_on(repo.get_by_name("Cottage")).is_err());
+        assert!(block_on(repo.get_by_name("Jo")).is_err());
+        assert!(block_on(repo.get_by_name("Ellis Vance")).is_ok());
+    }
+
+    #[test]
+    fn import_test_existing_location() {
+        let mut repo = Repository::new(MemoryDataStore::default());
+
+        block_on(import(
+            &mut repo,
+            "type,name\nplace,Thornwick",
+            ImportOptions::default(),
+        ))
+        .unwrap();
+        let stats = block_on(import(
+            &mut repo,
+            "name,location\nEllis Vance,THORNWICK",
+            ImportOptions::default(),
+        ))
+        .unwrap();
+
+        assert_eq!("Characters: 1 created", stats.to_string());
+        assert_eq!(
+            block_on(repo.get_by_name("Thornwick"))
+                .unwrap()
+                .uuid()
+                .copied(),
+            match block_on(repo.get_by_name("Ellis Vance")) {
+                Ok(Thing::Npc(npc)) => npc.location_uuid.value().map(|uuid| *uuid.as_ref()),
+                result => panic!("{:?}", result),
+            },
+        );
+    }
+
+    #[test]
+    fn import_test_invalid() {
+        let mut repo = Repository::new(MemoryDataStore::default());
+
+        assert_eq!(
+            Err("The CSV file must have a `name` column.".to_string()),
+            block_on(import(
+                &mut repo,
+                "type,gender\nnpc,female",
+                ImportOptions::default()
+            ))
+            .map(|stats| stats.to_string()),
+        );
+
+        assert_eq!(
+            "Nothing to import.",
+            block_on(import(&mut repo, "name", ImportOptions::default()))
+                .unwrap()
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn column_from_str_test() {
+        assert_eq!(Ok(Column::Name), "Name".parse());
+        assert_eq!(Ok(Column::Location), "Parent_Location".parse());
+        assert_eq!(Ok(Column::Location), "location name".parse());
+        assert_eq!(Err(()), "notes".parse::<Column>());
+    }
+}
@@ -90,6 +90,9 @@ impl<'a> Iterator for QuotedWordIterator<'a> {
                             first_index - quote_char.len_utf8()..i + c.len_utf8(),
                         ));
                     }
+                } else if c == '"' && self.phrase[..i].ends_with(|p: char| p.is_ascii_digit()) {
+                    // A double quote straight after a digit is an inch mark, as in 5'4", rather
+                    // than the start of a quotation.
                 } else if c == '"' {
                     self.quote_len = Some(c.len_utf8());
                     break i;
@@ -243,6 +246,22 @@ mod test {
         assert!(input_iter.next().is_none());
     }
 
+    #[test]
+    fn quoted_word_iter_test_inch_mark() {
+        let input = "5'4\" \"tall\"";
+        let mut input_iter = quoted_words(input);
+
+        let word = input_iter.next().unwrap();
+        assert_eq!("5'4\"", word.as_str());
+        assert_eq!(0..4, *word.range());
+
+        let word = input_iter.next().unwrap();
+        assert_eq!("tall", word.as_str());
+        assert_eq!(5..11, *word.range());
+
+        assert!(input_iter.next().is_none());
+    }
+
     #[test]
     fn quoted_word_iter_test_single_letter() {
         let input = "🥔";
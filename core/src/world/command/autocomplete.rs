@@ -6,7 +6,6 @@ use crate::world::place::{Place, PlaceType};
 use crate::world::Thing;
 use async_trait::async_trait;
 use std::collections::HashSet;
-use std::str::FromStr;
 
 struct ParsedInput<'a> {
     name_desc: &'a str,
@@ -68,8 +67,9 @@ impl<'a> From<&'a str> for ParsedInput<'a> {
     }
 }
 
-fn autocomplete_trailing_name<T: FromStr + Into<Thing>>(
+fn autocomplete_trailing_name<T: Into<Thing>>(
     input: &str,
+    parse: &impl Fn(&str) -> Result<T, ()>,
 ) -> Option<AutocompleteSuggestion> {
     if !quoted_words(input)
         .skip(1)
@@ -91,7 +91,7 @@ fn autocomplete_trailing_name<T: FromStr + Into<Thing>>(
     let before_pos: usize = input_iter.map(|s| s.len()).sum();
     let after_pos = before_pos + len_named;
 
-    if let Ok(thing) = input[..before_pos].trim().parse::<T>().map(|t| t.into()) {
+    if let Ok(thing) = parse(input[..before_pos].trim()).map(|t| t.into()) {
         if after_pos >= input.trim_end().len() && thing.name().is_none() {
             let mut suggestion = input.to_string();
             if !suggestion.ends_with(char::is_whitespace) {
@@ -110,12 +110,13 @@ fn autocomplete_trailing_name<T: FromStr + Into<Thing>>(
     }
 }
 
-fn autocomplete_terms<T: Default + FromStr + Into<Thing>>(
+fn autocomplete_terms<T: Default + Into<Thing>>(
     input: &str,
+    parse: impl Fn(&str) -> Result<T, ()>,
     basic_terms: &[&str],
     vocabulary: &[(&str, &str, &[&str])],
 ) -> Vec<AutocompleteSuggestion> {
-    if let Some(result) = autocomplete_trailing_name::<T>(input) {
+    if let Some(result) = autocomplete_trailing_name(input, &parse) {
         return vec![result];
     }
 
@@ -143,7 +144,7 @@ fn autocomplete_terms<T: Default + FromStr + Into<Thing>>(
                 suggestion,
                 format!("create {}", thing.display_description()),
             )]
-        } else if let Ok(thing) = parsed.name_desc.parse::<T>().map(|t| t.into()) {
+        } else if let Ok(thing) = parse(parsed.name_desc).map(|t| t.into()) {
             let mut suggestions = Vec::new();
 
             let words: HashSet<&str> = quoted_words(parsed.desc_lower())
@@ -179,7 +180,7 @@ fn autocomplete_terms<T: Default + FromStr + Into<Thing>>(
                 .collect()
         };
 
-        if words.is_empty() || parsed.name_desc.parse::<T>().is_ok() {
+        if words.is_empty() || parse(parsed.name_desc).is_ok() {
             vocabulary
                 .iter()
                 .filter(|(_, _, terms)| !terms.iter().any(|term| words.contains(term)))
@@ -188,7 +189,7 @@ fn autocomplete_terms<T: Default + FromStr + Into<Thing>>(
                 .filter(|term| term.starts_with_ci(parsed.partial))
                 .map(|term| parsed.suggestion(term))
                 .filter_map(|term| {
-                    if let Ok(thing) = term.parse::<T>().map(|t| t.into()) {
+                    if let Ok(thing) = parse(&term).map(|t| t.into()) {
                         Some(AutocompleteSuggestion::new(
                             term,
                             format!("create {}", thing.display_description()),
@@ -222,7 +223,7 @@ fn autocomplete_terms<T: Default + FromStr + Into<Thing>>(
             .filter(|s| s.starts_with_ci(parsed.partial))
             .filter_map(|term| {
                 let suggestion = parsed.suggestion(term);
-                suggestion.parse::<T>().ok().map(|thing| {
+                parse(&suggestion).ok().map(|thing| {
                     AutocompleteSuggestion::new(
                         suggestion,
                         format!("create {}", thing.into().display_description()),
@@ -240,6 +241,7 @@ impl Autocomplete for Place {
     async fn autocomplete(input: &str, _app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
         autocomplete_terms::<ParsedThing<Place>>(
             input,
+            |s| s.parse(),
             &["place"],
             &[(
                 "place type",
@@ -252,7 +254,9 @@ impl Autocomplete for Place {
 
 #[async_trait(?Send)]
 impl Autocomplete for Npc {
-    async fn autocomplete(input: &str, _app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
+    async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
+        let cultures = app_meta.demographics.cultures();
+
         if let Some(word) = quoted_words(input).last().filter(|w| {
             let s = w.as_str();
             s.starts_with(|c: char| c.is_ascii_digit())
@@ -269,23 +273,31 @@ impl Autocomplete for Npc {
                 )
             };
 
-            if let Some(summary) =
-                term.parse::<ParsedThing<Thing>>()
-                    .ok()
-                    .and_then(|parsed_thing| {
-                        parsed_thing
-                            .thing
-                            .npc()
-                            .map(|npc| format!("create {}", npc.display_description()))
-                    })
+            if let Some(summary) = ParsedThing::<Thing>::parse_with_cultures(&term, cultures)
+                .ok()
+                .and_then(|parsed_thing| {
+                    parsed_thing
+                        .thing
+                        .npc()
+                        .map(|npc| format!("create {}", npc.display_description()))
+                })
             {
                 vec![AutocompleteSuggestion::new(term, summary)]
             } else {
                 Vec::new()
             }
         } else {
+            // Custom cultures are suggested alongside the built-in ethnicities.
+            let culture_words: Vec<String> = cultures
+                .iter()
+                .map(|culture| culture.name.to_lowercase())
+                .collect();
+            let mut ethnicity_words: Vec<&str> = Ethnicity::get_words().collect();
+            ethnicity_words.extend(culture_words.iter().map(String::as_str));
+
             autocomplete_terms::<ParsedThing<Npc>>(
                 input,
+                |s| ParsedThing::<Npc>::parse_with_cultures(s, cultures),
                 &["character", "npc", "person"],
                 &[
                     (
@@ -296,7 +308,7 @@ impl Autocomplete for Npc {
                     (
                         "ethnicity",
                         "specify an ethnicity (eg. \"elvish\")",
-                        &Ethnicity::get_words().collect::<Vec<_>>(),
+                        &ethnicity_words,
                     ),
                     (
                         "gender",
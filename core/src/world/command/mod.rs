@@ -1,4 +1,9 @@
-use super::{Field, Npc, Place, Thing};
+use super::npc::{Gender, NpcRelations, Relationship};
+use super::place::building::business::BusinessType;
+use super::place::building::BuildingType;
+use super::place::location::LocationType;
+use super::place::PlaceType;
+use super::{Field, Npc, Place, PlaceUuid, Thing};
 use crate::app::{
     AppMeta, Autocomplete, AutocompleteSuggestion, CommandAlias, CommandMatches, ContextAwareParse,
     Runnable,
@@ -10,21 +15,59 @@ use futures::join;
 use std::fmt;
 use std::ops::Range;
 
+pub use populate::{display_patrons, with_patrons};
+
 mod autocomplete;
 mod parse;
+mod populate;
+mod shop;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum WorldCommand {
+    Buy {
+        item: String,
+        name: String,
+    },
     Create {
         thing: ParsedThing<Thing>,
     },
     CreateMultiple {
         thing: Thing,
     },
+    CreateRelative {
+        name: String,
+        relationship: Relationship,
+        gender: Option<Gender>,
+    },
     Edit {
         name: String,
         diff: ParsedThing<Thing>,
     },
+    Family {
+        name: String,
+    },
+    Patrons {
+        name: String,
+    },
+    Populate {
+        name: String,
+    },
+    Restock {
+        name: String,
+    },
+    SetEmployee {
+        name: String,
+        place: String,
+        is_owner: bool,
+    },
+    SetHome {
+        name: String,
+        place: String,
+    },
+    SetLocation {
+        name: String,
+        place: String,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -38,16 +81,23 @@ pub struct ParsedThing<T> {
 impl Runnable for WorldCommand {
     async fn run(self, input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
         match self {
+            Self::Buy { item, name } => shop::buy(app_meta, item, name).await,
             Self::Create {
                 thing: parsed_thing,
             } => {
                 let diff = parsed_thing.thing;
                 let unknown_words = parsed_thing.unknown_words.to_owned();
+                let location = location_type(app_meta, &diff).await;
                 let mut output = None;
 
                 for _ in 0..10 {
                     let mut thing = diff.clone();
-                    thing.regenerate(&mut app_meta.rng, &app_meta.demographics);
+                    regenerate(app_meta, &mut thing, location.as_ref());
+
+                    if is_generated_name_taken(app_meta, &thing).await {
+                        continue;
+                    }
+
                     let mut temp_output = format!(
                         "{}",
                         thing.display_details(
@@ -55,20 +105,26 @@ impl Runnable for WorldCommand {
                                 .repository
                                 .load_relations(&thing)
                                 .await
-                                .unwrap_or_default()
+                                .unwrap_or_default(),
+                            app_meta.repository.units().await,
                         )
                     );
                     let mut command_alias = None;
 
                     let change = match thing.name() {
                         Field::Locked(Some(name)) => {
-                            temp_output.push_str(&format!(
+                            let notice = format!(
                                     "\n\n_Because you specified a name, {name} has been automatically added to your `journal`. Use `undo` to remove {them}._",
                                     name = name,
                                     them = thing.gender().them(),
-                                ));
+                                );
+
+                            let (change, patrons) =
+                                with_patrons(app_meta, Change::CreateAndSave { thing }).await?;
+                            temp_output.push_str(&display_patrons(&patrons));
+                            temp_output.push_str(&notice);
 
-                            Change::CreateAndSave { thing }
+                            change
                         }
                         Field::Unlocked(Some(name)) => {
                             temp_output.push_str(&format!(
@@ -150,12 +206,19 @@ impl Runnable for WorldCommand {
                     thing.display_description(),
                 );
 
+                let location = location_type(app_meta, &thing).await;
+
                 for i in 1..=10 {
                     let mut thing_output = None;
 
                     for _ in 0..10 {
                         let mut thing = thing.clone();
-                        thing.regenerate(&mut app_meta.rng, &app_meta.demographics);
+                        regenerate(app_meta, &mut thing, location.as_ref());
+
+                        if is_generated_name_taken(app_meta, &thing).await {
+                            continue;
+                        }
+
                         let temp_thing_output = format!(
                             "{}~{}~ {}",
                             if i == 1 { "\n\n" } else { "\\\n" },
@@ -200,15 +263,197 @@ impl Runnable for WorldCommand {
 
                 Ok(output)
             }
+            Self::CreateRelative {
+                name,
+                relationship,
+                gender,
+            } => {
+                let npc = match app_meta.repository.get_by_name(&name).await {
+                    Ok(Thing::Npc(npc)) => npc,
+                    _ => return Err(format!(r#"There is no character named "{}"."#, name)),
+                };
+
+                let uuid = if let Some(uuid) = &npc.uuid {
+                    uuid.clone()
+                } else {
+                    return Err(format!(
+                        "{name} must be in your journal before you can add relatives. Use `save {name}` first.",
+                        name = name,
+                    ));
+                };
+
+                let relations: NpcRelations = app_meta
+                    .repository
+                    .load_relations(&npc.clone().into())
+                    .await
+                    .unwrap_or_default()
+                    .into();
+
+                let mut diff = Npc::default();
+
+                if let Some(gender) = gender {
+                    diff.gender = gender.into();
+                }
+
+                if let Some(species) = npc.species.value() {
+                    diff.age_years = species
+                        .gen_relative_age_years(
+                            &mut app_meta.rng,
+                            relationship,
+                            npc.age_years.value().copied(),
+                        )
+                        .ok_or_else(|| {
+                            format!(
+                                "{} is too young to have a {}.",
+                                name,
+                                relationship.word(gender.as_ref()),
+                            )
+                        })?
+                        .into();
+                    diff.species = (*species).into();
+                }
+
+                // Spouses don't share a family background, but blood relatives do.
+                if relationship != Relationship::Spouse {
+                    if let Some(ethnicity) = npc.ethnicity.value() {
+                        diff.ethnicity = (*ethnicity).into();
+                    }
+                }
+
+                diff.add_relative(relationship.inverse(), uuid);
+
+                // A sibling shares the same parents and siblings, and a child is probably the
+                // child of their parent's spouse too.
+                let shared: &[(Relationship, Relationship)] = match relationship {
+                    Relationship::Sibling => &[
+                        (Relationship::Parent, Relationship::Parent),
+                        (Relationship::Sibling, Relationship::Sibling),
+                    ],
+                    Relationship::Child => &[(Relationship::Spouse, Relationship::Parent)],
+                    Relationship::Parent | Relationship::Spouse => &[],
+                };
+
+                for &(theirs, ours) in shared {
+                    relations
+                        .relatives(theirs)
+                        .iter()
+                        .filter_map(|relative| relative.uuid.clone())
+                        .for_each(|relative_uuid| diff.add_relative(ours, relative_uuid));
+                }
+
+                Self::Create {
+                    thing: ParsedThing {
+                        thing: diff.into(),
+                        unknown_words: Vec::new(),
+                        word_count: 0,
+                    },
+                }
+                .run(input, app_meta)
+                .await
+            }
+            Self::Patrons { name } => populate::patrons(app_meta, name).await,
+            Self::Populate { name } => populate::populate(app_meta, name).await,
+            Self::Restock { name } => shop::restock(app_meta, name).await,
+            Self::SetEmployee {
+                name,
+                place,
+                is_owner,
+            } => set_employee(input, app_meta, name, place, is_owner).await,
+            Self::SetHome { name, place } => set_place(input, app_meta, name, place, true).await,
+            Self::SetLocation { name, place } => {
+                set_place(input, app_meta, name, place, false).await
+            }
+            Self::Family { name } => {
+                let npc = match app_meta.repository.get_by_name(&name).await {
+                    Ok(thing @ Thing::Npc(_)) => thing,
+                    _ => return Err(format!(r#"There is no character named "{}"."#, name)),
+                };
+
+                let relations: NpcRelations = app_meta
+                    .repository
+                    .load_relations(&npc)
+                    .await
+                    .map_err(|_| "Couldn't load the family from your journal.".to_string())?
+                    .into();
+
+                let name = npc.name().to_string();
+
+                if !relations.has_relatives() {
+                    return Err(format!(
+                        "{name} has no known relatives. You can create one with `{name}'s daughter` or `generate a sibling for {name}`.",
+                        name = name,
+                    ));
+                }
+
+                let mut output = format!("# Family of {}", name);
+
+                for &relationship in Relationship::ALL.iter() {
+                    let relatives = relations.relatives(relationship);
+
+                    if !relatives.is_empty() {
+                        output.push_str("\n\n## ");
+                        output.push_str(relationship.plural());
+
+                        relatives.iter().enumerate().for_each(|(i, relative)| {
+                            if i > 0 {
+                                output.push('\\');
+                            }
+
+                            output.push_str(&format!("\n{}", relative.display_summary()));
+                        });
+                    }
+                }
+
+                Ok(output)
+            }
             Self::Edit { name, diff } => {
                 let ParsedThing {
-                    thing: diff,
+                    thing: mut diff,
                     unknown_words,
                     word_count: _,
                 } = diff;
 
                 let thing_type = diff.as_str();
 
+                // Someone moving to a new home takes up a suitable line of work, as does someone
+                // without an occupation who turns up somewhere, unless their occupation was
+                // already settled by hand. Merely visiting a place doesn't change it.
+                if let Thing::Npc(npc_diff) = &mut diff {
+                    if npc_diff.occupation.is_unset() {
+                        if let Ok(Thing::Npc(mut npc)) =
+                            app_meta.repository.get_by_name(&name).await
+                        {
+                            let place_uuid = match (
+                                npc_diff.home_uuid.value(),
+                                npc_diff.location_uuid.value(),
+                            ) {
+                                (Some(home_uuid), _)
+                                    if npc.home_uuid.value() != Some(home_uuid) =>
+                                {
+                                    Some(home_uuid.clone())
+                                }
+                                (_, Some(location_uuid)) if npc.occupation.is_none() => {
+                                    Some(location_uuid.clone())
+                                }
+                                _ => None,
+                            };
+
+                            if let Some(place_uuid) = place_uuid {
+                                if let Some(location) = place_type(app_meta, &place_uuid).await {
+                                    if npc.occupation.is_unlocked() {
+                                        npc.regenerate_occupation(
+                                            &mut app_meta.rng,
+                                            Some(&location),
+                                        );
+                                        npc_diff.occupation =
+                                            npc.occupation.value().cloned().into();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
                 match app_meta.repository.modify(Change::Edit {
                         name: name.clone(),
                         uuid: None,
@@ -216,12 +461,12 @@ impl Runnable for WorldCommand {
                     }).await {
                     Ok(Some(thing)) if matches!(app_meta.repository.undo_history().next(), Some(Change::EditAndUnsave { .. })) => Ok(format!(
                         "{}\n\n_{} was successfully edited and automatically saved to your `journal`. Use `undo` to reverse this._",
-                        thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
+                        thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default(), app_meta.repository.units().await),
                         name,
                     )),
                     Ok(Some(thing)) => Ok(format!(
                         "{}\n\n_{} was successfully edited. Use `undo` to reverse this._",
-                        thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
+                        thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default(), app_meta.repository.units().await),
                         name,
                     )),
                     Err((_, RepositoryError::NotFound)) => Err(format!(r#"There is no {} named "{}"."#, thing_type, name)),
@@ -238,19 +483,175 @@ impl ContextAwareParse for WorldCommand {
     async fn parse_input(input: &str, app_meta: &AppMeta) -> CommandMatches<Self> {
         let mut matches = CommandMatches::default();
 
+        if let Some((name, relationship, gender)) = parse::parse_relative(input) {
+            if let Ok(Thing::Npc(npc)) = app_meta.repository.get_by_name(name).await {
+                matches.push_canonical(Self::CreateRelative {
+                    name: npc.name.to_string(),
+                    relationship,
+                    gender,
+                });
+            }
+        }
+
+        if let Some(name) = parse::parse_family(input) {
+            if let Ok(Thing::Npc(npc)) = app_meta.repository.get_by_name(name).await {
+                matches.push_canonical(Self::Family {
+                    name: npc.name.to_string(),
+                });
+            }
+        }
+
+        if let Some(name) = parse::parse_populate(input) {
+            if let Ok(Thing::Place(place)) = app_meta.repository.get_by_name(name).await {
+                matches.push_canonical(Self::Populate {
+                    name: place.name.to_string(),
+                });
+            }
+        }
+
+        if let Some(name) = parse::parse_patrons(input) {
+            if let Ok(Thing::Place(place)) = app_meta.repository.get_by_name(name).await {
+                matches.push_canonical(Self::Patrons {
+                    name: place.name.to_string(),
+                });
+            }
+        }
+
+        if let Some(name) = parse::parse_restock(input) {
+            if let Ok(Thing::Place(place)) = app_meta.repository.get_by_name(name).await {
+                matches.push_canonical(Self::Restock {
+                    name: place.name.to_string(),
+                });
+            }
+        }
+
+        if let Some((item, name)) = parse::parse_buy(input) {
+            if let Ok(Thing::Place(place)) = app_meta.repository.get_by_name(name).await {
+                matches.push_canonical(Self::Buy {
+                    item: item.to_string(),
+                    name: place.name.to_string(),
+                });
+
+                return matches;
+            }
+        }
+
+        let cultures = app_meta.demographics.cultures();
+
         if let Some(Ok(thing)) = input
             .strip_prefix_ci("create ")
-            .map(|s| s.parse::<ParsedThing<Thing>>())
+            .map(|s| ParsedThing::<Thing>::parse_with_cultures(s, cultures))
         {
             if thing.unknown_words.is_empty() {
                 matches.push_canonical(Self::Create { thing });
             } else {
                 matches.push_fuzzy(Self::Create { thing });
             }
-        } else if let Ok(thing) = input.parse::<ParsedThing<Thing>>() {
+        } else if let Ok(thing) = ParsedThing::<Thing>::parse_with_cultures(input, cultures) {
             matches.push_fuzzy(Self::Create { thing });
         }
 
+        if let Some((name, languages)) = parse::parse_languages(input) {
+            let word_count = languages.len();
+
+            let (name, languages) = match app_meta.repository.get_by_name(name).await {
+                Ok(Thing::Npc(npc)) => {
+                    let mut merged = npc.languages.value().cloned().unwrap_or_default();
+                    languages.into_iter().for_each(|language| {
+                        if !merged.contains(&language) {
+                            merged.push(language);
+                        }
+                    });
+                    (npc.name.to_string(), merged)
+                }
+                // As with "is" below, a missing character is reported when the command is run.
+                _ => (name.to_string(), languages),
+            };
+
+            matches.push_canonical(Self::Edit {
+                name,
+                diff: ParsedThing {
+                    thing: Npc {
+                        languages: languages.into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                    unknown_words: Vec::new(),
+                    word_count,
+                },
+            });
+        }
+
+        if let Some((place, name, is_owner)) = parse::parse_employment(input) {
+            if let (Ok(Thing::Place(place)), Ok(Thing::Npc(npc))) = join!(
+                app_meta.repository.get_by_name(place),
+                app_meta.repository.get_by_name(name),
+            ) {
+                matches.push_canonical(Self::SetEmployee {
+                    name: npc.name.to_string(),
+                    place: place.name.to_string(),
+                    is_owner,
+                });
+
+                return matches;
+            }
+        }
+
+        if let Some((name, services)) = parse::parse_services(input) {
+            if let Ok(Thing::Place(place)) = app_meta.repository.get_by_name(name).await {
+                matches.push_canonical(Self::Edit {
+                    name: place.name.to_string(),
+                    diff: ParsedThing {
+                        thing: Place {
+                            services: services.to_string().into(),
+                            ..Default::default()
+                        }
+                        .into(),
+                        unknown_words: Vec::new(),
+                        word_count: 1,
+                    },
+                });
+
+                return matches;
+            }
+        }
+
+        if let Some((name, menu)) = parse::parse_menu(input) {
+            if let Ok(Thing::Place(place)) = app_meta.repository.get_by_name(name).await {
+                matches.push_canonical(Self::Edit {
+                    name: place.name.to_string(),
+                    diff: ParsedThing {
+                        thing: Place {
+                            menu: menu.to_string().into(),
+                            ..Default::default()
+                        }
+                        .into(),
+                        unknown_words: Vec::new(),
+                        word_count: 1,
+                    },
+                });
+
+                return matches;
+            }
+        }
+
+        if let Some((name, place, is_home)) = parse::parse_location(input) {
+            if let (Ok(Thing::Npc(npc)), Ok(Thing::Place(place))) = join!(
+                app_meta.repository.get_by_name(name),
+                app_meta.repository.get_by_name(place),
+            ) {
+                let (name, place) = (npc.name.to_string(), place.name.to_string());
+
+                if is_home {
+                    matches.push_canonical(Self::SetHome { name, place });
+                } else {
+                    matches.push_canonical(Self::SetLocation { name, place });
+                }
+
+                return matches;
+            }
+        }
+
         if let Some(word) = quoted_words(input)
             .skip(1)
             .find(|word| word.as_str().eq_ci("is"))
@@ -263,9 +664,11 @@ impl ContextAwareParse for WorldCommand {
             let (diff, thing) = if let Ok(thing) = app_meta.repository.get_by_name(name).await {
                 (
                     match thing {
-                        Thing::Npc(_) => description
-                            .parse::<ParsedThing<Npc>>()
-                            .map(|npc| npc.into_thing()),
+                        Thing::Npc(_) => ParsedThing::<Npc>::parse_with_cultures(
+                            description,
+                            app_meta.demographics.cultures(),
+                        )
+                        .map(|npc| npc.into_thing()),
                         Thing::Place(_) => description
                             .parse::<ParsedThing<Place>>()
                             .map(|npc| npc.into_thing()),
@@ -346,6 +749,109 @@ impl Autocomplete for WorldCommand {
             }
         }
 
+        if let Some((name, word)) = input.rsplit_once("'s ") {
+            if let Ok(Thing::Npc(_)) = app_meta.repository.get_by_name(name).await {
+                suggestions.extend(
+                    Relationship::get_words()
+                        .iter()
+                        .filter(|relative| relative.starts_with_ci(word))
+                        .map(|relative| {
+                            AutocompleteSuggestion::new(
+                                format!("{}'s {}", name, relative),
+                                format!("create {}", relative),
+                            )
+                        }),
+                );
+
+                if "family".starts_with_ci(word) {
+                    suggestions.push(AutocompleteSuggestion::new(
+                        format!("{}'s family", name),
+                        "show relatives",
+                    ));
+                }
+            }
+        }
+
+        if let Some(name) = input.strip_prefix_ci("populate ") {
+            if let Ok(things) = app_meta.repository.get_by_name_start(name, Some(10)).await {
+                suggestions.extend(things.iter().filter_map(|thing| match thing {
+                    Thing::Place(Place {
+                        uuid: Some(_),
+                        subtype,
+                        ..
+                    }) if matches!(
+                        subtype.value(),
+                        Some(PlaceType::Location(LocationType::Settlement(_)))
+                    ) =>
+                    {
+                        Some(AutocompleteSuggestion::new(
+                            format!("populate {}", thing.name()),
+                            "add places and characters",
+                        ))
+                    }
+                    _ => None,
+                }));
+            }
+        }
+
+        if let Some(name) = ["generate patrons for ", "patrons for "]
+            .iter()
+            .find_map(|prefix| input.strip_prefix_ci(prefix))
+        {
+            if let Ok(things) = app_meta.repository.get_by_name_start(name, Some(10)).await {
+                suggestions.extend(things.iter().filter_map(|thing| match thing {
+                    Thing::Place(Place {
+                        uuid: Some(_),
+                        subtype,
+                        ..
+                    }) if matches!(
+                        subtype.value(),
+                        Some(PlaceType::Building(BuildingType::Business(
+                            BusinessType::Inn | BusinessType::Bar
+                        )))
+                    ) =>
+                    {
+                        Some(AutocompleteSuggestion::new(
+                            format!("generate patrons for {}", thing.name()),
+                            "add customers",
+                        ))
+                    }
+                    _ => None,
+                }));
+            }
+        }
+
+        if let Some(name) = input.strip_prefix_ci("restock ") {
+            if let Ok(things) = app_meta.repository.get_by_name_start(name, Some(10)).await {
+                suggestions.extend(things.iter().filter_map(|thing| match thing {
+                    Thing::Place(place) if place.inventory.is_some() => {
+                        Some(AutocompleteSuggestion::new(
+                            format!("restock {}", thing.name()),
+                            "refresh shop inventory",
+                        ))
+                    }
+                    _ => None,
+                }));
+            }
+        }
+
+        if let Some((item, name)) = input
+            .strip_prefix_ci("buy ")
+            .and_then(|s| s.split_once(" from "))
+        {
+            if let Ok(things) = app_meta.repository.get_by_name_start(name, Some(10)).await {
+                suggestions.extend(things.iter().filter_map(|thing| match thing {
+                    Thing::Place(place) if place.inventory.is_some() => {
+                        Some(AutocompleteSuggestion::new(
+                            format!("buy {} from {}", item, thing.name()),
+                            "buy from shop",
+                        ))
+                    }
+                    _ => None,
+                }));
+            }
+        }
+
         if let Ok(thing) = app_meta.repository.get_by_name(input.trim_end()).await {
             suggestions.push(AutocompleteSuggestion::new(
                 if input.ends_with(char::is_whitespace) {
@@ -413,13 +919,88 @@ impl Autocomplete for WorldCommand {
 impl fmt::Display for WorldCommand {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
+            Self::Buy { item, name } => write!(f, "buy {} from {}", item, name),
             Self::Create { thing } => write!(f, "create {}", thing.thing.display_description()),
             Self::CreateMultiple { thing } => {
                 write!(f, "create  multiple {}", thing.display_description())
             }
-            Self::Edit { name, diff } => {
-                write!(f, "{} is {}", name, diff.thing.display_description())
-            }
+            Self::CreateRelative {
+                name,
+                relationship,
+                gender,
+            } => write!(f, "{}'s {}", name, relationship.word(gender.as_ref())),
+            Self::Edit { name, diff } => match &diff.thing {
+                // A diff that only sets languages came from "Mira speaks Elvish".
+                Thing::Npc(npc)
+                    if npc.languages.is_some()
+                        && *npc
+                            == (Npc {
+                                languages: npc.languages.clone(),
+                                ..Default::default()
+                            }) =>
+                {
+                    write!(f, "{} speaks ", name)?;
+                    if let Some(languages) = npc.languages.value() {
+                        for (i, language) in languages.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            write!(f, "{}", language)?;
+                        }
+                    }
+                    Ok(())
+                }
+                // Likewise, a diff that only sets services came from "X offers hot baths".
+                Thing::Place(place)
+                    if place.services.is_some()
+                        && *place
+                            == (Place {
+                                services: place.services.clone(),
+                                ..Default::default()
+                            }) =>
+                {
+                    write!(f, "{} offers {}", name, place.services)
+                }
+                // And one that only sets the menu came from "X serves roast boar".
+                Thing::Place(place)
+                    if place.menu.is_some()
+                        && *place
+                            == (Place {
+                                menu: place.menu.clone(),
+                                ..Default::default()
+                            }) =>
+                {
+                    write!(f, "{} serves {}", name, place.menu)
+                }
+                // Adjectives such as "wealthy" don't need a trailing "place" when editing.
+                Thing::Place(place) if place.subtype.is_none() => write!(
+                    f,
+                    "{} is {}",
+                    name,
+                    place
+                        .display_description()
+                        .to_string()
+                        .trim_end_matches("place")
+                        .trim_end(),
+                ),
+                thing => write!(f, "{} is {}", name, thing.display_description()),
+            },
+            Self::Family { name } => write!(f, "family of {}", name),
+            Self::Patrons { name } => write!(f, "generate patrons for {}", name),
+            Self::Populate { name } => write!(f, "populate {}", name),
+            Self::Restock { name } => write!(f, "restock {}", name),
+            Self::SetEmployee {
+                name,
+                place,
+                is_owner: true,
+            } => write!(f, "{} owns {}", name, place),
+            Self::SetEmployee {
+                name,
+                place,
+                is_owner: false,
+            } => write!(f, "{} works at {}", name, place),
+            Self::SetHome { name, place } => write!(f, "{} lives in {}", name, place),
+            Self::SetLocation { name, place } => write!(f, "{} is in {}", name, place),
         }
     }
 }
@@ -450,6 +1031,137 @@ impl<T: Into<Thing>> From<ParsedThing<T>> for Thing {
     }
 }
 
+/// Set a character's home or current location to a place in the journal. Places need a UUID to
+/// be referred to, so unsaved places are rejected.
+async fn set_place(
+    input: &str,
+    app_meta: &mut AppMeta,
+    name: String,
+    place_name: String,
+    is_home: bool,
+) -> Result<String, String> {
+    let place = match app_meta.repository.get_by_name(&place_name).await {
+        Ok(Thing::Place(place)) => place,
+        _ => return Err(format!(r#"There is no place named "{}"."#, place_name)),
+    };
+
+    let uuid = if let Some(uuid) = place.uuid {
+        uuid
+    } else {
+        return Err(format!(
+            "{place} must be in your journal before anyone can be placed there. Use `save {place}` first.",
+            place = place_name,
+        ));
+    };
+
+    let mut diff = Npc::default();
+    if is_home {
+        diff.home_uuid = uuid.into();
+    } else {
+        diff.location_uuid = uuid.into();
+    }
+
+    WorldCommand::Edit {
+        name,
+        diff: ParsedThing {
+            thing: diff.into(),
+            unknown_words: Vec::new(),
+            word_count: 1,
+        },
+    }
+    .run(input, app_meta)
+    .await
+}
+
+/// The type of the place that a character is located in, so that their occupation can suit it.
+async fn location_type(app_meta: &AppMeta, thing: &Thing) -> Option<PlaceType> {
+    match thing {
+        Thing::Npc(npc) => place_type(app_meta, npc.location_uuid.value()?).await,
+        Thing::Place(_) => None,
+    }
+}
+
+async fn place_type(app_meta: &AppMeta, uuid: &PlaceUuid) -> Option<PlaceType> {
+    match app_meta
+        .repository
+        .get_by_uuid(&uuid.to_owned().into())
+        .await
+    {
+        Ok(Thing::Place(place)) => place.subtype.value().copied(),
+        _ => None,
+    }
+}
+
+fn regenerate(app_meta: &mut AppMeta, thing: &mut Thing, location: Option<&PlaceType>) {
+    match thing {
+        Thing::Npc(npc) => npc.regenerate_at(&mut app_meta.rng, &app_meta.demographics, location),
+        Thing::Place(_) => thing.regenerate(&mut app_meta.rng, &app_meta.demographics),
+    }
+}
+
+/// The name generators can't see the journal, so a generated name is checked against it before
+/// the thing is shown, and a new one generated if it's already taken. A name given by the user is
+/// left for the repository to reject, since that deserves an error message.
+async fn is_generated_name_taken(app_meta: &AppMeta, thing: &Thing) -> bool {
+    match thing.name() {
+        Field::Unlocked(Some(name)) => app_meta.repository.get_by_name(name).await.is_ok(),
+        _ => false,
+    }
+}
+
+/// Make a character the owner of a place, or add them to its staff. Characters need a UUID to
+/// be referred to, so unsaved characters are rejected.
+async fn set_employee(
+    input: &str,
+    app_meta: &mut AppMeta,
+    name: String,
+    place_name: String,
+    is_owner: bool,
+) -> Result<String, String> {
+    let (npc, place) = match join!(
+        app_meta.repository.get_by_name(&name),
+        app_meta.repository.get_by_name(&place_name),
+    ) {
+        (Ok(Thing::Npc(npc)), Ok(Thing::Place(place))) => (npc, place),
+        (Ok(Thing::Npc(_)), _) => {
+            return Err(format!(r#"There is no place named "{}"."#, place_name))
+        }
+        _ => return Err(format!(r#"There is no character named "{}"."#, name)),
+    };
+
+    let uuid = if let Some(uuid) = npc.uuid {
+        uuid
+    } else {
+        return Err(format!(
+            "{name} must be in your journal before {they} can work anywhere. Use `save {name}` first.",
+            name = name,
+            they = npc.gender().they(),
+        ));
+    };
+
+    let mut diff = Place::default();
+    if is_owner {
+        diff.owner = uuid.into();
+    } else {
+        let mut staff = place.staff.value().cloned().unwrap_or_default();
+        if !staff.contains(&uuid) {
+            staff.push(uuid);
+        }
+        diff.staff = staff.into();
+    }
+
+    WorldCommand::Edit {
+        name: place_name,
+        diff: ParsedThing {
+            thing: diff.into(),
+            unknown_words: Vec::new(),
+            word_count: 1,
+        },
+    }
+    .run(input, app_meta)
+    .await
+}
+
 fn append_unknown_words_notice(
     mut output: String,
     input: &str,
@@ -500,7 +1212,7 @@ mod test {
     use super::*;
     use crate::app::assert_autocomplete;
     use crate::storage::NullDataStore;
-    use crate::world::npc::{Age, Gender, Species};
+    use crate::world::npc::{Age, Gender, Language, Species};
     use crate::world::place::PlaceType;
     use crate::Event;
     use tokio_test::block_on;
@@ -560,6 +1272,28 @@ mod test {
                 }),
                 block_on(WorldCommand::parse_input("Spot is a good boy", &app_meta)),
             );
+
+            let command = WorldCommand::Edit {
+                name: "Spot".into(),
+                diff: ParsedThing {
+                    thing: Npc {
+                        languages: vec![Language::Elvish, Language::DeepSpeech].into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                    unknown_words: Vec::new(),
+                    word_count: 2,
+                },
+            };
+
+            assert_eq!(
+                CommandMatches::new_canonical(command.clone()),
+                block_on(WorldCommand::parse_input(
+                    "spot speaks Elvish and deep speech",
+                    &app_meta,
+                )),
+            );
+            assert_eq!("Spot speaks Elvish, Deep Speech", command.to_string());
         }
     }
 
@@ -584,6 +1318,7 @@ mod test {
         [
             ("npc", "create person"),
             // Species
+            ("aasimar", "create aasimar"),
             ("dragonborn", "create dragonborn"),
             ("dwarf", "create dwarf"),
             ("elf", "create elf"),
@@ -592,6 +1327,9 @@ mod test {
             ("half-orc", "create half-orc"),
             ("halfling", "create halfling"),
             ("human", "create human"),
+            ("kobold", "create kobold"),
+            ("lizardfolk", "create lizardfolk"),
+            ("tabaxi", "create tabaxi"),
             ("tiefling", "create tiefling"),
             // PlaceType
             ("inn", "create inn"),
@@ -1,5 +1,9 @@
 use crate::utils::{capitalize, quoted_words, CaseInsensitiveStr};
 use crate::world::command::ParsedThing;
+use crate::world::npc::{
+    parse_height, parse_weight, Culture, Gender, Language, Relationship, Size,
+};
+use crate::world::place::parse_floors;
 use crate::world::{Field, Npc, Place};
 use std::str::FromStr;
 
@@ -46,6 +50,25 @@ fn split_name(input: &str) -> Option<(&str, &str)> {
     }
 }
 
+/// Split "a temple dedicated to the Morninglord" into the description and the deity.
+fn split_worship(input: &str) -> Option<(&str, &str)> {
+    let mut words = quoted_words(input).peekable();
+
+    while let Some(word) = words.next() {
+        if word.as_str().eq_ci("dedicated") {
+            if let Some(next_word) = words.peek().filter(|w| w.as_str().eq_ci("to")) {
+                let worship = input[next_word.range().end..].trim();
+
+                if !worship.is_empty() {
+                    return Some((input[..word.range().start].trim_end(), worship));
+                }
+            }
+        }
+    }
+
+    None
+}
+
 impl FromStr for ParsedThing<Place> {
     type Err = ();
 
@@ -61,6 +84,14 @@ impl FromStr for ParsedThing<Place> {
             input
         };
 
+        let description = if let Some((description, worship)) = split_worship(description) {
+            place.worship = Field::new(worship.to_string());
+            word_count += 1;
+            description
+        } else {
+            description
+        };
+
         for word in quoted_words(description) {
             let word_str = &word.as_str();
             word_count += 1;
@@ -69,6 +100,14 @@ impl FromStr for ParsedThing<Place> {
                 word_count -= 1;
             } else if let Ok(place_type) = word_str.parse() {
                 place.subtype = Field::new(place_type);
+            } else if let Ok(quality) = word_str.parse() {
+                place.quality = Field::new(quality);
+            } else if let Ok(price) = word_str.parse() {
+                place.price = Field::new(price);
+            } else if let Ok(architecture) = word_str.parse() {
+                place.architecture = Field::new(architecture);
+            } else if let Some(floors) = parse_floors(word_str) {
+                place.floors = Field::new(floors);
             } else {
                 unknown_words.push(word.range().to_owned());
             }
@@ -90,6 +129,14 @@ impl FromStr for ParsedThing<Npc> {
     type Err = ();
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_cultures(input, &[])
+    }
+}
+
+impl ParsedThing<Npc> {
+    /// Parse a character description, recognizing the names of any custom cultures alongside the
+    /// built-in vocabulary, eg. "an elderly vesk".
+    pub fn parse_with_cultures(input: &str, cultures: &[Culture]) -> Result<Self, ()> {
         let mut npc = Npc::default();
         let mut unknown_words = Vec::new();
         let mut word_count = 0;
@@ -130,10 +177,31 @@ impl FromStr for ParsedThing<Npc> {
                 }
             } else if let Ok(ethnicity) = word_str.parse() {
                 npc.ethnicity = Field::new(ethnicity);
+            } else if let Ok(occupation) = word_str.parse() {
+                npc.occupation = Field::new(occupation);
+
+                if let Ok(archetype) = word_str.parse() {
+                    // "guard" and "priest" are both occupations and combat archetypes.
+                    npc.archetype = Field::new(archetype);
+                }
+            } else if let Ok(archetype) = word_str.parse() {
+                npc.archetype = Field::new(archetype);
+            } else if let Ok(personality) = word_str.parse() {
+                npc.personality = Field::new(personality);
+            } else if let Ok(feature) = word_str.parse() {
+                npc.feature = Field::new(feature);
             } else if let Some(Ok(age_years)) =
                 word_str.strip_suffix_ci("-year-old").map(|s| s.parse())
             {
                 npc.age_years = Field::new(age_years);
+            } else if let Some(height) = parse_height(word_str) {
+                let weight = npc.size.value().and_then(Size::weight);
+                npc.size = Field::new(Size::from_height_weight(Some(height), weight));
+            } else if let Some(weight) = parse_weight(word_str) {
+                let height = npc.size.value().and_then(Size::height);
+                npc.size = Field::new(Size::from_height_weight(height, Some(weight)));
+            } else if let Some(culture) = cultures.iter().find(|c| c.name.eq_ci(word_str)) {
+                npc.culture = Field::new(culture.name.clone());
             } else {
                 unknown_words.push(word.range().to_owned());
             }
@@ -151,11 +219,242 @@ impl FromStr for ParsedThing<Npc> {
     }
 }
 
+/// Like `CaseInsensitiveStr::strip_prefix_ci`, but borrowing from the input rather than the
+/// reference to it.
+fn strip_prefix_ci<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    if input.starts_with_ci(prefix) {
+        input.get(prefix.len()..)
+    } else {
+        None
+    }
+}
+
+fn strip_suffix_ci<'a>(input: &'a str, suffix: &str) -> Option<&'a str> {
+    if input.ends_with_ci(suffix) {
+        input.get(..input.len() - suffix.len())
+    } else {
+        None
+    }
+}
+
+/// Strip a possessive suffix from a name, so "Vance's" becomes "Vance" and "Bess'" becomes
+/// "Bess".
+fn strip_possessive(input: &str) -> Option<&str> {
+    strip_suffix_ci(input, "'s")
+        .or_else(|| strip_suffix_ci(input, "’s"))
+        .or_else(|| input.strip_suffix('\''))
+        .or_else(|| input.strip_suffix('’'))
+        .map(|s| s.trim_end())
+        .filter(|s| !s.is_empty())
+}
+
+/// Parse a request for a new relative of a named character, eg. "Vance's daughter" or "generate
+/// a sibling for Mira". Returns the name, the relationship, and the gender implied by the word
+/// used, if any.
+pub fn parse_relative(input: &str) -> Option<(&str, Relationship, Option<Gender>)> {
+    let input = input.trim();
+
+    // "Vance's daughter"
+    if let Some((name, word)) = input.rsplit_once(char::is_whitespace) {
+        if let (Some(name), Some((relationship, gender))) =
+            (strip_possessive(name), Relationship::parse_word(word))
+        {
+            return Some((name, relationship, gender));
+        }
+    }
+
+    // "generate a sibling for Mira"
+    let input = ["generate ", "create "]
+        .iter()
+        .find_map(|prefix| strip_prefix_ci(input, prefix))
+        .unwrap_or(input)
+        .trim_start();
+    let input = ["a ", "an "]
+        .iter()
+        .find_map(|prefix| strip_prefix_ci(input, prefix))
+        .unwrap_or(input)
+        .trim_start();
+
+    let (word, rest) = input.split_once(char::is_whitespace)?;
+    let (relationship, gender) = Relationship::parse_word(word)?;
+    let name = ["for ", "of "]
+        .iter()
+        .find_map(|prefix| strip_prefix_ci(rest.trim_start(), prefix))?
+        .trim();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some((name, relationship, gender))
+    }
+}
+
+/// Parse a request for a character's family tree, eg. "family of Mira" or "Mira's family".
+pub fn parse_family(input: &str) -> Option<&str> {
+    let input = input.trim();
+
+    strip_prefix_ci(input, "family of ")
+        .map(|s| s.trim_start())
+        .or_else(|| strip_suffix_ci(input, " family").and_then(strip_possessive))
+        .filter(|s| !s.is_empty())
+}
+
+/// Parse a request to fill a settlement with places and characters, eg. "populate Thornwick".
+pub fn parse_populate(input: &str) -> Option<&str> {
+    strip_prefix_ci(input.trim(), "populate ")
+        .map(|s| s.trim_start())
+        .filter(|s| !s.is_empty())
+}
+
+/// Parse "Mira lives in Thornwick" or "Mira is in (or at) the Prancing Pony", returning the
+/// character and place names, and whether the place is the character's home.
+pub fn parse_location(input: &str) -> Option<(&str, &str, bool)> {
+    let mut words = quoted_words(input).skip(1).peekable();
+
+    while let Some(word) = words.next() {
+        let is_home = if word.as_str().eq_ci("lives") {
+            true
+        } else if word.as_str().eq_ci("is") {
+            false
+        } else {
+            continue;
+        };
+
+        if let Some(next) = words.peek() {
+            if next.as_str().eq_ci("in") || (!is_home && next.as_str().eq_ci("at")) {
+                let name = input[..word.range().start].trim();
+                let place = input[next.range().end..].trim();
+
+                if !name.is_empty() && !place.is_empty() {
+                    return Some((name, place, is_home));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Split the input around a phrase such as "is owned by", returning the non-empty text on either
+/// side of it. The phrase can't be the first word, since that would leave nothing before it.
+fn split_phrase<'a>(input: &'a str, phrase: &[&str]) -> Option<(&'a str, &'a str)> {
+    let words: Vec<_> = quoted_words(input).collect();
+
+    words
+        .windows(phrase.len())
+        .skip(1)
+        .find(|window| {
+            window
+                .iter()
+                .zip(phrase)
+                .all(|(word, phrase_word)| word.as_str().eq_ci(phrase_word))
+        })
+        .map(|window| {
+            (
+                input[..window[0].range().start].trim(),
+                input[window[window.len() - 1].range().end..].trim(),
+            )
+        })
+        .filter(|(before, after)| !before.is_empty() && !after.is_empty())
+}
+
+/// Parse "The Prancing Pony is owned by Mira", "Mira owns the Prancing Pony" or "Mira works at
+/// the Prancing Pony", returning the place and character names, and whether the character is the
+/// owner rather than a member of staff.
+pub fn parse_employment(input: &str) -> Option<(&str, &str, bool)> {
+    if let Some((place, name)) = split_phrase(input, &["is", "owned", "by"]) {
+        Some((place, name, true))
+    } else if let Some((name, place)) = split_phrase(input, &["owns"]) {
+        Some((place, name, true))
+    } else {
+        split_phrase(input, &["works", "at"])
+            .or_else(|| split_phrase(input, &["works", "in"]))
+            .map(|(name, place)| (place, name, false))
+    }
+}
+
+/// Parse "The Prancing Pony offers hot baths", returning the place name and its services.
+pub fn parse_services(input: &str) -> Option<(&str, &str)> {
+    split_phrase(input, &["offers"])
+}
+
+/// Parse "The Prancing Pony serves roast boar", returning the place name and its menu.
+pub fn parse_menu(input: &str) -> Option<(&str, &str)> {
+    split_phrase(input, &["serves"])
+}
+
+/// Parse a request for customers at an inn or tavern, eg. "generate patrons for The Prancing
+/// Pony" or just "patrons at The Prancing Pony".
+pub fn parse_patrons(input: &str) -> Option<&str> {
+    let input = input.trim();
+    let input = ["generate ", "create "]
+        .iter()
+        .find_map(|verb| strip_prefix_ci(input, verb))
+        .map_or(input, |s| s.trim_start());
+
+    ["patrons for ", "patrons at "]
+        .iter()
+        .find_map(|prefix| strip_prefix_ci(input, prefix))
+        .map(|s| s.trim_start())
+        .filter(|s| !s.is_empty())
+}
+
+/// Parse a request to refill a shop's inventory, eg. "restock The Anvil".
+pub fn parse_restock(input: &str) -> Option<&str> {
+    strip_prefix_ci(input.trim(), "restock ")
+        .map(|s| s.trim_start())
+        .filter(|s| !s.is_empty())
+}
+
+/// Parse "buy a Longsword from The Anvil", returning the item and shop names.
+pub fn parse_buy(input: &str) -> Option<(&str, &str)> {
+    let (item, shop) = split_phrase(strip_prefix_ci(input.trim(), "buy ")?, &["from"])?;
+    let item = ["a ", "an ", "the "]
+        .iter()
+        .find_map(|article| strip_prefix_ci(item, article))
+        .map_or(item, |s| s.trim_start());
+
+    Some((item, shop))
+}
+
+/// Parse "Mira speaks Elvish and Dwarvish", returning the name along with the languages. Every
+/// word after "speaks" must be a language (or "and").
+pub fn parse_languages(input: &str) -> Option<(&str, Vec<Language>)> {
+    let word = quoted_words(input)
+        .skip(1)
+        .find(|word| word.as_str().eq_ci("speaks"))?;
+
+    let name = input[..word.range().start].trim();
+    let mut languages = Vec::new();
+
+    for part in input[word.range().end..].split(',') {
+        for part in part.split(" and ") {
+            let part = part.trim();
+            let part = strip_prefix_ci(part, "and ").unwrap_or(part).trim();
+
+            if part.is_empty() {
+                continue;
+            }
+
+            let language = part.parse::<Language>().ok()?;
+            if !languages.contains(&language) {
+                languages.push(language);
+            }
+        }
+    }
+
+    if languages.is_empty() {
+        None
+    } else {
+        Some((name, languages))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::world::npc::{Age, Gender, Species};
-    use crate::world::place::PlaceType;
+    use crate::world::npc::{Age, Archetype, Ethnicity, Feature, Personality, Role, Species};
+    use crate::world::place::{Architecture, PlaceType, Price, Quality};
 
     #[test]
     fn place_from_str_test() {
@@ -280,8 +579,250 @@ mod test {
                 .unwrap(),
         );
 
+        {
+            let npc: ParsedThing<Npc> = "5'4\" 140lbs elf".parse().unwrap();
+            assert_eq!(
+                Field::Locked(Some(Size::Medium {
+                    height: Some(64),
+                    weight: Some(140)
+                })),
+                npc.thing.size,
+            );
+            assert_eq!(0, npc.unknown_words.len());
+            assert_eq!(3, npc.word_count);
+        }
+
+        {
+            let npc: ParsedThing<Npc> = "dwarvish blacksmith".parse().unwrap();
+            assert_eq!(
+                Field::Locked(Some(Ethnicity::Dwarvish)),
+                npc.thing.ethnicity
+            );
+            assert_eq!(Field::Locked(Some(Role::Blacksmith)), npc.thing.occupation);
+            assert_eq!(0, npc.unknown_words.len());
+            assert_eq!(2, npc.word_count);
+        }
+
+        {
+            let npc: ParsedThing<Npc> = "a grumpy one-eyed dwarf".parse().unwrap();
+            assert_eq!(
+                Field::Locked(Some(Personality::Grumpy)),
+                npc.thing.personality,
+            );
+            assert_eq!(Field::Locked(Some(Feature::OneEyed)), npc.thing.feature);
+            assert_eq!(Field::Locked(Some(Species::Dwarf)), npc.thing.species);
+            assert_eq!(0, npc.unknown_words.len());
+            assert_eq!(3, npc.word_count);
+        }
+
+        {
+            let npc: ParsedThing<Npc> = "a dwarf veteran".parse().unwrap();
+            assert_eq!(Field::Locked(Some(Archetype::Veteran)), npc.thing.archetype);
+            assert_eq!(Field::Locked(Some(Species::Dwarf)), npc.thing.species);
+            assert!(npc.thing.occupation.is_none());
+            assert_eq!(2, npc.word_count);
+        }
+
+        {
+            let npc: ParsedThing<Npc> = "a guard".parse().unwrap();
+            assert_eq!(Field::Locked(Some(Archetype::Guard)), npc.thing.archetype);
+            assert_eq!(Field::Locked(Some(Role::Guard)), npc.thing.occupation);
+        }
+
         {
             assert!("potato".parse::<ParsedThing<Npc>>().is_err());
         }
     }
+
+    #[test]
+    fn parse_relative_test() {
+        assert_eq!(
+            Some(("Vance", Relationship::Child, Some(Gender::Feminine))),
+            parse_relative("Vance's daughter"),
+        );
+        assert_eq!(
+            Some(("Bess", Relationship::Parent, None)),
+            parse_relative("Bess' parent"),
+        );
+        assert_eq!(
+            Some(("Mira Ashdown", Relationship::Sibling, None)),
+            parse_relative("generate a sibling for Mira Ashdown"),
+        );
+        assert_eq!(
+            Some(("Mira", Relationship::Spouse, Some(Gender::Masculine))),
+            parse_relative("Husband of Mira"),
+        );
+        assert_eq!(None, parse_relative("Vance's cousin"));
+        assert_eq!(None, parse_relative("Vance daughter"));
+        assert_eq!(None, parse_relative("a sibling for"));
+        assert_eq!(None, parse_relative("daughter"));
+    }
+
+    #[test]
+    fn parse_family_test() {
+        assert_eq!(Some("Mira"), parse_family("family of Mira"));
+        assert_eq!(Some("Mira"), parse_family("Mira's family"));
+        assert_eq!(Some("Bess"), parse_family("Bess' family"));
+        assert_eq!(None, parse_family("family of "));
+        assert_eq!(None, parse_family("Mira family"));
+    }
+
+    #[test]
+    fn parse_employment_test() {
+        assert_eq!(
+            Some(("The Prancing Pony", "Barliman Butterbur", true)),
+            parse_employment("The Prancing Pony is owned by Barliman Butterbur"),
+        );
+        assert_eq!(
+            Some(("the Prancing Pony", "Barliman", true)),
+            parse_employment("Barliman OWNS the Prancing Pony"),
+        );
+        assert_eq!(
+            Some(("The Prancing Pony", "Nob", false)),
+            parse_employment("Nob works at The Prancing Pony"),
+        );
+        assert_eq!(
+            Some(("Bree", "Nob", false)),
+            parse_employment("Nob works in Bree"),
+        );
+        assert_eq!(None, parse_employment("owns the Prancing Pony"));
+        assert_eq!(None, parse_employment("Nob works at"));
+        assert_eq!(None, parse_employment("Nob works hard"));
+    }
+
+    #[test]
+    fn parse_services_test() {
+        assert_eq!(
+            Some(("The Prancing Pony", "hot baths")),
+            parse_services("The Prancing Pony offers hot baths"),
+        );
+        assert_eq!(None, parse_services("offers hot baths"));
+        assert_eq!(None, parse_services("The Prancing Pony offers "));
+    }
+
+    #[test]
+    fn parse_place_test() {
+        let place = "a wealthy marble two-storey temple dedicated to the Morninglord"
+            .parse::<ParsedThing<Place>>()
+            .unwrap()
+            .thing;
+
+        assert_eq!(Some(&Quality::Wealthy), place.quality.value());
+        assert_eq!(Some(&Architecture::Marble), place.architecture.value());
+        assert_eq!(Some(&2), place.floors.value());
+        assert_eq!(Some(&"the Morninglord".to_string()), place.worship.value(),);
+        assert_eq!("temple", place.subtype.value().unwrap().as_str());
+
+        let place = "a cheap inn".parse::<ParsedThing<Place>>().unwrap().thing;
+        assert_eq!(Some(&Price::Cheap), place.price.value());
+        assert_eq!(
+            Ok("the Sun".to_string()),
+            "dedicated to the Sun"
+                .parse::<ParsedThing<Place>>()
+                .map(|place| place.thing.worship.value().unwrap().to_owned()),
+        );
+    }
+
+    #[test]
+    fn parse_populate_test() {
+        assert_eq!(Some("Thornwick"), parse_populate("populate Thornwick"));
+        assert_eq!(Some("Bree"), parse_populate("POPULATE  Bree "));
+        assert_eq!(None, parse_populate("populate "));
+        assert_eq!(None, parse_populate("populated Bree"));
+    }
+
+    #[test]
+    fn parse_menu_test() {
+        assert_eq!(
+            Some(("The Prancing Pony", "roast boar and ale")),
+            parse_menu("The Prancing Pony serves roast boar and ale"),
+        );
+        assert_eq!(None, parse_menu("serves roast boar"));
+        assert_eq!(None, parse_menu("The Prancing Pony serves "));
+    }
+
+    #[test]
+    fn parse_patrons_test() {
+        assert_eq!(
+            Some("The Prancing Pony"),
+            parse_patrons("generate patrons for The Prancing Pony"),
+        );
+        assert_eq!(
+            Some("The Prancing Pony"),
+            parse_patrons("Create Patrons at  The Prancing Pony "),
+        );
+        assert_eq!(Some("Bree"), parse_patrons("patrons for Bree"));
+        assert_eq!(None, parse_patrons("generate patrons for "));
+        assert_eq!(None, parse_patrons("generate patrons"));
+        assert_eq!(None, parse_patrons("patronsfor Bree"));
+    }
+
+    #[test]
+    fn parse_restock_test() {
+        assert_eq!(Some("The Anvil"), parse_restock("restock The Anvil"));
+        assert_eq!(Some("The Anvil"), parse_restock("RESTOCK  The Anvil "));
+        assert_eq!(None, parse_restock("restock "));
+        assert_eq!(None, parse_restock("restocked The Anvil"));
+    }
+
+    #[test]
+    fn parse_buy_test() {
+        assert_eq!(
+            Some(("Longsword", "The Anvil")),
+            parse_buy("buy a Longsword from The Anvil"),
+        );
+        assert_eq!(
+            Some(("Hempen Rope (50 feet)", "The Crate and Lantern")),
+            parse_buy("Buy Hempen Rope (50 feet) FROM The Crate and Lantern"),
+        );
+        assert_eq!(
+            Some(("Abacus", "Mira")),
+            parse_buy("buy an Abacus from Mira")
+        );
+        assert_eq!(None, parse_buy("buy from The Anvil"));
+        assert_eq!(None, parse_buy("buy a Longsword"));
+        assert_eq!(None, parse_buy("Mira buys a Longsword from The Anvil"));
+    }
+
+    #[test]
+    fn parse_location_test() {
+        assert_eq!(
+            Some(("Mira", "Thornwick", true)),
+            parse_location("Mira lives in Thornwick"),
+        );
+        assert_eq!(
+            Some(("Mira Ashdown", "The Prancing Pony", false)),
+            parse_location("Mira Ashdown is at The Prancing Pony"),
+        );
+        assert_eq!(
+            Some(("Mira", "Bree", false)),
+            parse_location("Mira is in Bree"),
+        );
+        assert_eq!(None, parse_location("Mira lives at Bree"));
+        assert_eq!(None, parse_location("Mira is in "));
+        assert_eq!(None, parse_location("lives in Bree"));
+        assert_eq!(None, parse_location("Mira is an elf"));
+    }
+
+    #[test]
+    fn parse_languages_test() {
+        assert_eq!(
+            Some(("Mira", vec![Language::Elvish])),
+            parse_languages("Mira speaks Elvish"),
+        );
+        assert_eq!(
+            Some((
+                "Mira Ashdown",
+                vec![Language::Dwarvish, Language::DeepSpeech, Language::Orc],
+            )),
+            parse_languages("Mira Ashdown speaks dwarvish, Deep Speech, and orc"),
+        );
+        assert_eq!(
+            Some(("Bess", vec![Language::Giant, Language::Goblin])),
+            parse_languages("Bess speaks Giant and Goblin"),
+        );
+        assert_eq!(None, parse_languages("Mira speaks "));
+        assert_eq!(None, parse_languages("Mira speaks Elvish and potatoes"));
+        assert_eq!(None, parse_languages("speaks Elvish"));
+    }
 }
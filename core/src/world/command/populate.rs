@@ -0,0 +1,506 @@
+use crate::app::AppMeta;
+use crate::storage::{Change, RepositoryError};
+use crate::world::npc::{Age, Role, Uuid as NpcUuid};
+use crate::world::place::building::business::{stock, BusinessType};
+use crate::world::place::building::BuildingType;
+use crate::world::place::location::settlement::{Population, SettlementType};
+use crate::world::place::location::LocationType;
+use crate::world::place::{PlaceType, Price, Quality};
+use crate::world::{Npc, Place, PlaceUuid, Thing};
+use crate::Uuid;
+use rand::prelude::*;
+use std::collections::HashSet;
+
+/// Fill a settlement from the journal with the places and key characters that it ought to have,
+/// plus a few patrons at each inn and bar, all of which are saved to the journal as a single
+/// change so that one `undo` removes them.
+pub async fn populate(app_meta: &mut AppMeta, name: String) -> Result<String, String> {
+    let settlement = match app_meta.repository.get_by_name(&name).await {
+        Ok(Thing::Place(place)) => place,
+        _ => return Err(format!(r#"There is no place named "{}"."#, name)),
+    };
+
+    let name = settlement.name.to_string();
+
+    let settlement_type = match settlement.subtype.value() {
+        Some(&PlaceType::Location(LocationType::Settlement(subtype))) => subtype,
+        _ => {
+            return Err(format!(
+                "Only settlements can be populated, and {} is not a settlement.",
+                name,
+            ))
+        }
+    };
+
+    let settlement_uuid = if let Some(uuid) = settlement.uuid {
+        uuid
+    } else {
+        return Err(format!(
+            "{name} must be in your journal before it can be populated. Use `save {name}` first.",
+            name = name,
+        ));
+    };
+
+    let population = settlement_type.population();
+    let mut batch = Batch {
+        settlement: Some(settlement_type),
+        ..Default::default()
+    };
+    let mut key_characters = Vec::new();
+
+    let mut districts = Vec::with_capacity(population.districts);
+    for _ in 0..population.districts {
+        let district = PlaceType::Location(LocationType::Settlement(SettlementType::District));
+        districts.push(
+            batch
+                .push_place(app_meta, district, settlement_uuid.clone(), None)
+                .await?,
+        );
+    }
+
+    if population.mayor {
+        key_characters.push((
+            NpcUuid::from(Uuid::new_v4()),
+            Role::Mayor,
+            settlement_uuid.clone(),
+            settlement_uuid.clone(),
+            None,
+        ));
+    }
+
+    for building in buildings(&population, &mut app_meta.rng) {
+        let parent_uuid = districts
+            .choose(&mut app_meta.rng)
+            .unwrap_or(&settlement_uuid)
+            .clone();
+        let subtype = PlaceType::Building(building);
+
+        // Innkeepers run their own inns, while priests serve in temples that nobody owns.
+        let employee = match building {
+            BuildingType::Business(BusinessType::Inn) => Some((Role::Innkeeper, true)),
+            BuildingType::Religious(_) => Some((Role::Priest, false)),
+            _ => None,
+        }
+        .map(|(role, is_owner)| (NpcUuid::from(Uuid::new_v4()), role, is_owner));
+
+        let uuid = batch
+            .push_place(
+                app_meta,
+                subtype,
+                parent_uuid.clone(),
+                employee
+                    .as_ref()
+                    .map(|(npc_uuid, _, is_owner)| (npc_uuid.clone(), *is_owner)),
+            )
+            .await?;
+
+        if has_patrons(&subtype) {
+            batch.push_patrons(app_meta, &uuid).await?;
+        }
+
+        if let Some((npc_uuid, role, _)) = employee {
+            key_characters.push((npc_uuid, role, uuid, parent_uuid, Some(subtype)));
+        }
+    }
+
+    for (uuid, role, location_uuid, home_uuid, location) in key_characters {
+        batch
+            .push_npc(app_meta, uuid, role, location_uuid, home_uuid, location)
+            .await?;
+    }
+
+    let (place_count, npc_count) = batch
+        .things
+        .iter()
+        .fold((0, 0), |(p, n), thing| match thing {
+            Thing::Place(_) => (p + 1, n),
+            Thing::Npc(_) => (p, n + 1),
+        });
+
+    let mut output = format!("# {}", name);
+    for (heading, is_place) in [("Places", true), ("Characters", false)] {
+        let mut things = batch
+            .things
+            .iter()
+            .filter(|thing| matches!(thing, Thing::Place(_)) == is_place)
+            .peekable();
+
+        if things.peek().is_some() {
+            output.push_str(&format!("\n\n## {}", heading));
+
+            things.enumerate().for_each(|(i, thing)| {
+                if i > 0 {
+                    output.push('\\');
+                }
+                output.push_str(&format!("\n{}", thing.display_summary()));
+            });
+        }
+    }
+
+    match app_meta
+        .repository
+        .modify(Change::Batch {
+            description: format!("populating {}", name),
+            changes: batch
+                .things
+                .into_iter()
+                .map(|thing| Change::CreateAndSave { thing })
+                .collect(),
+        })
+        .await
+    {
+        Ok(_) => {}
+        Err((_, RepositoryError::NameAlreadyExists)) => {
+            return Err(format!(
+                "Couldn't populate {}, because a name was already in use.",
+                name,
+            ))
+        }
+        Err(_) => return Err(format!("Couldn't populate {}.", name)),
+    }
+
+    output.push_str(&format!(
+        "\n\n_{} has been populated with {} places and {} characters, all of which have been added to your `journal`. Use `undo` to remove them._",
+        name, place_count, npc_count,
+    ));
+
+    Ok(output)
+}
+
+/// Add a few more customers to an inn or tavern from the journal, saved as a single change so that
+/// one `undo` removes them all.
+///
+/// Unlike the rooms, menu and rumors, patrons aren't a field of the place: they're characters in
+/// their own right, placed at the inn through their `location_uuid`. They're generated along with
+/// the inn when it's saved to the journal (see [`with_patrons`]), can't be locked, and running
+/// this command adds more customers rather than replacing the existing ones.
+pub async fn patrons(app_meta: &mut AppMeta, name: String) -> Result<String, String> {
+    let place = match app_meta.repository.get_by_name(&name).await {
+        Ok(Thing::Place(place)) => place,
+        _ => return Err(format!(r#"There is no place named "{}"."#, name)),
+    };
+
+    let name = place.name.to_string();
+
+    if !place.subtype.value().is_some_and(has_patrons) {
+        return Err(format!(
+            "Only inns and bars have patrons, and {} is neither.",
+            name,
+        ));
+    }
+
+    let place_uuid = if let Some(uuid) = place.uuid {
+        uuid
+    } else {
+        return Err(format!(
+            "{name} must be in your journal before it can have patrons. Use `save {name}` first.",
+            name = name,
+        ));
+    };
+
+    let mut batch = Batch::default();
+    batch.push_patrons(app_meta, &place_uuid).await?;
+
+    let mut output = format!("# Patrons of {}\n", name);
+    batch.things.iter().enumerate().for_each(|(i, thing)| {
+        if i > 0 {
+            output.push('\\');
+        }
+        output.push_str(&format!("\n{}", thing.display_summary()));
+    });
+
+    let count = batch.things.len();
+
+    match app_meta
+        .repository
+        .modify(Change::Batch {
+            description: format!("adding patrons to {}", name),
+            changes: batch
+                .things
+                .into_iter()
+                .map(|thing| Change::CreateAndSave { thing })
+                .collect(),
+        })
+        .await
+    {
+        Ok(_) => {}
+        Err((_, RepositoryError::NameAlreadyExists)) => {
+            return Err(format!(
+                "Couldn't add patrons to {}, because a name was already in use.",
+                name,
+            ))
+        }
+        Err(_) => return Err(format!("Couldn't add patrons to {}.", name)),
+    }
+
+    output.push_str(&format!(
+        "\n\n_{} patrons have been added to {} and your `journal`. Use `undo` to remove them._",
+        count, name,
+    ));
+
+    Ok(output)
+}
+
+/// Save an inn or bar to the journal along with a few patrons, turning the change that would have
+/// saved it on its own into a batch so that one `undo` removes them all. Returns the patrons for
+/// display. Any other change, including one whose name is already taken, is returned as it is.
+pub async fn with_patrons(
+    app_meta: &mut AppMeta,
+    change: Change,
+) -> Result<(Change, Vec<Thing>), String> {
+    let (mut place, mut changes) = match &change {
+        Change::CreateAndSave {
+            thing: Thing::Place(place),
+        } => (place.clone(), Vec::new()),
+
+        // A place from the recent list is taken out of it and created again with a UUID, which
+        // the patrons need in order to be placed there.
+        Change::Save { name } => match app_meta.repository.get_by_name(name).await {
+            Ok(Thing::Place(place)) => (
+                place,
+                vec![Change::Delete {
+                    name: name.clone(),
+                    uuid: None,
+                }],
+            ),
+            _ => return Ok((change, Vec::new())),
+        },
+        _ => return Ok((change, Vec::new())),
+    };
+
+    let name = match place.name.value() {
+        Some(name) if place.uuid.is_none() && place.subtype.value().is_some_and(has_patrons) => {
+            name.to_string()
+        }
+        _ => return Ok((change, Vec::new())),
+    };
+
+    if changes.is_empty() && app_meta.repository.get_by_name(&name).await.is_ok() {
+        return Ok((change, Vec::new()));
+    }
+
+    let uuid: PlaceUuid = Uuid::new_v4().into();
+    place.uuid = Some(uuid.clone());
+
+    let mut batch = Batch::default();
+    batch.names.insert(name.to_lowercase());
+    batch.push_patrons(app_meta, &uuid).await?;
+
+    changes.push(Change::CreateAndSave {
+        thing: place.into(),
+    });
+    changes.extend(
+        batch
+            .things
+            .iter()
+            .cloned()
+            .map(|thing| Change::CreateAndSave { thing }),
+    );
+
+    Ok((
+        Change::Batch {
+            description: change.display_redo().to_string(),
+            changes,
+        },
+        batch.things,
+    ))
+}
+
+/// List the patrons generated by [`with_patrons`] under a heading of their own, to follow the
+/// details of the inn.
+pub fn display_patrons(patrons: &[Thing]) -> String {
+    let mut output = String::new();
+
+    if !patrons.is_empty() {
+        output.push_str("\n\n## Patrons\n");
+        patrons.iter().enumerate().for_each(|(i, thing)| {
+            if i > 0 {
+                output.push('\\');
+            }
+            output.push_str(&format!("\n{}", thing.display_summary()));
+        });
+    }
+
+    output
+}
+
+/// The things making up a populated settlement or a crowd of patrons, whose names must be unique
+/// among themselves as well as in the journal.
+#[derive(Default)]
+struct Batch {
+    things: Vec<Thing>,
+    names: HashSet<String>,
+    settlement: Option<SettlementType>,
+}
+
+impl Batch {
+    /// Add a place inside `parent_uuid`, returning the UUID that it will be saved with so that
+    /// other things in the batch can refer to it. The employee is a character yet to be added,
+    /// who either owns the place or works there.
+    async fn push_place(
+        &mut self,
+        app_meta: &mut AppMeta,
+        subtype: PlaceType,
+        parent_uuid: PlaceUuid,
+        employee: Option<(NpcUuid, bool)>,
+    ) -> Result<PlaceUuid, String> {
+        let uuid: PlaceUuid = Uuid::new_v4().into();
+
+        let mut place = Place {
+            uuid: Some(uuid.clone()),
+            location_uuid: parent_uuid.into(),
+            subtype: subtype.into(),
+            ..Default::default()
+        };
+
+        match employee {
+            Some((npc_uuid, true)) => place.owner = npc_uuid.into(),
+            Some((npc_uuid, false)) => place.staff = vec![npc_uuid].into(),
+            None => {}
+        }
+
+        self.push(app_meta, place.into(), None).await?;
+
+        // Shops are stocked for the settlement that they're in, which generating them on their
+        // own doesn't know about.
+        if let (
+            Some(Thing::Place(place)),
+            PlaceType::Building(BuildingType::Business(business_type)),
+        ) = (self.things.last_mut(), subtype)
+        {
+            if business_type.has_inventory() {
+                let quality = place.quality.value().copied().unwrap_or(Quality::Modest);
+                let price = place.price.value().copied().unwrap_or(Price::Reasonable);
+                let settlement = self.settlement;
+                place.inventory.replace_with(|_| {
+                    stock(&mut app_meta.rng, business_type, quality, price, settlement)
+                });
+            }
+        }
+
+        Ok(uuid)
+    }
+
+    /// Add a few customers to an inn or bar. Patrons are only passing through, so they aren't
+    /// generated as if they worked there.
+    async fn push_patrons(
+        &mut self,
+        app_meta: &mut AppMeta,
+        place_uuid: &PlaceUuid,
+    ) -> Result<(), String> {
+        for _ in 0..app_meta.rng.gen_range(2..=4) {
+            let age = *[
+                Age::YoungAdult,
+                Age::Adult,
+                Age::Adult,
+                Age::MiddleAged,
+                Age::Elderly,
+            ]
+            .choose(&mut app_meta.rng)
+            .unwrap();
+
+            self.push(
+                app_meta,
+                Npc {
+                    age: age.into(),
+                    location_uuid: place_uuid.clone().into(),
+                    ..Default::default()
+                }
+                .into(),
+                None,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn push_npc(
+        &mut self,
+        app_meta: &mut AppMeta,
+        uuid: NpcUuid,
+        role: Role,
+        location_uuid: PlaceUuid,
+        home_uuid: PlaceUuid,
+        location: Option<PlaceType>,
+    ) -> Result<(), String> {
+        // The people running things are at least grown up, and mayors tend to be older still.
+        let ages: &[Age] = if role == Role::Mayor {
+            &[Age::MiddleAged, Age::Elderly]
+        } else {
+            &[Age::Adult, Age::Adult, Age::MiddleAged]
+        };
+
+        let age = *ages.choose(&mut app_meta.rng).unwrap();
+
+        self.push(
+            app_meta,
+            Npc {
+                uuid: Some(uuid),
+                age: age.into(),
+                occupation: role.into(),
+                location_uuid: location_uuid.into(),
+                home_uuid: home_uuid.into(),
+                ..Default::default()
+            }
+            .into(),
+            location.as_ref(),
+        )
+        .await
+    }
+
+    async fn push(
+        &mut self,
+        app_meta: &mut AppMeta,
+        diff: Thing,
+        location: Option<&PlaceType>,
+    ) -> Result<(), String> {
+        for _ in 0..10 {
+            let mut thing = diff.clone();
+
+            match &mut thing {
+                Thing::Npc(npc) => {
+                    npc.regenerate_at(&mut app_meta.rng, &app_meta.demographics, location)
+                }
+                Thing::Place(_) => thing.regenerate(&mut app_meta.rng, &app_meta.demographics),
+            }
+
+            if let Some(name) = thing.name().value() {
+                if !self.names.contains(&name.to_lowercase())
+                    && app_meta.repository.get_by_name(name).await.is_err()
+                {
+                    self.names.insert(name.to_lowercase());
+                    self.things.push(thing);
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(format!(
+            "Couldn't create a unique {} name.",
+            diff.display_description(),
+        ))
+    }
+}
+
+fn has_patrons(subtype: &PlaceType) -> bool {
+    matches!(
+        subtype,
+        PlaceType::Building(BuildingType::Business(
+            BusinessType::Inn | BusinessType::Bar
+        ))
+    )
+}
+
+/// The population's buildings in a random order, so that each district gets a mix of them.
+fn buildings(population: &Population, rng: &mut impl Rng) -> Vec<BuildingType> {
+    let mut buildings: Vec<BuildingType> = population
+        .buildings
+        .iter()
+        .flat_map(|&(building, count)| std::iter::repeat_n(building, count))
+        .chain((0..population.shops).map(|_| BuildingType::Business(Population::gen_shop(rng))))
+        .collect();
+
+    buildings.shuffle(rng);
+    buildings
+}
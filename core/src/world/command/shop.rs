@@ -0,0 +1,153 @@
+use crate::app::AppMeta;
+use crate::storage::{Change, RepositoryError};
+use crate::utils::CaseInsensitiveStr;
+use crate::world::place::building::business::stock;
+use crate::world::place::building::BuildingType;
+use crate::world::place::location::settlement::SettlementType;
+use crate::world::place::location::LocationType;
+use crate::world::place::{format_cost, PlaceType, Price, Quality, Stock};
+use crate::world::{Place, PlaceRelations, Thing};
+
+/// Replace a shop's inventory with a fresh selection of wares, priced for the settlement that
+/// the shop is in.
+pub async fn restock(app_meta: &mut AppMeta, name: String) -> Result<String, String> {
+    let place = load_place(app_meta, &name).await?;
+    let name = place.name.to_string();
+
+    let subtype = match place.subtype.value() {
+        Some(&PlaceType::Building(BuildingType::Business(subtype))) if subtype.has_inventory() => {
+            subtype
+        }
+        _ => return Err(format!("{} doesn't have an inventory to restock.", name)),
+    };
+
+    let settlement = settlement(app_meta, &place).await;
+    let inventory = stock(
+        &mut app_meta.rng,
+        subtype,
+        place.quality.value().copied().unwrap_or(Quality::Modest),
+        place.price.value().copied().unwrap_or(Price::Reasonable),
+        settlement,
+    );
+
+    let message = format!("{} has been restocked", name);
+    edit_inventory(app_meta, name, inventory, message).await
+}
+
+/// Buy one of an item from a shop's inventory, removing the item once the last one is sold.
+pub async fn buy(app_meta: &mut AppMeta, item: String, name: String) -> Result<String, String> {
+    let place = load_place(app_meta, &name).await?;
+    let name = place.name.to_string();
+
+    let mut inventory = place
+        .inventory
+        .value()
+        .cloned()
+        .ok_or_else(|| format!("{} doesn't have anything for sale.", name))?;
+
+    let index = inventory
+        .iter()
+        .position(|stock| stock.name.eq_ci(&item))
+        .ok_or_else(|| format!("{} doesn't have any {} in stock.", name, item))?;
+
+    let message = format!(
+        "{} was bought from {} for {}",
+        inventory[index].name,
+        name,
+        format_cost(inventory[index].price),
+    );
+
+    if inventory[index].quantity > 1 {
+        inventory[index].quantity -= 1;
+    } else {
+        inventory.remove(index);
+    }
+
+    edit_inventory(app_meta, name, inventory, message).await
+}
+
+async fn load_place(app_meta: &AppMeta, name: &str) -> Result<Place, String> {
+    match app_meta.repository.get_by_name(name).await {
+        Ok(Thing::Place(place)) => Ok(place),
+        _ => Err(format!(r#"There is no place named "{}"."#, name)),
+    }
+}
+
+/// The type of settlement that a place is in. Districts are only used if they aren't part of a
+/// larger settlement.
+async fn settlement(app_meta: &AppMeta, place: &Place) -> Option<SettlementType> {
+    let relations: PlaceRelations = app_meta
+        .repository
+        .load_relations(&place.clone().into())
+        .await
+        .ok()?
+        .into();
+
+    let (parent, grandparent) = relations.location?;
+
+    let settlements: Vec<SettlementType> = [Some(parent), grandparent]
+        .into_iter()
+        .flatten()
+        .filter_map(|place| match place.subtype.value() {
+            Some(&PlaceType::Location(LocationType::Settlement(subtype))) => Some(subtype),
+            _ => None,
+        })
+        .collect();
+
+    settlements
+        .iter()
+        .find(|&&subtype| subtype != SettlementType::District)
+        .or_else(|| settlements.first())
+        .copied()
+}
+
+async fn edit_inventory(
+    app_meta: &mut AppMeta,
+    name: String,
+    inventory: Vec<Stock>,
+    message: String,
+) -> Result<String, String> {
+    let diff = Place {
+        inventory: inventory.into(),
+        ..Default::default()
+    };
+
+    match app_meta
+        .repository
+        .modify(Change::Edit {
+            name: name.clone(),
+            uuid: None,
+            diff: diff.into(),
+        })
+        .await
+    {
+        Ok(Some(thing)) => {
+            let saved = if matches!(
+                app_meta.repository.undo_history().next(),
+                Some(Change::EditAndUnsave { .. }),
+            ) {
+                format!(" {} has been automatically saved to your `journal`.", name)
+            } else {
+                String::new()
+            };
+
+            Ok(format!(
+                "{}\n\n_{}.{} Use `undo` to reverse this._",
+                thing.display_details(
+                    app_meta
+                        .repository
+                        .load_relations(&thing)
+                        .await
+                        .unwrap_or_default(),
+                    app_meta.repository.units().await,
+                ),
+                message,
+                saved,
+            ))
+        }
+        Err((_, RepositoryError::NotFound)) => {
+            Err(format!(r#"There is no place named "{}"."#, name))
+        }
+        _ => Err(format!("Couldn't update the inventory of `{}`.", name)),
+    }
+}
@@ -1,4 +1,5 @@
-use super::npc::{Ethnicity, Species};
+use super::npc::{Culture, Ethnicity, Species};
+use crate::utils::CaseInsensitiveStr;
 use rand::distributions::WeightedIndex;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -11,6 +12,9 @@ type GroupMapSerialized = Vec<(Species, Ethnicity, u64)>;
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Demographics {
     groups: GroupMapWrapper,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    cultures: Vec<Culture>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -21,9 +25,35 @@ impl Demographics {
     pub fn new(groups: GroupMap) -> Self {
         Self {
             groups: GroupMapWrapper(groups),
+            cultures: Vec::new(),
+        }
+    }
+
+    /// Add custom cultures to the mix, replacing any existing cultures of the same name.
+    pub fn add_cultures(&mut self, cultures: Vec<Culture>) {
+        for culture in cultures {
+            self.cultures.retain(|c| !c.name.eq_ci(&culture.name));
+            self.cultures.push(culture);
         }
     }
 
+    /// Replace all custom cultures with the given ones, eg. when switching to a campaign with a
+    /// different set.
+    pub fn set_cultures(&mut self, cultures: Vec<Culture>) {
+        self.cultures.clear();
+        self.add_cultures(cultures);
+    }
+
+    pub fn cultures(&self) -> &[Culture] {
+        &self.cultures
+    }
+
+    pub fn culture(&self, name: &str) -> Option<&Culture> {
+        self.cultures
+            .iter()
+            .find(|culture| culture.name.eq_ci(name))
+    }
+
     pub fn shift_species(&self, species: &Species, amount: f64) -> Self {
         self.shift_by(
             |s, _| s == species,
@@ -76,6 +106,24 @@ impl Demographics {
         }
     }
 
+    /// Decide whether a new character belongs to one of the custom cultures, which compete with
+    /// the built-in species and ethnicities according to their population. Returns `None` without
+    /// touching the RNG if there are no custom cultures.
+    pub fn gen_culture(&self, rng: &mut impl Rng) -> Option<&Culture> {
+        if self.cultures.is_empty() {
+            return None;
+        }
+
+        let weights = iter::once(self.groups().values().sum())
+            .chain(self.cultures.iter().map(|culture| culture.population));
+        let dist = WeightedIndex::new(weights).ok()?;
+
+        match dist.sample(rng) {
+            0 => None,
+            i => self.cultures.get(i - 1),
+        }
+    }
+
     fn shift_by<F: Fn(&Species, &Ethnicity) -> bool>(
         &self,
         f: F,
@@ -123,7 +171,10 @@ impl Demographics {
                 .collect()
         };
 
-        Self::new(groups)
+        Self {
+            groups: GroupMapWrapper(groups),
+            cultures: self.cultures.clone(),
+        }
     }
 
     fn groups(&self) -> &GroupMap {
@@ -139,6 +190,14 @@ impl Default for Demographics {
         groups.insert((Species::Elf, Ethnicity::Elvish), 220_000);
         groups.insert((Species::Gnome, Ethnicity::Gnomish), 220_000);
         groups.insert((Species::Halfling, Ethnicity::Halfling), 100_000);
+        groups.insert((Species::Goblin, Ethnicity::Goblin), 60_000);
+        groups.insert((Species::Orc, Ethnicity::Orcish), 40_000);
+        groups.insert((Species::Kobold, Ethnicity::Kobold), 30_000);
+        groups.insert((Species::Tabaxi, Ethnicity::Tabaxi), 30_000);
+        groups.insert((Species::Aasimar, Ethnicity::Human), 20_000);
+        groups.insert((Species::Genasi, Ethnicity::Human), 20_000);
+        groups.insert((Species::Firbolg, Ethnicity::Elvish), 15_000);
+        groups.insert((Species::Lizardfolk, Ethnicity::Lizardfolk), 15_000);
         // groups.insert(Species::Shifter, 60_000);
         // groups.insert(Species::Changeling, 40_000);
 
@@ -395,6 +454,30 @@ mod test {
         assert_eq!(Some(&5), counts.get(&(Species::Gnome, Ethnicity::Gnomish)));
     }
 
+    #[test]
+    fn gen_culture_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(None, demographics().gen_culture(&mut rng));
+
+        let mut demographics = demographics();
+        demographics.add_cultures(
+            Culture::parse_list(
+                r#"[{"name": "vesk", "population": 100, "names": {"first": {"start": ["Ka"], "end": ["rix"]}}}]"#,
+            )
+            .unwrap(),
+        );
+
+        let cultures = (0..100)
+            .filter(|_| demographics.gen_culture(&mut rng).is_some())
+            .count();
+        assert!((30..=70).contains(&cultures), "{}", cultures);
+        assert_eq!("vesk", demographics.culture("VESK").unwrap().name);
+        assert!(demographics.culture("potato").is_none());
+
+        let shifted = demographics.only_species(&Species::Human);
+        assert_eq!(demographics.cultures(), shifted.cultures());
+    }
+
     #[test]
     fn demographics_serialize_deserialize_test() {
         let demographics = demographics();
@@ -1,9 +1,11 @@
-use serde::{Deserialize, Serialize, Serializer};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::mem;
 
-#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
-#[serde(from = "Option<T>")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Field<T> {
     Locked(Option<T>),
     Unlocked(Option<T>),
@@ -47,6 +49,20 @@ impl<T> Field<T> {
         }
     }
 
+    pub fn set_locked(&mut self, is_locked: bool) {
+        if is_locked {
+            self.lock();
+        } else {
+            self.unlock();
+        }
+    }
+
+    pub fn unlock_if_none(&mut self) {
+        if self.is_none() {
+            self.unlock();
+        }
+    }
+
     #[cfg(test)]
     pub fn unlocked(mut self) -> Self {
         self.unlock();
@@ -94,6 +110,12 @@ impl<T> Field<T> {
         self.value().is_none()
     }
 
+    /// Whether the field is empty and unlocked, as it is when missing from serialized data. Such
+    /// fields can be left out when serializing without losing anything.
+    pub fn is_unset(&self) -> bool {
+        matches!(self, Self::Unlocked(None))
+    }
+
     pub fn apply_diff(&mut self, other: &mut Self) {
         if other.is_locked() {
             mem::swap(self, other);
@@ -156,6 +178,29 @@ impl<T: Serialize> Serialize for Field<T> {
     }
 }
 
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Field<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<T>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Field<T> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        Option::<T>::json_schema(gen)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Field;
@@ -285,4 +330,25 @@ mod test {
             assert_eq!(Field::Locked(Some(false)), diff);
         }
     }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        let fields = vec![
+            Field::Locked(Some(1u8)),
+            Field::Locked(None),
+            Field::Unlocked(Some(2)),
+            Field::Unlocked(None),
+        ];
+
+        assert_eq!("[1,null,2,null]", serde_json::to_string(&fields).unwrap());
+        assert_eq!(
+            vec![
+                Field::Locked(Some(1u8)),
+                Field::Locked(None),
+                Field::Locked(Some(2)),
+                Field::Locked(None),
+            ],
+            serde_json::from_str::<Vec<Field<u8>>>("[1,null,2,null]").unwrap(),
+        );
+    }
 }
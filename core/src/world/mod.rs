@@ -2,7 +2,7 @@ pub mod demographics;
 pub mod npc;
 pub mod place;
 
-pub use command::{ParsedThing, WorldCommand};
+pub use command::{display_patrons, with_patrons, ParsedThing, WorldCommand};
 pub use demographics::Demographics;
 pub use field::Field;
 pub use npc::{Npc, NpcRelations};
@@ -0,0 +1,292 @@
+use super::{Age, Gender, Npc, Species};
+use crate::world::weighted_index_from_tuple;
+use initiative_macros::WordList;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A distinguishing mark that makes a character stand out in a crowd.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Feature {
+    Bald,
+    Bearded,
+    Freckled,
+
+    #[term = "gap-toothed"]
+    GapToothed,
+
+    Hunchbacked,
+    Limping,
+
+    #[term = "one-eyed"]
+    OneEyed,
+    Pierced,
+    Pockmarked,
+    Scarred,
+    Tattooed,
+}
+
+impl Feature {
+    fn weights(npc: &Npc) -> &'static [(Option<Self>, usize)] {
+        match (npc.age.value(), npc.species.value(), npc.gender.value()) {
+            (Some(Age::Infant), _, _) => &[(None, 1)],
+            (Some(Age::Child), _, _) => &[(None, 6), (Some(Self::Freckled), 1)],
+            (_, Some(Species::Dragonborn | Species::Kobold | Species::Lizardfolk), _) => &[
+                (None, 12),
+                (Some(Self::Scarred), 3),
+                (Some(Self::OneEyed), 1),
+                (Some(Self::Pierced), 1),
+                (Some(Self::Limping), 1),
+            ],
+            (_, Some(Species::Dwarf), _) => &[
+                (None, 8),
+                (Some(Self::Bearded), 6),
+                (Some(Self::Scarred), 2),
+                (Some(Self::Tattooed), 1),
+                (Some(Self::OneEyed), 1),
+                (Some(Self::Bald), 1),
+            ],
+            (Some(Age::Elderly | Age::Geriatric), _, Some(Gender::Masculine)) => &[
+                (None, 10),
+                (Some(Self::Bald), 4),
+                (Some(Self::Bearded), 3),
+                (Some(Self::Hunchbacked), 1),
+                (Some(Self::Limping), 2),
+                (Some(Self::GapToothed), 1),
+            ],
+            (Some(Age::Elderly | Age::Geriatric), _, _) => &[
+                (None, 12),
+                (Some(Self::Hunchbacked), 1),
+                (Some(Self::Limping), 2),
+                (Some(Self::GapToothed), 1),
+            ],
+            (_, _, Some(Gender::Masculine)) => &[
+                (None, 12),
+                (Some(Self::Bearded), 3),
+                (Some(Self::Bald), 1),
+                (Some(Self::Scarred), 2),
+                (Some(Self::Tattooed), 1),
+                (Some(Self::Freckled), 1),
+                (Some(Self::Pierced), 1),
+                (Some(Self::Pockmarked), 1),
+                (Some(Self::OneEyed), 1),
+                (Some(Self::GapToothed), 1),
+            ],
+            _ => &[
+                (None, 12),
+                (Some(Self::Scarred), 2),
+                (Some(Self::Tattooed), 1),
+                (Some(Self::Freckled), 2),
+                (Some(Self::Pierced), 2),
+                (Some(Self::Pockmarked), 1),
+                (Some(Self::OneEyed), 1),
+                (Some(Self::GapToothed), 1),
+            ],
+        }
+    }
+}
+
+fn hair_colors(npc: &Npc) -> &'static [(&'static str, usize)] {
+    match (npc.age.value(), npc.species.value()) {
+        (Some(Age::Elderly | Age::Geriatric), _) => &[("grey", 4), ("white", 3), ("silver", 1)],
+        (_, Some(Species::Elf | Species::HalfElf)) => &[
+            ("silver", 2),
+            ("golden", 3),
+            ("black", 3),
+            ("copper", 2),
+            ("pale blonde", 2),
+            ("brown", 2),
+        ],
+        (_, Some(Species::Dwarf)) => &[("red", 3), ("brown", 4), ("black", 3), ("auburn", 2)],
+        (_, Some(Species::Gnome)) => &[
+            ("white", 2),
+            ("orange", 2),
+            ("brown", 3),
+            ("sandy", 2),
+            ("green", 1),
+        ],
+        (_, Some(Species::Firbolg)) => &[("red", 3), ("brown", 3), ("grey", 2), ("blonde", 1)],
+        (_, Some(Species::Genasi)) => &[
+            ("flame red", 3),
+            ("smouldering orange", 2),
+            ("coal black", 2),
+        ],
+        (_, Some(Species::Goblin)) => &[("black", 4), ("dark grey", 2), ("greasy brown", 2)],
+        (_, Some(Species::HalfOrc | Species::Orc)) => {
+            &[("black", 6), ("dark grey", 2), ("brown", 2)]
+        }
+        (_, Some(Species::Halfling)) => &[("brown", 6), ("sandy", 3), ("black", 2)],
+        (_, Some(Species::Tabaxi)) => &[
+            ("tawny", 4),
+            ("spotted", 3),
+            ("striped", 2),
+            ("black", 2),
+            ("grey", 1),
+        ],
+        (_, Some(Species::Tiefling)) => &[
+            ("black", 5),
+            ("dark red", 2),
+            ("deep purple", 2),
+            ("dark blue", 1),
+        ],
+        _ => &[
+            ("brown", 6),
+            ("black", 5),
+            ("blonde", 3),
+            ("red", 1),
+            ("auburn", 1),
+        ],
+    }
+}
+
+fn hair_styles(npc: &Npc) -> &'static [(&'static str, usize)] {
+    match (npc.age.value(), npc.species.value()) {
+        (Some(Age::Infant), _) => &[("wispy", 1)],
+        (Some(Age::Elderly | Age::Geriatric), _) => {
+            &[("thinning", 3), ("short", 2), ("long", 2), ("wild", 1)]
+        }
+        (_, Some(Species::Dwarf)) => &[("braided", 5), ("long", 2), ("short", 1)],
+        (_, Some(Species::Elf)) => &[("long", 5), ("braided", 2), ("flowing", 3)],
+        (_, Some(Species::Halfling)) => &[("curly", 6), ("short", 2), ("tousled", 2)],
+        _ => &[
+            ("short", 4),
+            ("long", 3),
+            ("curly", 2),
+            ("wavy", 2),
+            ("braided", 1),
+            ("cropped", 2),
+            ("tangled", 1),
+        ],
+    }
+}
+
+fn eye_colors(npc: &Npc) -> &'static [(&'static str, usize)] {
+    match npc.species.value() {
+        Some(Species::Dragonborn) => &[("amber", 4), ("gold", 3), ("red", 2), ("green", 1)],
+        Some(Species::Elf) => &[
+            ("green", 4),
+            ("violet", 2),
+            ("silver", 2),
+            ("blue", 3),
+            ("hazel", 2),
+        ],
+        Some(Species::Tiefling) => &[
+            ("solid black", 3),
+            ("solid red", 3),
+            ("golden", 2),
+            ("silver", 1),
+        ],
+        Some(Species::HalfOrc) => &[("brown", 4), ("grey", 2), ("red", 1), ("amber", 1)],
+        Some(Species::Gnome) => &[("blue", 4), ("green", 2), ("brown", 2), ("violet", 1)],
+        Some(Species::Aasimar) => &[("pupilless silver", 3), ("golden", 3), ("pale blue", 2)],
+        Some(Species::Genasi) => &[("glowing orange", 3), ("ember red", 2), ("golden", 1)],
+        Some(Species::Goblin) => &[("red", 3), ("yellow", 3), ("orange", 2)],
+        Some(Species::Kobold) => &[("red", 4), ("amber", 2), ("black", 2)],
+        Some(Species::Lizardfolk) => &[("yellow", 4), ("green", 2), ("amber", 2)],
+        Some(Species::Orc) => &[("red", 3), ("brown", 3), ("grey", 2)],
+        Some(Species::Tabaxi) => &[("green", 4), ("amber", 3), ("golden", 2), ("blue", 1)],
+        _ => &[
+            ("brown", 6),
+            ("blue", 3),
+            ("green", 2),
+            ("hazel", 2),
+            ("grey", 2),
+        ],
+    }
+}
+
+/// Generate the character's hair, eyes and distinguishing features, which depend on their species
+/// and age. Scaly species such as dragonborn have no hair, and nor do bald characters.
+pub fn regenerate(rng: &mut impl Rng, npc: &mut Npc) {
+    if npc.species.is_none() && npc.ethnicity.is_none() {
+        return;
+    }
+
+    if let Some(feature) = weighted_index_from_tuple(rng, Feature::weights(npc)) {
+        npc.feature.replace(*feature);
+    } else {
+        npc.feature.clear();
+    }
+
+    if matches!(
+        npc.species.value(),
+        Some(Species::Dragonborn | Species::Kobold | Species::Lizardfolk)
+    ) || npc.feature.value() == Some(&Feature::Bald)
+    {
+        npc.hair.clear();
+    } else {
+        let style = *weighted_index_from_tuple(rng, hair_styles(npc));
+        let color = *weighted_index_from_tuple(rng, hair_colors(npc));
+        npc.hair.replace(format!("{} {}", style, color));
+    }
+
+    let eyes = *weighted_index_from_tuple(rng, eye_colors(npc));
+    npc.eyes.replace(eyes.to_string());
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn regenerate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for _ in 0..100 {
+            let mut npc = Npc {
+                species: Species::Dragonborn.into(),
+                age: Age::Adult.into(),
+                ..Default::default()
+            };
+            regenerate(&mut rng, &mut npc);
+            assert!(npc.hair.is_none());
+            assert!(npc.eyes.is_some());
+
+            let mut npc = Npc {
+                species: Species::Human.into(),
+                age: Age::Infant.into(),
+                ..Default::default()
+            };
+            regenerate(&mut rng, &mut npc);
+            assert!(npc.feature.is_none());
+            assert!(npc.hair.value().unwrap().starts_with("wispy "));
+
+            let mut npc = Npc {
+                species: Species::Human.into(),
+                age: Age::Adult.into(),
+                feature: Feature::Bald.into(),
+                ..Default::default()
+            };
+            regenerate(&mut rng, &mut npc);
+            assert_eq!(Some(&Feature::Bald), npc.feature.value());
+            assert!(npc.hair.is_none());
+        }
+    }
+
+    #[test]
+    fn regenerate_test_no_species() {
+        let mut npc = Npc::default();
+        regenerate(&mut SmallRng::seed_from_u64(0), &mut npc);
+        assert_eq!(Npc::default(), npc);
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(Feature::OneEyed), "one-eyed".parse::<Feature>());
+        assert_eq!(Ok(Feature::Scarred), "Scarred".parse::<Feature>());
+        assert_eq!(Err(()), "potato".parse::<Feature>());
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!("gap-toothed", Feature::GapToothed.to_string());
+    }
+}
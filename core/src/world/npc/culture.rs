@@ -0,0 +1,496 @@
+use super::{ethnicity, Age, Gender, Language, Npc, Size, Species};
+use crate::world::weighted_index_from_tuple;
+use rand::Rng;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A homebrew culture loaded at runtime rather than compiled in, eg. from a `cultures.json` file.
+/// Characters belonging to a culture are generated in place of the built-in ethnicities, drawing
+/// their names, gender, age and size from the tables given here.
+///
+/// ```json
+/// {
+///   "name": "vesk",
+///   "species": "human",
+///   "population": 50000,
+///   "language": "draconic",
+///   "names": {
+///     "first": { "start": ["Ka", "Vo", "Thra"], "middle": ["ra"], "end": ["x", "sk"] },
+///     "last": { "syllables": [2], "start": ["Dun"], "end": ["mar", "vek"] }
+///   },
+///   "genders": { "feminine": 45, "masculine": 45, "non_binary": 10 },
+///   "ages": { "young_adult": 16, "lifespan": 60 },
+///   "sizes": { "height": [66, 80], "weight": [160, 280] }
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct Culture {
+    pub name: String,
+
+    #[serde(default = "Culture::default_species")]
+    pub species: Species,
+
+    #[serde(default = "Culture::default_population")]
+    pub population: u64,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    pub names: Names,
+
+    #[serde(default)]
+    pub genders: GenderWeights,
+
+    #[serde(default)]
+    pub ages: AgeStages,
+
+    #[serde(default)]
+    pub sizes: SizeRanges,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct Names {
+    pub first: NameParts,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last: Option<NameParts>,
+}
+
+/// Names are built by joining a `start` syllable, zero or more `middle` syllables, and an `end`
+/// syllable. The number of syllables is drawn from `syllables`, which defaults to two.
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct NameParts {
+    #[serde(default = "NameParts::default_syllables")]
+    pub syllables: Vec<u8>,
+    pub start: Vec<String>,
+
+    #[serde(default)]
+    pub middle: Vec<String>,
+    pub end: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(default)]
+pub struct GenderWeights {
+    pub feminine: u32,
+    pub masculine: u32,
+    pub non_binary: u32,
+}
+
+/// The age in years at which each stage of life begins, along with the oldest age a member of the
+/// culture can reach. Defaults to human ages.
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(default)]
+pub struct AgeStages {
+    pub child: u16,
+    pub adolescent: u16,
+    pub young_adult: u16,
+    pub adult: u16,
+    pub middle_aged: u16,
+    pub elderly: u16,
+    pub geriatric: u16,
+    pub lifespan: u16,
+}
+
+/// The range of adult heights (in inches) and weights (in pounds). Defaults to human sizes.
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(default)]
+pub struct SizeRanges {
+    pub height: (u16, u16),
+    pub weight: (u16, u16),
+}
+
+impl Culture {
+    /// Parse a list of culture definitions from JSON, rejecting any definition that can't be used
+    /// to generate characters.
+    pub fn parse_list(input: &str) -> Result<Vec<Self>, String> {
+        let cultures: Vec<Self> = serde_json::from_str(input).map_err(|e| e.to_string())?;
+        Self::validate_list(&cultures)?;
+        Ok(cultures)
+    }
+
+    /// Reject the list if any of its definitions can't be used to generate characters, eg. when
+    /// restoring cultures from a backup.
+    pub fn validate_list(cultures: &[Self]) -> Result<(), String> {
+        cultures.iter().try_for_each(|culture| {
+            culture
+                .validate()
+                .map_err(|e| format!("Invalid culture \"{}\": {}", culture.name, e))
+        })
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() || self.name.contains(char::is_whitespace) {
+            return Err("the name must be a single word".to_string());
+        }
+
+        self.names.first.validate()?;
+        if let Some(last) = &self.names.last {
+            last.validate()?;
+        }
+
+        let GenderWeights {
+            feminine,
+            masculine,
+            non_binary,
+        } = self.genders;
+        match feminine
+            .checked_add(masculine)
+            .and_then(|sum| sum.checked_add(non_binary))
+        {
+            Some(0) => return Err("at least one gender must have a weight".to_string()),
+            None => return Err("the gender weights are too large".to_string()),
+            Some(_) => {}
+        }
+
+        let stages = self.ages.stages();
+        if stages.windows(2).any(|pair| pair[0].0 >= pair[1].0)
+            || stages[stages.len() - 1].0 > self.ages.lifespan
+        {
+            return Err("the age stages must be in ascending order".to_string());
+        }
+
+        let SizeRanges { height, weight } = self.sizes;
+        if height.0 == 0 || height.0 > height.1 || weight.0 == 0 || weight.0 > weight.1 {
+            return Err("the size ranges must be non-zero and in ascending order".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Regenerate a character's gender, age, size and name according to the culture's tables.
+    /// Locked fields are left untouched.
+    pub fn regenerate(&self, rng: &mut impl Rng, npc: &mut Npc) {
+        npc.gender.replace_with(|_| self.gen_gender(rng));
+
+        match (npc.age.is_locked(), npc.age_years.is_locked()) {
+            (false, false) => {
+                let age_years = rng.gen_range(0..=self.ages.lifespan);
+                npc.age_years.replace(age_years);
+            }
+            (true, false) => {
+                let age_years = self.gen_years_from_age(rng, npc.age.value().unwrap());
+                npc.age_years.replace(age_years);
+            }
+            (_, true) => {}
+        }
+
+        if let Some(&years) = npc.age_years.value() {
+            npc.age.replace_with(|_| self.ages.age_from_years(years));
+        } else {
+            npc.age.clear();
+        }
+
+        if let Some(&age_years) = npc.age_years.value() {
+            npc.size.replace_with(|_| self.gen_size(rng, age_years));
+        }
+
+        if npc.gender.is_some() && npc.age.is_some() {
+            npc.name.replace_with(|_| self.gen_name(rng));
+        }
+    }
+
+    fn gen_gender(&self, rng: &mut impl Rng) -> Gender {
+        let weights = [
+            (Gender::Feminine, self.genders.feminine as usize),
+            (Gender::Masculine, self.genders.masculine as usize),
+            (Gender::NonBinaryThey, self.genders.non_binary as usize),
+        ];
+        *weighted_index_from_tuple(rng, &weights)
+    }
+
+    fn gen_years_from_age(&self, rng: &mut impl Rng, age: &Age) -> u16 {
+        let stages = self.ages.stages();
+        let index = stages.iter().position(|(_, a)| a == age).unwrap();
+        let start = stages[index].0;
+        let end = stages
+            .get(index + 1)
+            .map_or(self.ages.lifespan, |(years, _)| years - 1);
+        rng.gen_range(start..=end.max(start))
+    }
+
+    /// Children are scaled down from the adult size ranges until they come of age.
+    fn gen_size(&self, rng: &mut impl Rng, age_years: u16) -> Size {
+        let (min_height, max_height) = self.sizes.height;
+        let (min_weight, max_weight) = self.sizes.weight;
+
+        let height = rng.gen_range(min_height..=max_height);
+        let build = if max_height > min_height {
+            (height - min_height) as f32 / (max_height - min_height) as f32
+        } else {
+            0.5
+        };
+        let weight =
+            min_weight as f32 + (build + rng.gen::<f32>()) / 2. * (max_weight - min_weight) as f32;
+
+        Size::from_height_weight(Some(height), Some(weight.round() as u16))
+            .at_age(age_years, self.ages.young_adult)
+    }
+
+    fn gen_name(&self, rng: &mut impl Rng) -> String {
+        let first = self.names.first.gen_name(rng);
+
+        if let Some(last) = &self.names.last {
+            format!("{} {}", first, last.gen_name(rng))
+        } else {
+            first
+        }
+    }
+
+    fn default_species() -> Species {
+        Species::Human
+    }
+
+    fn default_population() -> u64 {
+        100_000
+    }
+}
+
+impl NameParts {
+    fn validate(&self) -> Result<(), String> {
+        if self.start.is_empty() || self.end.is_empty() {
+            Err("names must have at least one start and end syllable".to_string())
+        } else if self.syllables.is_empty() || self.syllables.iter().any(|&n| n < 2) {
+            Err("names must have at least two syllables".to_string())
+        } else if self.middle.is_empty() && self.syllables.iter().any(|&n| n > 2) {
+            Err("names with more than two syllables need middle syllables".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn gen_name(&self, rng: &mut impl Rng) -> String {
+        fn equal_weights<T: Copy>(input: impl Iterator<Item = T>) -> Vec<(T, usize)> {
+            input.map(|value| (value, 1)).collect()
+        }
+
+        ethnicity::gen_name(
+            rng,
+            &equal_weights(self.syllables.iter().copied()),
+            &equal_weights(self.start.iter().map(String::as_str)),
+            &equal_weights(self.middle.iter().map(String::as_str)),
+            &equal_weights(self.end.iter().map(String::as_str)),
+        )
+    }
+
+    fn default_syllables() -> Vec<u8> {
+        vec![2]
+    }
+}
+
+impl AgeStages {
+    fn stages(&self) -> [(u16, Age); 8] {
+        [
+            (0, Age::Infant),
+            (self.child, Age::Child),
+            (self.adolescent, Age::Adolescent),
+            (self.young_adult, Age::YoungAdult),
+            (self.adult, Age::Adult),
+            (self.middle_aged, Age::MiddleAged),
+            (self.elderly, Age::Elderly),
+            (self.geriatric, Age::Geriatric),
+        ]
+    }
+
+    fn age_from_years(&self, years: u16) -> Age {
+        self.stages()
+            .iter()
+            .rev()
+            .find(|(start, _)| years >= *start)
+            .map_or(Age::Infant, |(_, age)| *age)
+    }
+}
+
+impl Default for GenderWeights {
+    fn default() -> Self {
+        Self {
+            feminine: 50,
+            masculine: 50,
+            non_binary: 1,
+        }
+    }
+}
+
+impl Default for AgeStages {
+    fn default() -> Self {
+        Self {
+            child: 2,
+            adolescent: 10,
+            young_adult: 20,
+            adult: 30,
+            middle_aged: 40,
+            elderly: 60,
+            geriatric: 70,
+            lifespan: 79,
+        }
+    }
+}
+
+impl Default for SizeRanges {
+    fn default() -> Self {
+        Self {
+            height: (60, 74),
+            weight: (110, 220),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn parse_list_test_defaults() {
+        let cultures = Culture::parse_list(
+            r#"[{"name": "vesk", "names": {"first": {"start": ["Ka"], "end": ["rix"]}}}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![Culture {
+                name: "vesk".to_string(),
+                species: Species::Human,
+                population: 100_000,
+                language: None,
+                names: Names {
+                    first: NameParts {
+                        syllables: vec![2],
+                        start: vec!["Ka".to_string()],
+                        middle: Vec::new(),
+                        end: vec!["rix".to_string()],
+                    },
+                    last: None,
+                },
+                genders: GenderWeights::default(),
+                ages: AgeStages::default(),
+                sizes: SizeRanges::default(),
+            }],
+            cultures,
+        );
+    }
+
+    #[test]
+    fn parse_list_test_invalid() {
+        assert_eq!(
+            Err("Invalid culture \"two words\": the name must be a single word".to_string()),
+            Culture::parse_list(
+                r#"[{"name": "two words", "names": {"first": {"start": ["Ka"], "end": ["rix"]}}}]"#,
+            ),
+        );
+        assert_eq!(
+            Err("Invalid culture \"vesk\": names with more than two syllables need middle syllables".to_string()),
+            Culture::parse_list(
+                r#"[{"name": "vesk", "names": {"first": {"syllables": [3], "start": ["Ka"], "end": ["rix"]}}}]"#,
+            ),
+        );
+        assert_eq!(
+            Err("Invalid culture \"vesk\": the age stages must be in ascending order".to_string()),
+            Culture::parse_list(
+                r#"[{"name": "vesk", "names": {"first": {"start": ["Ka"], "end": ["rix"]}}, "ages": {"lifespan": 50}}]"#,
+            ),
+        );
+        assert_eq!(
+            Err("Invalid culture \"vesk\": the gender weights are too large".to_string()),
+            Culture::parse_list(
+                r#"[{"name": "vesk", "names": {"first": {"start": ["Ka"], "end": ["rix"]}}, "genders": {"feminine": 4294967295, "masculine": 1, "non_binary": 0}}]"#,
+            ),
+        );
+        assert!(Culture::parse_list(r#"[{"name": "vesk"}]"#).is_err());
+    }
+
+    #[test]
+    fn regenerate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let culture = culture();
+
+        for _ in 0..100 {
+            let mut npc = Npc::default();
+            culture.regenerate(&mut rng, &mut npc);
+
+            let age_years = *npc.age_years.value().unwrap();
+            assert!(age_years <= 30, "{}", age_years);
+            assert_eq!(
+                culture.ages.age_from_years(age_years),
+                *npc.age.value().unwrap()
+            );
+            assert_eq!(Some(&Gender::Feminine), npc.gender.value());
+            assert!(npc.name.value().unwrap().starts_with("Ka"));
+
+            if age_years >= 12 {
+                let size = npc.size.value().unwrap();
+                assert!((84..=90).contains(&size.height().unwrap()), "{:?}", size);
+                assert!((300..=350).contains(&size.weight().unwrap()), "{:?}", size);
+            }
+        }
+    }
+
+    #[test]
+    fn regenerate_test_locked() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let culture = culture();
+
+        for _ in 0..100 {
+            let mut npc = Npc {
+                age: Age::Elderly.into(),
+                gender: Gender::Masculine.into(),
+                ..Default::default()
+            };
+            culture.regenerate(&mut rng, &mut npc);
+
+            assert!(
+                (25..=27).contains(npc.age_years.value().unwrap()),
+                "{:?}",
+                npc.age_years,
+            );
+            assert_eq!(Some(&Gender::Masculine), npc.gender.value());
+        }
+    }
+
+    #[test]
+    fn age_from_years_test() {
+        let ages = AgeStages::default();
+
+        assert_eq!(Age::Infant, ages.age_from_years(0));
+        assert_eq!(Age::Child, ages.age_from_years(2));
+        assert_eq!(Age::YoungAdult, ages.age_from_years(29));
+        assert_eq!(Age::Geriatric, ages.age_from_years(u16::MAX));
+    }
+
+    fn culture() -> Culture {
+        Culture {
+            name: "vesk".to_string(),
+            species: Species::Dragonborn,
+            population: 1,
+            language: None,
+            names: Names {
+                first: NameParts {
+                    syllables: vec![2],
+                    start: vec!["Ka".to_string()],
+                    middle: Vec::new(),
+                    end: vec!["rix".to_string(), "thrax".to_string()],
+                },
+                last: None,
+            },
+            genders: GenderWeights {
+                feminine: 1,
+                masculine: 0,
+                non_binary: 0,
+            },
+            ages: AgeStages {
+                child: 2,
+                adolescent: 8,
+                young_adult: 12,
+                adult: 15,
+                middle_aged: 20,
+                elderly: 25,
+                geriatric: 28,
+                lifespan: 30,
+            },
+            sizes: SizeRanges {
+                height: (84, 90),
+                weight: (300, 350),
+            },
+        }
+    }
+}
@@ -146,6 +146,10 @@ impl GenerateSimple for Ethnicity {
         0.01652892561983471
     }
 
+    fn markov_probability() -> f64 {
+        0.25
+    }
+
     #[rustfmt::skip]
     fn word_lname_first() -> &'static [(&'static str, usize)] {
         &[
@@ -187,12 +191,12 @@ mod test_generate_for_ethnicity {
 
         assert_eq!(
             [
-                "Astaneshor Dragva",
-                "Ghonthor Crowngore",
-                "Gaumadasar Dreva",
-                "Bitar Lasthyl",
-                "Halalorax Flamesan",
-                "Akrena Ardendark",
+                "Astaneshor Crownwing",
+                "Vertor Dragclaw",
+                "Kedeh Dupresh",
+                "Bilalorith Flamesan",
+                "Ralithanotix Ardenmaugh",
+                "Maronnadrinathor Dragsan",
             ],
             [
                 gen_name(&mut rng, &age, &m),
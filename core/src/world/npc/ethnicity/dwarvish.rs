@@ -159,6 +159,10 @@ impl GenerateSimple for Ethnicity {
         0.323943661971831
     }
 
+    fn markov_probability() -> f64 {
+        0.25
+    }
+
     #[rustfmt::skip]
     fn word_lname_first() -> &'static [(&'static str, usize)] {
         &[
@@ -216,12 +220,12 @@ mod test_generate_for_ethnicity {
 
         assert_eq!(
             [
-                "Gargel Xunterler",
-                "Escokum Moonshield",
-                "Tileen Ironhand",
-                "Torketryd Muffinshield",
-                "Nalig Flamehewer",
-                "Turyn Stonesmelshield",
+                "Escocar Brewshield",
+                "Behlig Gemsgold",
+                "Veidi Gloomanvil",
+                "Youda Irondow",
+                "Molah Thunn",
+                "Garadavin Smokever",
             ],
             [
                 gen_name(&mut rng, &age, &m),
@@ -167,6 +167,10 @@ impl GenerateSimple for Ethnicity {
         0.14977477477477477
     }
 
+    fn markov_probability() -> f64 {
+        0.25
+    }
+
     #[rustfmt::skip]
     fn word_lname_first() -> &'static [(&'static str, usize)] {
         &[
@@ -235,15 +239,15 @@ mod test_generate_for_ethnicity {
 
         assert_eq!(
             [
-                "Sharyth Mitasom",
-                "Marlin Shiverstar",
-                "Arani Luversom",
-                "Tagar Evasong",
-                "Taros Dusthyl",
-                "Catel Irirolarn",
-                "Meleri Duleaf",
-                "Taten Shinnomath",
-                "Kina Riwareth",
+                "Sharyth Dusrastar",
+                "Duna Aenasong",
+                "Jaras Greensong",
+                "Ralansin Brightlong",
+                "Myrnar Irirolarn",
+                "Meheart Silverren",
+                "Fexa Blackrimeter",
+                "Mara Hadid",
+                "Raa Dusthil",
             ],
             [
                 gen_name(&mut rng, &Age::Infant, &m),
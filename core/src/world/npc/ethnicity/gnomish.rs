@@ -155,6 +155,10 @@ impl GenerateSimple for Ethnicity {
         0.2483221476510067
     }
 
+    fn markov_probability() -> f64 {
+        0.25
+    }
+
     #[rustfmt::skip]
     fn word_lname_first() -> &'static [(&'static str, usize)] {
         &[
@@ -208,12 +212,12 @@ mod test_generate_for_ethnicity {
 
         assert_eq!(
             [
-                "Gimger Glitkedjerel",
-                "Sankrist Weirdgrove",
-                "Ginzmilki Mirskilkerick",
-                "Jalni Harshade",
-                "Jimna Cosgershade",
-                "Malian Cosstone",
+                "Glynik Daerskillo",
+                "Gurno Spidersong",
+                "Ginki Kresrick",
+                "Arith Irontenshuthunrock",
+                "Grobles Gnarlight",
+                "Karist Weirdhand",
             ],
             [
                 gen_name(&mut rng, &age, &m),
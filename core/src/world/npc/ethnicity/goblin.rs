@@ -0,0 +1,189 @@
+use super::{Age, Gender, Generate, GenerateSimple};
+use rand::prelude::*;
+
+pub struct Ethnicity;
+
+impl GenerateSimple for Ethnicity {
+    fn syllable_fname_count_f() -> &'static [(u8, usize)] {
+        &[(2, 24), (3, 3)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_first_f() -> &'static [(&'static str, usize)] {
+        &[
+            ("Gri", 3), ("Nee", 2), ("Sni", 2), ("Yee", 2), ("Ska", 2), ("Bli", 1), ("Dru", 1),
+            ("Fli", 1), ("Gra", 1), ("Ki", 1), ("Mi", 1), ("Nar", 1), ("Pi", 1), ("Ri", 1),
+            ("Sla", 1), ("Ti", 1), ("Vri", 1), ("Zee", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_last_f() -> &'static [(&'static str, usize)] {
+        &[
+            ("ka", 3), ("mik", 2), ("la", 2), ("zza", 2), ("bit", 1), ("ble", 1), ("gee", 1),
+            ("na", 1), ("ra", 1), ("sha", 1), ("tik", 1), ("va", 1), ("xa", 1),
+        ]
+    }
+
+    fn syllable_fname_count_m() -> &'static [(u8, usize)] {
+        &[(2, 41), (3, 4)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_first_m() -> &'static [(&'static str, usize)] {
+        &[
+            ("Gro", 3), ("Kru", 2), ("Sna", 2), ("Vor", 2), ("Bo", 1), ("Dro", 1), ("Gla", 1),
+            ("Grak", 1), ("Hob", 1), ("Kla", 1), ("Lug", 1), ("Mug", 1), ("Nub", 1), ("Rug", 1),
+            ("Skab", 1), ("Spli", 1), ("Yar", 1), ("Zug", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_last_m() -> &'static [(&'static str, usize)] {
+        &[
+            ("gash", 3), ("bat", 2), ("nik", 2), ("rok", 2), ("bub", 1), ("dak", 1), ("gob", 1),
+            ("grub", 1), ("lob", 1), ("mak", 1), ("nog", 1), ("op", 1), ("rip", 1), ("snik", 1),
+            ("tuk", 1), ("zit", 1),
+        ]
+    }
+
+    fn syllable_fname_count() -> &'static [(u8, usize)] {
+        &[(2, 68), (3, 7)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_first() -> &'static [(&'static str, usize)] {
+        &[
+            ("Gri", 3), ("Gro", 3), ("Kru", 2), ("Nee", 2), ("Sna", 2), ("Sni", 2), ("Vor", 2),
+            ("Yee", 2), ("Ska", 2), ("Bli", 1), ("Bo", 1), ("Dro", 1), ("Dru", 1), ("Fli", 1),
+            ("Gla", 1), ("Gra", 1), ("Grak", 1), ("Hob", 1), ("Ki", 1), ("Kla", 1), ("Lug", 1),
+            ("Mi", 1), ("Mug", 1), ("Nar", 1), ("Nub", 1), ("Pi", 1), ("Ri", 1), ("Rug", 1),
+            ("Skab", 1), ("Sla", 1), ("Spli", 1), ("Ti", 1), ("Vri", 1), ("Yar", 1), ("Zee", 1),
+            ("Zug", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_last() -> &'static [(&'static str, usize)] {
+        &[
+            ("gash", 3), ("ka", 3), ("bat", 2), ("la", 2), ("mik", 2), ("nik", 2), ("rok", 2),
+            ("zza", 2), ("bit", 1), ("ble", 1), ("bub", 1), ("dak", 1), ("gee", 1), ("gob", 1),
+            ("grub", 1), ("lob", 1), ("mak", 1), ("na", 1), ("nog", 1), ("op", 1), ("ra", 1),
+            ("rip", 1), ("sha", 1), ("snik", 1), ("tik", 1), ("tuk", 1), ("va", 1), ("xa", 1),
+            ("zit", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_middle() -> &'static [(&'static str, usize)] {
+        &[
+            ("ga", 2), ("bi", 1), ("ku", 1), ("me", 1), ("na", 1), ("ri", 1), ("sni", 1),
+            ("zo", 1),
+        ]
+    }
+
+    fn syllable_lname_count() -> &'static [(u8, usize)] {
+        &[(2, 19), (3, 3)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_lname_first() -> &'static [(&'static str, usize)] {
+        &[
+            ("Crag", 2), ("Blood", 2), ("Bone", 2), ("Grim", 1), ("Gut", 1), ("Mud", 1),
+            ("Nak", 1), ("Rot", 1), ("Skul", 1), ("Snag", 1), ("Vex", 1), ("Zar", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_lname_middle() -> &'static [(&'static str, usize)] {
+        &[
+            ("bo", 1), ("ga", 1), ("na", 1), ("rik", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_lname_last() -> &'static [(&'static str, usize)] {
+        &[
+            ("maw", 3), ("tooth", 2), ("gnaw", 2), ("biter", 1), ("ear", 1), ("eye", 1),
+            ("fang", 1), ("gut", 1), ("nose", 1), ("rot", 1), ("skull", 1),
+        ]
+    }
+
+    fn compound_word_probability() -> f64 {
+        0.35714285714285715
+    }
+
+    fn markov_probability() -> f64 {
+        0.5
+    }
+
+    #[rustfmt::skip]
+    fn word_lname_first() -> &'static [(&'static str, usize)] {
+        &[
+            ("Bone", 2), ("Crag", 2), ("Black", 1), ("Blood", 1), ("Broken", 1), ("Mud", 1),
+            ("Red", 1), ("Rot", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn word_lname_last() -> &'static [(&'static str, usize)] {
+        &[
+            ("maw", 3), ("tooth", 2), ("ear", 1), ("eye", 1), ("fang", 1), ("gnaw", 1),
+            ("hand", 1), ("skull", 1),
+        ]
+    }
+}
+
+impl Generate for Ethnicity {
+    fn gen_name(rng: &mut impl Rng, _age: &Age, gender: &Gender) -> String {
+        format!(
+            "{} {}",
+            Self::gen_fname_simple(rng, gender),
+            Self::gen_lname_simple(rng),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_generate_for_ethnicity {
+    use super::*;
+    use crate::world::npc::ethnicity::{regenerate, Ethnicity};
+    use crate::world::Npc;
+
+    #[test]
+    fn gen_name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let age = Age::Adult;
+        let m = Gender::Masculine;
+        let f = Gender::Feminine;
+        let t = Gender::NonBinaryThey;
+
+        assert_eq!(
+            [
+                "Krurog Mudgnaw",
+                "Glamenibat Redhand",
+                "Tikarrina Bloodtooth",
+                "Ska Mudmaw",
+                "Yeera Cragear",
+                "Snigash Cragear",
+            ],
+            [
+                gen_name(&mut rng, &age, &m),
+                gen_name(&mut rng, &age, &m),
+                gen_name(&mut rng, &age, &f),
+                gen_name(&mut rng, &age, &f),
+                gen_name(&mut rng, &age, &t),
+                gen_name(&mut rng, &age, &t),
+            ],
+        );
+    }
+
+    fn gen_name(rng: &mut impl Rng, age: &Age, gender: &Gender) -> String {
+        let mut npc = Npc::default();
+        npc.gender.replace(*gender);
+        npc.age.replace(*age);
+        npc.ethnicity.replace(Ethnicity::Goblin);
+        regenerate(rng, &mut npc);
+        format!("{}", npc.name)
+    }
+}
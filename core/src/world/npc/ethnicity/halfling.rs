@@ -158,6 +158,10 @@ impl GenerateSimple for Ethnicity {
         0.28846153846153844
     }
 
+    fn markov_probability() -> f64 {
+        0.25
+    }
+
     #[rustfmt::skip]
     fn word_lname_first() -> &'static [(&'static str, usize)] {
         &[
@@ -215,12 +219,12 @@ mod test_generate_for_ethnicity {
 
         assert_eq!(
             [
-                "Buncicohock Listnes",
-                "Hudder Claypole",
-                "Brindell Netwiling",
-                "Gelter Listmabee",
-                "Wigdeha Appletopple",
-                "Sirimra Panaknife",
+                "Buncicohock Brightdelfir",
+                "Flalgope Fir",
+                "Brine Flameleaf",
+                "Notiwilek Rair",
+                "Estegope Alderwish",
+                "Ragast Longfin",
             ],
             [
                 gen_name(&mut rng, &age, &m),
@@ -163,6 +163,10 @@ impl GenerateSimple for Ethnicity {
         0.08863080684596578
     }
 
+    fn markov_probability() -> f64 {
+        0.25
+    }
+
     #[rustfmt::skip]
     fn word_lname_first() -> &'static [(&'static str, usize)] {
         &[
@@ -220,12 +224,12 @@ mod test_generate_for_ethnicity {
 
         assert_eq!(
             [
-                "Gorik Aulandran",
-                "Carleder Helkidran",
-                "Selania Maren",
-                "Delrasa Marton",
-                "Rina Tater",
-                "Mini Obarlin",
+                "Nibarik Miryn",
+                "Nabirer Obarskyr",
+                "Coni Obarar",
+                "Jharia Stormbar",
+                "Tatha Obarsaskyr",
+                "Neter Jagasri",
             ],
             [
                 gen_name(&mut rng, &age, &m),
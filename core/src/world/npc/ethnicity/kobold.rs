@@ -0,0 +1,183 @@
+use super::{Age, Gender, Generate, GenerateSimple};
+use rand::prelude::*;
+
+pub struct Ethnicity;
+
+impl GenerateSimple for Ethnicity {
+    fn syllable_fname_count_f() -> &'static [(u8, usize)] {
+        &[(2, 27), (3, 5)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_first_f() -> &'static [(&'static str, usize)] {
+        &[
+            ("Mee", 3), ("Ki", 2), ("Sa", 2), ("Ee", 2), ("Tes", 1), ("Vi", 1), ("Ix", 1),
+            ("Ne", 1), ("Oh", 1), ("Pik", 1), ("Si", 1), ("Zee", 1), ("Yip", 1), ("Ska", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_last_f() -> &'static [(&'static str, usize)] {
+        &[
+            ("po", 3), ("ki", 2), ("sa", 2), ("ka", 2), ("ssa", 1), ("tix", 1), ("la", 1),
+            ("vi", 1), ("ri", 1), ("ni", 1), ("ssik", 1), ("pi", 1),
+        ]
+    }
+
+    fn syllable_fname_count_m() -> &'static [(u8, usize)] {
+        &[(2, 34), (3, 6)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_first_m() -> &'static [(&'static str, usize)] {
+        &[
+            ("Ku", 3), ("Ga", 2), ("Ro", 2), ("Ta", 2), ("Ar", 1), ("Dee", 1), ("Ha", 1), ("Ir", 1),
+            ("Ka", 1), ("Mo", 1), ("Sni", 1), ("U", 1), ("Var", 1), ("Hox", 1), ("Sik", 1),
+            ("Ett", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_last_m() -> &'static [(&'static str, usize)] {
+        &[
+            ("rak", 3), ("lak", 2), ("tom", 2), ("kin", 2), ("ix", 1), ("lo", 1), ("gin", 1),
+            ("nar", 1), ("shak", 1), ("tos", 1), ("ru", 1), ("lax", 1), ("rit", 1), ("vik", 1),
+        ]
+    }
+
+    fn syllable_fname_count() -> &'static [(u8, usize)] {
+        &[(2, 61), (3, 11)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_first() -> &'static [(&'static str, usize)] {
+        &[
+            ("Ku", 3), ("Mee", 3), ("Ee", 2), ("Ga", 2), ("Ki", 2), ("Ro", 2), ("Sa", 2), ("Ta", 2),
+            ("Ar", 1), ("Dee", 1), ("Ett", 1), ("Ha", 1), ("Hox", 1), ("Ir", 1), ("Ix", 1),
+            ("Ka", 1), ("Mo", 1), ("Ne", 1), ("Oh", 1), ("Pik", 1), ("Si", 1), ("Sik", 1),
+            ("Ska", 1), ("Sni", 1), ("Tes", 1), ("U", 1), ("Var", 1), ("Vi", 1), ("Yip", 1),
+            ("Zee", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_last() -> &'static [(&'static str, usize)] {
+        &[
+            ("po", 3), ("rak", 3), ("ka", 2), ("ki", 2), ("kin", 2), ("lak", 2), ("sa", 2),
+            ("tom", 2), ("gin", 1), ("ix", 1), ("la", 1), ("lax", 1), ("lo", 1), ("nar", 1),
+            ("ni", 1), ("pi", 1), ("ri", 1), ("rit", 1), ("ru", 1), ("shak", 1), ("ssa", 1),
+            ("ssik", 1), ("tix", 1), ("tos", 1), ("vi", 1), ("vik", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_middle() -> &'static [(&'static str, usize)] {
+        &[
+            ("ku", 2), ("ta", 1), ("sa", 1), ("ri", 1), ("ga", 1), ("ko", 1), ("tul", 1), ("ma", 1),
+        ]
+    }
+
+    fn syllable_lname_count() -> &'static [(u8, usize)] {
+        &[(2, 12), (3, 2)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_lname_first() -> &'static [(&'static str, usize)] {
+        &[
+            ("Drak", 2), ("Ssar", 2), ("Ix", 1), ("Kur", 1), ("Tik", 1), ("Vas", 1), ("Zur", 1),
+            ("Sket", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_lname_middle() -> &'static [(&'static str, usize)] {
+        &[
+            ("ta", 1), ("ri", 1), ("sa", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_lname_last() -> &'static [(&'static str, usize)] {
+        &[
+            ("nak", 2), ("tul", 2), ("rix", 1), ("ssik", 1), ("mak", 1), ("kesh", 1), ("tok", 1),
+        ]
+    }
+
+    fn compound_word_probability() -> f64 {
+        0.7142857142857143
+    }
+
+    fn markov_probability() -> f64 {
+        0.5
+    }
+
+    #[rustfmt::skip]
+    fn word_lname_first() -> &'static [(&'static str, usize)] {
+        &[
+            ("Dragon", 4), ("Sharp", 2), ("Red", 2), ("Black", 1), ("Fire", 1), ("Iron", 1),
+            ("Sky", 1), ("Stone", 1), ("Trap", 1), ("Wyrm", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn word_lname_last() -> &'static [(&'static str, usize)] {
+        &[
+            ("shield", 3), ("fang", 2), ("scale", 2), ("claw", 2), ("tail", 1), ("horn", 1),
+            ("maker", 1), ("eye", 1), ("wing", 1),
+        ]
+    }
+}
+
+impl Generate for Ethnicity {
+    fn gen_name(rng: &mut impl Rng, _age: &Age, gender: &Gender) -> String {
+        format!(
+            "{} {}",
+            Self::gen_fname_simple(rng, gender),
+            Self::gen_lname_simple(rng),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_generate_for_ethnicity {
+    use super::*;
+    use crate::world::npc::ethnicity::{regenerate, Ethnicity};
+    use crate::world::Npc;
+
+    #[test]
+    fn gen_name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let age = Age::Adult;
+        let m = Gender::Masculine;
+        let f = Gender::Feminine;
+        let t = Gender::NonBinaryThey;
+
+        assert_eq!(
+            [
+                "Kumarakuix Ssartul",
+                "Varkin Kurnakritul",
+                "Satakoka Tikrix",
+                "Pik Ssarkesh",
+                "Gak Kurtatok",
+                "Hak Sketkesh",
+            ],
+            [
+                gen_name(&mut rng, &age, &m),
+                gen_name(&mut rng, &age, &m),
+                gen_name(&mut rng, &age, &f),
+                gen_name(&mut rng, &age, &f),
+                gen_name(&mut rng, &age, &t),
+                gen_name(&mut rng, &age, &t),
+            ],
+        );
+    }
+
+    fn gen_name(rng: &mut impl Rng, age: &Age, gender: &Gender) -> String {
+        let mut npc = Npc::default();
+        npc.gender.replace(*gender);
+        npc.age.replace(*age);
+        npc.ethnicity.replace(Ethnicity::Kobold);
+        regenerate(rng, &mut npc);
+        format!("{}", npc.name)
+    }
+}
@@ -0,0 +1,179 @@
+use super::{Age, Gender, Generate, GenerateSimple};
+use rand::prelude::*;
+
+pub struct Ethnicity;
+
+impl GenerateSimple for Ethnicity {
+    fn syllable_fname_count_f() -> &'static [(u8, usize)] {
+        &[(2, 20), (3, 9)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_first_f() -> &'static [(&'static str, usize)] {
+        &[
+            ("Ssa", 3), ("Ish", 2), ("Sre", 2), ("Tha", 2), ("Ve", 1), ("Ka", 1), ("Ssi", 1),
+            ("Tes", 1), ("Hi", 1), ("Xa", 1), ("Yss", 1), ("Ze", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_last_f() -> &'static [(&'static str, usize)] {
+        &[
+            ("ssa", 3), ("ka", 2), ("tha", 2), ("rix", 1), ("sha", 1), ("lis", 1), ("ksi", 1),
+            ("ressa", 1), ("sith", 1), ("ya", 1),
+        ]
+    }
+
+    fn syllable_fname_count_m() -> &'static [(u8, usize)] {
+        &[(2, 22), (3, 10)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_first_m() -> &'static [(&'static str, usize)] {
+        &[
+            ("Sess", 3), ("Ska", 2), ("Thur", 2), ("Gar", 2), ("Ach", 1), ("Draz", 1), ("Hiss", 1),
+            ("Kep", 1), ("Os", 1), ("Shuk", 1), ("Vus", 1), ("Zark", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_last_m() -> &'static [(&'static str, usize)] {
+        &[
+            ("hak", 3), ("rak", 2), ("sek", 2), ("kar", 2), ("thux", 1), ("zak", 1), ("ruk", 1),
+            ("shar", 1), ("gor", 1), ("nekh", 1), ("drak", 1),
+        ]
+    }
+
+    fn syllable_fname_count() -> &'static [(u8, usize)] {
+        &[(2, 42), (3, 19)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_first() -> &'static [(&'static str, usize)] {
+        &[
+            ("Sess", 3), ("Ssa", 3), ("Gar", 2), ("Ish", 2), ("Ska", 2), ("Sre", 2), ("Tha", 2),
+            ("Thur", 2), ("Ach", 1), ("Draz", 1), ("Hi", 1), ("Hiss", 1), ("Ka", 1), ("Kep", 1),
+            ("Os", 1), ("Shuk", 1), ("Ssi", 1), ("Tes", 1), ("Ve", 1), ("Vus", 1), ("Xa", 1),
+            ("Yss", 1), ("Zark", 1), ("Ze", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_last() -> &'static [(&'static str, usize)] {
+        &[
+            ("hak", 3), ("ssa", 3), ("ka", 2), ("kar", 2), ("rak", 2), ("sek", 2), ("tha", 2),
+            ("drak", 1), ("gor", 1), ("ksi", 1), ("lis", 1), ("nekh", 1), ("ressa", 1), ("rix", 1),
+            ("ruk", 1), ("sha", 1), ("shar", 1), ("sith", 1), ("thux", 1), ("ya", 1), ("zak", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_fname_middle() -> &'static [(&'static str, usize)] {
+        &[
+            ("sa", 2), ("ka", 2), ("ss", 1), ("tha", 1), ("ri", 1), ("xa", 1), ("ko", 1),
+        ]
+    }
+
+    fn syllable_lname_count() -> &'static [(u8, usize)] {
+        &[(2, 9), (3, 2)]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_lname_first() -> &'static [(&'static str, usize)] {
+        &[
+            ("Ssar", 1), ("Hak", 1), ("Thur", 1), ("Kess", 1), ("Ash", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_lname_middle() -> &'static [(&'static str, usize)] {
+        &[
+            ("sa", 1), ("ka", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn syllable_lname_last() -> &'static [(&'static str, usize)] {
+        &[
+            ("rak", 1), ("sith", 1), ("nekh", 1), ("thar", 1),
+        ]
+    }
+
+    fn compound_word_probability() -> f64 {
+        0.8260869565217391
+    }
+
+    fn markov_probability() -> f64 {
+        0.5
+    }
+
+    #[rustfmt::skip]
+    fn word_lname_first() -> &'static [(&'static str, usize)] {
+        &[
+            ("Mud", 3), ("Reed", 3), ("Marsh", 2), ("Swamp", 2), ("Stone", 1), ("Still", 1),
+            ("Bone", 1), ("Green", 1), ("Deep", 1), ("Black", 1), ("Cold", 1), ("Sun", 1),
+        ]
+    }
+
+    #[rustfmt::skip]
+    fn word_lname_last() -> &'static [(&'static str, usize)] {
+        &[
+            ("walker", 3), ("tail", 3), ("scale", 2), ("hunter", 2), ("fang", 2), ("eye", 1),
+            ("water", 1), ("skin", 1), ("biter", 1), ("stalker", 1), ("tooth", 1),
+        ]
+    }
+}
+
+impl Generate for Ethnicity {
+    fn gen_name(rng: &mut impl Rng, _age: &Age, gender: &Gender) -> String {
+        format!(
+            "{} {}",
+            Self::gen_fname_simple(rng, gender),
+            Self::gen_lname_simple(rng),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_generate_for_ethnicity {
+    use super::*;
+    use crate::world::npc::ethnicity::{regenerate, Ethnicity};
+    use crate::world::Npc;
+
+    #[test]
+    fn gen_name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let age = Age::Adult;
+        let m = Gender::Masculine;
+        let f = Gender::Feminine;
+        let t = Gender::NonBinaryThey;
+
+        assert_eq!(
+            [
+                "Zaruk Reedtail",
+                "Kepshak Marshfang",
+                "Zesya Swamptail",
+                "Hitha Marsheye",
+                "Keprirak Swampwalker",
+                "Zek Sunwalker",
+            ],
+            [
+                gen_name(&mut rng, &age, &m),
+                gen_name(&mut rng, &age, &m),
+                gen_name(&mut rng, &age, &f),
+                gen_name(&mut rng, &age, &f),
+                gen_name(&mut rng, &age, &t),
+                gen_name(&mut rng, &age, &t),
+            ],
+        );
+    }
+
+    fn gen_name(rng: &mut impl Rng, age: &Age, gender: &Gender) -> String {
+        let mut npc = Npc::default();
+        npc.gender.replace(*gender);
+        npc.age.replace(*age);
+        npc.ethnicity.replace(Ethnicity::Lizardfolk);
+        regenerate(rng, &mut npc);
+        format!("{}", npc.name)
+    }
+}
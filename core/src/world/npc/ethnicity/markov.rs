@@ -0,0 +1,355 @@
+use rand::Rng;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// The number of preceding characters used to predict the next one. Two is enough to keep names
+/// pronounceable without simply reproducing the training data.
+const ORDER: usize = 2;
+
+const START: char = '^';
+const END: char = '$';
+
+const MIN_LEN: usize = 3;
+const MAX_LEN: usize = 12;
+const MAX_ATTEMPTS: usize = 20;
+
+/// Generated names that happen to spell an ordinary word are rejected outright.
+#[rustfmt::skip]
+const REAL_WORDS: &[&str] = &[
+    "and", "bad", "bag", "bat", "bed", "big", "bit", "bob", "bog", "box", "bug", "but", "can",
+    "cat", "cot", "cub", "cut", "dad", "dog", "dot", "dug", "ear", "eat", "egg", "end", "eye",
+    "fan", "fat", "fig", "fin", "fit", "fog", "fun", "gag", "gap", "gas", "got", "gum", "gut",
+    "had", "ham", "has", "hat", "him", "his", "hit", "hog", "hot", "hub", "hug", "hut", "jar",
+    "kin", "kit", "lab", "lad", "lag", "lap", "let", "lid", "lip", "lit", "log", "lot", "mad",
+    "man", "map", "mat", "men", "mob", "mop", "mud", "mug", "nag", "nap", "net", "nib", "nod",
+    "not", "nut", "pad", "pal", "pan", "pat", "peg", "pen", "pet", "pig", "pin", "pit", "pod",
+    "pot", "pub", "pun", "rag", "ram", "rat", "red", "rib", "rid", "rim", "rip", "rob", "rod",
+    "rot", "rub", "rug", "sad", "sag", "sat", "set", "sin", "sip", "sit", "sob", "sod", "son",
+    "sub", "sum", "sun", "tab", "tag", "tan", "tap", "tar", "ten", "the", "tin", "tip", "top",
+    "tub", "tug", "van", "vat", "vet", "wag", "war", "was", "web", "wig", "win", "wit", "yak",
+    "yam", "zip", "baker", "banana", "bandit", "barber", "beggar", "bone", "bread", "cabbage",
+    "candle", "carrot", "dinner", "donkey", "garbage", "goblin", "grub", "kitten", "ladder",
+    "lemon", "melon", "mister", "mother", "muffin", "napkin", "noodle", "pickle", "rabbit",
+    "salad", "sister", "sugar", "toilet", "tomato", "trash", "wagon",
+];
+
+/// Generated names containing any of these are rejected, wherever they appear in the name.
+#[rustfmt::skip]
+const OFFENSIVE_WORDS: &[&str] = &[
+    "anus", "bitch", "butt", "cock", "cunt", "dick", "fag", "fart", "fuck", "jizz", "nazi",
+    "nigg", "penis", "piss", "poop", "porn", "rape", "shit", "slut", "tit", "twat", "wank",
+    "whore",
+];
+
+thread_local! {
+    /// Training a chain means enumerating every combination of syllables, so each set of tables
+    /// is only trained once. The tables are all `'static`, so their addresses identify them.
+    static CHAINS: RefCell<HashMap<[usize; 4], Rc<MarkovChain>>> = RefCell::default();
+}
+
+/// A character-level Markov chain trained on the names that a set of syllable tables can produce.
+/// Because it works one letter at a time, it can produce names that the tables never would, while
+/// keeping the same sounds and spelling conventions.
+///
+/// Weights are the product of several syllable weights, which can exceed a 32-bit `usize`, so
+/// they're counted as `u64` throughout.
+#[derive(Debug, Default)]
+pub struct MarkovChain {
+    transitions: HashMap<[char; ORDER], Vec<(char, u64)>>,
+    known: HashSet<String>,
+}
+
+impl MarkovChain {
+    pub fn train<S: AsRef<str>>(corpus: impl IntoIterator<Item = (S, u64)>) -> Self {
+        let mut counts: HashMap<[char; ORDER], HashMap<char, u64>> = HashMap::new();
+        let mut known = HashSet::new();
+
+        for (name, weight) in corpus {
+            let name = name.as_ref();
+            let mut context = [START; ORDER];
+
+            for c in name.chars().chain(std::iter::once(END)) {
+                *counts.entry(context).or_default().entry(c).or_default() += weight;
+                context.rotate_left(1);
+                context[ORDER - 1] = c;
+            }
+
+            known.insert(name.to_lowercase());
+        }
+
+        let transitions = counts
+            .into_iter()
+            .map(|(context, next)| {
+                let mut next: Vec<(char, u64)> = next.into_iter().collect();
+                // HashMap ordering is random, which would defeat seeded generation.
+                next.sort_unstable();
+                (context, next)
+            })
+            .collect();
+
+        Self { transitions, known }
+    }
+
+    /// Generate a name that doesn't appear in the training data and isn't a real word. Returns
+    /// `None` if no such name turns up after a reasonable number of attempts.
+    pub fn gen_name(&self, rng: &mut impl Rng) -> Option<String> {
+        (0..MAX_ATTEMPTS)
+            .filter_map(|_| self.gen_candidate(rng))
+            .find(|name| self.is_acceptable(name))
+    }
+
+    fn gen_candidate(&self, rng: &mut impl Rng) -> Option<String> {
+        let mut name = String::new();
+        let mut context = [START; ORDER];
+
+        loop {
+            let next = choose_weighted(rng, self.transitions.get(&context)?);
+
+            if next == END {
+                return Some(name);
+            } else if name.chars().count() >= MAX_LEN {
+                return None;
+            }
+
+            name.push(next);
+            context.rotate_left(1);
+            context[ORDER - 1] = next;
+        }
+    }
+
+    fn is_acceptable(&self, name: &str) -> bool {
+        let lower = name.to_lowercase();
+
+        lower.chars().count() >= MIN_LEN
+            && !self.known.contains(&lower)
+            && !REAL_WORDS.contains(&lower.as_str())
+            && !OFFENSIVE_WORDS.iter().any(|word| lower.contains(word))
+    }
+}
+
+/// Pick one of the options with a probability proportional to its weight. This does the same job
+/// as `weighted_index_from_tuple`, but with `u64` weights.
+fn choose_weighted(rng: &mut impl Rng, options: &[(char, u64)]) -> char {
+    let total: u64 = options.iter().map(|(_, weight)| weight).sum();
+    let mut target = rng.gen_range(0..total);
+
+    for &(c, weight) in options {
+        if target < weight {
+            return c;
+        }
+        target -= weight;
+    }
+
+    unreachable!()
+}
+
+/// Generate a name from a Markov chain trained on the given syllable tables, which take the same
+/// form as those passed to `ethnicity::gen_name`. The chain knows nothing of the journal, so the
+/// commands that create characters check each generated name against it before accepting it.
+pub fn gen_name(
+    rng: &mut impl Rng,
+    syllable_count_dist: &'static [(u8, usize)],
+    start_dist: &'static [(&'static str, usize)],
+    mid_dist: &'static [(&'static str, usize)],
+    end_dist: &'static [(&'static str, usize)],
+) -> Option<String> {
+    let key = [
+        syllable_count_dist.as_ptr() as usize,
+        start_dist.as_ptr() as usize,
+        mid_dist.as_ptr() as usize,
+        end_dist.as_ptr() as usize,
+    ];
+
+    let chain = CHAINS.with(|chains| {
+        chains
+            .borrow_mut()
+            .entry(key)
+            .or_insert_with(|| {
+                Rc::new(MarkovChain::train(corpus(
+                    syllable_count_dist,
+                    start_dist,
+                    mid_dist,
+                    end_dist,
+                )))
+            })
+            .clone()
+    });
+
+    chain.gen_name(rng)
+}
+
+/// Every two- and three-syllable name the tables can produce, weighted by how likely the syllable
+/// generator is to produce it. Longer names add little that the chain can't already infer.
+fn corpus(
+    syllable_count_dist: &[(u8, usize)],
+    start_dist: &[(&str, usize)],
+    mid_dist: &[(&str, usize)],
+    end_dist: &[(&str, usize)],
+) -> Vec<(String, u64)> {
+    let count_weight = |count: u8| -> u64 {
+        syllable_count_dist
+            .iter()
+            .filter(|(n, _)| *n == count || (count == 3 && *n > 3))
+            .map(|&(_, weight)| weight as u64)
+            .sum()
+    };
+    let (two_weight, three_weight) = (count_weight(2), count_weight(3));
+
+    let mut corpus = Vec::new();
+
+    for (start, start_weight) in start_dist {
+        for (end, end_weight) in end_dist {
+            if two_weight > 0 {
+                corpus.push((
+                    format!("{}{}", start, end),
+                    two_weight * *start_weight as u64 * *end_weight as u64,
+                ));
+            }
+
+            if three_weight > 0 {
+                for (mid, mid_weight) in mid_dist {
+                    corpus.push((
+                        format!("{}{}{}", start, mid, end),
+                        three_weight
+                            * *start_weight as u64
+                            * *mid_weight as u64
+                            * *end_weight as u64,
+                    ));
+                }
+            }
+        }
+    }
+
+    corpus
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn gen_name_test() {
+        let chain = chain();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            vec!["Grimirak", "Suldandorak", "Vadandorak", "Kel", "Grir"],
+            (0..5)
+                .map(|_| chain.gen_name(&mut rng).unwrap())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn gen_name_test_deterministic() {
+        let chain = chain();
+
+        let names = |seed| {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            (0..10)
+                .map(|_| chain.gen_name(&mut rng))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(1), names(1));
+        assert_ne!(names(1), names(2));
+    }
+
+    #[test]
+    fn gen_name_test_novel() {
+        let chain = chain();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let corpus: Vec<String> = corpus(TEST_COUNTS, TEST_START, TEST_MID, TEST_END)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        for _ in 0..100 {
+            if let Some(name) = chain.gen_name(&mut rng) {
+                assert!(!corpus.contains(&name), "{}", name);
+                assert!(name.len() >= MIN_LEN, "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn gen_name_test_exhausted() {
+        // The chain can only reproduce its single training name, which is rejected.
+        let chain = MarkovChain::train([("Kazak", 1)]);
+        assert_eq!(None, chain.gen_name(&mut SmallRng::seed_from_u64(0)));
+    }
+
+    #[test]
+    fn is_acceptable_test() {
+        let chain = MarkovChain::train([("Bob", 1)]);
+
+        assert!(chain.is_acceptable("Bobble"));
+        assert!(!chain.is_acceptable("Bob"));
+        assert!(!chain.is_acceptable("Mud"));
+        assert!(!chain.is_acceptable("Trash"));
+        assert!(!chain.is_acceptable("Bobshitty"));
+        assert!(!chain.is_acceptable("Bo"));
+    }
+
+    #[test]
+    fn corpus_test() {
+        assert_eq!(
+            vec![
+                ("Kaz".to_string(), 6),
+                ("Karaz".to_string(), 2),
+                ("Kak".to_string(), 3),
+                ("Karak".to_string(), 1),
+            ],
+            corpus(
+                &[(2, 3), (3, 1)],
+                &[("Ka", 1)],
+                &[("ra", 1)],
+                &[("z", 2), ("k", 1)]
+            ),
+        );
+    }
+
+    #[test]
+    fn corpus_test_large_weights() {
+        // Well beyond what a 32-bit `usize` can hold, as with the human syllable tables.
+        let corpus = corpus(
+            &[(2, 1_000), (3, 1_000)],
+            &[("Ka", 10_000), ("Zo", 10_000)],
+            &[("ra", 10_000)],
+            &[("z", 10_000), ("k", 10_000)],
+        );
+        assert_eq!(("Karaz".to_string(), 1_000_000_000_000_000), corpus[1]);
+
+        let chain = MarkovChain::train(corpus);
+        let mut rng = SmallRng::seed_from_u64(0);
+        (0..10).for_each(|_| {
+            chain.gen_name(&mut rng);
+        });
+    }
+
+    const TEST_COUNTS: &[(u8, usize)] = &[(2, 3), (3, 1)];
+    const TEST_START: &[(&str, usize)] = &[
+        ("Tha", 2),
+        ("Va", 2),
+        ("Gri", 1),
+        ("Kel", 1),
+        ("Mo", 1),
+        ("Sul", 1),
+    ];
+    const TEST_MID: &[(&str, usize)] = &[("ra", 1), ("li", 1), ("ndo", 1)];
+    const TEST_END: &[(&str, usize)] = &[
+        ("rak", 2),
+        ("zak", 1),
+        ("lok", 1),
+        ("mir", 1),
+        ("dan", 1),
+        ("vel", 1),
+    ];
+
+    fn chain() -> MarkovChain {
+        MarkovChain::train(corpus(TEST_COUNTS, TEST_START, TEST_MID, TEST_END))
+    }
+}
@@ -2,9 +2,14 @@ mod dragonborn;
 mod dwarvish;
 mod elvish;
 mod gnomish;
+mod goblin;
 mod halfling;
 mod human;
+mod kobold;
+mod lizardfolk;
+mod markov;
 mod orcish;
+mod tabaxi;
 mod tiefling;
 
 use super::{Age, Gender, Npc, Species};
@@ -21,9 +26,13 @@ pub enum Ethnicity {
     Dwarvish,
     Elvish,
     Gnomish,
+    Goblin,
     Orcish,
     Halfling,
     Human,
+    Kobold,
+    Lizardfolk,
+    Tabaxi,
     Tiefling,
 }
 
@@ -37,6 +46,10 @@ impl Ethnicity {
             Self::Gnomish => Species::Gnome,
             Self::Orcish => Species::HalfOrc,
             Self::Halfling => Species::Halfling,
+            Self::Goblin => Species::Goblin,
+            Self::Kobold => Species::Kobold,
+            Self::Lizardfolk => Species::Lizardfolk,
+            Self::Tabaxi => Species::Tabaxi,
             Self::Tiefling => Species::Tiefling,
         }
     }
@@ -54,25 +67,40 @@ trait Generate {
 
 trait GenerateSimple {
     fn gen_fname_simple(rng: &mut impl Rng, gender: &Gender) -> String {
-        gen_name(
+        let (count_dist, start_dist, end_dist) = match gender {
+            Gender::Feminine => (
+                Self::syllable_fname_count_f(),
+                Self::syllable_fname_first_f(),
+                Self::syllable_fname_last_f(),
+            ),
+            Gender::Masculine => (
+                Self::syllable_fname_count_m(),
+                Self::syllable_fname_first_m(),
+                Self::syllable_fname_last_m(),
+            ),
+            _ => (
+                Self::syllable_fname_count(),
+                Self::syllable_fname_first(),
+                Self::syllable_fname_last(),
+            ),
+        };
+
+        Self::gen_markov_name(
             rng,
-            match gender {
-                Gender::Feminine => Self::syllable_fname_count_f(),
-                Gender::Masculine => Self::syllable_fname_count_m(),
-                _ => Self::syllable_fname_count(),
-            },
-            match gender {
-                Gender::Feminine => Self::syllable_fname_first_f(),
-                Gender::Masculine => Self::syllable_fname_first_m(),
-                _ => Self::syllable_fname_first(),
-            },
+            count_dist,
+            start_dist,
             Self::syllable_fname_middle(),
-            match gender {
-                Gender::Feminine => Self::syllable_fname_last_f(),
-                Gender::Masculine => Self::syllable_fname_last_m(),
-                _ => Self::syllable_fname_last(),
-            },
+            end_dist,
         )
+        .unwrap_or_else(|| {
+            gen_name(
+                rng,
+                count_dist,
+                start_dist,
+                Self::syllable_fname_middle(),
+                end_dist,
+            )
+        })
     }
 
     fn gen_lname_simple(rng: &mut impl Rng) -> String {
@@ -83,13 +111,39 @@ trait GenerateSimple {
                 weighted_index_from_tuple(rng, Self::word_lname_last())
             )
         } else {
-            gen_name(
+            Self::gen_markov_name(
                 rng,
                 Self::syllable_lname_count(),
                 Self::syllable_lname_first(),
                 Self::syllable_lname_middle(),
                 Self::syllable_lname_last(),
             )
+            .unwrap_or_else(|| {
+                gen_name(
+                    rng,
+                    Self::syllable_lname_count(),
+                    Self::syllable_lname_first(),
+                    Self::syllable_lname_middle(),
+                    Self::syllable_lname_last(),
+                )
+            })
+        }
+    }
+
+    /// Some of the time, invent a new name from a Markov chain trained on the syllable tables
+    /// rather than assembling one from the syllables directly. Returns `None` if the syllable
+    /// tables should be used instead.
+    fn gen_markov_name(
+        rng: &mut impl Rng,
+        count_dist: &'static [(u8, usize)],
+        start_dist: &'static [(&'static str, usize)],
+        mid_dist: &'static [(&'static str, usize)],
+        end_dist: &'static [(&'static str, usize)],
+    ) -> Option<String> {
+        if rng.gen_bool(Self::markov_probability()) {
+            markov::gen_name(rng, count_dist, start_dist, mid_dist, end_dist)
+        } else {
+            None
         }
     }
 
@@ -108,6 +162,12 @@ trait GenerateSimple {
     fn syllable_lname_middle() -> &'static [(&'static str, usize)];
     fn syllable_lname_last() -> &'static [(&'static str, usize)];
     fn compound_word_probability() -> f64;
+
+    /// The share of names invented by the Markov chain, which keeps names from repeating over a
+    /// long campaign. Small syllable tables repeat sooner, so they lean on it more. This is fixed
+    /// for each ethnicity rather than being a user preference.
+    fn markov_probability() -> f64;
+
     fn word_lname_first() -> &'static [(&'static str, usize)];
     fn word_lname_last() -> &'static [(&'static str, usize)];
 }
@@ -119,9 +179,13 @@ pub fn regenerate(rng: &mut impl Rng, npc: &mut Npc) {
             Ethnicity::Dwarvish => dwarvish::Ethnicity::regenerate(rng, npc),
             Ethnicity::Elvish => elvish::Ethnicity::regenerate(rng, npc),
             Ethnicity::Gnomish => gnomish::Ethnicity::regenerate(rng, npc),
+            Ethnicity::Goblin => goblin::Ethnicity::regenerate(rng, npc),
             Ethnicity::Orcish => orcish::Ethnicity::regenerate(rng, npc),
             Ethnicity::Halfling => halfling::Ethnicity::regenerate(rng, npc),
             Ethnicity::Human => human::Ethnicity::regenerate(rng, npc),
+            Ethnicity::Kobold => kobold::Ethnicity::regenerate(rng, npc),
+            Ethnicity::Lizardfolk => lizardfolk::Ethnicity::regenerate(rng, npc),
+            Ethnicity::Tabaxi => tabaxi::Ethnicity::regenerate(rng, npc),
             Ethnicity::Tiefling => tiefling::Ethnicity::regenerate(rng, npc),
         }
     }
@@ -134,15 +198,19 @@ impl fmt::Display for Ethnicity {
             Self::Dwarvish => write!(f, "dwarvish"),
             Self::Elvish => write!(f, "elvish"),
             Self::Gnomish => write!(f, "gnomish"),
+            Self::Goblin => write!(f, "goblin"),
             Self::Orcish => write!(f, "orcish"),
             Self::Halfling => write!(f, "halfling"),
             Self::Human => write!(f, "human"),
+            Self::Kobold => write!(f, "kobold"),
+            Self::Lizardfolk => write!(f, "lizardfolk"),
+            Self::Tabaxi => write!(f, "tabaxi"),
             Self::Tiefling => write!(f, "tiefling"),
         }
     }
 }
 
-fn gen_name(
+pub(super) fn gen_name(
     rng: &mut impl Rng,
     syllable_count_dist: &[(u8, usize)],
     start_dist: &[(&str, usize)],
@@ -180,6 +248,10 @@ mod test {
         assert_eq!(Species::Halfling, Ethnicity::Halfling.default_species());
         assert_eq!(Species::Human, Ethnicity::Human.default_species());
         assert_eq!(Species::Tiefling, Ethnicity::Tiefling.default_species());
+        assert_eq!(Species::Goblin, Ethnicity::Goblin.default_species());
+        assert_eq!(Species::Kobold, Ethnicity::Kobold.default_species());
+        assert_eq!(Species::Lizardfolk, Ethnicity::Lizardfolk.default_species());
+        assert_eq!(Species::Tabaxi, Ethnicity::Tabaxi.default_species());
     }
 
     #[test]
@@ -135,6 +135,10 @@ impl GenerateSimple for Ethnicity {
         0.08333333333333333
     }
 
+    fn markov_probability() -> f64 {
+        0.25
+    }
+
     #[rustfmt::skip]
     fn word_lname_first() -> &'static [(&'static str, usize)] {
         &[
@@ -178,12 +182,12 @@ mod test_generate_for_ethnicity {
 
         assert_eq!(
             [
-                "Harmoel Ironsarkhan",
-                "Modatusk Il-the",
-                "Yagzel Threefist",
-                "Kreolik Ironsblood",
-                "Dahkan Deathfist",
-                "Jofalrak Krinri",
+                "Hoshnak Faykhan",
+                "Daeka Il-the",
+                "Thudalik Rarkat",
+                "Chaermith Besar",
+                "Rig Thelen",
+                "Mutrog Maslicort",
             ],
             [
                 gen_name(&mut rng, &age, &m),
@@ -0,0 +1,98 @@
+use super::{Age, Gender, Generate};
+use crate::world::weighted_index_from_tuple;
+use rand::prelude::*;
+
+pub struct Ethnicity;
+
+/// Tabaxi names are short phrases rather than syllables, so they can't be drawn from the same
+/// syllable tables as other cultures.
+impl Ethnicity {
+    const PHRASE_PROBABILITY: f64 = 0.25;
+
+    #[rustfmt::skip]
+    const WORD_FIRST: &'static [(&'static str, usize)] = &[
+        ("Five", 3), ("Jade", 3), ("Seven", 3), ("Smoke", 3), ("Bright", 2), ("Quiet", 2),
+        ("Silver", 2), ("Three", 2), ("Cloud", 1), ("Dancing", 1), ("Left-Handed", 1),
+        ("Moss", 1), ("Morning", 1), ("Rain", 1), ("Red", 1), ("Wind", 1),
+    ];
+
+    #[rustfmt::skip]
+    const WORD_LAST: &'static [(&'static str, usize)] = &[
+        ("Timber", 3), ("Feather", 2), ("Mirror", 2), ("Shoe", 2), ("Song", 2), ("Thundercloud", 2),
+        ("Basket", 1), ("Drum", 1), ("Hummingbird", 1), ("Lantern", 1), ("Leaf", 1),
+        ("Puddle", 1), ("River", 1), ("Star", 1), ("Stone", 1), ("Whisker", 1),
+    ];
+
+    #[rustfmt::skip]
+    const PHRASE_FIRST: &'static [(&'static str, usize)] = &[
+        ("Cloud", 2), ("Rain", 2), ("Smoke", 2), ("Bell", 1), ("Feather", 1), ("Shadow", 1),
+        ("Skirt", 1), ("Song", 1),
+    ];
+
+    #[rustfmt::skip]
+    const PHRASE_LAST: &'static [(&'static str, usize)] = &[
+        ("on the Mountaintop", 2), ("in the Reeds", 2), ("after the Storm", 1), ("at Dawn", 1),
+        ("in the Canopy", 1), ("of Many Colors", 1), ("of Snakes", 1), ("on the Wind", 1),
+        ("over the Water", 1), ("under the Moon", 1),
+    ];
+}
+
+impl Generate for Ethnicity {
+    fn gen_name(rng: &mut impl Rng, _age: &Age, _gender: &Gender) -> String {
+        let (first, last) = if rng.gen_bool(Self::PHRASE_PROBABILITY) {
+            (Self::PHRASE_FIRST, Self::PHRASE_LAST)
+        } else {
+            (Self::WORD_FIRST, Self::WORD_LAST)
+        };
+
+        format!(
+            "{} {}",
+            weighted_index_from_tuple(rng, first),
+            weighted_index_from_tuple(rng, last),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_generate_for_ethnicity {
+    use super::*;
+    use crate::world::npc::ethnicity::{regenerate, Ethnicity};
+    use crate::world::Npc;
+
+    #[test]
+    fn gen_name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let age = Age::Adult;
+        let m = Gender::Masculine;
+        let f = Gender::Feminine;
+        let t = Gender::NonBinaryThey;
+
+        assert_eq!(
+            [
+                "Bright Whisker",
+                "Rain Stone",
+                "Bright Song",
+                "Seven Whisker",
+                "Five Stone",
+                "Rain in the Reeds",
+            ],
+            [
+                gen_name(&mut rng, &age, &m),
+                gen_name(&mut rng, &age, &m),
+                gen_name(&mut rng, &age, &f),
+                gen_name(&mut rng, &age, &f),
+                gen_name(&mut rng, &age, &t),
+                gen_name(&mut rng, &age, &t),
+            ],
+        );
+    }
+
+    fn gen_name(rng: &mut impl Rng, age: &Age, gender: &Gender) -> String {
+        let mut npc = Npc::default();
+        npc.gender.replace(*gender);
+        npc.age.replace(*age);
+        npc.ethnicity.replace(Ethnicity::Tabaxi);
+        regenerate(rng, &mut npc);
+        format!("{}", npc.name)
+    }
+}
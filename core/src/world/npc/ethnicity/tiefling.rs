@@ -113,6 +113,10 @@ impl GenerateSimple for Ethnicity {
         0.
     }
 
+    fn markov_probability() -> f64 {
+        0.25
+    }
+
     fn word_lname_first() -> &'static [(&'static str, usize)] {
         &[]
     }
@@ -149,15 +153,15 @@ mod test_generate_for_ethnicity {
         assert_eq!(
             [
                 "Vidandos Shatos",
-                "Hevivyre Alegkwing",
-                "Bilmays Katos",
-                "Narra demaern",
-                "Matuor Shazar",
-                "Norislor Gulkwing",
-                "Tefer Shavitos",
-                "Orara deVore",
-                "Zevlia Sazar",
-                "Goroth Alegtos",
+                "Kavyre Kavith",
+                "Bilmam detos",
+                "Macvina Shabreath",
+                "Vimays Riizar",
+                "Kalen Sani",
+                "Orarpheris ShaVoreath",
+                "Nymana Alegzar",
+                "Kadrisdel Gulvikwing",
+                "Orader VrocVoreath",
             ],
             [
                 gen_name(&mut rng, &Age::Infant, &m),
@@ -0,0 +1,243 @@
+use super::{Age, Demographics, Ethnicity, Npc, Species};
+use initiative_macros::WordList;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Language {
+    Common,
+    Dwarvish,
+    Elvish,
+    Giant,
+    Gnomish,
+    Goblin,
+    Halfling,
+    Orc,
+    Abyssal,
+    Celestial,
+    Draconic,
+
+    #[term = "deep speech"]
+    DeepSpeech,
+    Infernal,
+    Primordial,
+    Sylvan,
+    Undercommon,
+}
+
+impl Language {
+    /// The name of the language as it is written, eg. "Deep Speech". This matches the SRD.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Common => "Common",
+            Self::Dwarvish => "Dwarvish",
+            Self::Elvish => "Elvish",
+            Self::Giant => "Giant",
+            Self::Gnomish => "Gnomish",
+            Self::Goblin => "Goblin",
+            Self::Halfling => "Halfling",
+            Self::Orc => "Orc",
+            Self::Abyssal => "Abyssal",
+            Self::Celestial => "Celestial",
+            Self::Draconic => "Draconic",
+            Self::DeepSpeech => "Deep Speech",
+            Self::Infernal => "Infernal",
+            Self::Primordial => "Primordial",
+            Self::Sylvan => "Sylvan",
+            Self::Undercommon => "Undercommon",
+        }
+    }
+
+    /// The tongue spoken by people raised in a given culture. Humans and tabaxi have no language
+    /// of their own beyond Common.
+    pub fn from_ethnicity(ethnicity: &Ethnicity) -> Option<Self> {
+        match ethnicity {
+            Ethnicity::Dragonborn => Some(Self::Draconic),
+            Ethnicity::Dwarvish => Some(Self::Dwarvish),
+            Ethnicity::Elvish => Some(Self::Elvish),
+            Ethnicity::Gnomish => Some(Self::Gnomish),
+            Ethnicity::Goblin => Some(Self::Goblin),
+            Ethnicity::Halfling => Some(Self::Halfling),
+            Ethnicity::Human | Ethnicity::Tabaxi => None,
+            Ethnicity::Kobold | Ethnicity::Lizardfolk => Some(Self::Draconic),
+            Ethnicity::Orcish => Some(Self::Orc),
+            Ethnicity::Tiefling => Some(Self::Infernal),
+        }
+    }
+
+    fn from_species(species: &Species) -> Option<Self> {
+        match species {
+            Species::Aasimar => Some(Self::Celestial),
+            Species::Dragonborn | Species::Kobold | Species::Lizardfolk => Some(Self::Draconic),
+            Species::Dwarf => Some(Self::Dwarvish),
+            Species::Elf | Species::HalfElf => Some(Self::Elvish),
+            Species::Firbolg => Some(Self::Giant),
+            Species::Genasi => Some(Self::Primordial),
+            Species::Gnome => Some(Self::Gnomish),
+            Species::Goblin => Some(Self::Goblin),
+            Species::HalfOrc | Species::Orc => Some(Self::Orc),
+            Species::Halfling => Some(Self::Halfling),
+            Species::Human | Species::Tabaxi => None,
+            Species::Tiefling => Some(Self::Infernal),
+        }
+    }
+}
+
+/// Everyone speaks Common along with the tongue of their people, including that of any custom
+/// culture they belong to. Some characters have also picked
+/// up the language of a neighbour, which is drawn from the local demographics; humans, who have
+/// no tongue of their own, are the most likely to. Infants don't speak anything yet.
+pub fn regenerate(rng: &mut impl Rng, npc: &mut Npc, demographics: &Demographics) {
+    if npc.species.is_none() && npc.ethnicity.is_none() {
+        return;
+    }
+
+    if npc.age.value() == Some(&Age::Infant) {
+        npc.languages.clear();
+        return;
+    }
+
+    let mut languages = vec![Language::Common];
+
+    let native = [
+        npc.ethnicity.value().and_then(Language::from_ethnicity),
+        npc.culture
+            .value()
+            .and_then(|name| demographics.culture(name))
+            .and_then(|culture| culture.language),
+        npc.species.value().and_then(Language::from_species),
+    ];
+
+    for language in native.into_iter().flatten() {
+        if !languages.contains(&language) {
+            languages.push(language);
+        }
+    }
+
+    let extra_chance = if languages.len() == 1 { 0.5 } else { 0.15 };
+
+    if rng.gen_bool(extra_chance) {
+        let (_, ethnicity) = demographics.gen_species_ethnicity(rng);
+
+        if let Some(language) = Language::from_ethnicity(&ethnicity) {
+            if !languages.contains(&language) {
+                languages.push(language);
+            }
+        }
+    }
+
+    npc.languages.replace_with(|_| languages);
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn regenerate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let demographics = Demographics::new(HashMap::from([
+            ((Species::Human, Ethnicity::Human), 1),
+            ((Species::Elf, Ethnicity::Elvish), 1),
+        ]));
+
+        let mut elvish_humans = 0;
+
+        for _ in 0..100 {
+            let mut npc = Npc {
+                species: Species::Dwarf.into(),
+                ethnicity: Ethnicity::Dwarvish.into(),
+                age: Age::Adult.into(),
+                ..Default::default()
+            };
+            regenerate(&mut rng, &mut npc, &demographics);
+            let languages = npc.languages.value().unwrap();
+            assert_eq!(&[Language::Common, Language::Dwarvish], &languages[..2]);
+
+            let mut npc = Npc {
+                species: Species::Human.into(),
+                ethnicity: Ethnicity::Human.into(),
+                age: Age::Adult.into(),
+                ..Default::default()
+            };
+            regenerate(&mut rng, &mut npc, &demographics);
+            let languages = npc.languages.value().unwrap();
+            assert_eq!(Language::Common, languages[0]);
+            if languages.contains(&Language::Elvish) {
+                elvish_humans += 1;
+            }
+        }
+
+        assert!(elvish_humans > 10, "{}", elvish_humans);
+    }
+
+    #[test]
+    fn regenerate_test_half_elf() {
+        let mut npc = Npc {
+            species: Species::HalfElf.into(),
+            ethnicity: Ethnicity::Human.into(),
+            age: Age::Adult.into(),
+            ..Default::default()
+        };
+        regenerate(
+            &mut SmallRng::seed_from_u64(0),
+            &mut npc,
+            &Demographics::default(),
+        );
+        assert!(npc.languages.value().unwrap().contains(&Language::Elvish));
+    }
+
+    #[test]
+    fn regenerate_test_infant() {
+        let mut npc = Npc {
+            species: Species::Elf.into(),
+            age: Age::Infant.into(),
+            ..Default::default()
+        };
+        regenerate(
+            &mut SmallRng::seed_from_u64(0),
+            &mut npc,
+            &Demographics::default(),
+        );
+        assert!(npc.languages.is_none());
+    }
+
+    #[test]
+    fn regenerate_test_locked() {
+        let mut npc = Npc {
+            species: Species::Elf.into(),
+            age: Age::Adult.into(),
+            languages: vec![Language::Sylvan].into(),
+            ..Default::default()
+        };
+        regenerate(
+            &mut SmallRng::seed_from_u64(0),
+            &mut npc,
+            &Demographics::default(),
+        );
+        assert_eq!(Some(&vec![Language::Sylvan]), npc.languages.value());
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(Language::DeepSpeech), "Deep Speech".parse::<Language>());
+        assert_eq!(Ok(Language::Elvish), "elvish".parse::<Language>());
+        assert_eq!(Err(()), "potato".parse::<Language>());
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!("Deep Speech", Language::DeepSpeech.to_string());
+        assert_eq!("deep speech", Language::DeepSpeech.as_str());
+    }
+}
@@ -1,46 +1,140 @@
 pub use age::Age;
+pub use appearance::Feature;
+pub use culture::Culture;
 pub use ethnicity::Ethnicity;
 pub use gender::Gender;
-pub use size::Size;
+pub use language::Language;
+pub use personality::Personality;
+pub use relationship::Relationship;
+pub use role::Role;
+pub use size::{parse_height, parse_weight, Size, Units};
 pub use species::Species;
+pub use stats::{Archetype, StatBlock};
 pub use view::{DescriptionView, DetailsView, SummaryView};
 
 mod age;
+mod appearance;
+mod culture;
 mod ethnicity;
 mod gender;
+mod language;
+mod personality;
+mod relationship;
+mod role;
 mod size;
 mod species;
+mod stats;
 mod view;
 
+use super::place::PlaceType;
 use super::{Demographics, Field, Generate, Place, PlaceUuid};
 use rand::Rng;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 initiative_macros::uuid!();
 
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
 pub struct Npc {
     pub uuid: Option<Uuid>,
     pub name: Field<String>,
     pub gender: Field<Gender>,
     pub age: Field<Age>,
     pub age_years: Field<u16>,
+
+    /// The character's size category, along with their height in inches and weight in pounds.
     pub size: Field<Size>,
+
     pub species: Field<Species>,
     pub ethnicity: Field<Ethnicity>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub culture: Field<String>,
+
     pub location_uuid: Field<PlaceUuid>,
-    // pub home: Field<PlaceUuid>,
-    // pub occupation: Field<Role>,
-    // pub languages: Field<Vec<String>>,
-    // pub parents: Field<Vec<Uuid>>,
-    // pub spouses: Field<Vec<Uuid>>,
-    // pub siblings: Field<Vec<Uuid>>,
-    // pub children: Field<Vec<Uuid>>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub home_uuid: Field<PlaceUuid>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub occupation: Field<Role>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub archetype: Field<Archetype>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub personality: Field<Personality>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub feature: Field<Feature>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub hair: Field<String>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub eyes: Field<String>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub ideal: Field<String>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub bond: Field<String>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub flaw: Field<String>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub motivation: Field<String>,
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub languages: Field<Vec<Language>>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub parents: Field<Vec<Uuid>>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub spouses: Field<Vec<Uuid>>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub siblings: Field<Vec<Uuid>>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub children: Field<Vec<Uuid>>,
 }
 
 #[derive(Debug, Default)]
 pub struct NpcRelations {
     pub location: Option<(Place, Option<Place>)>,
+    pub home: Option<Place>,
+    pub parents: Vec<Npc>,
+    pub spouses: Vec<Npc>,
+    pub siblings: Vec<Npc>,
+    pub children: Vec<Npc>,
+}
+
+impl NpcRelations {
+    pub fn relatives(&self, relationship: Relationship) -> &[Npc] {
+        match relationship {
+            Relationship::Parent => &self.parents,
+            Relationship::Spouse => &self.spouses,
+            Relationship::Sibling => &self.siblings,
+            Relationship::Child => &self.children,
+        }
+    }
+
+    pub fn relatives_mut(&mut self, relationship: Relationship) -> &mut Vec<Npc> {
+        match relationship {
+            Relationship::Parent => &mut self.parents,
+            Relationship::Spouse => &mut self.spouses,
+            Relationship::Sibling => &mut self.siblings,
+            Relationship::Child => &mut self.children,
+        }
+    }
+
+    pub fn has_relatives(&self) -> bool {
+        Relationship::ALL
+            .iter()
+            .any(|&relationship| !self.relatives(relationship).is_empty())
+    }
 }
 
 impl Npc {
@@ -52,8 +146,8 @@ impl Npc {
         DescriptionView::new(self)
     }
 
-    pub fn display_details(&self, relations: NpcRelations) -> DetailsView {
-        DetailsView::new(self, relations)
+    pub fn display_details(&self, relations: NpcRelations, units: Units) -> DetailsView {
+        DetailsView::new(self, relations, units)
     }
 
     pub fn gender(&self) -> Gender {
@@ -77,7 +171,24 @@ impl Npc {
             size,
             species,
             ethnicity,
+            culture,
             location_uuid,
+            home_uuid,
+            occupation,
+            archetype,
+            personality,
+            feature,
+            hair,
+            eyes,
+            ideal,
+            bond,
+            flaw,
+            motivation,
+            languages,
+            parents,
+            spouses,
+            siblings,
+            children,
         } = self;
 
         name.lock();
@@ -87,7 +198,207 @@ impl Npc {
         size.lock();
         species.lock();
         ethnicity.lock();
+        culture.lock();
         location_uuid.lock();
+        home_uuid.lock();
+        occupation.lock();
+        archetype.lock();
+        personality.lock();
+        feature.lock();
+        hair.lock();
+        eyes.lock();
+        ideal.lock();
+        bond.lock();
+        flaw.lock();
+        motivation.lock();
+        languages.lock();
+        parents.lock();
+        spouses.lock();
+        siblings.lock();
+        children.lock();
+    }
+
+    /// Unlock any empty fields, so that applying the NPC as a diff leaves them untouched.
+    pub fn unlock_empty(&mut self) {
+        let Self {
+            uuid: _,
+            name,
+            gender,
+            age,
+            age_years,
+            size,
+            species,
+            ethnicity,
+            culture,
+            location_uuid,
+            home_uuid,
+            occupation,
+            archetype,
+            personality,
+            feature,
+            hair,
+            eyes,
+            ideal,
+            bond,
+            flaw,
+            motivation,
+            languages,
+            parents,
+            spouses,
+            siblings,
+            children,
+        } = self;
+
+        name.unlock_if_none();
+        gender.unlock_if_none();
+        age.unlock_if_none();
+        age_years.unlock_if_none();
+        size.unlock_if_none();
+        species.unlock_if_none();
+        ethnicity.unlock_if_none();
+        culture.unlock_if_none();
+        location_uuid.unlock_if_none();
+        home_uuid.unlock_if_none();
+        occupation.unlock_if_none();
+        archetype.unlock_if_none();
+        personality.unlock_if_none();
+        feature.unlock_if_none();
+        hair.unlock_if_none();
+        eyes.unlock_if_none();
+        ideal.unlock_if_none();
+        bond.unlock_if_none();
+        flaw.unlock_if_none();
+        motivation.unlock_if_none();
+        languages.unlock_if_none();
+        parents.unlock_if_none();
+        spouses.unlock_if_none();
+        siblings.unlock_if_none();
+        children.unlock_if_none();
+    }
+
+    /// The names of the locked fields. Lock state isn't recorded when the character is serialized,
+    /// so anything that needs to preserve it must do so separately.
+    pub fn locked_fields(&self) -> Vec<&'static str> {
+        let Self {
+            uuid: _,
+            name,
+            gender,
+            age,
+            age_years,
+            size,
+            species,
+            ethnicity,
+            culture,
+            location_uuid,
+            home_uuid,
+            occupation,
+            archetype,
+            personality,
+            feature,
+            hair,
+            eyes,
+            ideal,
+            bond,
+            flaw,
+            motivation,
+            languages,
+            parents,
+            spouses,
+            siblings,
+            children,
+        } = self;
+
+        [
+            ("name", name.is_locked()),
+            ("gender", gender.is_locked()),
+            ("age", age.is_locked()),
+            ("age_years", age_years.is_locked()),
+            ("size", size.is_locked()),
+            ("species", species.is_locked()),
+            ("ethnicity", ethnicity.is_locked()),
+            ("culture", culture.is_locked()),
+            ("location_uuid", location_uuid.is_locked()),
+            ("home_uuid", home_uuid.is_locked()),
+            ("occupation", occupation.is_locked()),
+            ("archetype", archetype.is_locked()),
+            ("personality", personality.is_locked()),
+            ("feature", feature.is_locked()),
+            ("hair", hair.is_locked()),
+            ("eyes", eyes.is_locked()),
+            ("ideal", ideal.is_locked()),
+            ("bond", bond.is_locked()),
+            ("flaw", flaw.is_locked()),
+            ("motivation", motivation.is_locked()),
+            ("languages", languages.is_locked()),
+            ("parents", parents.is_locked()),
+            ("spouses", spouses.is_locked()),
+            ("siblings", siblings.is_locked()),
+            ("children", children.is_locked()),
+        ]
+        .into_iter()
+        .filter_map(|(field_name, is_locked)| is_locked.then_some(field_name))
+        .collect()
+    }
+
+    /// Lock the named fields and unlock all others, restoring the state recorded by
+    /// [`Self::locked_fields`].
+    pub fn set_locked_fields(&mut self, field_names: &[String]) {
+        let Self {
+            uuid: _,
+            name,
+            gender,
+            age,
+            age_years,
+            size,
+            species,
+            ethnicity,
+            culture,
+            location_uuid,
+            home_uuid,
+            occupation,
+            archetype,
+            personality,
+            feature,
+            hair,
+            eyes,
+            ideal,
+            bond,
+            flaw,
+            motivation,
+            languages,
+            parents,
+            spouses,
+            siblings,
+            children,
+        } = self;
+
+        let includes = |field_name: &str| field_names.iter().any(|s| s == field_name);
+
+        name.set_locked(includes("name"));
+        gender.set_locked(includes("gender"));
+        age.set_locked(includes("age"));
+        age_years.set_locked(includes("age_years"));
+        size.set_locked(includes("size"));
+        species.set_locked(includes("species"));
+        ethnicity.set_locked(includes("ethnicity"));
+        culture.set_locked(includes("culture"));
+        location_uuid.set_locked(includes("location_uuid"));
+        home_uuid.set_locked(includes("home_uuid"));
+        occupation.set_locked(includes("occupation"));
+        archetype.set_locked(includes("archetype"));
+        personality.set_locked(includes("personality"));
+        feature.set_locked(includes("feature"));
+        hair.set_locked(includes("hair"));
+        eyes.set_locked(includes("eyes"));
+        ideal.set_locked(includes("ideal"));
+        bond.set_locked(includes("bond"));
+        flaw.set_locked(includes("flaw"));
+        motivation.set_locked(includes("motivation"));
+        languages.set_locked(includes("languages"));
+        parents.set_locked(includes("parents"));
+        spouses.set_locked(includes("spouses"));
+        siblings.set_locked(includes("siblings"));
+        children.set_locked(includes("children"));
     }
 
     pub fn apply_diff(&mut self, diff: &mut Self) {
@@ -100,49 +411,224 @@ impl Npc {
             size,
             species,
             ethnicity,
+            culture,
             location_uuid,
+            home_uuid,
+            occupation,
+            archetype,
+            personality,
+            feature,
+            hair,
+            eyes,
+            ideal,
+            bond,
+            flaw,
+            motivation,
+            languages,
+            parents,
+            spouses,
+            siblings,
+            children,
         } = self;
 
         name.apply_diff(&mut diff.name);
         gender.apply_diff(&mut diff.gender);
         age.apply_diff(&mut diff.age);
         age_years.apply_diff(&mut diff.age_years);
+
+        // A diff may give only a height or a weight, eg. `Mira is 5'4"`, keeping the rest of the
+        // current size.
+        if let (Some(new_size), Some(current_size)) = (diff.size.value_mut(), size.value()) {
+            if !new_size.is_complete() {
+                *new_size = new_size.complete_with(current_size);
+            }
+        }
+
         size.apply_diff(&mut diff.size);
         species.apply_diff(&mut diff.species);
         ethnicity.apply_diff(&mut diff.ethnicity);
+        culture.apply_diff(&mut diff.culture);
         location_uuid.apply_diff(&mut diff.location_uuid);
+        home_uuid.apply_diff(&mut diff.home_uuid);
+        occupation.apply_diff(&mut diff.occupation);
+        archetype.apply_diff(&mut diff.archetype);
+        personality.apply_diff(&mut diff.personality);
+        feature.apply_diff(&mut diff.feature);
+        hair.apply_diff(&mut diff.hair);
+        eyes.apply_diff(&mut diff.eyes);
+        ideal.apply_diff(&mut diff.ideal);
+        bond.apply_diff(&mut diff.bond);
+        flaw.apply_diff(&mut diff.flaw);
+        motivation.apply_diff(&mut diff.motivation);
+        languages.apply_diff(&mut diff.languages);
+        parents.apply_diff(&mut diff.parents);
+        spouses.apply_diff(&mut diff.spouses);
+        siblings.apply_diff(&mut diff.siblings);
+        children.apply_diff(&mut diff.children);
+    }
+
+    /// The character's combat statistics, if they have been given an archetype.
+    pub fn stat_block(&self) -> Option<StatBlock> {
+        self.archetype
+            .value()
+            .map(|archetype| archetype.stat_block(self.species.value()))
+    }
+
+    /// The UUIDs of the relatives recorded on this NPC. Unsaved relatives that link to this NPC
+    /// aren't included; see `Repository::load_relations` for the full picture.
+    pub fn relatives(&self, relationship: Relationship) -> &[Uuid] {
+        match relationship {
+            Relationship::Parent => &self.parents,
+            Relationship::Spouse => &self.spouses,
+            Relationship::Sibling => &self.siblings,
+            Relationship::Child => &self.children,
+        }
+        .value()
+        .map_or(&[], |uuids| uuids.as_slice())
+    }
+
+    pub fn add_relative(&mut self, relationship: Relationship, uuid: Uuid) {
+        let field = self.relatives_field_mut(relationship);
+
+        if let Some(uuids) = field.value_mut() {
+            if !uuids.contains(&uuid) {
+                uuids.push(uuid);
+            }
+        } else {
+            *field = vec![uuid].into();
+        }
+    }
+
+    /// Remove any links to the given relative, returning the relationships that were removed.
+    pub fn remove_relative(&mut self, uuid: &Uuid) -> Vec<Relationship> {
+        Relationship::ALL
+            .iter()
+            .filter(|&&relationship| {
+                let field = self.relatives_field_mut(relationship);
+
+                if let Some(uuids) = field.value_mut() {
+                    if let Some(index) = uuids.iter().position(|u| u == uuid) {
+                        uuids.remove(index);
+                        if uuids.is_empty() {
+                            *field = Field::default();
+                        }
+                        return true;
+                    }
+                }
+
+                false
+            })
+            .copied()
+            .collect()
+    }
+
+    fn relatives_field_mut(&mut self, relationship: Relationship) -> &mut Field<Vec<Uuid>> {
+        match relationship {
+            Relationship::Parent => &mut self.parents,
+            Relationship::Spouse => &mut self.spouses,
+            Relationship::Sibling => &mut self.siblings,
+            Relationship::Child => &mut self.children,
+        }
     }
 }
 
-impl Generate for Npc {
-    fn regenerate(&mut self, rng: &mut impl Rng, demographics: &Demographics) {
-        match (self.species.is_locked(), self.ethnicity.is_locked()) {
-            (false, false) => {
-                let (species, ethnicity) = demographics.gen_species_ethnicity(rng);
-                self.ethnicity.replace(ethnicity);
-                self.species.replace(species);
+impl Npc {
+    /// Regenerate the NPC, taking into account the type of place where it is located. A
+    /// character generated in a temple is likely to be a priest, for instance.
+    pub fn regenerate_at(
+        &mut self,
+        rng: &mut impl Rng,
+        demographics: &Demographics,
+        location: Option<&PlaceType>,
+    ) {
+        if self.culture.is_unlocked() {
+            let culture = if self.species.is_unlocked() && self.ethnicity.is_unlocked() {
+                demographics.gen_culture(rng)
+            } else {
+                None
+            };
+
+            if let Some(culture) = culture {
+                self.culture.replace(culture.name.clone());
+            } else {
+                self.culture.clear();
             }
-            (false, true) => {
-                self.species.replace(
-                    demographics
-                        .only_ethnicity(self.ethnicity.value().unwrap())
-                        .gen_species_ethnicity(rng)
-                        .0,
-                );
+        }
+
+        // A height or weight given on its own is kept, and the rest of the size generated around
+        // it.
+        let partial_size = self
+            .size
+            .value()
+            .filter(|size| !size.is_complete())
+            .cloned();
+        if partial_size.is_some() {
+            self.size = Field::default();
+        }
+
+        // A custom culture takes the place of both the species and ethnicity generators.
+        if let Some(culture) = self
+            .culture
+            .value()
+            .and_then(|name| demographics.culture(name))
+        {
+            self.species.replace(culture.species);
+            self.ethnicity.clear();
+            culture.regenerate(rng, self);
+        } else {
+            match (self.species.is_locked(), self.ethnicity.is_locked()) {
+                (false, false) => {
+                    let (species, ethnicity) = demographics.gen_species_ethnicity(rng);
+                    self.ethnicity.replace(ethnicity);
+                    self.species.replace(species);
+                }
+                (false, true) => {
+                    self.species.replace(
+                        demographics
+                            .only_ethnicity(self.ethnicity.value().unwrap())
+                            .gen_species_ethnicity(rng)
+                            .0,
+                    );
+                }
+                (true, false) => {
+                    self.ethnicity.replace(
+                        demographics
+                            .only_species(self.species.value().unwrap())
+                            .gen_species_ethnicity(rng)
+                            .1,
+                    );
+                }
+                (true, true) => {}
             }
-            (true, false) => {
-                self.ethnicity.replace(
-                    demographics
-                        .only_species(self.species.value().unwrap())
-                        .gen_species_ethnicity(rng)
-                        .1,
-                );
+
+            species::regenerate(rng, self);
+            ethnicity::regenerate(rng, self);
+        }
+
+        if let Some(partial_size) = partial_size {
+            self.size = match self.size.value() {
+                Some(size) => partial_size.complete_with(size),
+                None => partial_size,
             }
-            (true, true) => {}
+            .into();
         }
 
-        species::regenerate(rng, self);
-        ethnicity::regenerate(rng, self);
+        self.regenerate_occupation(rng, location);
+        appearance::regenerate(rng, self);
+        personality::regenerate(rng, self);
+        language::regenerate(rng, self, demographics);
+    }
+
+    /// Regenerate only the occupation, as when an existing character moves to a new location.
+    pub fn regenerate_occupation(&mut self, rng: &mut impl Rng, location: Option<&PlaceType>) {
+        role::regenerate(rng, self, location);
+        stats::regenerate(self);
+    }
+}
+
+impl Generate for Npc {
+    fn regenerate(&mut self, rng: &mut impl Rng, demographics: &Demographics) {
+        self.regenerate_at(rng, demographics, None);
     }
 }
 
@@ -176,11 +662,11 @@ mod test {
         let npc = gandalf();
 
         assert_eq!(
-            r#"{"uuid":"00000000-0000-0000-0000-000000000000","name":"Gandalf the Grey","gender":"neuter","age":"geriatric","age_years":65535,"size":{"type":"Medium","height":72,"weight":200},"species":"human","ethnicity":"human","location_uuid":null}"#,
+            r#"{"uuid":"00000000-0000-0000-0000-000000000000","name":"Gandalf the Grey","gender":"neuter","age":"geriatric","age_years":65535,"size":{"type":"Medium","height":72,"weight":200},"species":"human","ethnicity":"human","culture":null,"location_uuid":null,"home_uuid":null,"occupation":"scholar","archetype":"mage","personality":"witty","feature":"bearded","hair":"long grey","eyes":"blue","ideal":"knowledge","bond":"the Fellowship","flaw":"quick to anger","motivation":"to see Sauron defeated","languages":["common","elvish"],"parents":null,"spouses":null,"siblings":null,"children":null}"#,
             serde_json::to_string(&npc).unwrap()
         );
 
-        let value: Npc = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","name":"Gandalf the Grey","gender":"neuter","age":"geriatric","age_years":65535,"size":{"type":"Medium","height":72,"weight":200},"species":"human","ethnicity":"human","location_uuid":null}"#).unwrap();
+        let value: Npc = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","name":"Gandalf the Grey","gender":"neuter","age":"geriatric","age_years":65535,"size":{"type":"Medium","height":72,"weight":200},"species":"human","ethnicity":"human","culture":null,"location_uuid":null,"home_uuid":null,"occupation":"scholar","archetype":"mage","personality":"witty","feature":"bearded","hair":"long grey","eyes":"blue","ideal":"knowledge","bond":"the Fellowship","flaw":"quick to anger","motivation":"to see Sauron defeated","languages":["common","elvish"],"parents":null,"spouses":null,"siblings":null,"children":null}"#).unwrap();
 
         assert_eq!(npc, value);
     }
@@ -213,6 +699,68 @@ mod test {
         assert_eq!(empty_locked, diff);
     }
 
+    #[test]
+    fn apply_diff_test_height() {
+        let mut npc = gandalf();
+        let mut diff = Npc {
+            size: Size::from_height_weight(Some(64), None).into(),
+            ..Default::default()
+        };
+
+        npc.apply_diff(&mut diff);
+
+        assert_eq!(
+            Some(&Size::Medium {
+                height: Some(64),
+                weight: Some(200)
+            }),
+            npc.size.value(),
+        );
+
+        npc.apply_diff(&mut diff);
+        assert_eq!(gandalf(), npc);
+    }
+
+    #[test]
+    fn regenerate_test_locked_weight() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let demographics = Demographics::default();
+
+        let mut npc = Npc {
+            species: Species::Human.into(),
+            age: Age::Adult.into(),
+            size: Size::from_height_weight(None, Some(140)).into(),
+            ..Default::default()
+        };
+        npc.regenerate(&mut rng, &demographics);
+
+        let size = npc.size.value().unwrap();
+        assert_eq!(Some(140), size.weight());
+        assert!(size.is_complete());
+    }
+
+    #[test]
+    fn regenerate_test_child_size() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let demographics = Demographics::default();
+
+        // Adult dwarves are at least 4'2", so a child shouldn't come close.
+        for _ in 0..10 {
+            let mut npc = Npc {
+                species: Species::Dwarf.into(),
+                age: Age::Child.into(),
+                ..Default::default()
+            };
+            npc.regenerate(&mut rng, &demographics);
+
+            assert!(
+                npc.size.value().unwrap().height().unwrap() < 48,
+                "{:?}",
+                npc.size
+            );
+        }
+    }
+
     fn gandalf() -> Npc {
         Npc {
             uuid: Some(uuid::Uuid::nil().into()),
@@ -221,13 +769,30 @@ mod test {
             age: Age::Geriatric.into(),
             age_years: u16::MAX.into(),
             size: Size::Medium {
-                height: 72,
-                weight: 200,
+                height: Some(72),
+                weight: Some(200),
             }
             .into(),
             species: Species::Human.into(),
             ethnicity: Ethnicity::Human.into(),
+            culture: None.into(),
             location_uuid: None.into(),
+            home_uuid: None.into(),
+            occupation: Role::Scholar.into(),
+            archetype: Archetype::Mage.into(),
+            personality: Personality::Witty.into(),
+            feature: Feature::Bearded.into(),
+            hair: "long grey".into(),
+            eyes: "blue".into(),
+            ideal: "knowledge".into(),
+            bond: "the Fellowship".into(),
+            flaw: "quick to anger".into(),
+            motivation: "to see Sauron defeated".into(),
+            languages: vec![Language::Common, Language::Elvish].into(),
+            parents: None.into(),
+            spouses: None.into(),
+            siblings: None.into(),
+            children: None.into(),
         }
     }
 
@@ -246,9 +811,53 @@ mod test {
                 size: Field::Locked(None),
                 species: Field::Locked(None),
                 ethnicity: Field::Locked(None),
+                culture: Field::Locked(None),
                 location_uuid: Field::Locked(None),
+                home_uuid: Field::Locked(None),
+                occupation: Field::Locked(None),
+                archetype: Field::Locked(None),
+                personality: Field::Locked(None),
+                feature: Field::Locked(None),
+                hair: Field::Locked(None),
+                eyes: Field::Locked(None),
+                ideal: Field::Locked(None),
+                bond: Field::Locked(None),
+                flaw: Field::Locked(None),
+                motivation: Field::Locked(None),
+                languages: Field::Locked(None),
+                parents: Field::Locked(None),
+                spouses: Field::Locked(None),
+                siblings: Field::Locked(None),
+                children: Field::Locked(None),
             },
             npc,
         );
     }
+
+    #[test]
+    fn relatives_test() {
+        let (alice, bob): (Uuid, Uuid) =
+            (uuid::Uuid::nil().into(), uuid::Uuid::from_u128(1).into());
+        let mut npc = Npc::default();
+
+        npc.add_relative(Relationship::Parent, alice.clone());
+        npc.add_relative(Relationship::Parent, alice.clone());
+        npc.add_relative(Relationship::Parent, bob.clone());
+        npc.add_relative(Relationship::Sibling, alice.clone());
+
+        assert_eq!(
+            &[alice.clone(), bob.clone()][..],
+            npc.relatives(Relationship::Parent),
+        );
+        assert_eq!(&[alice.clone()][..], npc.relatives(Relationship::Sibling));
+        assert!(npc.relatives(Relationship::Child).is_empty());
+
+        assert_eq!(
+            vec![Relationship::Parent, Relationship::Sibling],
+            npc.remove_relative(&alice),
+        );
+        assert_eq!(&[bob.clone()][..], npc.relatives(Relationship::Parent));
+        assert!(npc.siblings.is_unset());
+        assert!(npc.remove_relative(&alice).is_empty());
+    }
 }
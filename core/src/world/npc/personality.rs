@@ -0,0 +1,284 @@
+use super::{Age, Npc, Species};
+use crate::world::weighted_index_from_tuple;
+use initiative_macros::WordList;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Personality {
+    Arrogant,
+    Brave,
+    Cheerful,
+    Cowardly,
+    Curious,
+    Friendly,
+    Greedy,
+
+    #[alias = "grouchy"]
+    Grumpy,
+    Honest,
+    Lazy,
+    Nervous,
+    Pious,
+    Rude,
+    Shy,
+    Stoic,
+    Suspicious,
+    Talkative,
+    Witty,
+}
+
+impl Personality {
+    const WEIGHTS: &'static [(Self, usize)] = &[
+        (Self::Arrogant, 2),
+        (Self::Brave, 2),
+        (Self::Cheerful, 3),
+        (Self::Cowardly, 1),
+        (Self::Curious, 3),
+        (Self::Friendly, 3),
+        (Self::Greedy, 2),
+        (Self::Grumpy, 2),
+        (Self::Honest, 2),
+        (Self::Lazy, 2),
+        (Self::Nervous, 2),
+        (Self::Pious, 1),
+        (Self::Rude, 1),
+        (Self::Shy, 2),
+        (Self::Stoic, 2),
+        (Self::Suspicious, 2),
+        (Self::Talkative, 2),
+        (Self::Witty, 2),
+    ];
+
+    /// Children are rarely stoic or pious, and more likely to be curious or shy.
+    const WEIGHTS_CHILD: &'static [(Self, usize)] = &[
+        (Self::Brave, 2),
+        (Self::Cheerful, 4),
+        (Self::Curious, 5),
+        (Self::Friendly, 3),
+        (Self::Grumpy, 1),
+        (Self::Lazy, 1),
+        (Self::Nervous, 2),
+        (Self::Rude, 1),
+        (Self::Shy, 3),
+        (Self::Talkative, 3),
+    ];
+}
+
+fn ideals(npc: &Npc) -> &'static [(&'static str, usize)] {
+    match npc.species.value() {
+        Some(Species::Dwarf) => &[
+            ("tradition", 5),
+            ("honor", 4),
+            ("community", 2),
+            ("wealth", 2),
+            ("fairness", 1),
+        ],
+        Some(Species::Elf) => &[
+            ("beauty", 4),
+            ("freedom", 3),
+            ("knowledge", 3),
+            ("tradition", 2),
+            ("independence", 1),
+        ],
+        Some(Species::Halfling) => &[
+            ("community", 5),
+            ("charity", 3),
+            ("freedom", 2),
+            ("fairness", 2),
+        ],
+        Some(Species::Dragonborn) => &[
+            ("honor", 5),
+            ("power", 3),
+            ("tradition", 2),
+            ("fairness", 1),
+        ],
+        _ => &[
+            ("charity", 2),
+            ("community", 2),
+            ("faith", 2),
+            ("fairness", 2),
+            ("freedom", 2),
+            ("honor", 2),
+            ("independence", 2),
+            ("knowledge", 2),
+            ("power", 2),
+            ("tradition", 2),
+            ("wealth", 2),
+        ],
+    }
+}
+
+fn bonds(npc: &Npc) -> &'static [(&'static str, usize)] {
+    match npc.species.value() {
+        Some(Species::Dwarf) => &[
+            ("the honor of their clan", 5),
+            ("an ancestral weapon", 2),
+            ("their family", 3),
+            ("the hold where they were born", 2),
+        ],
+        _ => &[
+            ("their family", 5),
+            ("their hometown", 3),
+            ("a childhood friend", 2),
+            ("their mentor", 2),
+            ("a lost love", 1),
+            ("their faith", 2),
+            ("their employer", 1),
+            ("a treasured heirloom", 1),
+        ],
+    }
+}
+
+const FLAWS: &[(&str, usize)] = &[
+    ("quick to anger", 2),
+    ("can't resist a wager", 1),
+    ("terrible at keeping secrets", 2),
+    ("holds a grudge", 2),
+    ("drinks too much", 2),
+    ("trusts too easily", 2),
+    ("panics in a crisis", 1),
+    ("vain", 1),
+    ("greedy", 1),
+    ("stubborn", 2),
+];
+
+fn motivations(npc: &Npc) -> &'static [(&'static str, usize)] {
+    match npc.age.value() {
+        Some(Age::Child) => &[
+            ("to go on an adventure", 3),
+            ("to get a pet", 2),
+            ("to be taken seriously", 2),
+            ("to avoid their chores", 2),
+        ],
+        Some(Age::Adolescent | Age::YoungAdult) => &[
+            ("to make a name for themself", 3),
+            ("to see the world", 3),
+            ("to win someone's heart", 2),
+            ("to escape their family's expectations", 2),
+            ("to get rich", 1),
+        ],
+        Some(Age::Elderly | Age::Geriatric) => &[
+            ("to pass on what they know", 3),
+            ("to make amends for past mistakes", 2),
+            ("to see their family settled", 2),
+            ("to enjoy a quiet retirement", 2),
+            ("to settle an old score", 1),
+        ],
+        _ => &[
+            ("to provide for their family", 4),
+            ("to pay off a debt", 2),
+            ("to get rich", 2),
+            ("to get revenge on a rival", 1),
+            ("to protect their home", 2),
+            ("to find a missing relative", 1),
+            ("to earn a promotion", 2),
+        ],
+    }
+}
+
+/// Infants don't have much of a personality yet, and children aren't old enough for ideals,
+/// bonds or flaws, although they certainly know what they want.
+pub fn regenerate(rng: &mut impl Rng, npc: &mut Npc) {
+    let age = npc.age.value().copied();
+
+    match age {
+        Some(Age::Infant) | None => npc.personality.clear(),
+        Some(Age::Child) => npc
+            .personality
+            .replace_with(|_| *weighted_index_from_tuple(rng, Personality::WEIGHTS_CHILD)),
+        Some(_) => npc
+            .personality
+            .replace_with(|_| *weighted_index_from_tuple(rng, Personality::WEIGHTS)),
+    }
+
+    if matches!(age, Some(Age::Infant | Age::Child) | None) {
+        npc.ideal.clear();
+        npc.bond.clear();
+        npc.flaw.clear();
+    } else {
+        let ideal = *weighted_index_from_tuple(rng, ideals(npc));
+        let bond = *weighted_index_from_tuple(rng, bonds(npc));
+        let flaw = *weighted_index_from_tuple(rng, FLAWS);
+
+        npc.ideal.replace(ideal.to_string());
+        npc.bond.replace(bond.to_string());
+        npc.flaw.replace(flaw.to_string());
+    }
+
+    if matches!(age, Some(Age::Infant) | None) {
+        npc.motivation.clear();
+    } else {
+        let motivation = *weighted_index_from_tuple(rng, motivations(npc));
+        npc.motivation.replace(motivation.to_string());
+    }
+}
+
+impl fmt::Display for Personality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn regenerate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let mut npc = Npc {
+            age: Age::Adult.into(),
+            species: Species::Dwarf.into(),
+            ..Default::default()
+        };
+        regenerate(&mut rng, &mut npc);
+        assert!(npc.personality.is_some());
+        assert!(npc.ideal.is_some());
+        assert!(npc.bond.is_some());
+        assert!(npc.flaw.is_some());
+        assert!(npc.motivation.is_some());
+
+        npc.age = Age::Child.into();
+        regenerate(&mut rng, &mut npc);
+        assert!(npc.personality.is_some());
+        assert!(npc.ideal.is_none());
+        assert!(npc.motivation.is_some());
+
+        npc.age = Age::Infant.into();
+        regenerate(&mut rng, &mut npc);
+        assert!(npc.personality.is_none());
+        assert!(npc.motivation.is_none());
+    }
+
+    #[test]
+    fn regenerate_test_locked() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut npc = Npc {
+            age: Age::Adult.into(),
+            personality: Personality::Grumpy.into(),
+            ..Default::default()
+        };
+
+        for _ in 0..10 {
+            regenerate(&mut rng, &mut npc);
+            assert_eq!(Some(&Personality::Grumpy), npc.personality.value());
+        }
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(Personality::Grumpy), "grumpy".parse::<Personality>());
+        assert_eq!(Ok(Personality::Grumpy), "Grouchy".parse::<Personality>());
+        assert_eq!(Err(()), "potato".parse::<Personality>());
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!("cheerful", Personality::Cheerful.to_string());
+    }
+}
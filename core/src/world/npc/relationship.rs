@@ -0,0 +1,123 @@
+use super::Gender;
+use std::fmt;
+
+/// A family tie between two characters, from the perspective of the relative. A `Parent` is the
+/// parent of the other character, a `Child` is their child, and so on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Relationship {
+    Parent,
+    Spouse,
+    Sibling,
+    Child,
+}
+
+impl Relationship {
+    pub const ALL: [Self; 4] = [Self::Parent, Self::Spouse, Self::Sibling, Self::Child];
+
+    /// Parse a word like "daughter", returning the relationship along with the gender it implies,
+    /// if any.
+    pub fn parse_word(word: &str) -> Option<(Self, Option<Gender>)> {
+        match word.to_lowercase().as_str() {
+            "parent" => Some((Self::Parent, None)),
+            "mother" => Some((Self::Parent, Some(Gender::Feminine))),
+            "father" => Some((Self::Parent, Some(Gender::Masculine))),
+            "spouse" => Some((Self::Spouse, None)),
+            "wife" => Some((Self::Spouse, Some(Gender::Feminine))),
+            "husband" => Some((Self::Spouse, Some(Gender::Masculine))),
+            "sibling" => Some((Self::Sibling, None)),
+            "sister" => Some((Self::Sibling, Some(Gender::Feminine))),
+            "brother" => Some((Self::Sibling, Some(Gender::Masculine))),
+            "child" => Some((Self::Child, None)),
+            "daughter" => Some((Self::Child, Some(Gender::Feminine))),
+            "son" => Some((Self::Child, Some(Gender::Masculine))),
+            _ => None,
+        }
+    }
+
+    pub fn get_words() -> &'static [&'static str] {
+        &[
+            "parent", "mother", "father", "spouse", "wife", "husband", "sibling", "sister",
+            "brother", "child", "daughter", "son",
+        ][..]
+    }
+
+    /// The word for a relative of this kind and gender, eg. `Child` + `Feminine` = "daughter".
+    pub fn word(&self, gender: Option<&Gender>) -> &'static str {
+        match (self, gender) {
+            (Self::Parent, Some(Gender::Feminine)) => "mother",
+            (Self::Parent, Some(Gender::Masculine)) => "father",
+            (Self::Parent, _) => "parent",
+            (Self::Spouse, Some(Gender::Feminine)) => "wife",
+            (Self::Spouse, Some(Gender::Masculine)) => "husband",
+            (Self::Spouse, _) => "spouse",
+            (Self::Sibling, Some(Gender::Feminine)) => "sister",
+            (Self::Sibling, Some(Gender::Masculine)) => "brother",
+            (Self::Sibling, _) => "sibling",
+            (Self::Child, Some(Gender::Feminine)) => "daughter",
+            (Self::Child, Some(Gender::Masculine)) => "son",
+            (Self::Child, _) => "child",
+        }
+    }
+
+    /// The relationship seen from the other side: my parent is someone whose child I am.
+    pub fn inverse(&self) -> Self {
+        match self {
+            Self::Parent => Self::Child,
+            Self::Spouse => Self::Spouse,
+            Self::Sibling => Self::Sibling,
+            Self::Child => Self::Parent,
+        }
+    }
+
+    pub fn plural(&self) -> &'static str {
+        match self {
+            Self::Parent => "Parents",
+            Self::Spouse => "Spouses",
+            Self::Sibling => "Siblings",
+            Self::Child => "Children",
+        }
+    }
+}
+
+impl fmt::Display for Relationship {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.word(None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_word_test() {
+        assert_eq!(
+            Some((Relationship::Child, Some(Gender::Feminine))),
+            Relationship::parse_word("Daughter"),
+        );
+        assert_eq!(
+            Some((Relationship::Sibling, None)),
+            Relationship::parse_word("sibling"),
+        );
+        assert_eq!(None, Relationship::parse_word("cousin"));
+
+        Relationship::get_words().iter().for_each(|word| {
+            let (relationship, gender) = Relationship::parse_word(word).unwrap();
+            assert_eq!(*word, relationship.word(gender.as_ref()));
+        });
+    }
+
+    #[test]
+    fn inverse_test() {
+        Relationship::ALL.iter().for_each(|relationship| {
+            assert_eq!(*relationship, relationship.inverse().inverse());
+        });
+        assert_eq!(Relationship::Child, Relationship::Parent.inverse());
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!("spouse", Relationship::Spouse.to_string());
+        assert_eq!("Children", Relationship::Child.plural());
+    }
+}
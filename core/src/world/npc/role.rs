@@ -0,0 +1,276 @@
+use super::{Age, Npc};
+use crate::world::place::building::business::BusinessType;
+use crate::world::place::building::education::EducationType;
+use crate::world::place::building::government::GovernmentType;
+use crate::world::place::building::travel::TravelType;
+use crate::world::place::building::BuildingType;
+use crate::world::place::PlaceType;
+use crate::world::weighted_index_from_tuple;
+use initiative_macros::WordList;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Role {
+    Acolyte,
+    Apprentice,
+    Baker,
+    Bard,
+
+    #[alias = "bartender"]
+    Barkeep,
+
+    #[alias = "smith"]
+    Blacksmith,
+    Brewer,
+    Butcher,
+    Carpenter,
+    Cook,
+    Farmer,
+
+    #[alias = "fisherman"]
+    Fisher,
+    Guard,
+    Healer,
+    Hunter,
+    Innkeeper,
+    Laborer,
+    Mason,
+
+    #[alias = "burgomaster"]
+    Mayor,
+
+    #[alias = "shopkeeper"]
+    #[alias = "trader"]
+    Merchant,
+    Miner,
+
+    #[alias = "nun"]
+    Monk,
+    Noble,
+
+    #[alias = "cleric"]
+    #[alias = "priestess"]
+    Priest,
+    Sailor,
+    Scholar,
+    Servant,
+    Soldier,
+    Tailor,
+    Teacher,
+    Thief,
+}
+
+impl Role {
+    /// Pick a plausible occupation for someone found at a given type of place, eg. a character
+    /// in a smithy is probably a blacksmith. Without a location, common occupations are favoured.
+    pub fn generate(rng: &mut impl Rng, location: Option<&PlaceType>) -> Self {
+        *weighted_index_from_tuple(rng, Self::weights(location))
+    }
+
+    fn weights(location: Option<&PlaceType>) -> &'static [(Self, usize)] {
+        let building = match location {
+            Some(PlaceType::Building(building)) => building,
+            _ => return Self::COMMON,
+        };
+
+        match building {
+            BuildingType::Business(
+                BusinessType::Blacksmith
+                | BusinessType::Forge
+                | BusinessType::Armorer
+                | BusinessType::Weaponsmith,
+            ) => &[
+                (Self::Blacksmith, 6),
+                (Self::Apprentice, 3),
+                (Self::Merchant, 1),
+            ],
+            BuildingType::Business(BusinessType::Inn | BusinessType::Bar) => &[
+                (Self::Innkeeper, 3),
+                (Self::Barkeep, 3),
+                (Self::Cook, 2),
+                (Self::Servant, 2),
+                (Self::Bard, 1),
+            ],
+            BuildingType::Business(BusinessType::Bakery) => {
+                &[(Self::Baker, 6), (Self::Apprentice, 2), (Self::Merchant, 1)]
+            }
+            BuildingType::Business(BusinessType::Brewery | BusinessType::Distillery) => {
+                &[(Self::Brewer, 6), (Self::Laborer, 2), (Self::Merchant, 1)]
+            }
+            BuildingType::Business(BusinessType::Restaurant | BusinessType::FoodCounter) => {
+                &[(Self::Cook, 5), (Self::Servant, 3), (Self::Merchant, 1)]
+            }
+            BuildingType::Business(BusinessType::TextilesShop | BusinessType::Furrier) => &[
+                (Self::Tailor, 5),
+                (Self::Apprentice, 2),
+                (Self::Merchant, 2),
+            ],
+            BuildingType::Business(
+                BusinessType::Lumberyard | BusinessType::Woodshop | BusinessType::FurnitureShop,
+            ) => &[
+                (Self::Carpenter, 5),
+                (Self::Laborer, 3),
+                (Self::Merchant, 1),
+            ],
+            BuildingType::Business(BusinessType::Theater) => {
+                &[(Self::Bard, 6), (Self::Servant, 2), (Self::Merchant, 1)]
+            }
+            BuildingType::Business(
+                BusinessType::Warehouse | BusinessType::Mill | BusinessType::Stable,
+            ) => &[(Self::Laborer, 6), (Self::Merchant, 1), (Self::Guard, 1)],
+            BuildingType::Business(_) => &[
+                (Self::Merchant, 6),
+                (Self::Apprentice, 2),
+                (Self::Guard, 1),
+                (Self::Laborer, 1),
+            ],
+            BuildingType::Religious(_) => &[
+                (Self::Priest, 4),
+                (Self::Acolyte, 4),
+                (Self::Monk, 2),
+                (Self::Healer, 1),
+            ],
+            BuildingType::Military(_)
+            | BuildingType::Government(GovernmentType::Guardhouse | GovernmentType::Prison) => {
+                &[(Self::Guard, 5), (Self::Soldier, 4), (Self::Servant, 1)]
+            }
+            BuildingType::Government(_) => &[
+                (Self::Noble, 3),
+                (Self::Servant, 3),
+                (Self::Guard, 3),
+                (Self::Scholar, 1),
+            ],
+            BuildingType::Education(EducationType::Library) => &[
+                (Self::Scholar, 6),
+                (Self::Apprentice, 2),
+                (Self::Servant, 1),
+            ],
+            BuildingType::Education(_) => &[
+                (Self::Teacher, 4),
+                (Self::Scholar, 3),
+                (Self::Apprentice, 3),
+            ],
+            BuildingType::Travel(
+                TravelType::Pier
+                | TravelType::Shipyard
+                | TravelType::Ferry
+                | TravelType::Lighthouse,
+            ) => &[
+                (Self::Sailor, 4),
+                (Self::Fisher, 3),
+                (Self::Laborer, 3),
+                (Self::Carpenter, 1),
+            ],
+            BuildingType::Travel(TravelType::Market) => &[
+                (Self::Merchant, 6),
+                (Self::Farmer, 2),
+                (Self::Thief, 1),
+                (Self::Guard, 1),
+            ],
+            BuildingType::Travel(_) => &[(Self::Guard, 4), (Self::Merchant, 3), (Self::Laborer, 2)],
+            BuildingType::Residence | BuildingType::Any => Self::COMMON,
+        }
+    }
+
+    const COMMON: &'static [(Self, usize)] = &[
+        (Self::Farmer, 10),
+        (Self::Laborer, 6),
+        (Self::Merchant, 3),
+        (Self::Servant, 3),
+        (Self::Carpenter, 2),
+        (Self::Fisher, 2),
+        (Self::Guard, 2),
+        (Self::Baker, 1),
+        (Self::Blacksmith, 1),
+        (Self::Brewer, 1),
+        (Self::Butcher, 1),
+        (Self::Cook, 1),
+        (Self::Hunter, 1),
+        (Self::Mason, 1),
+        (Self::Miner, 1),
+        (Self::Priest, 1),
+        (Self::Sailor, 1),
+        (Self::Scholar, 1),
+        (Self::Soldier, 1),
+        (Self::Tailor, 1),
+    ];
+}
+
+/// Children don't have occupations, and nor does anyone whose age is unknown.
+pub fn regenerate(rng: &mut impl Rng, npc: &mut Npc, location: Option<&PlaceType>) {
+    match npc.age.value() {
+        Some(Age::Infant | Age::Child) | None => npc.occupation.clear(),
+        Some(_) => npc
+            .occupation
+            .replace_with(|_| Role::generate(rng, location)),
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut count = |location: &str, role: Role| {
+            let location = location.parse::<PlaceType>().ok();
+            (0..100)
+                .filter(|_| Role::generate(&mut rng, location.as_ref()) == role)
+                .count()
+        };
+
+        assert!(count("smithy", Role::Blacksmith) > 40);
+        assert!(count("temple", Role::Priest) + count("temple", Role::Acolyte) > 40);
+        assert!(count("barracks", Role::Guard) > 20);
+        assert!(count("town", Role::Blacksmith) < 10);
+        assert_eq!(0, count("temple", Role::Blacksmith));
+    }
+
+    #[test]
+    fn regenerate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut npc = Npc {
+            age: Age::Adult.into(),
+            ..Default::default()
+        };
+
+        regenerate(&mut rng, &mut npc, "inn".parse().ok().as_ref());
+        assert!(npc.occupation.is_some());
+
+        npc.age = Age::Child.into();
+        regenerate(&mut rng, &mut npc, None);
+        assert!(npc.occupation.is_none());
+
+        let mut npc = Npc {
+            age: Age::Adult.into(),
+            occupation: Role::Noble.into(),
+            ..Default::default()
+        };
+        regenerate(&mut rng, &mut npc, "smithy".parse().ok().as_ref());
+        assert_eq!(Some(&Role::Noble), npc.occupation.value());
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(Role::Blacksmith), "blacksmith".parse::<Role>());
+        assert_eq!(Ok(Role::Blacksmith), "smith".parse::<Role>());
+        assert_eq!(Ok(Role::Merchant), "Shopkeeper".parse::<Role>());
+        assert_eq!(Err(()), "potato".parse::<Role>());
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!("innkeeper", Role::Innkeeper.to_string());
+    }
+}
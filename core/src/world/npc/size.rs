@@ -1,19 +1,59 @@
+use crate::utils::CaseInsensitiveStr;
+use initiative_macros::WordList;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// A character's size category, along with their height in inches and weight in pounds. Either
+/// measurement may be missing, as when the user gives only a height, until the rest of the size is
+/// generated around it.
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub enum Size {
-    Tiny { height: u16, weight: u16 },
-    Small { height: u16, weight: u16 },
-    Medium { height: u16, weight: u16 },
-    // Large { height: u16, weight: u16 },
-    // Huge { height: u16, weight: u16 },
-    // Gargantuan { height: u16, weight: u16 },
+    Tiny {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        height: Option<u16>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        weight: Option<u16>,
+    },
+    Small {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        height: Option<u16>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        weight: Option<u16>,
+    },
+    Medium {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        height: Option<u16>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        weight: Option<u16>,
+    },
+    // Large { height: Option<u16>, weight: Option<u16> },
+    // Huge { height: Option<u16>, weight: Option<u16> },
+    // Gargantuan { height: Option<u16>, weight: Option<u16> },
+}
+
+/// The system of measurement used to display heights and weights. Values are always stored in
+/// inches and pounds.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, WordList)]
+pub enum Units {
+    #[default]
+    Imperial,
+    Metric,
 }
 
 impl Size {
-    pub fn height_weight(&self) -> (u16, u16) {
+    /// Categorize a height (in inches) and weight (in pounds) by height alone. Without a height,
+    /// the category is medium until the size is completed.
+    pub fn from_height_weight(height: Option<u16>, weight: Option<u16>) -> Self {
+        match height {
+            Some(0..=29) => Self::Tiny { height, weight },
+            Some(30..=47) => Self::Small { height, weight },
+            _ => Self::Medium { height, weight },
+        }
+    }
+
+    pub fn height_weight(&self) -> (Option<u16>, Option<u16>) {
         match self {
             Self::Tiny { height, weight } => (*height, *weight),
             Self::Small { height, weight } => (*height, *weight),
@@ -21,16 +61,16 @@ impl Size {
         }
     }
 
-    pub fn height(&self) -> u16 {
+    pub fn height(&self) -> Option<u16> {
         self.height_weight().0
     }
 
-    pub fn height_ft_in(&self) -> (u8, u8) {
-        let height = self.height();
-        ((height / 12) as u8, (height % 12) as u8)
+    pub fn height_ft_in(&self) -> Option<(u8, u8)> {
+        self.height()
+            .map(|height| ((height / 12) as u8, (height % 12) as u8))
     }
 
-    pub fn weight(&self) -> u16 {
+    pub fn weight(&self) -> Option<u16> {
         self.height_weight().1
     }
 
@@ -41,19 +81,162 @@ impl Size {
             Self::Medium { .. } => "medium",
         }
     }
+
+    /// Whether both the height and weight are known.
+    pub fn is_complete(&self) -> bool {
+        self.height().is_some() && self.weight().is_some()
+    }
+
+    /// Fill in a missing height or weight from `other`. The size category is recalculated from the
+    /// resulting height, so that the two always agree.
+    pub fn complete_with(&self, other: &Self) -> Self {
+        Self::from_height_weight(
+            self.height().or_else(|| other.height()),
+            self.weight().or_else(|| other.weight()),
+        )
+    }
+
+    /// Display the height and weight in the given units, along with the size category.
+    pub fn display(&self, units: Units) -> impl fmt::Display + '_ {
+        SizeView(self, units)
+    }
+
+    /// The same size category with a different height and weight.
+    pub fn with_height_weight(&self, height: Option<u16>, weight: Option<u16>) -> Self {
+        match self {
+            Self::Tiny { .. } => Self::Tiny { height, weight },
+            Self::Small { .. } => Self::Small { height, weight },
+            Self::Medium { .. } => Self::Medium { height, weight },
+        }
+    }
+
+    /// Scale an adult's size down to that of a child `age_years` old, for a species that comes of
+    /// age at `adult_age_years`. Children never fall into a larger category than their parents.
+    pub fn at_age(&self, age_years: u16, adult_age_years: u16) -> Self {
+        if age_years >= adult_age_years {
+            return self.clone();
+        }
+
+        let growth = 0.3 + 0.7 * (age_years as f32 / adult_age_years as f32).sqrt();
+        let height = self
+            .height()
+            .map(|height| ((height as f32 * growth).round() as u16).max(1));
+        let weight = self
+            .weight()
+            .map(|weight| ((weight as f32 * growth.powi(3)).round() as u16).max(1));
+
+        let child = Self::from_height_weight(height, weight);
+        if child.rank() < self.rank() {
+            child
+        } else {
+            self.with_height_weight(height, weight)
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Tiny { .. } => 0,
+            Self::Small { .. } => 1,
+            Self::Medium { .. } => 2,
+        }
+    }
 }
 
-impl fmt::Display for Size {
+/// Parse a height into inches, eg. `5'4"`, `5'4`, `6ft`, `64in`, `163cm` or `1.63m`.
+pub fn parse_height(input: &str) -> Option<u16> {
+    let number = |s: &str| s.parse::<f32>().ok().filter(|n| n.is_finite() && *n > 0.);
+
+    let inches = if let Some((feet, inches)) = input.split_once('\'') {
+        let feet = feet.parse::<u16>().ok()?;
+        let inches = inches.strip_suffix('"').unwrap_or(inches);
+        let inches = if inches.is_empty() {
+            0
+        } else {
+            inches.parse::<u16>().ok().filter(|&i| i < 12)?
+        };
+        (feet * 12 + inches) as f32
+    } else if let Some(s) = input.strip_suffix_ci("cm") {
+        number(s)? / 2.54
+    } else if let Some(s) = input.strip_suffix_ci("ft") {
+        number(s)? * 12.
+    } else if let Some(s) = input.strip_suffix_ci("in") {
+        number(s)?
+    } else if let Some(s) = input.strip_suffix_ci("m") {
+        number(s)? / 0.0254
+    } else {
+        return None;
+    };
+
+    Some(inches.round() as u16).filter(|&inches| inches > 0)
+}
+
+/// Parse a weight into pounds, eg. `140lbs`, `140lb` or `64kg`.
+pub fn parse_weight(input: &str) -> Option<u16> {
+    let number = |s: &str| s.parse::<f32>().ok().filter(|n| n.is_finite() && *n > 0.);
+
+    let pounds = if let Some(s) = input
+        .strip_suffix_ci("lbs")
+        .or_else(|| input.strip_suffix_ci("lb"))
+    {
+        number(s)?
+    } else if let Some(s) = input.strip_suffix_ci("kg") {
+        number(s)? / 0.453_592
+    } else {
+        return None;
+    };
+
+    Some(pounds.round() as u16).filter(|&pounds| pounds > 0)
+}
+
+/// Display a height given in inches in the given units.
+pub fn display_height(inches: u16, units: Units) -> impl fmt::Display {
+    DisplayMeasure(inches, true, units)
+}
+
+/// Display a weight given in pounds in the given units.
+pub fn display_weight(pounds: u16, units: Units) -> impl fmt::Display {
+    DisplayMeasure(pounds, false, units)
+}
+
+struct DisplayMeasure(u16, bool, Units);
+
+impl fmt::Display for DisplayMeasure {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (height_ft, height_in) = self.height_ft_in();
-        write!(
-            f,
-            "{}'{}\", {} lbs ({})",
-            height_ft,
-            height_in,
-            self.weight(),
-            self.name(),
-        )
+        let Self(value, is_height, units) = *self;
+
+        match (units, is_height) {
+            (Units::Imperial, true) => write!(f, "{}'{}\"", value / 12, value % 12),
+            (Units::Imperial, false) => write!(f, "{} lbs", value),
+            (Units::Metric, true) => write!(f, "{} cm", (value as f32 * 2.54).round()),
+            (Units::Metric, false) => write!(f, "{} kg", (value as f32 * 0.453_592).round()),
+        }
+    }
+}
+
+impl fmt::Display for Units {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+struct SizeView<'a>(&'a Size, Units);
+
+impl fmt::Display for SizeView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self(size, units) = *self;
+
+        match size.height_weight() {
+            (Some(height), Some(weight)) => write!(
+                f,
+                "{}, {} ({})",
+                display_height(height, units),
+                display_weight(weight, units),
+                size.name(),
+            ),
+            (Some(height), None) => write!(f, "{}", display_height(height, units)),
+            (None, Some(weight)) => write!(f, "{}", display_weight(weight, units)),
+            (None, None) => write!(f, "{}", size.name()),
+        }
     }
 }
 
@@ -64,19 +247,19 @@ mod test {
     #[test]
     fn height_weight_test() {
         assert_eq!(
-            (71, 140),
+            (Some(71), Some(140)),
             Size::Small {
-                height: 71,
-                weight: 140
+                height: Some(71),
+                weight: Some(140)
             }
             .height_weight()
         );
 
         assert_eq!(
-            (71, 140),
+            (Some(71), Some(140)),
             Size::Medium {
-                height: 71,
-                weight: 140
+                height: Some(71),
+                weight: Some(140)
             }
             .height_weight()
         );
@@ -84,17 +267,17 @@ mod test {
 
     #[test]
     fn height_test() {
-        assert_eq!(71, size().height());
+        assert_eq!(Some(71), size().height());
     }
 
     #[test]
     fn height_ft_in_test() {
-        assert_eq!((5, 11), size().height_ft_in());
+        assert_eq!(Some((5, 11)), size().height_ft_in());
     }
 
     #[test]
     fn weight_test() {
-        assert_eq!(140, size().weight());
+        assert_eq!(Some(140), size().weight());
     }
 
     #[test]
@@ -102,24 +285,154 @@ mod test {
         assert_eq!(
             "small",
             Size::Small {
-                height: 0,
-                weight: 0
+                height: None,
+                weight: None
             }
             .name()
         );
         assert_eq!(
             "medium",
             Size::Medium {
-                height: 0,
-                weight: 0
+                height: None,
+                weight: None
             }
             .name()
         );
     }
 
     #[test]
-    fn fmt_test() {
-        assert_eq!("5'11\", 140 lbs (medium)", format!("{}", size()));
+    fn display_test() {
+        assert_eq!(
+            "5'11\", 140 lbs (medium)",
+            size().display(Units::Imperial).to_string(),
+        );
+        assert_eq!(
+            "180 cm, 64 kg (medium)",
+            size().display(Units::Metric).to_string(),
+        );
+        assert_eq!(
+            "5'4\"",
+            Size::from_height_weight(Some(64), None)
+                .display(Units::Imperial)
+                .to_string(),
+        );
+        assert_eq!(
+            "64 kg",
+            Size::from_height_weight(None, Some(140))
+                .display(Units::Metric)
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn complete_with_test() {
+        assert!(size().is_complete());
+        assert!(!Size::from_height_weight(Some(64), None).is_complete());
+        assert!(!Size::from_height_weight(None, Some(120)).is_complete());
+
+        assert_eq!(
+            Size::Medium {
+                height: Some(64),
+                weight: Some(140)
+            },
+            Size::from_height_weight(Some(64), None).complete_with(&size()),
+        );
+        assert_eq!(
+            Size::Medium {
+                height: Some(71),
+                weight: Some(120)
+            },
+            Size::from_height_weight(None, Some(120)).complete_with(&size()),
+        );
+
+        // The size category follows the new height.
+        assert_eq!(
+            Size::Small {
+                height: Some(36),
+                weight: Some(140)
+            },
+            Size::from_height_weight(Some(36), None).complete_with(&size()),
+        );
+    }
+
+    #[test]
+    fn from_height_weight_test() {
+        assert_eq!("tiny", Size::from_height_weight(Some(29), Some(20)).name());
+        assert_eq!("small", Size::from_height_weight(Some(30), Some(20)).name());
+        assert_eq!("small", Size::from_height_weight(Some(47), Some(60)).name());
+        assert_eq!(
+            "medium",
+            Size::from_height_weight(Some(48), Some(60)).name()
+        );
+    }
+
+    #[test]
+    fn at_age_test() {
+        assert_eq!(size(), size().at_age(20, 20));
+        assert_eq!(size(), size().at_age(300, 20));
+        assert_eq!(
+            Size::Tiny {
+                height: Some(21),
+                weight: Some(4)
+            },
+            size().at_age(0, 20)
+        );
+        assert_eq!(
+            Size::Small {
+                height: Some(37),
+                weight: Some(20)
+            },
+            size().at_age(2, 20)
+        );
+        assert_eq!(
+            Size::Medium {
+                height: Some(68),
+                weight: Some(125)
+            },
+            size().at_age(18, 20),
+        );
+
+        // A child is never larger than the adults of their species.
+        assert_eq!(
+            Size::Small {
+                height: Some(58),
+                weight: Some(90)
+            },
+            Size::Small {
+                height: Some(60),
+                weight: Some(100)
+            }
+            .at_age(18, 20),
+        );
+    }
+
+    #[test]
+    fn parse_height_test() {
+        assert_eq!(Some(64), parse_height("5'4\""));
+        assert_eq!(Some(64), parse_height("5'4"));
+        assert_eq!(Some(60), parse_height("5'"));
+        assert_eq!(Some(72), parse_height("6ft"));
+        assert_eq!(Some(64), parse_height("64in"));
+        assert_eq!(Some(64), parse_height("163cm"));
+        assert_eq!(Some(64), parse_height("1.63M"));
+
+        assert_eq!(None, parse_height("5'12\""));
+        assert_eq!(None, parse_height("64"));
+        assert_eq!(None, parse_height("0cm"));
+        assert_eq!(None, parse_height("tallcm"));
+        assert_eq!(None, parse_height("elf"));
+        assert_eq!(None, parse_height("'"));
+    }
+
+    #[test]
+    fn parse_weight_test() {
+        assert_eq!(Some(140), parse_weight("140lbs"));
+        assert_eq!(Some(140), parse_weight("140LB"));
+        assert_eq!(Some(141), parse_weight("64kg"));
+
+        assert_eq!(None, parse_weight("140"));
+        assert_eq!(None, parse_weight("-5kg"));
+        assert_eq!(None, parse_weight("lbs"));
     }
 
     #[test]
@@ -132,12 +445,20 @@ mod test {
         let value: Size =
             serde_json::from_str(r#"{"type":"Medium","height":71,"weight":140}"#).unwrap();
         assert_eq!(size(), value);
+
+        let partial = Size::from_height_weight(Some(64), None);
+        assert_eq!(
+            r#"{"type":"Medium","height":64}"#,
+            serde_json::to_string(&partial).unwrap(),
+        );
+        let value: Size = serde_json::from_str(r#"{"type":"Medium","height":64}"#).unwrap();
+        assert_eq!(partial, value);
     }
 
     fn size() -> Size {
         Size::Medium {
-            height: 71,
-            weight: 140,
+            height: Some(71),
+            weight: Some(140),
         }
     }
 }
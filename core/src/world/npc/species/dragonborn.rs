@@ -45,8 +45,8 @@ impl Generate for Species {
     fn gen_size(rng: &mut impl Rng, _age_years: u16, _gender: &Gender) -> Size {
         let size = rng.gen_range(1..=6) + rng.gen_range(1..=6);
         Size::Medium {
-            height: 72 + size,
-            weight: 220 + size * 6,
+            height: Some(72 + size),
+            weight: Some(220 + size * 6),
         }
     }
 }
@@ -142,7 +142,10 @@ mod test_generate_for_species {
         let mut rng = SmallRng::seed_from_u64(0);
         let t = Gender::NonBinaryThey;
 
-        let size = |height, weight| Size::Medium { height, weight };
+        let size = |height, weight| Size::Medium {
+            height: Some(height),
+            weight: Some(weight),
+        };
 
         assert_eq!(
             [
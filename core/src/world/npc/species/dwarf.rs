@@ -46,8 +46,8 @@ impl Generate for Species {
     fn gen_size(rng: &mut impl Rng, _age_years: u16, _gender: &Gender) -> Size {
         let size = rng.gen_range(1..=6) + rng.gen_range(1..=6);
         Size::Medium {
-            height: 48 + size,
-            weight: 125 + size * 4,
+            height: Some(48 + size),
+            weight: Some(125 + size * 4),
         }
     }
 }
@@ -144,7 +144,10 @@ mod test_generate_for_species {
         let mut rng = SmallRng::seed_from_u64(0);
         let t = Gender::NonBinaryThey;
 
-        let size = |height, weight| Size::Medium { height, weight };
+        let size = |height, weight| Size::Medium {
+            height: Some(height),
+            weight: Some(weight),
+        };
 
         assert_eq!(
             [
@@ -46,8 +46,8 @@ impl Generate for Species {
     fn gen_size(rng: &mut impl Rng, _age_years: u16, _gender: &Gender) -> Size {
         let size = rng.gen_range(1..=4) + rng.gen_range(1..=4);
         Size::Small {
-            height: 36 + size,
-            weight: 32 + size * 2,
+            height: Some(36 + size),
+            weight: Some(32 + size * 2),
         }
     }
 }
@@ -144,7 +144,10 @@ mod test_generate_for_species {
         let mut rng = SmallRng::seed_from_u64(0);
         let t = Gender::NonBinaryThey;
 
-        let size = |height, weight| Size::Small { height, weight };
+        let size = |height, weight| Size::Small {
+            height: Some(height),
+            weight: Some(weight),
+        };
 
         assert_eq!(
             [
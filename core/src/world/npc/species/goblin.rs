@@ -0,0 +1,157 @@
+use super::human::Species as Human;
+use super::{Age, Gender, Generate, Size};
+use rand::prelude::*;
+
+pub struct Species;
+
+impl Generate for Species {
+    fn gen_gender(rng: &mut impl Rng) -> Gender {
+        Human::gen_gender(rng)
+    }
+
+    fn gen_age_years(rng: &mut impl Rng) -> u16 {
+        rng.gen_range(0..=60)
+    }
+
+    fn gen_years_from_age(rng: &mut impl Rng, age: &Age) -> u16 {
+        rng.gen_range(match age {
+            Age::Infant => 0..=1,
+            Age::Child => 2..=5,
+            Age::Adolescent => 6..=7,
+            Age::YoungAdult => 8..=14,
+            Age::Adult => 15..=29,
+            Age::MiddleAged => 30..=44,
+            Age::Elderly => 45..=54,
+            Age::Geriatric => 55..=60,
+        })
+    }
+
+    fn age_from_years(years: u16) -> Age {
+        match years {
+            i if i < 2 => Age::Infant,
+            i if i < 6 => Age::Child,
+            i if i < 8 => Age::Adolescent,
+            i if i < 15 => Age::YoungAdult,
+            i if i < 30 => Age::Adult,
+            i if i < 45 => Age::MiddleAged,
+            i if i < 55 => Age::Elderly,
+            _ => Age::Geriatric,
+        }
+    }
+
+    fn gen_size(rng: &mut impl Rng, _age_years: u16, _gender: &Gender) -> Size {
+        let size = rng.gen_range(1..=4) + rng.gen_range(1..=4);
+        Size::Small {
+            height: Some(41 + size),
+            weight: Some(35 + size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_generate_for_species {
+    use super::*;
+
+    #[test]
+    fn gen_gender_test() {
+        let (mut rng1, mut rng2) = (SmallRng::seed_from_u64(0), SmallRng::seed_from_u64(0));
+
+        for _ in 0..10 {
+            assert_eq!(Species::gen_gender(&mut rng1), Human::gen_gender(&mut rng2));
+        }
+    }
+
+    #[test]
+    fn gen_age_years_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [27, 26, 59, 28, 54],
+            [
+                Species::gen_age_years(&mut rng),
+                Species::gen_age_years(&mut rng),
+                Species::gen_age_years(&mut rng),
+                Species::gen_age_years(&mut rng),
+                Species::gen_age_years(&mut rng),
+            ],
+        );
+    }
+
+    #[test]
+    fn gen_years_from_age_test() {
+        let ages = [
+            Age::Infant,
+            Age::Child,
+            Age::Adolescent,
+            Age::YoungAdult,
+            Age::Adult,
+            Age::MiddleAged,
+            Age::Elderly,
+            Age::Geriatric,
+        ];
+
+        for age in ages {
+            let mut rng = SmallRng::seed_from_u64(0);
+
+            for _ in 0..10 {
+                let age_years = Species::gen_years_from_age(&mut rng, &age);
+                assert_eq!(age, Species::age_from_years(age_years));
+            }
+        }
+    }
+
+    #[test]
+    fn age_from_years_test() {
+        assert_eq!(Age::Infant, Species::age_from_years(0));
+        assert_eq!(Age::Infant, Species::age_from_years(1));
+
+        assert_eq!(Age::Child, Species::age_from_years(2));
+        assert_eq!(Age::Child, Species::age_from_years(5));
+
+        assert_eq!(Age::Adolescent, Species::age_from_years(6));
+        assert_eq!(Age::Adolescent, Species::age_from_years(7));
+
+        assert_eq!(Age::YoungAdult, Species::age_from_years(8));
+        assert_eq!(Age::YoungAdult, Species::age_from_years(14));
+
+        assert_eq!(Age::Adult, Species::age_from_years(15));
+        assert_eq!(Age::Adult, Species::age_from_years(29));
+
+        assert_eq!(Age::MiddleAged, Species::age_from_years(30));
+        assert_eq!(Age::MiddleAged, Species::age_from_years(44));
+
+        assert_eq!(Age::Elderly, Species::age_from_years(45));
+        assert_eq!(Age::Elderly, Species::age_from_years(54));
+
+        assert_eq!(Age::Geriatric, Species::age_from_years(55));
+        assert_eq!(Age::Geriatric, Species::age_from_years(u16::MAX));
+    }
+
+    #[test]
+    fn gen_size_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let t = Gender::NonBinaryThey;
+
+        let size = |height, weight| Size::Small {
+            height: Some(height),
+            weight: Some(weight),
+        };
+
+        assert_eq!(
+            [
+                size(45, 39),
+                size(47, 41),
+                size(49, 43),
+                size(46, 40),
+                size(47, 41),
+            ],
+            [
+                Species::gen_size(&mut rng, 0, &t),
+                Species::gen_size(&mut rng, 0, &t),
+                Species::gen_size(&mut rng, 0, &t),
+                Species::gen_size(&mut rng, 0, &t),
+                Species::gen_size(&mut rng, 0, &t),
+            ]
+        );
+    }
+}
@@ -46,8 +46,8 @@ impl Generate for Species {
     fn gen_size(rng: &mut impl Rng, _age_years: u16, _gender: &Gender) -> Size {
         let size = rng.gen_range(1..=8) + rng.gen_range(1..=8);
         Size::Medium {
-            height: 60 + size,
-            weight: 130 + size * 6,
+            height: Some(60 + size),
+            weight: Some(130 + size * 6),
         }
     }
 }
@@ -143,7 +143,10 @@ mod test_generate_for_species {
         let mut rng = SmallRng::seed_from_u64(0);
         let t = Gender::NonBinaryThey;
 
-        let size = |height, weight| Size::Medium { height, weight };
+        let size = |height, weight| Size::Medium {
+            height: Some(height),
+            weight: Some(weight),
+        };
 
         assert_eq!(
             [
@@ -54,22 +54,25 @@ impl Generate for Species {
             (0, _) => {
                 let size = rng.gen_range(0..=30);
                 Size::Tiny {
-                    height: 20 + size / 3,
-                    weight: 7 + size / 2,
+                    height: Some(20 + size / 3),
+                    weight: Some(7 + size / 2),
                 }
             }
             (1, _) => {
                 let size = rng.gen_range(0..=5);
                 Size::Tiny {
-                    height: 30 + size,
-                    weight: 22 + size,
+                    height: Some(30 + size),
+                    weight: Some(22 + size),
                 }
             }
             (2..=9, _) => {
                 let y = (age_years - 2) as f32 / 8.;
                 let (height, weight) =
                     super::gen_height_weight(rng, (33. + y * 18.)..=(35. + y * 22.), 14.0..=17.0);
-                Size::Small { height, weight }
+                Size::Small {
+                    height: Some(height),
+                    weight: Some(weight),
+                }
             }
             (10..=19, true) => {
                 let y = (age_years - 10) as f32;
@@ -78,7 +81,10 @@ impl Generate for Species {
                     (51. + y * 2.).min(61.)..=(65. + y * 2.).min(67.),
                     (15. + y * 2.5 / 5.).min(18.5)..=(19. + y * 4.5 / 5.).min(25.),
                 );
-                Size::Medium { height, weight }
+                Size::Medium {
+                    height: Some(height),
+                    weight: Some(weight),
+                }
             }
             (10..=19, false) => {
                 let y = (age_years - 10) as f32 / 5.;
@@ -87,18 +93,32 @@ impl Generate for Species {
                     (51. + y * 12.).min(66.)..=(57. + y * 13.).min(72.),
                     (15. + y * 2.5).min(18.5)..=(18.5 + y * 4.5).min(29.),
                 );
-                Size::Medium { height, weight }
+                Size::Medium {
+                    height: Some(height),
+                    weight: Some(weight),
+                }
             }
             (_, true) => {
                 let (height, weight) = super::gen_height_weight(rng, 61.0..=67.0, 19.0..=25.0);
-                Size::Medium { height, weight }
+                Size::Medium {
+                    height: Some(height),
+                    weight: Some(weight),
+                }
             }
             (_, false) => {
                 let (height, weight) = super::gen_height_weight(rng, 66.0..=72.0, 18.5..=29.0);
-                Size::Medium { height, weight }
+                Size::Medium {
+                    height: Some(height),
+                    weight: Some(weight),
+                }
             }
         }
     }
+
+    /// Children are already accounted for by `gen_size`.
+    fn gen_size_at_age(rng: &mut impl Rng, age_years: u16, gender: &Gender) -> Size {
+        Self::gen_size(rng, age_years, gender)
+    }
 }
 
 #[cfg(test)]
@@ -220,7 +240,12 @@ mod test_generate_for_species {
             (0u16..=20)
                 .map(move |y| {
                     let size = Species::gen_size(&mut rng, y, &Gender::Masculine);
-                    (y, size.name(), size.height(), size.weight())
+                    (
+                        y,
+                        size.name(),
+                        size.height().unwrap(),
+                        size.weight().unwrap(),
+                    )
                 })
                 .collect::<Vec<_>>(),
         );
@@ -258,7 +283,12 @@ mod test_generate_for_species {
             (0u16..=20)
                 .map(move |y| {
                     let size = Species::gen_size(&mut rng, y, &Gender::Feminine);
-                    (y, size.name(), size.height(), size.weight())
+                    (
+                        y,
+                        size.name(),
+                        size.height().unwrap(),
+                        size.weight().unwrap(),
+                    )
                 })
                 .collect::<Vec<_>>(),
         );
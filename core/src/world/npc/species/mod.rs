@@ -1,14 +1,22 @@
+mod aasimar;
 mod dragonborn;
 mod dwarf;
 mod elf;
+mod firbolg;
+mod genasi;
 mod gnome;
+mod goblin;
 mod half_elf;
 mod half_orc;
 mod halfling;
 mod human;
+mod kobold;
+mod lizardfolk;
+mod orc;
+mod tabaxi;
 mod tiefling;
 
-use super::{Age, Ethnicity, Gender, Npc, Size};
+use super::{Age, Ethnicity, Gender, Npc, Relationship, Size};
 use initiative_macros::WordList;
 use rand::prelude::*;
 use rand_distr::{Distribution, Normal};
@@ -19,10 +27,14 @@ use std::ops::RangeInclusive;
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, WordList, Serialize, Deserialize)]
 #[serde(into = "&'static str", try_from = "&str")]
 pub enum Species {
+    Aasimar,
     Dragonborn,
     Dwarf,
     Elf,
+    Firbolg,
+    Genasi,
     Gnome,
+    Goblin,
 
     #[alias = "half elf"]
     HalfElf,
@@ -31,6 +43,10 @@ pub enum Species {
     HalfOrc,
     Halfling,
     Human,
+    Kobold,
+    Lizardfolk,
+    Orc,
+    Tabaxi,
     Tiefling,
 }
 
@@ -63,7 +79,7 @@ trait Generate {
 
         if let (Some(gender), Some(age_years)) = (npc.gender.value(), npc.age_years.value()) {
             npc.size
-                .replace_with(|_| Self::gen_size(rng, *age_years, gender));
+                .replace_with(|_| Self::gen_size_at_age(rng, *age_years, gender));
         }
     }
 
@@ -75,20 +91,41 @@ trait Generate {
 
     fn age_from_years(years: u16) -> Age;
 
+    /// The age at which members of the species come of age, and can plausibly start a family.
+    fn adult_age_years() -> u16 {
+        (0..u16::MAX)
+            .find(|&years| Self::age_from_years(years) == Age::YoungAdult)
+            .unwrap_or_default()
+    }
+
     fn gen_size(rng: &mut impl Rng, age_years: u16, gender: &Gender) -> Size;
+
+    /// Generate the size of a member of the species who is `age_years` old. Unless overridden,
+    /// `gen_size` describes adults, and children are scaled down from it.
+    fn gen_size_at_age(rng: &mut impl Rng, age_years: u16, gender: &Gender) -> Size {
+        Self::gen_size(rng, age_years, gender).at_age(age_years, Self::adult_age_years())
+    }
 }
 
 pub fn regenerate(rng: &mut impl Rng, npc: &mut Npc) {
     if let Some(species) = npc.species.value() {
         match species {
+            Species::Aasimar => aasimar::Species::regenerate(rng, npc),
             Species::Dragonborn => dragonborn::Species::regenerate(rng, npc),
             Species::Dwarf => dwarf::Species::regenerate(rng, npc),
             Species::Elf => elf::Species::regenerate(rng, npc),
+            Species::Firbolg => firbolg::Species::regenerate(rng, npc),
+            Species::Genasi => genasi::Species::regenerate(rng, npc),
             Species::Gnome => gnome::Species::regenerate(rng, npc),
+            Species::Goblin => goblin::Species::regenerate(rng, npc),
             Species::HalfElf => half_elf::Species::regenerate(rng, npc),
             Species::HalfOrc => half_orc::Species::regenerate(rng, npc),
             Species::Halfling => halfling::Species::regenerate(rng, npc),
             Species::Human => human::Species::regenerate(rng, npc),
+            Species::Kobold => kobold::Species::regenerate(rng, npc),
+            Species::Lizardfolk => lizardfolk::Species::regenerate(rng, npc),
+            Species::Orc => orc::Species::regenerate(rng, npc),
+            Species::Tabaxi => tabaxi::Species::regenerate(rng, npc),
             Species::Tiefling => tiefling::Species::regenerate(rng, npc),
         }
     }
@@ -117,16 +154,105 @@ fn gen_height_weight(
 }
 
 impl Species {
+    pub fn gen_age_years(&self, rng: &mut impl Rng) -> u16 {
+        match self {
+            Self::Aasimar => aasimar::Species::gen_age_years(rng),
+            Self::Dragonborn => dragonborn::Species::gen_age_years(rng),
+            Self::Dwarf => dwarf::Species::gen_age_years(rng),
+            Self::Elf => elf::Species::gen_age_years(rng),
+            Self::Firbolg => firbolg::Species::gen_age_years(rng),
+            Self::Genasi => genasi::Species::gen_age_years(rng),
+            Self::Gnome => gnome::Species::gen_age_years(rng),
+            Self::Goblin => goblin::Species::gen_age_years(rng),
+            Self::HalfElf => half_elf::Species::gen_age_years(rng),
+            Self::HalfOrc => half_orc::Species::gen_age_years(rng),
+            Self::Halfling => halfling::Species::gen_age_years(rng),
+            Self::Human => human::Species::gen_age_years(rng),
+            Self::Kobold => kobold::Species::gen_age_years(rng),
+            Self::Lizardfolk => lizardfolk::Species::gen_age_years(rng),
+            Self::Orc => orc::Species::gen_age_years(rng),
+            Self::Tabaxi => tabaxi::Species::gen_age_years(rng),
+            Self::Tiefling => tiefling::Species::gen_age_years(rng),
+        }
+    }
+
+    pub fn adult_age_years(&self) -> u16 {
+        match self {
+            Self::Aasimar => aasimar::Species::adult_age_years(),
+            Self::Dragonborn => dragonborn::Species::adult_age_years(),
+            Self::Dwarf => dwarf::Species::adult_age_years(),
+            Self::Elf => elf::Species::adult_age_years(),
+            Self::Firbolg => firbolg::Species::adult_age_years(),
+            Self::Genasi => genasi::Species::adult_age_years(),
+            Self::Gnome => gnome::Species::adult_age_years(),
+            Self::Goblin => goblin::Species::adult_age_years(),
+            Self::HalfElf => half_elf::Species::adult_age_years(),
+            Self::HalfOrc => half_orc::Species::adult_age_years(),
+            Self::Halfling => halfling::Species::adult_age_years(),
+            Self::Human => human::Species::adult_age_years(),
+            Self::Kobold => kobold::Species::adult_age_years(),
+            Self::Lizardfolk => lizardfolk::Species::adult_age_years(),
+            Self::Orc => orc::Species::adult_age_years(),
+            Self::Tabaxi => tabaxi::Species::adult_age_years(),
+            Self::Tiefling => tiefling::Species::adult_age_years(),
+        }
+    }
+
+    /// Generate the age of a new relative of someone who is `age_years` old. Parents and children
+    /// are a generation apart, while siblings and spouses are of a similar age. Returns `None` if
+    /// the relative can't exist, eg. the child of a child. Without a known age to work from, any
+    /// age is plausible.
+    pub fn gen_relative_age_years(
+        &self,
+        rng: &mut impl Rng,
+        relationship: Relationship,
+        age_years: Option<u16>,
+    ) -> Option<u16> {
+        let age_years = if let Some(age_years) = age_years {
+            age_years
+        } else {
+            return Some(self.gen_age_years(rng));
+        };
+
+        let adult = self.adult_age_years().max(1);
+        let spread = rng.gen_range(0..=adult / 2);
+
+        match relationship {
+            Relationship::Parent => {
+                Some(age_years.saturating_add(rng.gen_range(adult..=adult.saturating_mul(2))))
+            }
+            Relationship::Child if age_years >= adult => {
+                Some(age_years - rng.gen_range(adult..=age_years.min(adult.saturating_mul(2))))
+            }
+            Relationship::Child => None,
+            Relationship::Sibling if rng.gen_bool(0.5) => Some(age_years.saturating_add(spread)),
+            Relationship::Sibling => Some(age_years.saturating_sub(spread)),
+            Relationship::Spouse if age_years < adult => None,
+            Relationship::Spouse if rng.gen_bool(0.5) => Some(age_years.saturating_add(spread)),
+            Relationship::Spouse => Some(age_years.saturating_sub(spread).max(adult)),
+        }
+    }
+
+    /// The culture a member of the species is most likely to be raised in. Aasimar and genasi are
+    /// born to human parents, while firbolgs take elven names when dealing with outsiders.
     pub fn default_ethnicity(&self) -> Ethnicity {
         match self {
+            Self::Aasimar => Ethnicity::Human,
             Self::Dragonborn => Ethnicity::Dragonborn,
             Self::Dwarf => Ethnicity::Dwarvish,
             Self::Elf => Ethnicity::Elvish,
+            Self::Firbolg => Ethnicity::Elvish,
+            Self::Genasi => Ethnicity::Human,
             Self::Gnome => Ethnicity::Gnomish,
+            Self::Goblin => Ethnicity::Goblin,
             Self::HalfElf => Ethnicity::Human,
             Self::HalfOrc => Ethnicity::Orcish,
             Self::Halfling => Ethnicity::Halfling,
             Self::Human => Ethnicity::Human,
+            Self::Kobold => Ethnicity::Kobold,
+            Self::Lizardfolk => Ethnicity::Lizardfolk,
+            Self::Orc => Ethnicity::Orcish,
+            Self::Tabaxi => Ethnicity::Tabaxi,
             Self::Tiefling => Ethnicity::Tiefling,
         }
     }
@@ -135,14 +261,22 @@ impl Species {
 impl fmt::Display for Species {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Self::Aasimar => write!(f, "aasimar"),
             Self::Dragonborn => write!(f, "dragonborn"),
             Self::Dwarf => write!(f, "dwarf"),
             Self::Elf => write!(f, "elf"),
+            Self::Firbolg => write!(f, "firbolg"),
+            Self::Genasi => write!(f, "genasi"),
             Self::Gnome => write!(f, "gnome"),
+            Self::Goblin => write!(f, "goblin"),
             Self::HalfElf => write!(f, "half-elf"),
             Self::HalfOrc => write!(f, "half-orc"),
             Self::Halfling => write!(f, "halfling"),
             Self::Human => write!(f, "human"),
+            Self::Kobold => write!(f, "kobold"),
+            Self::Lizardfolk => write!(f, "lizardfolk"),
+            Self::Orc => write!(f, "orc"),
+            Self::Tabaxi => write!(f, "tabaxi"),
             Self::Tiefling => write!(f, "tiefling"),
         }
     }
@@ -175,8 +309,8 @@ mod test {
         npc.age_years = u16::MAX.into();
         npc.gender = Gender::Neuter.into();
         npc.size = Size::Tiny {
-            height: u16::MAX,
-            weight: u16::MAX,
+            height: Some(u16::MAX),
+            weight: Some(u16::MAX),
         }
         .into();
 
@@ -189,8 +323,8 @@ mod test {
         assert_eq!(Some(&Gender::Neuter), npc.gender.value());
         assert_eq!(
             Some(&Size::Tiny {
-                height: u16::MAX,
-                weight: u16::MAX
+                height: Some(u16::MAX),
+                weight: Some(u16::MAX)
             }),
             npc.size.value(),
         );
@@ -237,6 +371,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn adult_age_years_test() {
+        assert_eq!(20, Species::Human.adult_age_years());
+        assert_eq!(100, Species::Elf.adult_age_years());
+    }
+
+    #[test]
+    fn gen_relative_age_years_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for _ in 0..100 {
+            let mut gen = |relationship, age_years| {
+                Species::Human.gen_relative_age_years(&mut rng, relationship, age_years)
+            };
+
+            assert!((50..=70).contains(&gen(Relationship::Parent, Some(30)).unwrap()));
+            assert!((0..=10).contains(&gen(Relationship::Child, Some(30)).unwrap()));
+            assert!((20..=40).contains(&gen(Relationship::Sibling, Some(30)).unwrap()));
+            assert!((20..=40).contains(&gen(Relationship::Spouse, Some(21)).unwrap()));
+            assert!((0..=79).contains(&gen(Relationship::Child, None).unwrap()));
+            assert_eq!(None, gen(Relationship::Child, Some(15)));
+            assert_eq!(None, gen(Relationship::Spouse, Some(5)));
+        }
+    }
+
     #[test]
     fn default_ethnicity_test() {
         assert_eq!(
@@ -251,6 +410,17 @@ mod test {
         assert_eq!(Ethnicity::Halfling, Species::Halfling.default_ethnicity());
         assert_eq!(Ethnicity::Human, Species::Human.default_ethnicity());
         assert_eq!(Ethnicity::Tiefling, Species::Tiefling.default_ethnicity());
+        assert_eq!(Ethnicity::Human, Species::Aasimar.default_ethnicity());
+        assert_eq!(Ethnicity::Elvish, Species::Firbolg.default_ethnicity());
+        assert_eq!(Ethnicity::Human, Species::Genasi.default_ethnicity());
+        assert_eq!(Ethnicity::Goblin, Species::Goblin.default_ethnicity());
+        assert_eq!(Ethnicity::Kobold, Species::Kobold.default_ethnicity());
+        assert_eq!(
+            Ethnicity::Lizardfolk,
+            Species::Lizardfolk.default_ethnicity()
+        );
+        assert_eq!(Ethnicity::Orcish, Species::Orc.default_ethnicity());
+        assert_eq!(Ethnicity::Tabaxi, Species::Tabaxi.default_ethnicity());
     }
 
     #[test]
@@ -270,6 +440,14 @@ mod test {
         assert_eq!("halfling", format!("{}", Species::Halfling));
         assert_eq!("human", format!("{}", Species::Human));
         assert_eq!("tiefling", format!("{}", Species::Tiefling));
+        assert_eq!("aasimar", format!("{}", Species::Aasimar));
+        assert_eq!("firbolg", format!("{}", Species::Firbolg));
+        assert_eq!("genasi", format!("{}", Species::Genasi));
+        assert_eq!("goblin", format!("{}", Species::Goblin));
+        assert_eq!("kobold", format!("{}", Species::Kobold));
+        assert_eq!("lizardfolk", format!("{}", Species::Lizardfolk));
+        assert_eq!("orc", format!("{}", Species::Orc));
+        assert_eq!("tabaxi", format!("{}", Species::Tabaxi));
     }
 
     #[test]
@@ -42,6 +42,11 @@ impl Generate for Species {
     fn gen_size(rng: &mut impl Rng, age_years: u16, gender: &Gender) -> Size {
         Human::gen_size(rng, age_years, gender)
     }
+
+    /// Children are already accounted for by `Human::gen_size`.
+    fn gen_size_at_age(rng: &mut impl Rng, age_years: u16, gender: &Gender) -> Size {
+        Self::gen_size(rng, age_years, gender)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,632 @@
+use super::{Npc, Role, Species};
+use crate::reference;
+use initiative_macros::WordList;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A stock combat role from the SRD, such as "guard" or "veteran", used to give a character a
+/// stat block.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Archetype {
+    Acolyte,
+    Bandit,
+    Commoner,
+    Cultist,
+    Gladiator,
+    Guard,
+    Knight,
+
+    #[alias = "wizard"]
+    Mage,
+    Noble,
+    Priest,
+    Scout,
+    Spy,
+    Thug,
+    Veteran,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Armor {
+    Unarmored,
+    Leather,
+    StuddedLeather,
+    ChainShirt,
+    Breastplate,
+    Splint,
+    Plate,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Ability {
+    Str,
+    Dex,
+}
+
+struct Template {
+    abilities: [u8; 6],
+    armor: Armor,
+    shield: bool,
+    hit_dice: u8,
+    challenge: &'static str,
+    xp: u32,
+    attack: (&'static str, Ability, u8, u8, &'static str),
+}
+
+/// The combat statistics of a character, derived from their archetype and species.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatBlock {
+    pub archetype: Archetype,
+    pub abilities: [u8; 6],
+    pub armor_class: u8,
+    pub hit_dice: u8,
+    pub hit_die: u8,
+    pub hit_point_bonus: i32,
+    pub hit_points: u16,
+    pub speed: u8,
+    pub proficiency_bonus: u8,
+    pub challenge: &'static str,
+    pub xp: u32,
+    pub darkvision: bool,
+    pub traits: Vec<&'static str>,
+    armor: Armor,
+    shield: bool,
+    attack: (&'static str, Ability, u8, u8, &'static str),
+}
+
+const ABILITY_NAMES: [&str; 6] = ["STR", "DEX", "CON", "INT", "WIS", "CHA"];
+
+impl Archetype {
+    fn template(&self) -> Template {
+        match self {
+            Self::Acolyte => Template {
+                abilities: [10, 10, 10, 10, 14, 11],
+                armor: Armor::Unarmored,
+                shield: false,
+                hit_dice: 2,
+                challenge: "1/4",
+                xp: 50,
+                attack: ("Club", Ability::Str, 1, 4, "bludgeoning"),
+            },
+            Self::Bandit => Template {
+                abilities: [11, 12, 12, 10, 10, 10],
+                armor: Armor::Leather,
+                shield: false,
+                hit_dice: 2,
+                challenge: "1/8",
+                xp: 25,
+                attack: ("Scimitar", Ability::Dex, 1, 6, "slashing"),
+            },
+            Self::Commoner => Template {
+                abilities: [10, 10, 10, 10, 10, 10],
+                armor: Armor::Unarmored,
+                shield: false,
+                hit_dice: 1,
+                challenge: "0",
+                xp: 10,
+                attack: ("Club", Ability::Str, 1, 4, "bludgeoning"),
+            },
+            Self::Cultist => Template {
+                abilities: [11, 12, 10, 10, 11, 10],
+                armor: Armor::Leather,
+                shield: false,
+                hit_dice: 2,
+                challenge: "1/8",
+                xp: 25,
+                attack: ("Scimitar", Ability::Dex, 1, 6, "slashing"),
+            },
+            Self::Gladiator => Template {
+                abilities: [18, 15, 16, 10, 12, 15],
+                armor: Armor::StuddedLeather,
+                shield: true,
+                hit_dice: 15,
+                challenge: "5",
+                xp: 1800,
+                attack: ("Spear", Ability::Str, 2, 6, "piercing"),
+            },
+            Self::Guard => Template {
+                abilities: [13, 12, 12, 10, 11, 10],
+                armor: Armor::ChainShirt,
+                shield: true,
+                hit_dice: 2,
+                challenge: "1/8",
+                xp: 25,
+                attack: ("Spear", Ability::Str, 1, 6, "piercing"),
+            },
+            Self::Knight => Template {
+                abilities: [16, 11, 14, 11, 11, 15],
+                armor: Armor::Plate,
+                shield: false,
+                hit_dice: 8,
+                challenge: "3",
+                xp: 700,
+                attack: ("Greatsword", Ability::Str, 2, 6, "slashing"),
+            },
+            Self::Mage => Template {
+                abilities: [9, 14, 11, 17, 12, 11],
+                armor: Armor::Unarmored,
+                shield: false,
+                hit_dice: 9,
+                challenge: "6",
+                xp: 2300,
+                attack: ("Dagger", Ability::Dex, 1, 4, "piercing"),
+            },
+            Self::Noble => Template {
+                abilities: [11, 12, 11, 12, 14, 16],
+                armor: Armor::Breastplate,
+                shield: false,
+                hit_dice: 2,
+                challenge: "1/8",
+                xp: 25,
+                attack: ("Rapier", Ability::Dex, 1, 8, "piercing"),
+            },
+            Self::Priest => Template {
+                abilities: [10, 10, 12, 13, 16, 13],
+                armor: Armor::ChainShirt,
+                shield: false,
+                hit_dice: 5,
+                challenge: "2",
+                xp: 450,
+                attack: ("Mace", Ability::Str, 1, 6, "bludgeoning"),
+            },
+            Self::Scout => Template {
+                abilities: [11, 14, 12, 11, 13, 11],
+                armor: Armor::Leather,
+                shield: false,
+                hit_dice: 3,
+                challenge: "1/2",
+                xp: 100,
+                attack: ("Longbow", Ability::Dex, 1, 8, "piercing"),
+            },
+            Self::Spy => Template {
+                abilities: [10, 15, 10, 12, 14, 16],
+                armor: Armor::Unarmored,
+                shield: false,
+                hit_dice: 6,
+                challenge: "1",
+                xp: 200,
+                attack: ("Shortsword", Ability::Dex, 1, 6, "piercing"),
+            },
+            Self::Thug => Template {
+                abilities: [15, 11, 14, 10, 10, 11],
+                armor: Armor::Leather,
+                shield: false,
+                hit_dice: 5,
+                challenge: "1/2",
+                xp: 100,
+                attack: ("Mace", Ability::Str, 1, 6, "bludgeoning"),
+            },
+            Self::Veteran => Template {
+                abilities: [16, 13, 14, 10, 11, 10],
+                armor: Armor::Splint,
+                shield: false,
+                hit_dice: 9,
+                challenge: "3",
+                xp: 700,
+                attack: ("Longsword", Ability::Str, 1, 8, "slashing"),
+            },
+        }
+    }
+
+    /// The occupation most closely matching the archetype, if there is one. A veteran is
+    /// presumably a soldier, but a commoner could be anyone.
+    pub fn occupation(&self) -> Option<Role> {
+        match self {
+            Self::Acolyte => Some(Role::Acolyte),
+            Self::Bandit | Self::Spy | Self::Thug => Some(Role::Thief),
+            Self::Gladiator | Self::Knight | Self::Veteran => Some(Role::Soldier),
+            Self::Guard => Some(Role::Guard),
+            Self::Mage => Some(Role::Scholar),
+            Self::Noble => Some(Role::Noble),
+            Self::Priest => Some(Role::Priest),
+            Self::Scout => Some(Role::Hunter),
+            Self::Commoner | Self::Cultist => None,
+        }
+    }
+
+    /// Build a stat block for a character of the given species, applying the species' ability
+    /// score increases, speed, size and traits.
+    pub fn stat_block(&self, species: Option<&Species>) -> StatBlock {
+        let template = self.template();
+        let mut abilities = template.abilities;
+
+        species_ability_bonuses(species)
+            .iter()
+            .zip(abilities.iter_mut())
+            .for_each(|(bonus, score)| *score += bonus);
+
+        let traits = species_traits(species);
+
+        // Small creatures use a smaller hit die, and hill dwarves are especially hardy.
+        let hit_die = if matches!(
+            species,
+            Some(Species::Gnome | Species::Goblin | Species::Halfling | Species::Kobold)
+        ) {
+            6
+        } else {
+            8
+        };
+        let toughness = if traits.contains(&"Dwarven Toughness") {
+            template.hit_dice as i32
+        } else {
+            0
+        };
+        let hit_point_bonus = template.hit_dice as i32 * modifier(abilities[2]) + toughness;
+        let hit_points =
+            (template.hit_dice as i32 * (hit_die as i32 + 1) / 2 + hit_point_bonus).max(1) as u16;
+
+        let speed = if matches!(
+            species,
+            Some(Species::Dwarf | Species::Gnome | Species::Halfling)
+        ) {
+            25
+        } else {
+            30
+        };
+
+        StatBlock {
+            archetype: *self,
+            armor_class: template.armor.armor_class(modifier(abilities[1]))
+                + if template.shield { 2 } else { 0 },
+            abilities,
+            hit_dice: template.hit_dice,
+            hit_die,
+            hit_point_bonus,
+            hit_points,
+            speed,
+            proficiency_bonus: proficiency_bonus(template.challenge),
+            challenge: template.challenge,
+            xp: template.xp,
+            darkvision: traits.contains(&"Darkvision"),
+            traits,
+            armor: template.armor,
+            shield: template.shield,
+            attack: template.attack,
+        }
+    }
+}
+
+impl Armor {
+    fn armor_class(&self, dex_modifier: i32) -> u8 {
+        let (base, max_dex) = match self {
+            Self::Unarmored => (10, None),
+            Self::Leather => (11, None),
+            Self::StuddedLeather => (12, None),
+            Self::ChainShirt => (13, Some(2)),
+            Self::Breastplate => (14, Some(2)),
+            Self::Splint => (17, Some(0)),
+            Self::Plate => (18, Some(0)),
+        };
+
+        let dex_bonus = max_dex.map_or(dex_modifier, |max| dex_modifier.min(max));
+        (base + dex_bonus).max(1) as u8
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        match self {
+            Self::Unarmored => None,
+            Self::Leather => Some("leather armor"),
+            Self::StuddedLeather => Some("studded leather armor"),
+            Self::ChainShirt => Some("chain shirt"),
+            Self::Breastplate => Some("breastplate"),
+            Self::Splint => Some("splint"),
+            Self::Plate => Some("plate"),
+        }
+    }
+}
+
+fn species_ability_bonuses(species: Option<&Species>) -> [u8; 6] {
+    match species {
+        Some(Species::Aasimar) => [0, 0, 0, 0, 1, 2],
+        Some(Species::Dragonborn) => [2, 0, 0, 0, 0, 1],
+        Some(Species::Dwarf) => [0, 0, 2, 0, 1, 0],
+        Some(Species::Elf) => [0, 2, 0, 1, 0, 0],
+        Some(Species::Firbolg) => [1, 0, 0, 0, 2, 0],
+        Some(Species::Genasi) => [0, 0, 2, 1, 0, 0],
+        Some(Species::Gnome) => [0, 0, 1, 2, 0, 0],
+        Some(Species::Goblin) => [0, 2, 1, 0, 0, 0],
+        Some(Species::HalfElf) => [0, 1, 1, 0, 0, 2],
+        Some(Species::HalfOrc) => [2, 0, 1, 0, 0, 0],
+        Some(Species::Halfling) => [0, 2, 1, 0, 0, 0],
+        Some(Species::Human) => [1, 1, 1, 1, 1, 1],
+        Some(Species::Kobold) => [0, 2, 0, 0, 0, 0],
+        Some(Species::Lizardfolk) => [0, 0, 2, 0, 1, 0],
+        Some(Species::Orc) => [2, 0, 1, 0, 0, 0],
+        Some(Species::Tabaxi) => [0, 2, 0, 0, 0, 1],
+        Some(Species::Tiefling) => [0, 0, 0, 1, 0, 2],
+        None => [0; 6],
+    }
+}
+
+/// The traits of each species, by name. Species covered by the SRD take their traits from the
+/// reference data, using the most common subspecies.
+fn species_traits(species: Option<&Species>) -> Vec<&'static str> {
+    let (srd_species, srd_subspecies) = match species {
+        Some(Species::Dragonborn) => ("dragonborn", None),
+        Some(Species::Dwarf) => ("dwarf", Some("hill-dwarf")),
+        Some(Species::Elf) => ("elf", Some("high-elf")),
+        Some(Species::Gnome) => ("gnome", Some("rock-gnome")),
+        Some(Species::HalfElf) => ("half-elf", None),
+        Some(Species::HalfOrc) => ("half-orc", None),
+        Some(Species::Halfling) => ("halfling", Some("lightfoot-halfling")),
+        Some(Species::Human) => ("human", None),
+        Some(Species::Tiefling) => ("tiefling", None),
+        Some(species) => return non_srd_species_traits(species).to_vec(),
+        None => return Vec::new(),
+    };
+
+    reference::Trait::ALL
+        .iter()
+        .filter(|species_trait| {
+            species_trait
+                .get_species()
+                .iter()
+                .any(|&s| s == srd_species || Some(s) == srd_subspecies)
+        })
+        .map(|species_trait| species_trait.get_name())
+        .collect()
+}
+
+/// The SRD doesn't describe these species, so their traits are listed here instead. Genasi are
+/// assumed to be fire genasi.
+fn non_srd_species_traits(species: &Species) -> &'static [&'static str] {
+    match species {
+        Species::Aasimar => &[
+            "Darkvision",
+            "Celestial Resistance",
+            "Healing Hands",
+            "Light Bearer",
+        ],
+        Species::Firbolg => &[
+            "Firbolg Magic",
+            "Hidden Step",
+            "Powerful Build",
+            "Speech of Beast and Leaf",
+        ],
+        Species::Genasi => &["Darkvision", "Fire Resistance", "Reach to the Blaze"],
+        Species::Goblin => &["Darkvision", "Fury of the Small", "Nimble Escape"],
+        Species::Kobold => &[
+            "Darkvision",
+            "Grovel",
+            "Pack Tactics",
+            "Sunlight Sensitivity",
+        ],
+        Species::Lizardfolk => &["Bite", "Hold Breath", "Hungry Jaws", "Natural Armor"],
+        Species::Orc => &["Darkvision", "Aggressive", "Menacing", "Powerful Build"],
+        Species::Tabaxi => &[
+            "Darkvision",
+            "Feline Agility",
+            "Cat's Claws",
+            "Cat's Talent",
+        ],
+        _ => &[],
+    }
+}
+
+fn modifier(score: u8) -> i32 {
+    (score as i32 - 10).div_euclid(2)
+}
+
+fn proficiency_bonus(challenge: &str) -> u8 {
+    match challenge.parse::<u8>() {
+        Ok(cr) if cr >= 9 => 4,
+        Ok(cr) if cr >= 5 => 3,
+        _ => 2,
+    }
+}
+
+fn signed(value: i32) -> String {
+    if value < 0 {
+        value.to_string()
+    } else {
+        format!("+{}", value)
+    }
+}
+
+/// Characters only have a stat block if they've been given an archetype. The archetype also
+/// suggests an occupation, which takes precedence over one implied by the character's location.
+pub fn regenerate(npc: &mut Npc) {
+    if let Some(occupation) = npc.archetype.value().and_then(|a| a.occupation()) {
+        npc.occupation.replace(occupation);
+    }
+}
+
+impl fmt::Display for Archetype {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Display for StatBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "**Stats:** {} (CR {}, {} XP)\\\n**Armor Class:** {}",
+            self.archetype, self.challenge, self.xp, self.armor_class,
+        )?;
+
+        match (self.armor.name(), self.shield) {
+            (Some(armor), true) => write!(f, " ({}, shield)", armor)?,
+            (Some(armor), false) => write!(f, " ({})", armor)?,
+            (None, true) => write!(f, " (shield)")?,
+            (None, false) => {}
+        }
+
+        write!(
+            f,
+            "\\\n**Hit Points:** {} ({}d{}",
+            self.hit_points, self.hit_dice, self.hit_die,
+        )?;
+        match self.hit_point_bonus {
+            0 => write!(f, ")")?,
+            b if b > 0 => write!(f, " + {})", b)?,
+            b => write!(f, " - {})", -b)?,
+        }
+
+        write!(
+            f,
+            "\\\n**Speed:** {} ft.\\\n**Proficiency Bonus:** +{}",
+            self.speed, self.proficiency_bonus,
+        )?;
+
+        write!(f, "\n\n|")?;
+        ABILITY_NAMES
+            .iter()
+            .try_for_each(|name| write!(f, " {} |", name))?;
+        write!(f, "\n|")?;
+        ABILITY_NAMES.iter().try_for_each(|_| write!(f, ":---:|"))?;
+        write!(f, "\n|")?;
+        self.abilities
+            .iter()
+            .try_for_each(|&score| write!(f, " {} ({}) |", score, signed(modifier(score))))?;
+
+        write!(f, "\n\n")?;
+
+        if self.darkvision {
+            writeln!(f, "**Senses:** darkvision 60 ft.\\")?;
+        }
+
+        if !self.traits.is_empty() {
+            write!(f, "**Traits:** ")?;
+            for (i, name) in self.traits.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+
+                // Link to the SRD entry for the trait, if there is one.
+                if name.parse::<reference::Trait>().is_ok() {
+                    write!(f, "`{}`", name)?;
+                } else {
+                    write!(f, "{}", name)?;
+                }
+            }
+            writeln!(f, "\\")?;
+        }
+
+        let (weapon, ability, dice, die, damage_type) = self.attack;
+        let ability_modifier = match ability {
+            Ability::Str => modifier(self.abilities[0]),
+            Ability::Dex => modifier(self.abilities[1]),
+        };
+
+        write!(
+            f,
+            "**{}:** {} to hit, {}d{}",
+            weapon,
+            signed(ability_modifier + self.proficiency_bonus as i32),
+            dice,
+            die,
+        )?;
+        match ability_modifier {
+            0 => {}
+            m if m > 0 => write!(f, " + {}", m)?,
+            m => write!(f, " - {}", -m)?,
+        }
+        write!(f, " {} damage", damage_type)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::world::npc::Age;
+    use rand::prelude::*;
+
+    #[test]
+    fn stat_block_test() {
+        let stats = Archetype::Veteran.stat_block(None);
+        assert_eq!([16, 13, 14, 10, 11, 10], stats.abilities);
+        assert_eq!(17, stats.armor_class);
+        assert_eq!(58, stats.hit_points);
+        assert_eq!(30, stats.speed);
+        assert_eq!(2, stats.proficiency_bonus);
+
+        let stats = Archetype::Guard.stat_block(None);
+        assert_eq!(16, stats.armor_class);
+        assert_eq!(11, stats.hit_points);
+
+        let stats = Archetype::Gladiator.stat_block(None);
+        assert_eq!(3, stats.proficiency_bonus);
+    }
+
+    #[test]
+    fn stat_block_test_species() {
+        let stats = Archetype::Veteran.stat_block(Some(&Species::Dwarf));
+        assert_eq!([16, 13, 16, 10, 12, 10], stats.abilities);
+        assert_eq!(25, stats.speed);
+        assert_eq!(40 + 9 * 3 + 9, stats.hit_points);
+        assert!(stats.darkvision);
+        assert!(stats.to_string().contains("**Traits:** `Darkvision`, "));
+        assert!(stats.to_string().contains("**Hit Points:** 76 (9d8 + 36)"));
+
+        let stats = Archetype::Commoner.stat_block(Some(&Species::Halfling));
+        assert_eq!(6, stats.hit_die);
+        assert_eq!(12, stats.abilities[1]);
+        assert_eq!(11, stats.armor_class);
+        assert!(!stats.darkvision);
+
+        let stats = Archetype::Bandit.stat_block(Some(&Species::Goblin));
+        assert_eq!(6, stats.hit_die);
+        assert_eq!(30, stats.speed);
+        assert!(stats.darkvision);
+        assert!(stats.to_string().contains("Fury of the Small"));
+    }
+
+    #[test]
+    fn species_traits_test() {
+        let traits = species_traits(Some(&Species::Dwarf));
+        assert!(traits.contains(&"Darkvision"), "{:?}", traits);
+        assert!(traits.contains(&"Dwarven Toughness"), "{:?}", traits);
+        assert!(traits
+            .iter()
+            .all(|name| name.parse::<reference::Trait>().is_ok()));
+
+        assert!(species_traits(Some(&Species::Goblin)).contains(&"Nimble Escape"));
+        assert!(species_traits(None).is_empty());
+    }
+
+    #[test]
+    fn regenerate_test() {
+        let mut npc = Npc {
+            age: Age::Adult.into(),
+            archetype: Archetype::Veteran.into(),
+            ..Default::default()
+        };
+
+        npc.regenerate_at(
+            &mut SmallRng::seed_from_u64(0),
+            &Default::default(),
+            "temple".parse().ok().as_ref(),
+        );
+        assert_eq!(Some(&Role::Soldier), npc.occupation.value());
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(
+            "\
+**Stats:** guard (CR 1/8, 25 XP)\\
+**Armor Class:** 16 (chain shirt, shield)\\
+**Hit Points:** 11 (2d8 + 2)\\
+**Speed:** 30 ft.\\
+**Proficiency Bonus:** +2
+
+| STR | DEX | CON | INT | WIS | CHA |
+|:---:|:---:|:---:|:---:|:---:|:---:|
+| 14 (+2) | 13 (+1) | 13 (+1) | 11 (+0) | 12 (+1) | 11 (+0) |
+
+**Spear:** +4 to hit, 1d6 + 2 piercing damage",
+            Archetype::Guard
+                .stat_block(Some(&Species::Human))
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(Archetype::Veteran), "veteran".parse::<Archetype>());
+        assert_eq!(Ok(Archetype::Mage), "Wizard".parse::<Archetype>());
+        assert_eq!(Err(()), "potato".parse::<Archetype>());
+    }
+}
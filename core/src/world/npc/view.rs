@@ -1,4 +1,5 @@
-use super::{Age, Gender, Npc, NpcRelations};
+use super::{Age, Gender, Npc, NpcRelations, Relationship, Units};
+use crate::reference;
 use std::fmt;
 
 pub struct SummaryView<'a>(&'a Npc);
@@ -8,17 +9,22 @@ pub struct DescriptionView<'a>(&'a Npc);
 pub struct DetailsView<'a> {
     npc: &'a Npc,
     relations: NpcRelations,
+    units: Units,
 }
 
 fn write_summary_details(npc: &Npc, f: &mut fmt::Formatter) -> fmt::Result {
-    if let Some(age) = npc.age.value() {
-        age.fmt_with_species_ethnicity(npc.species.value(), npc.ethnicity.value(), f)?;
-    } else if let Some(species) = npc.species.value() {
-        write!(f, "{}", species)?;
-    } else if let Some(ethnicity) = npc.ethnicity.value() {
-        write!(f, "{} person", ethnicity)?;
+    let description = DemographicsView(npc).to_string();
+
+    if let Some(occupation) = npc.occupation.value() {
+        // "elvish person" becomes "elvish blacksmith", "adult human" becomes "adult human
+        // blacksmith".
+        if let Some(description) = description.strip_suffix("person") {
+            write!(f, "{}{}", description, occupation)?;
+        } else {
+            write!(f, "{} {}", description, occupation)?;
+        }
     } else {
-        write!(f, "person")?;
+        write!(f, "{}", description)?;
     }
 
     if let Some(gender) = npc.gender.value() {
@@ -28,6 +34,27 @@ fn write_summary_details(npc: &Npc, f: &mut fmt::Formatter) -> fmt::Result {
     Ok(())
 }
 
+/// The NPC's age, species and ethnicity, eg. "elderly elvish person".
+struct DemographicsView<'a>(&'a Npc);
+
+impl<'a> fmt::Display for DemographicsView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let npc = self.0;
+
+        if let Some(age) = npc.age.value() {
+            age.fmt_with_species_ethnicity(npc.species.value(), npc.ethnicity.value(), f)
+        } else if let Some(species) = npc.species.value() {
+            write!(f, "{}", species)
+        } else if let Some(ethnicity) = npc.ethnicity.value() {
+            write!(f, "{} person", ethnicity)
+        } else if let Some(culture) = npc.culture.value() {
+            write!(f, "{} person", culture)
+        } else {
+            write!(f, "person")
+        }
+    }
+}
+
 impl<'a> SummaryView<'a> {
     pub fn new(npc: &'a Npc) -> Self {
         Self(npc)
@@ -41,8 +68,12 @@ impl<'a> DescriptionView<'a> {
 }
 
 impl<'a> DetailsView<'a> {
-    pub fn new(npc: &'a Npc, relations: NpcRelations) -> Self {
-        Self { npc, relations }
+    pub fn new(npc: &'a Npc, relations: NpcRelations, units: Units) -> Self {
+        Self {
+            npc,
+            relations,
+            units,
+        }
     }
 }
 
@@ -51,7 +82,9 @@ impl<'a> fmt::Display for SummaryView<'a> {
         let npc = self.0;
         let has_details = npc.age.is_some()
             || npc.ethnicity.is_some()
+            || npc.culture.is_some()
             || npc.gender.is_some()
+            || npc.occupation.is_some()
             || npc.species.is_some();
 
         write!(
@@ -89,13 +122,27 @@ impl<'a> fmt::Display for SummaryView<'a> {
 
 impl<'a> fmt::Display for DescriptionView<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write_summary_details(self.0, f)
+        let npc = self.0;
+
+        if let Some(personality) = npc.personality.value() {
+            write!(f, "{} ", personality)?;
+        }
+
+        if let Some(feature) = npc.feature.value() {
+            write!(f, "{} ", feature)?;
+        }
+
+        write_summary_details(npc, f)
     }
 }
 
 impl<'a> fmt::Display for DetailsView<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let Self { npc, relations } = self;
+        let Self {
+            npc,
+            relations,
+            units,
+        } = self;
 
         writeln!(f, "<div class=\"thing-box npc\">\n")?;
 
@@ -108,13 +155,21 @@ impl<'a> fmt::Display for DetailsView<'a> {
         write_summary_details(npc, f)?;
         write!(f, "*")?;
 
-        match (npc.species.value(), npc.ethnicity.value()) {
-            (Some(species), Some(ethnicity)) if ethnicity != &species.default_ethnicity() => {
+        match (
+            npc.species.value(),
+            npc.ethnicity.value(),
+            npc.culture.value(),
+        ) {
+            (Some(species), _, Some(culture)) => {
+                write!(f, "\n\n**Species:** {} ({})", species, culture)?
+            }
+            (Some(species), Some(ethnicity), None) if ethnicity != &species.default_ethnicity() => {
                 write!(f, "\n\n**Species:** {} ({})", species, ethnicity)?
             }
-            (Some(species), _) => write!(f, "\n\n**Species:** {}", species)?,
-            (None, Some(ethnicity)) => write!(f, "\n\n**Ethnicity:** {}", ethnicity)?,
-            (None, None) => write!(f, "\n\n**Species:** N/A")?,
+            (Some(species), _, None) => write!(f, "\n\n**Species:** {}", species)?,
+            (None, Some(ethnicity), _) => write!(f, "\n\n**Ethnicity:** {}", ethnicity)?,
+            (None, None, Some(culture)) => write!(f, "\n\n**Culture:** {}", culture)?,
+            (None, None, None) => write!(f, "\n\n**Species:** N/A")?,
         }
 
         npc.gender
@@ -125,11 +180,48 @@ impl<'a> fmt::Display for DetailsView<'a> {
             .value()
             .map(|age_years| write!(f, "\\\n**Age:** {} years", age_years))
             .transpose()?;
+        npc.occupation
+            .value()
+            .map(|occupation| write!(f, "\\\n**Occupation:** {}", occupation))
+            .transpose()?;
         npc.size
             .value()
-            .map(|size| write!(f, "\\\n**Size:** {}", size))
+            .map(|size| write!(f, "\\\n**Size:** {}", size.display(*units)))
             .transpose()?;
 
+        {
+            let mut appearance = npc
+                .feature
+                .value()
+                .map(|feature| feature.to_string())
+                .into_iter()
+                .chain(npc.hair.value().map(|hair| format!("{} hair", hair)))
+                .chain(npc.eyes.value().map(|eyes| format!("{} eyes", eyes)));
+
+            if let Some(first) = appearance.next() {
+                write!(f, "\\\n**Appearance:** {}", first)?;
+                appearance.try_for_each(|part| write!(f, ", {}", part))?;
+            }
+        }
+
+        if let Some(languages) = npc.languages.value().filter(|l| !l.is_empty()) {
+            write!(f, "\\\n**Languages:** ")?;
+
+            for (i, language) in languages.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+
+                // Link to the SRD entry for the language, if there is one. The bare name would
+                // generate a character of the matching ethnicity instead.
+                if language.name().parse::<reference::Language>().is_ok() {
+                    write!(f, "`srd language {}`", language)?;
+                } else {
+                    write!(f, "{}", language)?;
+                }
+            }
+        }
+
         relations
             .location
             .as_ref()
@@ -146,6 +238,52 @@ impl<'a> fmt::Display for DetailsView<'a> {
                 }
             })
             .transpose()?;
+        relations
+            .home
+            .as_ref()
+            .map(|home| write!(f, "\\\n**Home:** {}", home.display_summary()))
+            .transpose()?;
+
+        for &relationship in Relationship::ALL.iter() {
+            let mut names = relations
+                .relatives(relationship)
+                .iter()
+                .filter_map(|relative| relative.name.value());
+
+            if let Some(name) = names.next() {
+                write!(f, "\\\n**{}:** `{}`", relationship.plural(), name)?;
+                names.try_for_each(|name| write!(f, ", `{}`", name))?;
+            }
+        }
+
+        {
+            let mut personality = [
+                (
+                    "Personality",
+                    npc.personality.value().map(|p| p.to_string()),
+                ),
+                ("Ideal", npc.ideal.value().cloned()),
+                ("Bond", npc.bond.value().cloned()),
+                ("Flaw", npc.flaw.value().cloned()),
+                ("Motivation", npc.motivation.value().cloned()),
+            ]
+            .into_iter()
+            .filter_map(|(label, value)| value.map(|value| (label, value)));
+
+            if let Some((label, value)) = personality.next() {
+                write!(f, "\n\n**{}:** {}", label, value)?;
+                personality
+                    .try_for_each(|(label, value)| write!(f, "\\\n**{}:** {}", label, value))?;
+            }
+        }
+
+        if let Some(stat_block) = npc.stat_block() {
+            write!(
+                f,
+                "\n\n{}",
+                reference::linkify_dice(&stat_block.to_string())
+            )?;
+        }
 
         write!(f, "\n\n</div>")?;
 
@@ -156,7 +294,9 @@ impl<'a> fmt::Display for DetailsView<'a> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::world::npc::{Age, Ethnicity, Gender, Size, Species};
+    use crate::world::npc::{
+        Age, Ethnicity, Feature, Gender, Language, Personality, Role, Size, Species,
+    };
     use crate::world::place::{Place, PlaceType};
     use crate::world::Field;
 
@@ -215,6 +355,39 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn summary_view_test_occupation() {
+        let mut npc = gen_npc(NAME | AGE | SPECIES | GENDER);
+        npc.occupation.replace(Role::Blacksmith);
+        assert_eq!(
+            "👴 `Potato Johnson` (elderly human blacksmith, he/him)",
+            npc.display_summary().to_string(),
+        );
+
+        let mut npc = gen_npc(ETHNICITY);
+        npc.occupation.replace(Role::Priest);
+        assert_eq!("🧑 elvish priest", npc.display_summary().to_string());
+
+        let mut npc = Npc::default();
+        npc.occupation.replace(Role::Guard);
+        assert_eq!("guard", npc.display_description().to_string());
+    }
+
+    #[test]
+    fn description_view_test() {
+        let mut npc = Npc::default();
+        npc.species.replace(Species::Dwarf);
+        assert_eq!("dwarf", npc.display_description().to_string());
+
+        npc.personality.replace(Personality::Grumpy);
+        npc.feature.replace(Feature::OneEyed);
+        npc.eyes.replace("brown".to_string());
+        assert_eq!(
+            "grumpy one-eyed dwarf",
+            npc.display_description().to_string()
+        );
+    }
+
     #[test]
     fn details_view_test_filled() {
         let mut npc = Npc::default();
@@ -224,24 +397,47 @@ mod test {
         npc.gender.replace(Gender::NonBinaryThey);
         npc.age.replace(Age::Adult);
         npc.age_years.replace(30);
+        npc.occupation.replace(Role::Innkeeper);
         npc.size.replace(Size::Medium {
-            height: 71,
-            weight: 140,
+            height: Some(71),
+            weight: Some(140),
         });
+        npc.feature.replace(Feature::Freckled);
+        npc.hair.replace("curly red".to_string());
+        npc.eyes.replace("green".to_string());
+        npc.languages
+            .replace(vec![Language::Common, Language::Elvish]);
+        npc.personality.replace(Personality::Cheerful);
+        npc.ideal.replace("community".to_string());
+        npc.bond.replace("their hometown".to_string());
+        npc.flaw.replace("drinks too much".to_string());
+        npc.motivation.replace("to pay off a debt".to_string());
 
         assert_eq!(
             r#"<div class="thing-box npc">
 
 # Potato Johnson
-*adult human, they/them*
+*adult human innkeeper, they/them*
 
 **Species:** human (elvish)\
 **Gender:** non-binary\
 **Age:** 30 years\
-**Size:** 5'11", 140 lbs (medium)
+**Occupation:** innkeeper\
+**Size:** 5'11", 140 lbs (medium)\
+**Appearance:** freckled, curly red hair, green eyes\
+**Languages:** `srd language Common`, `srd language Elvish`
+
+**Personality:** cheerful\
+**Ideal:** community\
+**Bond:** their hometown\
+**Flaw:** drinks too much\
+**Motivation:** to pay off a debt
 
 </div>"#,
-            format!("{}", npc.display_details(NpcRelations::default()))
+            format!(
+                "{}",
+                npc.display_details(NpcRelations::default(), Units::default())
+            )
         );
     }
 
@@ -258,7 +454,7 @@ mod test {
 </div>"#,
             format!(
                 "{}",
-                gen_npc(SPECIES).display_details(NpcRelations::default())
+                gen_npc(SPECIES).display_details(NpcRelations::default(), Units::default())
             )
         );
         assert_eq!(
@@ -272,7 +468,7 @@ mod test {
 </div>"#,
             format!(
                 "{}",
-                gen_npc(ETHNICITY).display_details(NpcRelations::default())
+                gen_npc(ETHNICITY).display_details(NpcRelations::default(), Units::default())
             )
         );
         assert_eq!(
@@ -286,7 +482,8 @@ mod test {
 </div>"#,
             format!(
                 "{}",
-                gen_npc(ETHNICITY | SPECIES).display_details(NpcRelations::default())
+                gen_npc(ETHNICITY | SPECIES)
+                    .display_details(NpcRelations::default(), Units::default())
             )
         );
     }
@@ -304,7 +501,7 @@ mod test {
 </div>"#,
             format!(
                 "{}",
-                &Npc::default().display_details(NpcRelations::default())
+                &Npc::default().display_details(NpcRelations::default(), Units::default())
             )
         );
     }
@@ -325,6 +522,7 @@ mod test {
                 },
                 None,
             )),
+            ..Default::default()
         };
 
         assert_eq!(
@@ -337,7 +535,46 @@ mod test {
 **Location:** ⛰ `Mount Doom` (mountain)
 
 </div>",
-            format!("{}", DetailsView::new(&npc, relations)),
+            format!("{}", DetailsView::new(&npc, relations, Units::default())),
+        );
+    }
+
+    #[test]
+    fn details_view_test_with_home() {
+        let npc = Npc {
+            name: "Frodo Baggins".into(),
+            ..Default::default()
+        };
+
+        let relations = NpcRelations {
+            location: Some((
+                Place {
+                    name: "Mount Doom".into(),
+                    subtype: "mountain".parse::<PlaceType>().unwrap().into(),
+                    ..Default::default()
+                },
+                None,
+            )),
+            home: Some(Place {
+                name: "Bag End".into(),
+                subtype: "house".parse::<PlaceType>().unwrap().into(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "<div class=\"thing-box npc\">
+
+# Frodo Baggins
+*person*
+
+**Species:** N/A\\
+**Location:** ⛰ `Mount Doom` (mountain)\\
+**Home:** 🏠 `Bag End` (residence)
+
+</div>",
+            format!("{}", DetailsView::new(&npc, relations, Units::default())),
         );
     }
 
@@ -361,6 +598,7 @@ mod test {
                     ..Default::default()
                 }),
             )),
+            ..Default::default()
         };
 
         assert_eq!(
@@ -373,7 +611,40 @@ mod test {
 **Location:** 🏨 `The Prancing Pony`, 🏘 `Bree`
 
 </div>",
-            format!("{}", DetailsView::new(&npc, relations)),
+            format!("{}", DetailsView::new(&npc, relations, Units::default())),
+        );
+    }
+
+    #[test]
+    fn details_view_test_with_relatives() {
+        let npc = Npc {
+            name: "Frodo Baggins".into(),
+            ..Default::default()
+        };
+
+        let relative = |name: &str| Npc {
+            name: name.into(),
+            ..Default::default()
+        };
+
+        let relations = NpcRelations {
+            parents: vec![relative("Drogo Baggins"), relative("Primula Brandybuck")],
+            children: vec![relative("Unnamed"), Npc::default()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "<div class=\"thing-box npc\">
+
+# Frodo Baggins
+*person*
+
+**Species:** N/A\\
+**Parents:** `Drogo Baggins`, `Primula Brandybuck`\\
+**Children:** `Unnamed`
+
+</div>",
+            format!("{}", DetailsView::new(&npc, relations, Units::default())),
         );
     }
 
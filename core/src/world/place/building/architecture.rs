@@ -0,0 +1,71 @@
+use crate::utils::CaseInsensitiveStr;
+use initiative_macros::WordList;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What a building is built from, which largely determines its look.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Architecture {
+    #[alias = "mud-brick"]
+    Adobe,
+
+    Brick,
+
+    #[alias = "tudor"]
+    HalfTimbered,
+
+    Marble,
+
+    #[alias = "stone-built"]
+    Stone,
+
+    #[alias = "wooden"]
+    Timber,
+}
+
+impl fmt::Display for Architecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Parse a building's height, eg. `3-storey`, `two-story` or `single-storey`.
+pub fn parse_floors(input: &str) -> Option<u8> {
+    let number = ["-storey", "-story", "-floor"]
+        .iter()
+        .find_map(|suffix| input.strip_suffix_ci(suffix))?;
+
+    if let Ok(floors) = number.parse::<u8>() {
+        Some(floors).filter(|&floors| floors > 0)
+    } else {
+        [
+            "single", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        ]
+        .iter()
+        .position(|word| word.eq_ci(number))
+        .map(|i| i as u8 + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_floors_test() {
+        assert_eq!(Some(3), parse_floors("3-storey"));
+        assert_eq!(Some(2), parse_floors("Two-Story"));
+        assert_eq!(Some(1), parse_floors("single-storey"));
+        assert_eq!(Some(10), parse_floors("ten-floor"));
+        assert_eq!(None, parse_floors("0-storey"));
+        assert_eq!(None, parse_floors("storey"));
+        assert_eq!(None, parse_floors("many-storey"));
+    }
+
+    #[test]
+    fn architecture_test() {
+        assert_eq!("half-timbered", Architecture::HalfTimbered.to_string());
+        assert_eq!(Ok(Architecture::Timber), "wooden".parse());
+    }
+}
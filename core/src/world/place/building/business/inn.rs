@@ -1,9 +1,174 @@
-use crate::utils::pluralize;
+use crate::utils::{capitalize, pluralize};
+use crate::world::place::{format_cost, Price, Quality};
 use crate::world::{word, word::ListGenerator, Demographics, Place};
 use rand::prelude::*;
 
 pub fn generate(place: &mut Place, rng: &mut impl Rng, _demographics: &Demographics) {
     place.name.replace_with(|_| name(rng));
+
+    let quality = place.quality.value().copied().unwrap_or(Quality::Modest);
+    let price = place.price.value().copied().unwrap_or(Price::Reasonable);
+    place.rooms.replace_with(|_| rooms(quality, price));
+
+    generate_tavern(place, rng);
+}
+
+/// The menu and gossip of anywhere that serves drinks, whether or not it has rooms to let.
+pub fn generate_tavern(place: &mut Place, rng: &mut impl Rng) {
+    let quality = place.quality.value().copied().unwrap_or(Quality::Modest);
+    let price = place.price.value().copied().unwrap_or(Price::Reasonable);
+    place.menu.replace_with(|_| menu(rng, quality, price));
+    place.rumors.replace_with(|_| rumors(rng));
+}
+
+/// The sort of bed on offer and its nightly rate, priced according to the inn's quality and price
+/// tier.
+fn rooms(quality: Quality, price: Price) -> String {
+    let room = match quality {
+        Quality::Squalid => "a heap of straw on the common room floor",
+        Quality::Poor => "a cot in the common room",
+        Quality::Modest => "a bed in a shared room",
+        Quality::Comfortable => "a private room",
+        Quality::Wealthy => "a private room with a feather bed",
+        Quality::Aristocratic => "a suite with its own bath and a servant on call",
+    };
+
+    format!(
+        "{} at {} a night, with board at {} a day",
+        room,
+        format_cost(price.apply(quality.lodging_cp())),
+        format_cost(price.apply(quality.meals_cp())),
+    )
+}
+
+/// Tonight's dish and a choice of drinks, priced according to the quality and price tier.
+fn menu(rng: &mut impl Rng, quality: Quality, price: Price) -> String {
+    let plate_cp = match quality {
+        Quality::Squalid => 1,
+        Quality::Poor => 2,
+        Quality::Modest => 10,
+        Quality::Comfortable => 20,
+        Quality::Wealthy => 30,
+        Quality::Aristocratic => 70,
+    };
+
+    let drinks: [(&str, &str, u32); 2] = match quality {
+        Quality::Squalid | Quality::Poor => [("ale", "mug", 4), ("cider", "mug", 3)],
+        Quality::Modest | Quality::Comfortable => [("ale", "mug", 4), ("wine", "pitcher", 20)],
+        Quality::Wealthy | Quality::Aristocratic => {
+            [("wine", "pitcher", 20), ("fine wine", "bottle", 1000)]
+        }
+    };
+
+    format!(
+        "{} at {} a plate, {} at {} a {}, and {} at {} a {}",
+        dish(rng, quality),
+        format_cost(price.apply(plate_cp)),
+        drinks[0].0,
+        format_cost(price.apply(drinks[0].2)),
+        drinks[0].1,
+        drinks[1].0,
+        format_cost(price.apply(drinks[1].2)),
+        drinks[1].1,
+    )
+}
+
+#[rustfmt::skip]
+fn dish(rng: &mut impl Rng, quality: Quality) -> &'static str {
+    ListGenerator(match quality {
+        Quality::Squalid | Quality::Poor => &[
+            "thin gruel", "turnip soup", "boiled cabbage and bread", "pease pottage",
+            "day-old bread and dripping", "eel pie",
+        ],
+        Quality::Modest | Quality::Comfortable => &[
+            "mutton stew", "venison pie", "roast chicken", "fish chowder",
+            "sausages and mash", "rabbit stew with dumplings", "leek and potato soup",
+        ],
+        Quality::Wealthy | Quality::Aristocratic => &[
+            "roast pheasant", "honey-glazed ham", "spiced venison", "quail in wine sauce",
+            "salmon with herbs", "roast swan",
+        ],
+    })
+    .gen(rng)
+}
+
+/// A few pieces of gossip overheard in the common room, any of which might be the hook for an
+/// adventure.
+fn rumors(rng: &mut impl Rng) -> Vec<String> {
+    let count = rng.gen_range(2..=3);
+    let mut rumors: Vec<String> = Vec::with_capacity(count);
+
+    while rumors.len() < count {
+        let rumor = rumor(rng);
+        if !rumors.contains(&rumor) {
+            rumors.push(rumor);
+        }
+    }
+
+    rumors
+}
+
+fn rumor(rng: &mut impl Rng) -> String {
+    match rng.gen_range(0..8) {
+        0 => {
+            let enemy = word::enemy(rng).to_lowercase();
+            format!(
+                "{} {} has been seen on the road {} of town",
+                capitalize(article(&enemy)),
+                enemy,
+                word::cardinal_direction(rng).to_lowercase(),
+            )
+        }
+        1 => format!(
+            "An old {} swears there's treasure buried beneath the {}",
+            word::profession(rng).to_lowercase(),
+            word::symbol(rng).to_lowercase(),
+        ),
+        2 => format!(
+            "Someone is offering a handsome reward for a stolen {}",
+            word::gem(rng).to_lowercase(),
+        ),
+        3 => {
+            let profession = word::profession(rng).to_lowercase();
+            format!(
+                "{} {} went missing last week, and nobody seems to be looking",
+                capitalize(article(&profession)),
+                profession,
+            )
+        }
+        4 => format!(
+            "The {} {} that haunts the hills is really a cursed {}",
+            word::adjective(rng).to_lowercase(),
+            word::land_animal(rng).to_lowercase(),
+            word::profession(rng).to_lowercase(),
+        ),
+        5 => {
+            let enemy = word::enemy(rng).to_lowercase();
+            format!(
+                "The cellar connects to {} {}'s lair",
+                article(&enemy),
+                enemy,
+            )
+        }
+        6 => format!(
+            "The last {} to take the attic room never came back down",
+            word::profession(rng).to_lowercase(),
+        ),
+        7 => format!(
+            "The {} guild is quietly hiring sellswords, and asking no questions",
+            word::profession(rng).to_lowercase(),
+        ),
+        _ => unreachable!(),
+    }
+}
+
+/// The indefinite article to use before a lowercase word.
+fn article(word: &str) -> &'static str {
+    if word.starts_with(['a', 'e', 'i', 'o']) || word.starts_with('u') && !word.starts_with("uni") {
+        "an"
+    } else {
+        "a"
+    }
 }
 
 fn name(rng: &mut impl Rng) -> String {
@@ -111,4 +276,58 @@ mod test {
             (0..20).map(|_| name(&mut rng)).collect::<Vec<String>>(),
         );
     }
+
+    #[test]
+    fn rooms_test() {
+        assert_eq!(
+            "a private room at 16 sp a night, with board at 1 gp a day",
+            rooms(Quality::Comfortable, Price::Expensive),
+        );
+        assert_eq!(
+            "a heap of straw on the common room floor at 3 cp a night, with board at 1 cp a day",
+            rooms(Quality::Squalid, Price::Cheap),
+        );
+    }
+
+    #[test]
+    fn menu_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let menu = menu(&mut rng, Quality::Comfortable, Price::Expensive);
+        assert!(
+            menu.ends_with(" a plate, ale at 8 cp a mug, and wine at 4 sp a pitcher"),
+            "{}",
+            menu,
+        );
+
+        let menu = super::menu(&mut rng, Quality::Squalid, Price::Cheap);
+        assert!(
+            menu.ends_with(" at 1 cp a plate, ale at 2 cp a mug, and cider at 1 cp a mug"),
+            "{}",
+            menu,
+        );
+    }
+
+    #[test]
+    fn rumors_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        (0..20).for_each(|_| {
+            let rumors = rumors(&mut rng);
+            assert!((2..=3).contains(&rumors.len()), "{:?}", rumors);
+            rumors.iter().enumerate().for_each(|(i, rumor)| {
+                assert!(rumor.starts_with(char::is_uppercase), "{}", rumor);
+                assert!(!rumors[..i].contains(rumor), "{:?}", rumors);
+            });
+        });
+    }
+
+    #[test]
+    fn article_test() {
+        assert_eq!("an", article("orc"));
+        assert_eq!("an", article("imp"));
+        assert_eq!("a", article("unicorn"));
+        assert_eq!("an", article("undertaker"));
+        assert_eq!("a", article("troll"));
+    }
 }
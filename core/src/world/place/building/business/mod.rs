@@ -1,9 +1,12 @@
+pub use shop::{stock, Stock};
+
 mod inn;
+mod shop;
 mod theater;
 
 use super::BuildingType;
-use crate::world::place::{Place, PlaceType};
-use crate::world::Demographics;
+use crate::world::place::{Place, PlaceType, Price, Quality};
+use crate::world::{word::NameGenerator, Demographics};
 use initiative_macros::WordList;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -105,16 +108,232 @@ impl BusinessType {
             | Self::Wainwright => Some("🪙"),
         }
     }
+
+    /// Inns and theaters have their own, more elaborate name generators.
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Inn | Self::Theater => None,
+
+            Self::Any => business(
+                &["Emporium", "Shop", "Store", "Trading Company"],
+                &["Barrel", "Coin", "Crate", "Lantern", "Scale"],
+            ),
+            Self::Arena => business(
+                &["Arena", "Colosseum", "Grounds", "Pit"],
+                &["Blade", "Champion", "Laurel", "Lion", "Shield"],
+            ),
+            Self::Armorer => business(
+                &["Armorer", "Armory", "Mail Shop"],
+                &["Breastplate", "Buckler", "Gauntlet", "Helm", "Shield"],
+            ),
+            Self::Bakery => business(
+                &["Bakehouse", "Bakery", "Ovens"],
+                &["Bun", "Crust", "Honey", "Loaf", "Oven", "Wheat"],
+            ),
+            Self::Bank => business(
+                &["Bank", "Counting House", "Exchange", "Treasury"],
+                &["Coin", "Crown", "Ledger", "Purse", "Scale"],
+            ),
+            Self::Bar => business(
+                &["Alehouse", "Bar", "Taproom", "Tavern"],
+                &["Barrel", "Cask", "Keg", "Mug", "Tankard"],
+            ),
+            Self::Bathhouse => business(
+                &["Bathhouse", "Baths", "Springs"],
+                &["Basin", "Lily", "Pearl", "Spring", "Steam"],
+            ),
+            Self::Blacksmith => business(
+                &["Forge", "Ironworks", "Smithy"],
+                &["Anvil", "Bellows", "Ember", "Hammer", "Tongs"],
+            ),
+            Self::Brewery => business(
+                &["Brewery", "Brewhouse"],
+                &["Barley", "Barrel", "Cask", "Hop", "Malt"],
+            ),
+            Self::Casino => business(
+                &["Card House", "Casino", "Gambling Hall", "Parlor"],
+                &["Ace", "Card", "Dice", "Fortune", "Wager"],
+            ),
+            Self::Club => business(
+                &["Club", "Lodge", "Salon", "Society"],
+                &["Candle", "Quill", "Rose", "Pipe", "Velvet"],
+            ),
+            Self::Distillery => business(
+                &["Distillery", "Spirits", "Stillhouse"],
+                &["Barrel", "Cask", "Juniper", "Rye", "Still"],
+            ),
+            Self::FightingPit => business(
+                &["Fighting Pit", "Pit", "Ring", "Yard"],
+                &["Blood", "Bone", "Fist", "Jaw", "Knuckle"],
+            ),
+            Self::FoodCounter => business(
+                &["Cookhouse", "Counter", "Kitchen", "Stall"],
+                &["Ladle", "Pie", "Pot", "Skewer", "Stew"],
+            ),
+            Self::Forge => business(
+                &["Forge", "Foundry", "Works"],
+                &["Anvil", "Crucible", "Ember", "Flame", "Furnace"],
+            ),
+            Self::FurnitureShop => business(
+                &["Cabinetry", "Furnishings", "Joinery"],
+                &["Chair", "Chest", "Oak", "Stool", "Table"],
+            ),
+            Self::Furrier => business(
+                &["Furrier", "Furs", "Pelts", "Tannery"],
+                &["Bear", "Ermine", "Fox", "Mink", "Sable"],
+            ),
+            Self::GeneralStore => business(
+                &["General Store", "Goods", "Provisions", "Supply"],
+                &["Barrel", "Crate", "Lantern", "Rope", "Sack"],
+            ),
+            Self::GuildHall => business(
+                &["Guild Hall", "Guildhouse", "Hall", "Lodge"],
+                &["Banner", "Charter", "Compass", "Key", "Seal"],
+            ),
+            Self::ImportsShop => business(
+                &["Bazaar", "Emporium", "Imports", "Trading House"],
+                &["Camel", "Caravan", "Compass", "Silk", "Spice"],
+            ),
+            Self::Jeweller => business(
+                &["Gems", "Goldsmith", "Jeweller", "Jewels"],
+                &["Brooch", "Crown", "Locket", "Ring", "{gem}"],
+            ),
+            Self::Lumberyard => business(
+                &["Lumberyard", "Sawmill", "Timberyard", "Woodyard"],
+                &["Ash", "Axe", "Log", "Oak", "Pine", "Saw"],
+            ),
+            Self::MagicShop => business(
+                &["Arcana", "Curiosities", "Emporium", "Oddities", "Sundries"],
+                &["Crystal", "Moon", "Orb", "Star", "Tome", "Wand"],
+            ),
+            Self::Mill => business(
+                &["Gristmill", "Mill", "Watermill", "Windmill"],
+                &["Grain", "Millstone", "Sail", "Wheat", "Wheel"],
+            ),
+            Self::PetStore => business(
+                &["Aviary", "Kennels", "Menagerie", "Pets"],
+                &["Collar", "Feather", "Paw", "Whisker", "{animal}"],
+            ),
+            Self::Restaurant => business(
+                &["Bistro", "Dining Room", "Eatery", "Kitchen", "Table"],
+                &["Feast", "Pepper", "Platter", "Spoon", "{food}"],
+            ),
+            Self::SpecialtyShop => business(
+                &["Boutique", "Emporium", "Shop", "Specialties"],
+                &["Bauble", "Lantern", "Quill", "Ribbon", "Thimble"],
+            ),
+            Self::SpiritsShop => business(
+                &["Bottle Shop", "Cellar", "Spirits", "Wine Shop"],
+                &["Bottle", "Cork", "Flask", "Grape", "Vine"],
+            ),
+            Self::Stable => business(
+                &["Livery", "Paddock", "Stable", "Stables"],
+                &["Bridle", "Hay", "Horseshoe", "Mare", "Saddle", "Stallion"],
+            ),
+            Self::TextilesShop => business(
+                &["Cloth Hall", "Drapers", "Textiles", "Weavers"],
+                &["Loom", "Needle", "Silk", "Spindle", "Thread", "Wool"],
+            ),
+            Self::TradingPost => business(
+                &["Exchange", "Outpost", "Post", "Trading Post"],
+                &["Compass", "Crossroads", "Lantern", "Pelt", "Wagon"],
+            ),
+            Self::Vault => business(
+                &["Coffers", "Depository", "Strongroom", "Vault"],
+                &["Chain", "Coin", "Iron", "Key", "Lock"],
+            ),
+            Self::Wainwright => business(
+                &["Cartworks", "Wagonworks", "Wainwright", "Wheelwright"],
+                &["Axle", "Cart", "Spoke", "Wagon", "Wheel"],
+            ),
+            Self::Warehouse => business(
+                &["Depot", "Storehouse", "Stores", "Warehouse"],
+                &["Bale", "Barrel", "Crate", "Hook", "Sack"],
+            ),
+            Self::Weaponsmith => business(
+                &["Armory", "Blades", "Bladeworks", "Weaponsmith"],
+                &["Axe", "Blade", "Dagger", "Mace", "Spear", "Sword"],
+            ),
+            Self::Woodshop => business(
+                &["Carpentry", "Joinery", "Woodshop", "Woodworks"],
+                &["Chisel", "Lathe", "Maple", "Oak", "Plane", "Saw"],
+            ),
+        }
+    }
+}
+
+const fn business(
+    synonyms: &'static [&'static str],
+    themes: &'static [&'static str],
+) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("The {adjective} {theme}", 3),
+            ("The {theme} and {symbol}", 1),
+            ("The {animal} and {theme}", 1),
+            ("{founder}'s {synonym}", 3),
+            ("{theme} {synonym}", 2),
+            ("{place_name} {synonym}", 1),
+        ],
+        synonyms,
+        themes,
+    })
 }
 
 pub fn generate(place: &mut Place, rng: &mut impl Rng, demographics: &Demographics) {
     #[allow(clippy::collapsible_match)]
-    if let Some(PlaceType::Building(BuildingType::Business(subtype))) = place.subtype.value() {
-        #[allow(clippy::single_match)]
+    if let Some(&PlaceType::Building(BuildingType::Business(subtype))) = place.subtype.value() {
         match subtype {
+            BusinessType::Bar => inn::generate_tavern(place, rng),
             BusinessType::Inn => inn::generate(place, rng, demographics),
             BusinessType::Theater => theater::generate(place, rng, demographics),
             _ => {}
         }
+
+        if subtype.has_inventory() {
+            let quality = place.quality.value().copied().unwrap_or(Quality::Modest);
+            let price = place.price.value().copied().unwrap_or(Price::Reasonable);
+            place
+                .inventory
+                .replace_with(|_| stock(rng, subtype, quality, price, None));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_generator_test() {
+        let generator = BusinessType::Blacksmith.name_generator().unwrap();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Vera's Ironworks",
+                "The Mermaid and Hammer",
+                "The Thirsty Bellows",
+                "The Grey Hammer",
+                "Edric's Smithy",
+                "The Whelk and Anvil",
+                "Anvil Ironworks",
+                "Fairford Smithy",
+                "The Frog and Ember",
+                "Ivo's Ironworks",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            (0..10).map(|_| generator.gen(&mut rng)).collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn name_generator_test_bespoke() {
+        assert!(BusinessType::Inn.name_generator().is_none());
+        assert!(BusinessType::Theater.name_generator().is_none());
+        assert!(BusinessType::Any.name_generator().is_some());
     }
 }
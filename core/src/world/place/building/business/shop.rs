@@ -0,0 +1,282 @@
+use super::BusinessType;
+use crate::reference::{Item, MagicItem};
+use crate::world::place::location::settlement::SettlementType;
+use crate::world::place::{Price, Quality};
+use rand::prelude::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A line in a shop's inventory.
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct Stock {
+    /// The name of the SRD item, eg. "Longsword".
+    pub name: String,
+
+    /// Whether the item is a magic item rather than ordinary equipment.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub magic: bool,
+
+    pub quantity: u16,
+
+    /// The asking price in copper pieces.
+    pub price: u32,
+}
+
+const RARITIES: &[&str] = &["common", "uncommon", "rare", "very rare", "legendary"];
+
+impl BusinessType {
+    /// The SRD equipment categories and magic item categories that a shop sells from. Shops
+    /// selling neither don't keep an inventory.
+    pub const fn wares(&self) -> (&'static [&'static str], &'static [&'static str]) {
+        match self {
+            Self::Armorer => (&["armor"], &[]),
+            Self::Blacksmith | Self::Forge => (&["armor", "weapon"], &[]),
+            Self::GeneralStore | Self::TradingPost => (&["adventuring-gear"], &[]),
+            Self::ImportsShop | Self::SpecialtyShop => (&["adventuring-gear", "tools"], &[]),
+            Self::Jeweller => (&[], &["ring"]),
+            Self::MagicShop => (
+                &[],
+                &[
+                    "armor",
+                    "potion",
+                    "ring",
+                    "rod",
+                    "scroll",
+                    "staff",
+                    "wand",
+                    "weapon",
+                    "wondrous-items",
+                ],
+            ),
+            Self::Stable | Self::Wainwright => (&["mounts-and-vehicles"], &[]),
+            Self::Weaponsmith => (&["weapon"], &[]),
+            Self::Woodshop => (&["tools"], &[]),
+            _ => (&[], &[]),
+        }
+    }
+
+    pub const fn has_inventory(&self) -> bool {
+        let (items, magic_items) = self.wares();
+        !items.is_empty() || !magic_items.is_empty()
+    }
+}
+
+/// Pick a selection of a shop's wares. Better shops charge more but carry rarer magic items, while
+/// remote settlements pay to have goods carted in and have less to choose from. The settlement
+/// may not be known, in which case the shop is treated as being in a town.
+pub fn stock(
+    rng: &mut impl Rng,
+    subtype: BusinessType,
+    quality: Quality,
+    price: Price,
+    settlement: Option<SettlementType>,
+) -> Vec<Stock> {
+    let (categories, magic_categories) = subtype.wares();
+    let max_rarity = max_rarity(quality, settlement);
+    let percent = quality_percent(quality) * settlement_percent(settlement) / 100;
+
+    let items = Item::ALL
+        .iter()
+        .filter(|item| categories.contains(&item.get_category()))
+        .map(|item| (item.get_name(), false, item.get_cost_cp()));
+
+    let magic_items = MagicItem::ALL
+        .iter()
+        .filter(|item| {
+            item.get_category()
+                .is_some_and(|category| magic_categories.contains(&category))
+        })
+        .filter_map(|item| {
+            let rarity = RARITIES
+                .iter()
+                .position(|&rarity| Some(rarity) == item.get_rarity())
+                .filter(|&rarity| rarity <= max_rarity)?;
+            Some((item.get_name(), true, magic_item_cost_cp(rarity)))
+        });
+
+    let candidates: Vec<_> = items.chain(magic_items).collect();
+
+    let mut inventory: Vec<Stock> = candidates
+        .choose_multiple(rng, lines(settlement))
+        .map(|&(name, magic, cost_cp)| Stock {
+            name: name.to_string(),
+            magic,
+            quantity: if magic {
+                1
+            } else {
+                rng.gen_range(quantity_range(cost_cp))
+            },
+            price: round_cost(price.apply(cost_cp) as u64 * percent / 100),
+        })
+        .collect();
+
+    inventory.sort_by(|a, b| a.name.cmp(&b.name));
+    inventory
+}
+
+const fn quality_percent(quality: Quality) -> u64 {
+    match quality {
+        Quality::Squalid => 80,
+        Quality::Poor => 90,
+        Quality::Modest => 100,
+        Quality::Comfortable => 110,
+        Quality::Wealthy => 125,
+        Quality::Aristocratic => 150,
+    }
+}
+
+const fn settlement_percent(settlement: Option<SettlementType>) -> u64 {
+    match settlement {
+        Some(SettlementType::Camp) => 150,
+        Some(SettlementType::Outpost) => 125,
+        Some(SettlementType::Town) | None => 110,
+        Some(SettlementType::District | SettlementType::City | SettlementType::Capital) => 100,
+    }
+}
+
+/// The number of different items on the shelves.
+const fn lines(settlement: Option<SettlementType>) -> usize {
+    match settlement {
+        Some(SettlementType::Camp) => 3,
+        Some(SettlementType::Outpost) => 4,
+        Some(SettlementType::Town) | None => 6,
+        Some(SettlementType::District) => 8,
+        Some(SettlementType::City) => 10,
+        Some(SettlementType::Capital) => 12,
+    }
+}
+
+/// The rarest magic item a shop will stock, as an index into `RARITIES`.
+fn max_rarity(quality: Quality, settlement: Option<SettlementType>) -> usize {
+    let by_quality = match quality {
+        Quality::Squalid | Quality::Poor => 0,
+        Quality::Modest => 1,
+        Quality::Comfortable => 2,
+        Quality::Wealthy => 3,
+        Quality::Aristocratic => 4,
+    };
+
+    let by_settlement = match settlement {
+        Some(SettlementType::Camp | SettlementType::Outpost) => 0,
+        Some(SettlementType::Town) | None => 1,
+        Some(SettlementType::District | SettlementType::City) => 2,
+        Some(SettlementType::Capital) => 3,
+    };
+
+    by_quality.min(by_settlement)
+}
+
+/// The SRD doesn't price magic items, so use the middle of the range suggested for each rarity.
+const fn magic_item_cost_cp(rarity: usize) -> u32 {
+    match rarity {
+        0 => 7_500,
+        1 => 30_000,
+        2 => 275_000,
+        3 => 2_750_000,
+        _ => 10_000_000,
+    }
+}
+
+/// Cheap items are stocked by the dozen, expensive ones one or two at a time.
+fn quantity_range(cost_cp: u32) -> std::ops::RangeInclusive<u16> {
+    match cost_cp {
+        0..=10 => 5..=20,
+        11..=100 => 2..=10,
+        101..=5_000 => 1..=4,
+        _ => 1..=2,
+    }
+}
+
+/// Round a price to the nearest silver or gold piece, so that adjusted prices don't come out as
+/// 1,650 copper pieces.
+fn round_cost(cp: u64) -> u32 {
+    let cp = match cp {
+        0 => 1,
+        1..=99 => cp,
+        100..=999 => (cp + 5) / 10 * 10,
+        _ => (cp + 50) / 100 * 100,
+    };
+
+    cp.min(u32::MAX as u64) as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stock_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        (0..10).for_each(|_| {
+            let inventory = stock(
+                &mut rng,
+                BusinessType::Weaponsmith,
+                Quality::Modest,
+                Price::Reasonable,
+                Some(SettlementType::Camp),
+            );
+
+            assert!(!inventory.is_empty());
+            assert!(inventory.len() <= 3, "{:?}", inventory);
+            inventory.iter().for_each(|stock| {
+                let item: Item = stock.name.parse().unwrap();
+                assert_eq!("weapon", item.get_category());
+                assert!(!stock.magic);
+                assert!(stock.quantity > 0);
+                assert!(stock.price >= item.get_cost_cp(), "{:?}", stock);
+            });
+        });
+
+        assert!(stock(
+            &mut rng,
+            BusinessType::Bakery,
+            Quality::Modest,
+            Price::Reasonable,
+            None,
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn stock_test_magic() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        (0..10).for_each(|_| {
+            stock(
+                &mut rng,
+                BusinessType::MagicShop,
+                Quality::Aristocratic,
+                Price::Reasonable,
+                Some(SettlementType::Town),
+            )
+            .iter()
+            .for_each(|stock| {
+                let item: MagicItem = stock.name.parse().unwrap();
+                assert!(stock.magic);
+                assert_eq!(1, stock.quantity);
+                assert!(
+                    matches!(item.get_rarity(), Some("common" | "uncommon")),
+                    "{:?}",
+                    stock,
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn has_inventory_test() {
+        assert!(BusinessType::GeneralStore.has_inventory());
+        assert!(BusinessType::MagicShop.has_inventory());
+        assert!(!BusinessType::Inn.has_inventory());
+        assert!(!BusinessType::Bakery.has_inventory());
+    }
+
+    #[test]
+    fn round_cost_test() {
+        assert_eq!(1, round_cost(0));
+        assert_eq!(55, round_cost(55));
+        assert_eq!(170, round_cost(165));
+        assert_eq!(1700, round_cost(1650));
+    }
+}
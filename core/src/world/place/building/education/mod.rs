@@ -1,3 +1,4 @@
+use crate::world::word::NameGenerator;
 use initiative_macros::WordList;
 use serde::{Deserialize, Serialize};
 
@@ -18,4 +19,36 @@ impl EducationType {
             Self::Library => Some("📚"),
         }
     }
+
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Academy => education(&["Academy", "Conservatory"]),
+            Self::College => education(&["College", "Collegium"]),
+            Self::Library => education(&["Archive", "Athenaeum", "Library"]),
+            Self::School => education(&["School", "Schoolhouse"]),
+            Self::University => education(&["University"]),
+        }
+    }
+}
+
+const fn education(synonyms: &'static [&'static str]) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{place_name} {synonym}", 3),
+            ("{synonym} of {theme}", 3),
+            ("{founder}'s {synonym}", 1),
+        ],
+        synonyms,
+        themes: &[
+            "Alchemy",
+            "Astronomy",
+            "Divinity",
+            "History",
+            "Letters",
+            "Music",
+            "Natural Philosophy",
+            "the Arcane Arts",
+        ],
+    })
 }
@@ -1,3 +1,4 @@
+use crate::world::word::NameGenerator;
 use initiative_macros::WordList;
 use serde::{Deserialize, Serialize};
 
@@ -22,4 +23,45 @@ impl GovernmentType {
             Self::Court | Self::Palace => Some("🏰"),
         }
     }
+
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Court => government(
+                &["Assizes", "Court", "Courthouse", "Tribunal"],
+                &["High", "King's", "Magistrate's", "Queen's"],
+            ),
+            Self::Embassy => government(
+                &["Consulate", "Embassy", "Legation"],
+                &["Envoy's", "Foreign", "{cardinal_direction}"],
+            ),
+            Self::Guardhouse => government(
+                &["Garrison", "Guardhouse", "Watch House"],
+                &["Gate", "Lantern", "Night", "{cardinal_direction}"],
+            ),
+            Self::Palace => government(
+                &["Court", "Palace"],
+                &["Crown", "Lion", "Moon", "Summer", "Sun", "Winter"],
+            ),
+            Self::Prison => government(
+                &["Dungeon", "Gaol", "Prison", "Stockade"],
+                &["Black", "Chain", "Iron", "Shackle", "Stone"],
+            ),
+        }
+    }
+}
+
+const fn government(
+    synonyms: &'static [&'static str],
+    themes: &'static [&'static str],
+) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{place_name} {synonym}", 3),
+            ("The {theme} {synonym}", 3),
+            ("{synonym} of {place_name}", 1),
+        ],
+        synonyms,
+        themes,
+    })
 }
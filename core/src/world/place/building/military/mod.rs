@@ -1,3 +1,4 @@
+use crate::world::word::NameGenerator;
 use initiative_macros::WordList;
 use serde::{Deserialize, Serialize};
 
@@ -28,4 +29,35 @@ impl MilitaryType {
             Self::Barracks | Self::Base => Some("⚔"),
         }
     }
+
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Barracks => military(&["Barracks", "Billets", "Quarters"]),
+            Self::Base => military(&["Base", "Camp", "Encampment"]),
+            Self::Castle => military(&["Castle"]),
+            Self::Citadel => military(&["Citadel"]),
+            Self::Fort => military(&["Fort", "Redoubt"]),
+            Self::Fortress => military(&["Bastion", "Fortress"]),
+            Self::Keep => military(&["Hold", "Keep"]),
+            Self::Stronghold => military(&["Hold", "Stronghold"]),
+            Self::Tower => military(&["Spire", "Tower", "Watchtower"]),
+        }
+    }
+}
+
+const fn military(synonyms: &'static [&'static str]) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{theme} {synonym}", 3),
+            ("{place_name} {synonym}", 3),
+            ("{cardinal_direction} {synonym}", 1),
+            ("{founder}'s {synonym}", 1),
+            ("The {landscape_adjective} {synonym}", 1),
+        ],
+        synonyms,
+        themes: &[
+            "Dragon", "Eagle", "Iron", "Lion", "Raven", "Shield", "Storm", "Sword", "Wolf",
+        ],
+    })
 }
@@ -1,12 +1,15 @@
-mod business;
-mod education;
-mod government;
-mod military;
-mod religious;
-mod travel;
-
-use crate::world::place::{Place, PlaceType};
-use crate::world::Demographics;
+pub use architecture::{parse_floors, Architecture};
+
+mod architecture;
+pub mod business;
+pub mod education;
+pub mod government;
+pub mod military;
+pub mod religious;
+pub mod travel;
+
+use crate::world::place::{Place, PlaceType, Price, Quality};
+use crate::world::{weighted_index_from_tuple, word::NameGenerator, Demographics};
 use initiative_macros::WordList;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -30,6 +33,15 @@ pub enum BuildingType {
 }
 
 impl BuildingType {
+    /// Whether `other` is this type of building or a more specific kind of it.
+    pub fn includes(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Any, _) => true,
+            (Self::Business(business::BusinessType::Any), Self::Business(_)) => true,
+            _ => self == other,
+        }
+    }
+
     pub const fn get_emoji(&self) -> Option<&'static str> {
         match self {
             Self::Any => None,
@@ -42,11 +54,112 @@ impl BuildingType {
             Self::Travel(subtype) => subtype.get_emoji(),
         }
     }
+
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Any => building(&["Building", "Hall", "House"]),
+            Self::Business(subtype) => subtype.name_generator(),
+            Self::Education(subtype) => subtype.name_generator(),
+            Self::Government(subtype) => subtype.name_generator(),
+            Self::Military(subtype) => subtype.name_generator(),
+            Self::Religious(subtype) => subtype.name_generator(),
+            Self::Residence => building(&["Cottage", "Hall", "House", "Lodge", "Manor"]),
+            Self::Travel(subtype) => subtype.name_generator(),
+        }
+    }
+}
+
+impl BuildingType {
+    /// Places of worship and government are built to last, while most other buildings are built
+    /// from whatever is to hand.
+    const fn architecture_weights(&self) -> &'static [(Architecture, usize)] {
+        match self {
+            Self::Religious(_) => &[
+                (Architecture::Stone, 4),
+                (Architecture::Marble, 2),
+                (Architecture::Brick, 1),
+            ],
+            Self::Government(_) => &[
+                (Architecture::Stone, 4),
+                (Architecture::Brick, 2),
+                (Architecture::Marble, 1),
+            ],
+            Self::Military(_) => &[(Architecture::Stone, 5), (Architecture::Brick, 1)],
+            _ => &[
+                (Architecture::Timber, 4),
+                (Architecture::HalfTimbered, 3),
+                (Architecture::Stone, 2),
+                (Architecture::Brick, 2),
+                (Architecture::Adobe, 1),
+            ],
+        }
+    }
+
+    const fn floors_weights(&self) -> &'static [(u8, usize)] {
+        match self {
+            Self::Business(business::BusinessType::Inn) => &[(2, 3), (3, 2), (4, 1)],
+            Self::Government(government::GovernmentType::Palace) => &[(2, 1), (3, 2), (4, 2)],
+            Self::Government(_) | Self::Education(_) => &[(1, 1), (2, 3), (3, 1)],
+            Self::Religious(_) | Self::Travel(_) => &[(1, 4), (2, 1)],
+            _ => &[(1, 3), (2, 3), (3, 1)],
+        }
+    }
+
+    /// Only places that people pay to use or live in are graded by quality.
+    const fn quality_weights(&self) -> Option<&'static [(Quality, usize)]> {
+        match self {
+            Self::Business(_) => Some(&[
+                (Quality::Squalid, 1),
+                (Quality::Poor, 3),
+                (Quality::Modest, 5),
+                (Quality::Comfortable, 3),
+                (Quality::Wealthy, 1),
+            ]),
+            Self::Residence => Some(&[
+                (Quality::Squalid, 2),
+                (Quality::Poor, 4),
+                (Quality::Modest, 5),
+                (Quality::Comfortable, 2),
+                (Quality::Wealthy, 1),
+                (Quality::Aristocratic, 1),
+            ]),
+            Self::Government(government::GovernmentType::Palace) => {
+                Some(&[(Quality::Wealthy, 1), (Quality::Aristocratic, 3)])
+            }
+            _ => None,
+        }
+    }
+}
+
+const fn building(synonyms: &'static [&'static str]) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{place_name} {synonym}", 3),
+            ("{founder}'s {synonym}", 2),
+            ("The {landscape_adjective} {synonym}", 1),
+        ],
+        synonyms,
+        themes: &[],
+    })
 }
 
 pub fn generate(place: &mut Place, rng: &mut impl Rng, demographics: &Demographics) {
-    if let Some(PlaceType::Building(subtype)) = place.subtype.value() {
-        #[allow(clippy::single_match)]
+    if let Some(&PlaceType::Building(subtype)) = place.subtype.value() {
+        place
+            .architecture
+            .replace_with(|_| *weighted_index_from_tuple(rng, subtype.architecture_weights()));
+        place
+            .floors
+            .replace_with(|_| *weighted_index_from_tuple(rng, subtype.floors_weights()));
+
+        if let Some(weights) = subtype.quality_weights() {
+            place.quality.replace_with(|_| Quality::gen(rng, weights));
+        }
+
+        if matches!(subtype, BuildingType::Business(_)) {
+            place.price.replace_with(|_| Price::gen(rng));
+        }
+
         match subtype {
             BuildingType::Business(_) => business::generate(place, rng, demographics),
             BuildingType::Religious(_) => religious::generate(place, rng, demographics),
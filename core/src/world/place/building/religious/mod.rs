@@ -3,7 +3,7 @@ use initiative_macros::WordList;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::world::{place::PlaceType, Demographics, Place};
+use crate::world::{place::PlaceType, word::NameGenerator, Demographics, Place};
 
 use super::BuildingType;
 
@@ -34,11 +34,65 @@ impl ReligiousType {
             Self::Cemetery | Self::Crypt | Self::Mausoleum | Self::Tomb => Some("🪦"),
         }
     }
+
+    /// Shrines have their own, more elaborate name generator.
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Shrine => None,
+
+            Self::Abbey => religious(&["Abbey", "Priory"], WORSHIP_THEMES),
+            Self::Cemetery => religious(&["Boneyard", "Cemetery", "Graveyard"], BURIAL_THEMES),
+            Self::Crypt => religious(&["Catacombs", "Crypt", "Vault"], BURIAL_THEMES),
+            Self::Mausoleum => religious(&["Mausoleum", "Sepulchre"], BURIAL_THEMES),
+            Self::Monastery => religious(&["Cloister", "Hermitage", "Monastery"], WORSHIP_THEMES),
+            Self::Temple => religious(&["Chapel", "Church", "Sanctum", "Temple"], WORSHIP_THEMES),
+            Self::Tomb => religious(&["Barrow", "Sepulchre", "Tomb"], BURIAL_THEMES),
+        }
+    }
+}
+
+#[rustfmt::skip]
+const WORSHIP_THEMES: &[&str] = &[
+    "Mercy", "Saint {founder}", "the Dawn", "the Eternal Flame", "the Harvest", "the Moon",
+    "the Morning Star", "the Seven", "the Sun", "the Twin Gods",
+];
+
+#[rustfmt::skip]
+const BURIAL_THEMES: &[&str] = &[
+    "Eternal Rest", "Saint {founder}", "the Ancestors", "the Fallen", "the Forgotten",
+    "the Kings", "the Lost", "the Restless Dead", "the Silent",
+];
+
+const fn religious(
+    synonyms: &'static [&'static str],
+    themes: &'static [&'static str],
+) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{synonym} of {theme}", 3),
+            ("{place_name} {synonym}", 2),
+            ("The {landscape_adjective} {synonym}", 1),
+        ],
+        synonyms,
+        themes,
+    })
 }
 
 pub fn generate(place: &mut Place, rng: &mut impl Rng, demographics: &Demographics) {
     #[allow(clippy::collapsible_match)]
     if let Some(PlaceType::Building(BuildingType::Religious(subtype))) = place.subtype.value() {
+        // Burial grounds honour the dead rather than a particular deity.
+        if matches!(
+            subtype,
+            ReligiousType::Abbey
+                | ReligiousType::Monastery
+                | ReligiousType::Shrine
+                | ReligiousType::Temple
+        ) {
+            place.worship.replace_with(|_| shrine::deity(rng));
+        }
+
         #[allow(clippy::single_match)]
         match subtype {
             ReligiousType::Shrine => shrine::generate(place, rng, demographics),
@@ -1,3 +1,4 @@
+use crate::world::word::NameGenerator;
 use initiative_macros::WordList;
 use serde::{Deserialize, Serialize};
 
@@ -26,4 +27,61 @@ impl TravelType {
             Self::Portal => None,
         }
     }
+
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Bridge => travel(
+                &["Bridge", "Crossing"],
+                &["Arch", "Stone", "Toll", "Troll", "{enemy}'s"],
+            ),
+            Self::DutyHouse => travel(
+                &["Custom House", "Duty House", "Toll House"],
+                &["Coin", "Ledger", "Seal", "Toll"],
+            ),
+            Self::Ferry => travel(
+                &["Crossing", "Ferry", "Landing"],
+                &["Oar", "Reed", "Rope", "{coastal_animal}"],
+            ),
+            Self::Gate => travel(
+                &["Gate", "Gateway", "Postern"],
+                &["Dragon", "King's", "Lion", "Traitor's", "{cardinal_direction}"],
+            ),
+            Self::Lighthouse => travel(
+                &["Beacon", "Light", "Lighthouse"],
+                &["Gull", "Lantern", "Storm", "Wreck", "{coastal_animal}"],
+            ),
+            Self::Market => travel(
+                &["Bazaar", "Market", "Marketplace", "Square"],
+                &["Corn", "Fish", "Cattle", "Night", "{food}"],
+            ),
+            Self::Pier => travel(
+                &["Dock", "Jetty", "Pier", "Wharf"],
+                &["Anchor", "Fisherman's", "Salt", "Tide", "{coastal_animal}"],
+            ),
+            Self::Portal => travel(
+                &["Door", "Gate", "Portal", "Rift"],
+                &["Astral", "Shimmering", "Star", "Void", "{gem}"],
+            ),
+            Self::Shipyard => travel(
+                &["Drydock", "Shipworks", "Shipyard", "Slipway"],
+                &["Anchor", "Hull", "Keel", "Mast", "Shipwright's"],
+            ),
+        }
+    }
+}
+
+const fn travel(
+    synonyms: &'static [&'static str],
+    themes: &'static [&'static str],
+) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{place_name} {synonym}", 3),
+            ("{theme} {synonym}", 3),
+            ("The {theme} {synonym}", 1),
+        ],
+        synonyms,
+        themes,
+    })
 }
@@ -5,7 +5,7 @@ use initiative_macros::WordList;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::world::{place::PlaceType, Demographics, Place};
+use crate::world::{place::PlaceType, word::NameGenerator, Demographics, Place};
 
 use super::LocationType;
 
@@ -48,6 +48,45 @@ impl GeographicalType {
             Self::Cave | Self::Rift => None,
         }
     }
+
+    /// Beaches and canyons have their own, more elaborate name generators.
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Beach | Self::Canyon => None,
+
+            Self::Cave => geographical(&["Cave", "Cavern", "Grotto", "Hollow"]),
+            Self::Chasm => geographical(&["Abyss", "Chasm", "Gulf", "Rift"]),
+            Self::Glacier => geographical(&["Glacier", "Ice", "Icefield"]),
+            Self::Grove => geographical(&["Copse", "Grove", "Thicket", "Wood"]),
+            Self::Hill => geographical(&["Down", "Hill", "Knoll", "Mound", "Tor"]),
+            Self::Island => geographical(&["Island", "Isle", "Key"]),
+            Self::Monolith => geographical(&["Menhir", "Monolith", "Standing Stone", "Stone"]),
+            Self::Oasis => geographical(&["Oasis", "Springs", "Wells"]),
+            Self::Pass => geographical(&["Gap", "Pass", "Saddle"]),
+            Self::Peninsula => geographical(&["Head", "Peninsula", "Point"]),
+            Self::Ridge => geographical(&["Crest", "Edge", "Ridge", "Spine"]),
+            Self::Rift => geographical(&["Cleft", "Fissure", "Rift", "Scar"]),
+            Self::River => geographical(&["Brook", "River", "Run", "Stream"]),
+            Self::Tree => geographical(&["Ash", "Elm", "Oak", "Tree", "Willow", "Yew"]),
+            Self::Valley => geographical(&["Dale", "Glen", "Hollow", "Valley"]),
+        }
+    }
+}
+
+const fn geographical(synonyms: &'static [&'static str]) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{place_name} {synonym}", 2),
+            ("{landscape_adjective} {synonym}", 2),
+            ("{land_animal} {synonym}", 2),
+            ("The {landscape_adjective} {synonym}", 1),
+            ("{founder}'s {synonym}", 1),
+            ("{synonym} of the {enemy}", 1),
+        ],
+        synonyms,
+        themes: &[],
+    })
 }
 
 pub fn generate(place: &mut Place, rng: &mut impl Rng, demographics: &Demographics) {
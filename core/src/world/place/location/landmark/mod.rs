@@ -1,3 +1,4 @@
+use crate::world::word::NameGenerator;
 use initiative_macros::WordList;
 use serde::{Deserialize, Serialize};
 
@@ -29,4 +30,62 @@ impl LandmarkType {
             Self::Monument => Some("🗽"),
         }
     }
+
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Farm => landmark(
+                &["Acres", "Farm", "Farmstead", "Fields", "Orchard"],
+                &["Apple", "Barley", "Clover", "Goose", "Sheep"],
+            ),
+            Self::Fountain => landmark(
+                &["Fountain", "Spring", "Well"],
+                &["Dolphin", "Lion", "Maiden", "Mermaid", "Swan"],
+            ),
+            Self::Garden => landmark(
+                &["Garden", "Gardens", "Park"],
+                &["Hedge", "Lily", "Moon", "Rose", "Willow"],
+            ),
+            Self::Harbor => landmark(
+                &["Docks", "Harbor", "Quay", "Wharf"],
+                &["Anchor", "Gull", "Salt", "Tide"],
+            ),
+            Self::Mine => landmark(
+                &["Delve", "Diggings", "Mine", "Pit"],
+                &["Coal", "Copper", "Iron", "Silver", "{gem}"],
+            ),
+            Self::Monument => landmark(
+                &["Memorial", "Monument", "Obelisk", "Statue"],
+                &["Founder's", "Hero's", "King's", "Victory"],
+            ),
+            Self::Ruin => landmark(
+                &["Remains", "Ruin", "Ruins"],
+                &["Burnt", "Fallen", "Forgotten", "Sunken"],
+            ),
+            Self::Street => landmark(
+                &["Lane", "Road", "Row", "Street", "Way"],
+                &["Baker's", "Cobble", "Lantern", "Market", "Mill"],
+            ),
+            Self::Wall => landmark(
+                &["Bulwark", "Rampart", "Wall"],
+                &["Great", "King's", "Long", "Old"],
+            ),
+        }
+    }
+}
+
+const fn landmark(
+    synonyms: &'static [&'static str],
+    themes: &'static [&'static str],
+) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{founder}'s {synonym}", 2),
+            ("{place_name} {synonym}", 2),
+            ("The {theme} {synonym}", 2),
+            ("The {landscape_adjective} {synonym}", 1),
+        ],
+        synonyms,
+        themes,
+    })
 }
@@ -1,12 +1,12 @@
 mod geographical;
 mod landmark;
-mod settlement;
+pub mod settlement;
 
 use initiative_macros::WordList;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::world::Demographics;
+use crate::world::{word::NameGenerator, Demographics};
 
 use super::{Place, PlaceType};
 
@@ -30,6 +30,23 @@ impl LocationType {
             Self::Settlement(subtype) => subtype.get_emoji(),
         }
     }
+
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Any => Some(NameGenerator {
+                patterns: &[
+                    ("{place_name}", 2),
+                    ("{place_name} {synonym}", 1),
+                    ("The {landscape_adjective} {synonym}", 1),
+                ],
+                synonyms: &["Corner", "Place", "Reach"],
+                themes: &[],
+            }),
+            Self::Geographical(subtype) => subtype.name_generator(),
+            Self::Landmark(subtype) => subtype.name_generator(),
+            Self::Settlement(subtype) => subtype.name_generator(),
+        }
+    }
 }
 
 pub fn generate(place: &mut Place, rng: &mut impl Rng, demographics: &Demographics) {
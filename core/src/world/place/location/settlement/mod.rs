@@ -1,4 +1,10 @@
+use crate::world::place::building::business::BusinessType;
+use crate::world::place::building::government::GovernmentType;
+use crate::world::place::building::religious::ReligiousType;
+use crate::world::place::building::BuildingType;
+use crate::world::{weighted_index_from_tuple, word::NameGenerator};
 use initiative_macros::WordList;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
@@ -20,6 +26,22 @@ pub enum SettlementType {
     Town,
 }
 
+/// The places and characters that `populate` adds to a settlement.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Population {
+    /// Districts, between which the settlement's buildings are divided.
+    pub districts: usize,
+
+    /// Buildings of a particular type, such as inns and temples, with how many of each.
+    pub buildings: &'static [(BuildingType, usize)],
+
+    /// Shops of assorted types, chosen with `gen_shop`.
+    pub shops: usize,
+
+    /// Whether the settlement has a mayor.
+    pub mayor: bool,
+}
+
 impl SettlementType {
     pub const fn get_emoji(&self) -> Option<&'static str> {
         match self {
@@ -29,4 +51,196 @@ impl SettlementType {
             Self::District | Self::Town => Some("🏘"),
         }
     }
+
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Camp => camp(&["Bivouac", "Camp", "Encampment"]),
+            Self::Capital | Self::City => town(&["City"]),
+            Self::District => Some(NameGenerator {
+                patterns: &[
+                    ("The {theme} {synonym}", 2),
+                    ("{place_name} {synonym}", 1),
+                    ("{cardinal_direction} {synonym}", 1),
+                ],
+                synonyms: &["District", "End", "Quarter", "Row", "Ward"],
+                themes: &[
+                    "Guild", "Harbor", "Lantern", "Merchant", "Old", "Tanners'", "Temple",
+                ],
+            }),
+            Self::Outpost => camp(&["Outpost", "Post", "Station", "Watch"]),
+            Self::Town => town(&["Crossing", "End", "Green", "Market"]),
+        }
+    }
+
+    pub const fn population(&self) -> Population {
+        const INN: BuildingType = BuildingType::Business(BusinessType::Inn);
+        const GUARDHOUSE: BuildingType = BuildingType::Government(GovernmentType::Guardhouse);
+        const TEMPLE: BuildingType = BuildingType::Religious(ReligiousType::Temple);
+
+        match self {
+            Self::Camp => Population {
+                districts: 0,
+                buildings: &[(BuildingType::Religious(ReligiousType::Shrine), 1)],
+                shops: 1,
+                mayor: false,
+            },
+            Self::Outpost => Population {
+                districts: 0,
+                buildings: &[(INN, 1), (GUARDHOUSE, 1)],
+                shops: 1,
+                mayor: false,
+            },
+            Self::District => Population {
+                districts: 0,
+                buildings: &[(INN, 1), (TEMPLE, 1), (GUARDHOUSE, 1)],
+                shops: 4,
+                mayor: false,
+            },
+            Self::Town => Population {
+                districts: 0,
+                buildings: &[(INN, 1), (TEMPLE, 1), (GUARDHOUSE, 1)],
+                shops: 3,
+                mayor: true,
+            },
+            Self::City => Population {
+                districts: 3,
+                buildings: &[(INN, 3), (TEMPLE, 2), (GUARDHOUSE, 2)],
+                shops: 8,
+                mayor: true,
+            },
+            Self::Capital => Population {
+                districts: 5,
+                buildings: &[
+                    (INN, 4),
+                    (TEMPLE, 3),
+                    (GUARDHOUSE, 3),
+                    (BuildingType::Government(GovernmentType::Palace), 1),
+                ],
+                shops: 12,
+                mayor: true,
+            },
+        }
+    }
+}
+
+impl Population {
+    /// Pick a type of shop, favouring the everyday over the exotic.
+    pub fn gen_shop(rng: &mut impl Rng) -> BusinessType {
+        *weighted_index_from_tuple(
+            rng,
+            &[
+                (BusinessType::GeneralStore, 4),
+                (BusinessType::Bakery, 3),
+                (BusinessType::Blacksmith, 3),
+                (BusinessType::Bar, 2),
+                (BusinessType::Stable, 2),
+                (BusinessType::TextilesShop, 2),
+                (BusinessType::Armorer, 1),
+                (BusinessType::Bathhouse, 1),
+                (BusinessType::FoodCounter, 1),
+                (BusinessType::Furrier, 1),
+                (BusinessType::Jeweller, 1),
+                (BusinessType::MagicShop, 1),
+                (BusinessType::Mill, 1),
+                (BusinessType::Restaurant, 1),
+                (BusinessType::SpecialtyShop, 1),
+                (BusinessType::SpiritsShop, 1),
+                (BusinessType::Wainwright, 1),
+                (BusinessType::Weaponsmith, 1),
+                (BusinessType::Woodshop, 1),
+            ],
+        )
+    }
+}
+
+/// Towns and cities mostly go by a single name, eg. "Ashford".
+const fn town(synonyms: &'static [&'static str]) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{place_name}", 6),
+            ("{place_name} {synonym}", 1),
+            ("{cardinal_direction} {place_name}", 1),
+        ],
+        synonyms,
+        themes: &[],
+    })
+}
+
+const fn camp(synonyms: &'static [&'static str]) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{founder}'s {synonym}", 2),
+            ("{place_name} {synonym}", 2),
+            ("{landscape_adjective} {synonym}", 1),
+        ],
+        synonyms,
+        themes: &[],
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn name_generator_test() {
+        let generator = SettlementType::Town.name_generator().unwrap();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "East Silverwood",
+                "Whitedale",
+                "Saltmouth",
+                "Elmgate",
+                "North Silverham",
+                "Frostwick",
+                "Fairmoor",
+                "Oakford",
+                "Hawkmoor",
+                "Silvermere",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            (0..10).map(|_| generator.gen(&mut rng)).collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn population_test() {
+        let count = |subtype: SettlementType, building: BuildingType| {
+            subtype
+                .population()
+                .buildings
+                .iter()
+                .find(|(b, _)| *b == building)
+                .map_or(0, |(_, count)| *count)
+        };
+        let inn = BuildingType::Business(BusinessType::Inn);
+
+        assert_eq!(0, count(SettlementType::Camp, inn));
+        assert_eq!(1, count(SettlementType::Town, inn));
+        assert_eq!(3, count(SettlementType::City, inn));
+        assert_eq!(4, count(SettlementType::Capital, inn));
+
+        assert!(!SettlementType::Camp.population().mayor);
+        assert!(SettlementType::Town.population().mayor);
+        assert_eq!(0, SettlementType::Town.population().districts);
+        assert_eq!(5, SettlementType::Capital.population().districts);
+        assert!(SettlementType::Town.population().shops < SettlementType::City.population().shops);
+    }
+
+    #[test]
+    fn gen_shop_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        (0..100).for_each(|_| {
+            let shop = Population::gen_shop(&mut rng);
+            assert_ne!(BusinessType::Inn, shop);
+            assert_ne!(BusinessType::Any, shop);
+        });
+    }
 }
@@ -1,19 +1,26 @@
 pub use view::{DescriptionView, DetailsView, NameView, SummaryView};
 
-mod building;
-mod location;
+pub use building::business::Stock;
+pub use building::{parse_floors, Architecture};
+pub use quality::{format_cost, Price, Quality};
+
+pub mod building;
+pub mod location;
+mod quality;
 mod region;
 mod view;
 
-use super::{Demographics, Field, Generate};
+use super::npc::Uuid as NpcUuid;
+use super::{word::NameGenerator, Demographics, Field, Generate, Npc};
 use initiative_macros::WordList;
 use rand::prelude::*;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 initiative_macros::uuid!();
 
-#[derive(Clone, Debug, Deserialize, Default, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Default, Eq, JsonSchema, PartialEq, Serialize)]
 pub struct Place {
     pub uuid: Option<Uuid>,
     pub location_uuid: Field<Uuid>,
@@ -21,20 +28,57 @@ pub struct Place {
 
     pub name: Field<String>,
     pub description: Field<String>,
-    // pub architecture: Option<String>,
-    // pub floors: Field<u8>,
-    // pub owner: Field<Vec<NpcUuid>>,
-    // pub staff: Field<Vec<NpcUuid>>,
-    // pub occupants: Field<Vec<NpcUuid>>,
-    // pub services: Option<String>,
-    // pub worship: Field<String>,
-    // pub quality: something
-    // pub price: something
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub architecture: Field<Architecture>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub floors: Field<u8>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub owner: Field<NpcUuid>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub staff: Field<Vec<NpcUuid>>,
+
+    /// What the place offers its customers, eg. an inn's room rates and menu.
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub services: Field<String>,
+
+    /// The rooms an inn has to let and what they cost, eg. "a private room at 8 sp a night".
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub rooms: Field<String>,
+
+    /// The food and drink served at an inn or tavern, with prices.
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub menu: Field<String>,
+
+    /// Gossip that can be picked up by anyone willing to buy a round.
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub rumors: Field<Vec<String>>,
+
+    /// The deity or concept to which a place of worship is dedicated.
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub worship: Field<String>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub quality: Field<Quality>,
+
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub price: Field<Price>,
+
+    /// What a shop has for sale, with quantities and prices.
+    #[serde(default, skip_serializing_if = "Field::is_unset")]
+    pub inventory: Field<Vec<Stock>>,
 }
 
 #[derive(Debug, Default)]
 pub struct PlaceRelations {
     pub location: Option<(Place, Option<Place>)>,
+    pub owner: Option<Npc>,
+    pub staff: Vec<Npc>,
+    pub residents: Vec<Npc>,
+    pub occupants: Vec<Npc>,
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
@@ -76,12 +120,165 @@ impl Place {
             subtype,
             name,
             description,
+            architecture,
+            floors,
+            owner,
+            staff,
+            services,
+            rooms,
+            menu,
+            rumors,
+            worship,
+            quality,
+            price,
+            inventory,
         } = self;
 
         location_uuid.lock();
         subtype.lock();
         name.lock();
         description.lock();
+        architecture.lock();
+        floors.lock();
+        owner.lock();
+        staff.lock();
+        services.lock();
+        rooms.lock();
+        menu.lock();
+        rumors.lock();
+        worship.lock();
+        quality.lock();
+        price.lock();
+        inventory.lock();
+    }
+
+    /// Unlock any empty fields, so that applying the place as a diff leaves them untouched.
+    pub fn unlock_empty(&mut self) {
+        let Self {
+            uuid: _,
+            location_uuid,
+            subtype,
+            name,
+            description,
+            architecture,
+            floors,
+            owner,
+            staff,
+            services,
+            rooms,
+            menu,
+            rumors,
+            worship,
+            quality,
+            price,
+            inventory,
+        } = self;
+
+        location_uuid.unlock_if_none();
+        subtype.unlock_if_none();
+        name.unlock_if_none();
+        description.unlock_if_none();
+        architecture.unlock_if_none();
+        floors.unlock_if_none();
+        owner.unlock_if_none();
+        staff.unlock_if_none();
+        services.unlock_if_none();
+        rooms.unlock_if_none();
+        menu.unlock_if_none();
+        rumors.unlock_if_none();
+        worship.unlock_if_none();
+        quality.unlock_if_none();
+        price.unlock_if_none();
+        inventory.unlock_if_none();
+    }
+
+    /// The names of the locked fields. Lock state isn't recorded when the place is serialized,
+    /// so anything that needs to preserve it must do so separately.
+    pub fn locked_fields(&self) -> Vec<&'static str> {
+        let Self {
+            uuid: _,
+            location_uuid,
+            subtype,
+            name,
+            description,
+            architecture,
+            floors,
+            owner,
+            staff,
+            services,
+            rooms,
+            menu,
+            rumors,
+            worship,
+            quality,
+            price,
+            inventory,
+        } = self;
+
+        [
+            ("location_uuid", location_uuid.is_locked()),
+            ("subtype", subtype.is_locked()),
+            ("name", name.is_locked()),
+            ("description", description.is_locked()),
+            ("architecture", architecture.is_locked()),
+            ("floors", floors.is_locked()),
+            ("owner", owner.is_locked()),
+            ("staff", staff.is_locked()),
+            ("services", services.is_locked()),
+            ("rooms", rooms.is_locked()),
+            ("menu", menu.is_locked()),
+            ("rumors", rumors.is_locked()),
+            ("worship", worship.is_locked()),
+            ("quality", quality.is_locked()),
+            ("price", price.is_locked()),
+            ("inventory", inventory.is_locked()),
+        ]
+        .into_iter()
+        .filter_map(|(field_name, is_locked)| is_locked.then_some(field_name))
+        .collect()
+    }
+
+    /// Lock the named fields and unlock all others, restoring the state recorded by
+    /// [`Self::locked_fields`].
+    pub fn set_locked_fields(&mut self, field_names: &[String]) {
+        let Self {
+            uuid: _,
+            location_uuid,
+            subtype,
+            name,
+            description,
+            architecture,
+            floors,
+            owner,
+            staff,
+            services,
+            rooms,
+            menu,
+            rumors,
+            worship,
+            quality,
+            price,
+            inventory,
+        } = self;
+
+        let includes = |field_name: &str| field_names.iter().any(|s| s == field_name);
+
+        location_uuid.set_locked(includes("location_uuid"));
+        subtype.set_locked(includes("subtype"));
+        name.set_locked(includes("name"));
+        description.set_locked(includes("description"));
+        architecture.set_locked(includes("architecture"));
+        floors.set_locked(includes("floors"));
+        owner.set_locked(includes("owner"));
+        staff.set_locked(includes("staff"));
+        services.set_locked(includes("services"));
+        rooms.set_locked(includes("rooms"));
+        menu.set_locked(includes("menu"));
+        rumors.set_locked(includes("rumors"));
+        worship.set_locked(includes("worship"));
+        quality.set_locked(includes("quality"));
+        price.set_locked(includes("price"));
+        inventory.set_locked(includes("inventory"));
     }
 
     pub fn apply_diff(&mut self, diff: &mut Self) {
@@ -91,12 +288,59 @@ impl Place {
             subtype,
             name,
             description,
+            architecture,
+            floors,
+            owner,
+            staff,
+            services,
+            rooms,
+            menu,
+            rumors,
+            worship,
+            quality,
+            price,
+            inventory,
         } = self;
 
         location_uuid.apply_diff(&mut diff.location_uuid);
         subtype.apply_diff(&mut diff.subtype);
         name.apply_diff(&mut diff.name);
         description.apply_diff(&mut diff.description);
+        architecture.apply_diff(&mut diff.architecture);
+        floors.apply_diff(&mut diff.floors);
+        owner.apply_diff(&mut diff.owner);
+        staff.apply_diff(&mut diff.staff);
+        services.apply_diff(&mut diff.services);
+        rooms.apply_diff(&mut diff.rooms);
+        menu.apply_diff(&mut diff.menu);
+        rumors.apply_diff(&mut diff.rumors);
+        worship.apply_diff(&mut diff.worship);
+        quality.apply_diff(&mut diff.quality);
+        price.apply_diff(&mut diff.price);
+        inventory.apply_diff(&mut diff.inventory);
+    }
+
+    /// Remove a character from the place's owner and staff, eg. when they are deleted. Returns
+    /// whether they were found.
+    pub fn remove_employee(&mut self, npc_uuid: &NpcUuid) -> bool {
+        let mut found = false;
+
+        if self.owner.value() == Some(npc_uuid) {
+            self.owner = Field::default();
+            found = true;
+        }
+
+        if let Some(staff) = self.staff.value_mut() {
+            if let Some(index) = staff.iter().position(|uuid| uuid == npc_uuid) {
+                staff.remove(index);
+                if staff.is_empty() {
+                    self.staff = Field::default();
+                }
+                found = true;
+            }
+        }
+
+        found
     }
 }
 
@@ -107,19 +351,33 @@ impl Generate for Place {
                 .replace_with(|_| PlaceType::generate(rng, demographics));
         }
 
-        #[allow(clippy::collapsible_match)]
-        if let Some(value) = self.subtype.value() {
-            #[allow(clippy::single_match)]
+        if let Some(&value) = self.subtype.value() {
             match value {
                 PlaceType::Building(_) => building::generate(self, rng, demographics),
                 PlaceType::Location(_) => location::generate(self, rng, demographics),
-                _ => {}
+                PlaceType::Any | PlaceType::Region(_) => {}
+            }
+
+            if let Some(generator) = value.name_generator() {
+                self.name.replace_with(|_| generator.gen(rng));
             }
         }
     }
 }
 
 impl PlaceType {
+    /// Whether `other` is this type of place or a more specific kind of it, eg. an inn is a
+    /// business, which is a building, which is a place.
+    pub fn includes(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Any, _) => true,
+            (Self::Building(a), Self::Building(b)) => a.includes(b),
+            (Self::Location(location::LocationType::Any), Self::Location(_)) => true,
+            (Self::Region(region::RegionType::Any), Self::Region(_)) => true,
+            _ => self == other,
+        }
+    }
+
     pub const fn get_emoji(&self) -> &'static str {
         if let Some(emoji) = match self {
             Self::Any => None,
@@ -132,6 +390,17 @@ impl PlaceType {
             "📍"
         }
     }
+
+    /// The generic name generator for this type of place, or `None` if it has a more elaborate
+    /// generator of its own, which is run as part of generating the rest of the place.
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Any => location::LocationType::Any.name_generator(),
+            Self::Building(subtype) => subtype.name_generator(),
+            Self::Location(subtype) => subtype.name_generator(),
+            Self::Region(subtype) => subtype.name_generator(),
+        }
+    }
 }
 
 impl Default for PlaceType {
@@ -178,11 +447,80 @@ mod test {
         );
     }
 
+    #[test]
+    fn name_generator_test_all_patterns() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for word in PlaceType::get_words() {
+            let place_type: PlaceType = word.parse().unwrap();
+
+            if let Some(generator) = place_type.name_generator() {
+                for name in generator.gen_all(&mut rng) {
+                    assert!(
+                        !name.is_empty() && !name.contains(['{', '}']),
+                        "{}: {:?}",
+                        word,
+                        name,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generate_test_name() {
+        let demographics = Demographics::default();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for word in PlaceType::get_words() {
+            let subtype: PlaceType = word.parse().unwrap();
+
+            for _ in 0..20 {
+                let mut place = Place {
+                    subtype: subtype.into(),
+                    ..Default::default()
+                };
+                place.regenerate(&mut rng, &demographics);
+
+                assert_eq!(Some(&subtype), place.subtype.value(), "{}", word);
+                let name = place.name.value().unwrap_or_else(|| panic!("{}", word));
+                assert!(!name.is_empty(), "{}", word);
+            }
+        }
+    }
+
+    #[test]
+    fn generate_test_locked_name() {
+        let demographics = Demographics::default();
+        let mut place = Place {
+            subtype: "duchy".parse::<PlaceType>().unwrap().into(),
+            name: "Duchy of Mirrors".into(),
+            ..Default::default()
+        };
+
+        place.regenerate(&mut SmallRng::seed_from_u64(0), &demographics);
+        assert_eq!(Some(&"Duchy of Mirrors".to_string()), place.name.value());
+    }
+
     #[test]
     fn default_test() {
         assert_eq!(PlaceType::Any, PlaceType::default());
     }
 
+    #[test]
+    fn place_type_includes_test() {
+        let place_type = |s: &str| s.parse::<PlaceType>().unwrap();
+
+        assert!(place_type("place").includes(&place_type("inn")));
+        assert!(place_type("building").includes(&place_type("inn")));
+        assert!(place_type("business").includes(&place_type("inn")));
+        assert!(place_type("inn").includes(&place_type("inn")));
+        assert!(place_type("location").includes(&place_type("town")));
+        assert!(!place_type("inn").includes(&place_type("business")));
+        assert!(!place_type("building").includes(&place_type("duchy")));
+        assert!(!place_type("location").includes(&place_type("place")));
+    }
+
     #[test]
     fn place_type_serialize_deserialize_test() {
         {
@@ -224,15 +562,39 @@ mod test {
         let place = oaken_mermaid_inn();
 
         assert_eq!(
-            r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen"}"#,
+            r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen","architecture":"half-timbered","floors":2,"owner":"00000000-0000-0000-0000-000000000000","staff":["00000000-0000-0000-0000-000000000000"],"services":"lodging at 8 sp a night","rooms":"a private room at 8 sp a night","menu":"mutton stew at 2 sp a plate","rumors":["The cellar connects to a troll's lair"],"worship":"the Raven Queen","quality":"comfortable","price":"reasonable","inventory":[{"name":"Torch","quantity":10,"price":1},{"name":"Bag of Holding","magic":true,"quantity":1,"price":30000}]}"#,
             serde_json::to_string(&place).unwrap(),
         );
 
-        let value: Place = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen"}"#).unwrap();
+        let value: Place = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen","architecture":"half-timbered","floors":2,"owner":"00000000-0000-0000-0000-000000000000","staff":["00000000-0000-0000-0000-000000000000"],"services":"lodging at 8 sp a night","rooms":"a private room at 8 sp a night","menu":"mutton stew at 2 sp a plate","rumors":["The cellar connects to a troll's lair"],"worship":"the Raven Queen","quality":"comfortable","price":"reasonable","inventory":[{"name":"Torch","quantity":10,"price":1},{"name":"Bag of Holding","magic":true,"quantity":1,"price":30000}]}"#).unwrap();
 
         assert_eq!(place, value);
     }
 
+    #[test]
+    fn place_deserialize_test_without_details() {
+        let value: Place = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen"}"#).unwrap();
+
+        assert_eq!(
+            Place {
+                architecture: Field::default(),
+                floors: Field::default(),
+                owner: Field::default(),
+                staff: Field::default(),
+                services: Field::default(),
+                rooms: Field::default(),
+                menu: Field::default(),
+                rumors: Field::default(),
+                worship: Field::default(),
+                quality: Field::default(),
+                price: Field::default(),
+                inventory: Field::default(),
+                ..oaken_mermaid_inn()
+            },
+            value,
+        );
+    }
+
     #[test]
     fn apply_diff_test_no_change() {
         let mut place = oaken_mermaid_inn();
@@ -273,6 +635,18 @@ mod test {
                 subtype: Field::Locked(None),
                 name: Field::Locked(None),
                 description: Field::Locked(None),
+                architecture: Field::Locked(None),
+                floors: Field::Locked(None),
+                owner: Field::Locked(None),
+                staff: Field::Locked(None),
+                services: Field::Locked(None),
+                rooms: Field::Locked(None),
+                menu: Field::Locked(None),
+                rumors: Field::Locked(None),
+                worship: Field::Locked(None),
+                quality: Field::Locked(None),
+                price: Field::Locked(None),
+                inventory: Field::Locked(None),
             },
             place,
         );
@@ -495,6 +869,32 @@ mod test {
 
             name: "Oaken Mermaid Inn".into(),
             description: "I am Mordenkainen".into(),
+            architecture: Architecture::HalfTimbered.into(),
+            floors: 2.into(),
+            owner: NpcUuid::from(uuid::Uuid::nil()).into(),
+            staff: vec![NpcUuid::from(uuid::Uuid::nil())].into(),
+            services: "lodging at 8 sp a night".into(),
+            rooms: "a private room at 8 sp a night".into(),
+            menu: "mutton stew at 2 sp a plate".into(),
+            rumors: vec!["The cellar connects to a troll's lair".to_string()].into(),
+            worship: "the Raven Queen".into(),
+            quality: Quality::Comfortable.into(),
+            price: Price::Reasonable.into(),
+            inventory: vec![
+                Stock {
+                    name: "Torch".to_string(),
+                    magic: false,
+                    quantity: 10,
+                    price: 1,
+                },
+                Stock {
+                    name: "Bag of Holding".to_string(),
+                    magic: true,
+                    quantity: 1,
+                    price: 30_000,
+                },
+            ]
+            .into(),
         }
     }
 }
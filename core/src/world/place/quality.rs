@@ -0,0 +1,144 @@
+use crate::world::weighted_index_from_tuple;
+use initiative_macros::WordList;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How well-appointed a place is, using the same tiers as the lifestyle expenses in the SRD.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Quality {
+    #[alias = "filthy"]
+    Squalid,
+
+    #[alias = "shabby"]
+    Poor,
+
+    Modest,
+    Comfortable,
+
+    #[alias = "upscale"]
+    Wealthy,
+
+    #[alias = "luxurious"]
+    Aristocratic,
+}
+
+/// How a place's prices compare to others of the same quality.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Price {
+    #[alias = "affordable"]
+    Cheap,
+
+    #[alias = "fair"]
+    Reasonable,
+
+    #[alias = "overpriced"]
+    #[alias = "pricey"]
+    Expensive,
+}
+
+impl Quality {
+    pub fn gen(rng: &mut impl Rng, weights: &[(Self, usize)]) -> Self {
+        *weighted_index_from_tuple(rng, weights)
+    }
+
+    /// The cost in copper pieces of a night's lodging, per the SRD.
+    pub const fn lodging_cp(&self) -> u32 {
+        match self {
+            Self::Squalid => 7,
+            Self::Poor => 10,
+            Self::Modest => 50,
+            Self::Comfortable => 80,
+            Self::Wealthy => 200,
+            Self::Aristocratic => 400,
+        }
+    }
+
+    /// The cost in copper pieces of a day's meals, per the SRD.
+    pub const fn meals_cp(&self) -> u32 {
+        match self {
+            Self::Squalid => 3,
+            Self::Poor => 6,
+            Self::Modest => 30,
+            Self::Comfortable => 50,
+            Self::Wealthy => 80,
+            Self::Aristocratic => 200,
+        }
+    }
+}
+
+impl Price {
+    const WEIGHTS: &'static [(Self, usize)] = &[
+        (Self::Cheap, 1),
+        (Self::Reasonable, 3),
+        (Self::Expensive, 1),
+    ];
+
+    pub fn gen(rng: &mut impl Rng) -> Self {
+        *weighted_index_from_tuple(rng, Self::WEIGHTS)
+    }
+
+    /// Adjust a cost in copper pieces for this price tier.
+    pub const fn apply(&self, cp: u32) -> u32 {
+        match self {
+            Self::Cheap if cp > 1 => cp / 2,
+            Self::Cheap | Self::Reasonable => cp,
+            Self::Expensive => cp * 2,
+        }
+    }
+}
+
+/// Format a cost in copper pieces using the largest coin that divides it evenly, eg. "8 sp".
+pub fn format_cost(cp: u32) -> String {
+    if cp >= 100 && cp.is_multiple_of(100) {
+        format!("{} gp", cp / 100)
+    } else if cp >= 10 && cp.is_multiple_of(10) {
+        format!("{} sp", cp / 10)
+    } else {
+        format!("{} cp", cp)
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn apply_test() {
+        assert_eq!(40, Price::Cheap.apply(80));
+        assert_eq!(1, Price::Cheap.apply(1));
+        assert_eq!(80, Price::Reasonable.apply(80));
+        assert_eq!(160, Price::Expensive.apply(80));
+    }
+
+    #[test]
+    fn format_cost_test() {
+        assert_eq!("7 cp", format_cost(7));
+        assert_eq!("1 sp", format_cost(10));
+        assert_eq!("15 cp", format_cost(15));
+        assert_eq!("16 sp", format_cost(160));
+        assert_eq!("4 gp", format_cost(400));
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Ok(Quality::Aristocratic), "luxurious".parse());
+        assert_eq!(Ok(Price::Expensive), "pricey".parse());
+        assert_eq!("squalid", Quality::Squalid.to_string());
+        assert_eq!("reasonable", Price::Reasonable.to_string());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::world::word::NameGenerator;
 use initiative_macros::WordList;
 use serde::{Deserialize, Serialize};
 
@@ -47,4 +48,47 @@ impl GeographyType {
             | Self::Swamp => None,
         }
     }
+
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Continent | Self::World => Some(NameGenerator {
+                patterns: &[("{place_name}", 1)],
+                synonyms: &[],
+                themes: &[],
+            }),
+
+            Self::Archipelago => geography(&["Archipelago", "Islands", "Isles"]),
+            Self::Barrens => geography(&["Badlands", "Barrens", "Wastes"]),
+            Self::Coastline => geography(&["Coast", "Shore", "Strand"]),
+            Self::Desert => geography(&["Desert", "Dunes", "Sands"]),
+            Self::Forest => geography(&["Forest", "Weald", "Wood", "Woods"]),
+            Self::Jungle => geography(&["Jungle", "Rainforest", "Wilds"]),
+            Self::Lake => geography(&["Lake", "Loch", "Mere", "Water"]),
+            Self::Marsh => geography(&["Fens", "Marsh", "Marshes", "Mire"]),
+            Self::Mesa => geography(&["Butte", "Mesa", "Table"]),
+            Self::Moor => geography(&["Heath", "Moor", "Moors"]),
+            Self::Mountain => geography(&["Crag", "Mountain", "Peak", "Spire"]),
+            Self::Ocean => geography(&["Deep", "Ocean", "Waters"]),
+            Self::Plain => geography(&["Fields", "Grasslands", "Plains", "Steppe"]),
+            Self::Plateau => geography(&["Highlands", "Plateau", "Tableland"]),
+            Self::Reef => geography(&["Reef", "Shoals", "Teeth"]),
+            Self::Sea => geography(&["Gulf", "Sea", "Sound"]),
+            Self::Swamp => geography(&["Bog", "Fen", "Swamp"]),
+            Self::Tundra => geography(&["Frozen Wastes", "Tundra", "Wastes"]),
+            Self::Wasteland => geography(&["Blight", "Wasteland", "Wastes"]),
+        }
+    }
+}
+
+pub(super) const fn geography(synonyms: &'static [&'static str]) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("The {place_name} {synonym}", 2),
+            ("The {landscape_adjective} {synonym}", 2),
+            ("{place_name} {synonym}", 2),
+        ],
+        synonyms,
+        themes: &[],
+    })
 }
@@ -1,6 +1,7 @@
 mod geography;
 mod political;
 
+use crate::world::word::NameGenerator;
 use initiative_macros::WordList;
 use serde::{Deserialize, Serialize};
 
@@ -22,4 +23,12 @@ impl RegionType {
             Self::Political(subtype) => subtype.get_emoji(),
         }
     }
+
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Any => geography::geography(&["Expanse", "Lands", "Marches", "Reach"]),
+            Self::Geography(subtype) => subtype.name_generator(),
+            Self::Political(subtype) => subtype.name_generator(),
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use crate::world::word::NameGenerator;
 use initiative_macros::WordList;
 use serde::{Deserialize, Serialize};
 
@@ -25,4 +26,37 @@ impl PoliticalType {
     pub const fn get_emoji(&self) -> Option<&'static str> {
         Some("👑")
     }
+
+    #[rustfmt::skip]
+    pub const fn name_generator(&self) -> Option<NameGenerator> {
+        match self {
+            Self::Barony => political(&["Barony"]),
+            Self::CityState => political(&["City-State", "Free City"]),
+            Self::Confederation => political(&["Confederacy", "Confederation", "League"]),
+            Self::Country => political(&["Commonwealth", "Land", "Republic"]),
+            Self::County => political(&["County", "March", "Shire"]),
+            Self::Domain => political(&["Demesne", "Domain", "Holding"]),
+            Self::Duchy => political(&["Duchy", "Grand Duchy"]),
+            Self::Empire => political(&["Dominion", "Empire"]),
+            Self::Kingdom => political(&["Kingdom"]),
+            Self::Nation => political(&["Nation", "Republic"]),
+            Self::Principality => political(&["Principality"]),
+            Self::Province => political(&["Province", "Satrapy"]),
+            Self::Realm => political(&["Dominion", "Realm"]),
+            Self::Region => political(&["Lands", "Marches", "Reaches", "Region"]),
+            Self::Territory => political(&["Frontier", "Protectorate", "Territory"]),
+        }
+    }
+}
+
+const fn political(synonyms: &'static [&'static str]) -> Option<NameGenerator> {
+    Some(NameGenerator {
+        patterns: &[
+            ("{synonym} of {place_name}", 3),
+            ("{place_name} {synonym}", 1),
+            ("The {landscape_adjective} {synonym}", 1),
+        ],
+        synonyms,
+        themes: &[],
+    })
 }
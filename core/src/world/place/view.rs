@@ -1,4 +1,4 @@
-use super::{Place, PlaceRelations, PlaceType};
+use super::{format_cost, Place, PlaceRelations, PlaceType};
 use std::fmt;
 
 pub struct NameView<'a>(&'a Place);
@@ -68,7 +68,17 @@ impl<'a> fmt::Display for SummaryView<'a> {
 
 impl<'a> fmt::Display for DescriptionView<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if let Some(subtype) = self.0.subtype.value() {
+        let place = self.0;
+
+        if let Some(quality) = place.quality.value() {
+            write!(f, "{} ", quality)?;
+        }
+
+        if let Some(architecture) = place.architecture.value() {
+            write!(f, "{} ", architecture)?;
+        }
+
+        if let Some(subtype) = place.subtype.value() {
             write!(f, "{}", subtype)
         } else {
             write!(f, "place")
@@ -107,12 +117,101 @@ impl<'a> fmt::Display for DetailsView<'a> {
             })
             .transpose()?;
 
+        {
+            let mut separator = if relations.location.is_some() {
+                "\\\n"
+            } else {
+                "\n\n"
+            };
+
+            let fields = [
+                (
+                    "Architecture",
+                    place.architecture.value().map(|a| a.to_string()),
+                ),
+                ("Floors", place.floors.value().map(|f| f.to_string())),
+                ("Quality", place.quality.value().map(|q| q.to_string())),
+                ("Prices", place.price.value().map(|p| p.to_string())),
+                ("Worship", place.worship.value().cloned()),
+                (
+                    "Owner",
+                    relations
+                        .owner
+                        .as_ref()
+                        .and_then(|npc| npc.name.value())
+                        .map(|name| format!("`{}`", name)),
+                ),
+            ];
+
+            for (label, value) in fields {
+                if let Some(value) = value {
+                    write!(f, "{}**{}:** {}", separator, label, value)?;
+                    separator = "\\\n";
+                }
+            }
+
+            for (label, npcs) in [
+                ("Staff", &relations.staff),
+                ("Residents", &relations.residents),
+                ("Occupants", &relations.occupants),
+            ] {
+                let mut names = npcs.iter().filter_map(|npc| npc.name.value());
+
+                if let Some(name) = names.next() {
+                    write!(f, "{}**{}:** `{}`", separator, label, name)?;
+                    names.try_for_each(|name| write!(f, ", `{}`", name))?;
+                    separator = "\\\n";
+                }
+            }
+        }
+
+        {
+            let mut separator = "\n\n";
+
+            for (label, value) in [
+                ("Services", place.services.value()),
+                ("Rooms", place.rooms.value()),
+                ("Menu", place.menu.value()),
+            ] {
+                if let Some(value) = value {
+                    write!(f, "{}**{}:** {}", separator, label, value)?;
+                    separator = "\\\n";
+                }
+            }
+        }
+
         place
             .description
             .value()
             .map(|description| write!(f, "\n\n{}", description))
             .transpose()?;
 
+        if let Some(rumors) = place.rumors.value().filter(|rumors| !rumors.is_empty()) {
+            write!(f, "\n\n**Rumors:**")?;
+            rumors
+                .iter()
+                .try_for_each(|rumor| write!(f, "\n* {}", rumor))?;
+        }
+
+        match place.inventory.value() {
+            Some(inventory) if inventory.is_empty() => write!(f, "\n\n**Stock:** sold out")?,
+            Some(inventory) => {
+                write!(f, "\n\n| Stock | Price | Quantity |\n|---|--:|--:|")?;
+
+                for stock in inventory {
+                    write!(
+                        f,
+                        "\n| `srd {}item {}` | {} | {} |",
+                        if stock.magic { "magic " } else { "" },
+                        stock.name,
+                        format_cost(stock.price),
+                        stock.quantity,
+                    )?;
+                }
+            }
+            None => {}
+        }
+
         write!(f, "\n\n</div>")?;
 
         Ok(())
@@ -122,7 +221,8 @@ impl<'a> fmt::Display for DetailsView<'a> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::world::place::PlaceType;
+    use crate::world::place::{Architecture, PlaceType, Price, Quality, Stock};
+    use crate::world::Npc;
 
     #[test]
     fn view_test_empty() {
@@ -335,6 +435,7 @@ I am Mordenkainen.
                 },
                 None,
             )),
+            ..Default::default()
         };
 
         assert_eq!(
@@ -371,6 +472,7 @@ I am Mordenkainen.
                     ..Default::default()
                 }),
             )),
+            ..Default::default()
         };
 
         assert_eq!(
@@ -385,4 +487,160 @@ I am Mordenkainen.
             format!("{}", DetailsView::new(&place, relations)),
         );
     }
+
+    #[test]
+    fn details_view_test_with_inhabitants() {
+        let place = Place {
+            name: "Bag End".into(),
+            subtype: "house".parse::<PlaceType>().unwrap().into(),
+            ..Default::default()
+        };
+
+        let relations = PlaceRelations {
+            residents: vec![
+                Npc {
+                    name: "Bilbo".into(),
+                    ..Default::default()
+                },
+                Npc {
+                    name: "Frodo".into(),
+                    ..Default::default()
+                },
+            ],
+            occupants: vec![Npc {
+                name: "Gandalf".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "<div class=\"thing-box place\">
+
+# Bag End
+*residence*
+
+**Residents:** `Bilbo`, `Frodo`\\
+**Occupants:** `Gandalf`
+
+</div>",
+            format!("{}", DetailsView::new(&place, relations)),
+        );
+    }
+
+    #[test]
+    fn details_view_test_with_details() {
+        let place = Place {
+            name: "The Prancing Pony".into(),
+            subtype: "inn".parse::<PlaceType>().unwrap().into(),
+            architecture: Architecture::HalfTimbered.into(),
+            floors: 3.into(),
+            quality: Quality::Comfortable.into(),
+            price: Price::Expensive.into(),
+            worship: "Chauntea".into(),
+            services: "lodging at 16 sp a night".into(),
+            rooms: "a private room at 16 sp a night".into(),
+            menu: "mutton stew at 3 sp a plate".into(),
+            rumors: vec![
+                "A troll has been seen on the road north of town".to_string(),
+                "The cellar connects to an orc's lair".to_string(),
+            ]
+            .into(),
+            description: "A rambling old inn.".into(),
+            ..Default::default()
+        };
+
+        let relations = PlaceRelations {
+            owner: Some(Npc {
+                name: "Barliman".into(),
+                ..Default::default()
+            }),
+            staff: vec![Npc {
+                name: "Nob".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "<div class=\"thing-box place\">
+
+# The Prancing Pony
+*comfortable half-timbered inn*
+
+**Architecture:** half-timbered\\
+**Floors:** 3\\
+**Quality:** comfortable\\
+**Prices:** expensive\\
+**Worship:** Chauntea\\
+**Owner:** `Barliman`\\
+**Staff:** `Nob`
+
+**Services:** lodging at 16 sp a night\\
+**Rooms:** a private room at 16 sp a night\\
+**Menu:** mutton stew at 3 sp a plate
+
+A rambling old inn.
+
+**Rumors:**
+* A troll has been seen on the road north of town
+* The cellar connects to an orc's lair
+
+</div>",
+            format!("{}", DetailsView::new(&place, relations)),
+        );
+    }
+
+    #[test]
+    fn details_view_test_with_inventory() {
+        let mut place = Place {
+            name: "The Anvil".into(),
+            subtype: "weaponsmith".parse::<PlaceType>().unwrap().into(),
+            inventory: vec![
+                Stock {
+                    name: "Longsword".to_string(),
+                    magic: false,
+                    quantity: 2,
+                    price: 1500,
+                },
+                Stock {
+                    name: "Ring of Protection".to_string(),
+                    magic: true,
+                    quantity: 1,
+                    price: 275_000,
+                },
+            ]
+            .into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "<div class=\"thing-box place\">
+
+# The Anvil
+*weaponsmith*
+
+| Stock | Price | Quantity |
+|---|--:|--:|
+| `srd item Longsword` | 15 gp | 2 |
+| `srd magic item Ring of Protection` | 2750 gp | 1 |
+
+</div>",
+            format!("{}", DetailsView::new(&place, PlaceRelations::default())),
+        );
+
+        place.inventory = Vec::new().into();
+
+        assert_eq!(
+            "<div class=\"thing-box place\">
+
+# The Anvil
+*weaponsmith*
+
+**Stock:** sold out
+
+</div>",
+            format!("{}", DetailsView::new(&place, PlaceRelations::default())),
+        );
+    }
 }
@@ -1,21 +1,26 @@
 use super::{Demographics, Field, Generate, Npc, NpcRelations, Place, PlaceRelations};
 use crate::world::command::ParsedThing;
-use crate::world::npc::{DetailsView as NpcDetailsView, Gender};
+use crate::world::npc::{Culture, DetailsView as NpcDetailsView, Gender, Units};
 use crate::world::place::DetailsView as PlaceDetailsView;
 use rand::Rng;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
 use std::str::FromStr;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub enum Thing {
     Npc(Npc),
     Place(Place),
 }
 
+// Relations and views only live as long as it takes to display a thing, so their size is of no
+// concern.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Default)]
 pub enum ThingRelations {
     #[default]
@@ -28,6 +33,7 @@ pub struct SummaryView<'a>(&'a Thing);
 
 pub struct DescriptionView<'a>(&'a Thing);
 
+#[allow(clippy::large_enum_variant)]
 pub enum DetailsView<'a> {
     Npc(NpcDetailsView<'a>),
     Place(PlaceDetailsView<'a>),
@@ -96,6 +102,7 @@ impl Thing {
         }
     }
 
+    #[allow(clippy::result_large_err)]
     pub fn into_place(self) -> Result<Place, Thing> {
         if let Self::Place(place) = self {
             Ok(place)
@@ -112,6 +119,7 @@ impl Thing {
         }
     }
 
+    #[allow(clippy::result_large_err)]
     pub fn into_npc(self) -> Result<Npc, Thing> {
         if let Self::Npc(npc) = self {
             Ok(npc)
@@ -128,9 +136,10 @@ impl Thing {
         DescriptionView(self)
     }
 
-    pub fn display_details(&self, relations: ThingRelations) -> DetailsView {
+    /// Heights and weights are shown in the given units.
+    pub fn display_details(&self, relations: ThingRelations, units: Units) -> DetailsView {
         match self {
-            Self::Npc(npc) => DetailsView::Npc(npc.display_details(relations.into())),
+            Self::Npc(npc) => DetailsView::Npc(npc.display_details(relations.into(), units)),
             Self::Place(place) => DetailsView::Place(place.display_details(relations.into())),
         }
     }
@@ -142,6 +151,27 @@ impl Thing {
         }
     }
 
+    pub fn unlock_empty(&mut self) {
+        match self {
+            Self::Npc(npc) => npc.unlock_empty(),
+            Self::Place(place) => place.unlock_empty(),
+        }
+    }
+
+    pub fn locked_fields(&self) -> Vec<&'static str> {
+        match self {
+            Self::Npc(npc) => npc.locked_fields(),
+            Self::Place(place) => place.locked_fields(),
+        }
+    }
+
+    pub fn set_locked_fields(&mut self, field_names: &[String]) {
+        match self {
+            Self::Npc(npc) => npc.set_locked_fields(field_names),
+            Self::Place(place) => place.set_locked_fields(field_names),
+        }
+    }
+
     #[allow(clippy::result_unit_err)]
     pub fn try_apply_diff(&mut self, diff: &mut Self) -> Result<(), ()> {
         match (self, diff) {
@@ -202,8 +232,16 @@ impl FromStr for ParsedThing<Thing> {
     type Err = ();
 
     fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_cultures(raw, &[])
+    }
+}
+
+impl ParsedThing<Thing> {
+    /// Parse a description of either a character or a place, recognizing the names of any custom
+    /// cultures as character descriptions.
+    pub fn parse_with_cultures(raw: &str, cultures: &[Culture]) -> Result<Self, ()> {
         match (
-            raw.parse::<ParsedThing<Npc>>(),
+            ParsedThing::<Npc>::parse_with_cultures(raw, cultures),
             raw.parse::<ParsedThing<Place>>(),
         ) {
             (Ok(parsed_npc), Ok(parsed_place)) => match parsed_npc
@@ -212,6 +250,9 @@ impl FromStr for ParsedThing<Thing> {
                 .cmp(&parsed_place.unknown_words.len())
             {
                 Ordering::Less => Ok(parsed_npc.into_thing()),
+                Ordering::Equal if is_occupation_only(&parsed_npc.thing) => {
+                    Ok(parsed_place.into_thing())
+                }
                 Ordering::Equal => Err(()),
                 Ordering::Greater => Ok(parsed_place.into_thing()),
             },
@@ -222,6 +263,17 @@ impl FromStr for ParsedThing<Thing> {
     }
 }
 
+/// Words like "blacksmith" describe both a business and the person who runs it. Unless something
+/// else marks the thing as a character, assume the place is meant.
+fn is_occupation_only(npc: &Npc) -> bool {
+    npc.occupation.is_some()
+        && Npc {
+            name: Field::default(),
+            occupation: Field::default(),
+            ..npc.clone()
+        } == Npc::default()
+}
+
 impl<'a> fmt::Display for SummaryView<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.0 {
@@ -253,6 +305,31 @@ impl<'a> fmt::Display for DetailsView<'a> {
 mod test {
     use super::*;
 
+    #[test]
+    fn parsed_thing_from_str_test_occupation() {
+        assert!(matches!(
+            "blacksmith".parse::<ParsedThing<Thing>>(),
+            Ok(ParsedThing {
+                thing: Thing::Place(_),
+                ..
+            }),
+        ));
+        assert!(matches!(
+            "dwarvish blacksmith".parse::<ParsedThing<Thing>>(),
+            Ok(ParsedThing {
+                thing: Thing::Npc(_),
+                ..
+            }),
+        ));
+        assert!(matches!(
+            "innkeeper".parse::<ParsedThing<Thing>>(),
+            Ok(ParsedThing {
+                thing: Thing::Npc(_),
+                ..
+            }),
+        ));
+    }
+
     #[test]
     fn name_test() {
         {
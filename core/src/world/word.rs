@@ -1,3 +1,5 @@
+use super::weighted_index_from_tuple;
+use crate::utils::pluralize;
 use rand::distributions::WeightedIndex;
 use rand::prelude::*;
 use rand::Rng;
@@ -10,6 +12,36 @@ const ADJECTIVES: &[&str] = &[
     "Wasted", "Wild",
 ];
 
+#[rustfmt::skip]
+const LANDSCAPE_ADJECTIVES: &[&str] = &[
+    "Black", "Bleak", "Broken", "Cold", "Crooked", "Dark", "Deep", "Far", "Frozen", "Golden",
+    "Grey", "Hidden", "High", "Howling", "Lonely", "Misty", "Old", "Red", "Shadowed",
+    "Silent", "Silver", "Stony", "Sunken", "Twisted", "Whispering", "White", "Wild", "Windy",
+];
+
+#[rustfmt::skip]
+const FOUNDERS: &[&str] = &[
+    "Alda", "Bram", "Corin", "Dunstan", "Edric", "Fenna", "Garrick", "Hilde", "Ivo", "Jory",
+    "Kestra", "Lorne", "Marta", "Nell", "Osric", "Perrin", "Quill", "Rosa", "Sable", "Tamsin",
+    "Ulric", "Vera", "Wendel", "Yara",
+];
+
+/// The first half of a compound place name, eg. "Ash" in "Ashford".
+#[rustfmt::skip]
+const PLACE_NAME_PREFIXES: &[&str] = &[
+    "Alder", "Amber", "Ash", "Black", "Bright", "Cold", "Crow", "Deep", "Elm", "Fair", "Fern",
+    "Frost", "Glen", "Green", "Hawk", "High", "Iron", "Long", "Marsh", "Mill", "Moss", "Oak",
+    "Raven", "Red", "Rose", "Salt", "Silver", "Stone", "Thorn", "White", "Wolf", "Wood",
+];
+
+/// The second half of a compound place name, eg. "ford" in "Ashford".
+#[rustfmt::skip]
+const PLACE_NAME_SUFFIXES: &[&str] = &[
+    "bridge", "brook", "burg", "bury", "by", "dale", "fall", "field", "ford", "gate", "ham",
+    "haven", "hold", "hollow", "march", "mere", "moor", "mouth", "port", "stead", "ton",
+    "vale", "wick", "wood",
+];
+
 #[rustfmt::skip]
 const LAND_ANIMALS: &[&str] = &[
     "Antelope", "Ape", "Baboon", "Badger", "Bat", "Bear", "Beaver", "Bee", "Beetle", "Boar",
@@ -80,6 +112,22 @@ pub fn adjective(rng: &mut impl Rng) -> &'static str {
     ListGenerator(ADJECTIVES).gen(rng)
 }
 
+pub fn landscape_adjective(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(LANDSCAPE_ADJECTIVES).gen(rng)
+}
+
+pub fn founder(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(FOUNDERS).gen(rng)
+}
+
+pub fn place_name(rng: &mut impl Rng) -> String {
+    format!(
+        "{}{}",
+        ListGenerator(PLACE_NAME_PREFIXES).gen(rng),
+        ListGenerator(PLACE_NAME_SUFFIXES).gen(rng),
+    )
+}
+
 pub fn cardinal_direction(rng: &mut impl Rng) -> &'static str {
     ListGenerator(&["North", "South", "East", "West"]).gen(rng)
 }
@@ -132,3 +180,152 @@ impl ListGenerator {
         self.0[rng.gen_range(0..self.0.len())]
     }
 }
+
+/// Generates names from weighted patterns such as `"The {adjective} {synonym}"`. Each placeholder
+/// is replaced with a word from the list of the same name in this module, or from one of the
+/// generator's own lists: `synonym` for words meaning the kind of place, and `theme` for things
+/// associated with it. Themes may themselves contain placeholders, eg. `"Saint {founder}"`.
+#[derive(Clone, Copy, Debug)]
+pub struct NameGenerator {
+    pub patterns: &'static [(&'static str, usize)],
+    pub synonyms: &'static [&'static str],
+    pub themes: &'static [&'static str],
+}
+
+impl NameGenerator {
+    pub fn gen(&self, rng: &mut impl Rng) -> String {
+        let pattern = *weighted_index_from_tuple(rng, self.patterns);
+        self.fill(pattern, rng)
+    }
+
+    /// Fill in every pattern, synonym and theme once, so that tests can catch a misspelled
+    /// placeholder that would otherwise only panic when it happens to be chosen.
+    #[cfg(test)]
+    pub fn gen_all(&self, rng: &mut impl Rng) -> Vec<String> {
+        self.patterns
+            .iter()
+            .map(|(pattern, _)| *pattern)
+            .chain(self.synonyms.iter().copied())
+            .chain(self.themes.iter().copied())
+            .map(|pattern| self.fill(pattern, rng))
+            .collect()
+    }
+
+    fn fill(&self, pattern: &str, rng: &mut impl Rng) -> String {
+        let mut result = String::new();
+        let mut remaining = pattern;
+
+        while let Some(start) = remaining.find('{') {
+            let end = start
+                + remaining[start..]
+                    .find('}')
+                    .unwrap_or_else(|| panic!("Unclosed placeholder in {:?}.", pattern));
+
+            result.push_str(&remaining[..start]);
+            let word = self.word(&remaining[start + 1..end], rng);
+            if word.contains('{') {
+                result.push_str(&self.fill(&word, rng));
+            } else {
+                result.push_str(&word);
+            }
+
+            remaining = &remaining[end + 1..];
+        }
+
+        result.push_str(remaining);
+        result
+    }
+
+    fn word(&self, placeholder: &str, rng: &mut impl Rng) -> String {
+        match placeholder {
+            "adjective" => adjective(rng).to_string(),
+            "animal" => animal(rng).to_string(),
+            "cardinal_direction" => cardinal_direction(rng).to_string(),
+            "coastal_animal" => coastal_animal(rng).to_string(),
+            "enemy" => enemy(rng).to_string(),
+            "food" => food(rng).to_string(),
+            "founder" => founder(rng).to_string(),
+            "gem" => gem(rng).to_string(),
+            "land_animal" => land_animal(rng).to_string(),
+            "landscape_adjective" => landscape_adjective(rng).to_string(),
+            "person" => person(rng).to_string(),
+            "place_name" => place_name(rng),
+            "profession" => profession(rng).to_string(),
+            "professions" => {
+                let (profession, s) = pluralize(profession(rng));
+                format!("{}{}", profession, s)
+            }
+            "symbol" => symbol(rng).to_string(),
+            "synonym" => ListGenerator(self.synonyms).gen(rng).to_string(),
+            "theme" => ListGenerator(self.themes).gen(rng).to_string(),
+            _ => panic!("Unknown placeholder {{{}}}.", placeholder),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_generator_test() {
+        let generator = NameGenerator {
+            patterns: &[
+                ("The {theme} {synonym}", 1),
+                ("{founder}'s {synonym}", 1),
+                ("{place_name} {synonym}", 1),
+            ],
+            synonyms: &["Hall"],
+            themes: &["Green", "{gem}"],
+        };
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Kestra's Hall",
+                "Wolfmarch Hall",
+                "Tamsin's Hall",
+                "The Green Hall",
+                "Redby Hall",
+                "Glenvale Hall",
+                "Stonebridge Hall",
+                "Quill's Hall",
+                "The Quartz Hall",
+                "The Beryl Hall",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            (0..10).map(|_| generator.gen(&mut rng)).collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn name_generator_test_placeholders() {
+        let generator = NameGenerator {
+            patterns: &[(
+                "{adjective} {animal} {cardinal_direction} {coastal_animal} {enemy} {food} \
+                {founder} {gem} {land_animal} {landscape_adjective} {person} {place_name} \
+                {profession} {professions} {symbol} {synonym} {theme}",
+                1,
+            )],
+            synonyms: &["Synonym"],
+            themes: &["Theme"],
+        };
+
+        let name = generator.gen(&mut SmallRng::seed_from_u64(0));
+        assert!(!name.contains(['{', '}']), "{}", name);
+        assert!(name.ends_with("Synonym Theme"), "{}", name);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown placeholder {potato}.")]
+    fn name_generator_test_unknown_placeholder() {
+        NameGenerator {
+            patterns: &[("{potato}", 1)],
+            synonyms: &[],
+            themes: &[],
+        }
+        .gen(&mut SmallRng::seed_from_u64(0));
+    }
+}
@@ -51,6 +51,10 @@ impl SyncApp {
         block_on(self.0.init())
     }
 
+    pub fn load_cultures(&mut self, input: &str) -> Result<usize, String> {
+        self.0.load_cultures(input)
+    }
+
     pub fn command(&mut self, input: &str) -> Result<String, String> {
         block_on(self.0.command(input))
     }
@@ -62,4 +66,8 @@ impl SyncApp {
     pub fn bulk_import(&mut self, data: BackupData) -> Result<String, String> {
         block_on(self.0.bulk_import(data))
     }
+
+    pub fn bulk_import_csv(&mut self, input: &str) -> Result<String, String> {
+        block_on(self.0.bulk_import_csv(input))
+    }
 }
@@ -0,0 +1,47 @@
+use crate::common::{sync_app, sync_app_with_data_store};
+use initiative_core::{DataStore, MemoryDataStore};
+use tokio_test::block_on;
+
+#[test]
+fn it_works() {
+    let mut app = sync_app();
+
+    let output = app.command("history length").unwrap();
+    assert_eq!(
+        "Up to 100 changes are kept in your `history`. Use `history length [number]` to change this.",
+        output,
+    );
+
+    for name in ["Mira", "Bess", "Odo"] {
+        app.command(&format!("{}, an adult human", name)).unwrap();
+    }
+
+    let output = app.command("history length 2").unwrap();
+    assert_eq!(
+        "Up to 2 changes will now be kept in your `history`. Use `undo` to reverse this.",
+        output,
+    );
+
+    let output = app.command("history").unwrap();
+    assert!(output.contains("\n* creating Odo\n"), "{}", output);
+    assert!(!output.contains("creating Bess"), "{}", output);
+
+    app.command("undo").unwrap();
+    let output = app.command("history length").unwrap();
+    assert!(output.starts_with("Up to 100 changes "), "{}", output);
+}
+
+#[test]
+fn preference_is_persisted() {
+    let mut data_store = MemoryDataStore::default();
+    block_on(data_store.set_value("undoHistoryLen", "1")).unwrap();
+    let mut app = sync_app_with_data_store(data_store);
+
+    let output = app.command("history length").unwrap();
+    assert!(output.starts_with("Up to 1 change is "), "{}", output);
+
+    app.command("Mira, an adult human").unwrap();
+    app.command("Bess, an adult human").unwrap();
+    app.command("undo").unwrap();
+    assert!(app.command("undo").is_err());
+}
@@ -2,4 +2,6 @@ mod about;
 mod changelog;
 mod debug;
 mod help;
+mod history_length;
 mod roll;
+mod units;
@@ -0,0 +1,46 @@
+use crate::common::{sync_app, sync_app_with_data_store};
+use initiative_core::{DataStore, MemoryDataStore};
+use tokio_test::block_on;
+
+#[test]
+fn it_works() {
+    let mut app = sync_app();
+
+    app.command("Mira, an adult human").unwrap();
+    app.command("Mira is 5'4\"").unwrap();
+    app.command("Mira is 140lbs").unwrap();
+
+    let output = app.command("units").unwrap();
+    assert!(output.contains("imperial units"), "{}", output);
+
+    let output = app.command("units metric").unwrap();
+    assert_eq!(
+        "Heights and weights will now be shown in metric units. Use `undo` to reverse this.",
+        output,
+    );
+
+    let output = app.command("Mira").unwrap();
+    assert!(output.contains("**Size:** 163 cm, 64 kg"), "{}", output);
+
+    let output = app.command("undo").unwrap();
+    assert_eq!(
+        "Successfully undid changing the units. Use `redo` to reverse this.",
+        output,
+    );
+
+    let output = app.command("Mira").unwrap();
+    assert!(output.contains("**Size:** 5'4\", 140 lbs"), "{}", output);
+}
+
+#[test]
+fn preference_is_persisted() {
+    let mut data_store = MemoryDataStore::default();
+    block_on(data_store.set_value("units", "metric")).unwrap();
+    let mut app = sync_app_with_data_store(data_store);
+
+    let output = app.command("units").unwrap();
+    assert!(output.contains("metric units"), "{}", output);
+
+    let output = app.command("npc").unwrap();
+    assert!(output.contains(" cm, "), "{}", output);
+}
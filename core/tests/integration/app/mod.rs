@@ -40,11 +40,14 @@ fn autocomplete_proper_noun() {
     let query = npc_name.split_whitespace().next().unwrap();
     let autocomplete_results = app.autocomplete(query);
 
+    // The description is prefixed by personality and distinguishing features, if any.
     assert!(
-        autocomplete_results.contains(&AutocompleteSuggestion::new(
-            npc_name.to_string(),
-            format!("{} (unsaved)", npc_description),
-        )),
+        autocomplete_results.iter().any(|suggestion| {
+            suggestion.term == npc_name
+                && suggestion
+                    .summary
+                    .ends_with(&format!("{} (unsaved)", npc_description))
+        }),
         "Generator output:\n{}\n\nQuery: {}\nResults: {:?}",
         output,
         query,
@@ -0,0 +1,44 @@
+use crate::common::sync_app;
+use initiative_core::app::AutocompleteSuggestion;
+
+#[test]
+fn elvish() {
+    let output = sync_app().command("srd language Elvish").unwrap();
+
+    assert_eq!(
+        "\
+# Elvish
+*Standard language*
+
+**Typical Speakers:** Elves\\
+**Script:** Elvish
+
+*Elvish is Open Game Content subject to the `Open Game License`.*",
+        output,
+    );
+
+    assert_eq!(output, sync_app().command("srd language elvish").unwrap());
+
+    assert!(sync_app()
+        .autocomplete("srd language elvi")
+        .contains(&AutocompleteSuggestion::new(
+            "srd language Elvish",
+            "SRD language",
+        )));
+}
+
+#[test]
+fn bare_name_describes_a_character() {
+    let mut app = sync_app();
+
+    // However it's capitalized, as by a phone keyboard.
+    for input in ["Halfling", "halfling", "Elvish"] {
+        let output = app.command(input).unwrap();
+        assert!(output.contains("**Species:**"), "{}", output);
+    }
+
+    assert!(!app
+        .autocomplete("Elvi")
+        .iter()
+        .any(|suggestion| suggestion.summary == "SRD language"));
+}
@@ -1,6 +1,7 @@
 mod condition;
 mod item;
 mod item_category;
+mod language;
 mod magic_item;
 mod open_game_license;
 mod spell;
@@ -0,0 +1,106 @@
+use crate::common::{sync_app, sync_app_with_dispatcher};
+use initiative_core::{Event, ExportFile};
+use std::cell::RefCell;
+
+thread_local! {
+    static LAST_EVENT: RefCell<Option<Event>> = const { RefCell::new(None) };
+}
+
+fn event_dispatcher(event: Event) {
+    LAST_EVENT.with(|last_event| last_event.replace(Some(event)));
+}
+
+#[test]
+fn campaigns_are_isolated() {
+    let mut app = sync_app();
+
+    app.command("npc named Gandalf").unwrap();
+    app.command("+1d").unwrap();
+
+    app.command("campaign new Curse of Strahd").unwrap();
+
+    assert_eq!(
+        "# Journal\n\n*Your journal is currently empty.*",
+        app.command("journal").unwrap(),
+    );
+    assert_eq!(Err("Nothing to undo.".to_string()), app.command("undo"));
+    assert!(app.command("Gandalf").is_err());
+    assert_eq!(
+        "It is currently day 1 at 8:00:00 am.",
+        app.command("now").unwrap(),
+    );
+
+    app.command("npc named Strahd").unwrap();
+    app.command("+2h").unwrap();
+
+    app.command("campaign switch default").unwrap();
+
+    assert!(app.command("Strahd").is_err());
+    assert!(app.command("Gandalf").is_ok());
+    assert_eq!(
+        "It is currently day 2 at 8:00:00 am.",
+        app.command("now").unwrap(),
+    );
+    assert_eq!(
+        "Successfully undid changing the time. Use `redo` to reverse this.",
+        app.command("undo").unwrap(),
+    );
+
+    app.command("campaign switch curse of strahd").unwrap();
+
+    assert!(app.command("Strahd").is_ok());
+    assert_eq!(
+        "It is currently day 1 at 10:00:00 am.",
+        app.command("now").unwrap(),
+    );
+}
+
+#[test]
+fn campaign_export_and_import() {
+    let mut app = sync_app_with_dispatcher(&event_dispatcher);
+
+    app.command("campaign new Curse of Strahd").unwrap();
+    app.command("npc named Strahd").unwrap();
+    app.command("export").unwrap();
+
+    let backup_data = match LAST_EVENT.with(|last_event| last_event.take()) {
+        Some(Event::Export(ExportFile::Backup(data))) => data,
+        event => panic!("{:?}", event),
+    };
+
+    assert_eq!(1, backup_data.things.len());
+
+    app.command("campaign new Strahd Again").unwrap();
+    assert_eq!(
+        "Characters: 1 created",
+        app.bulk_import(backup_data).unwrap(),
+    );
+    assert!(app.command("Strahd").is_ok());
+
+    app.command("campaign switch Default").unwrap();
+    assert!(app.command("Strahd").is_err());
+}
+
+#[test]
+fn campaigns_have_their_own_history_length() {
+    let mut app = sync_app();
+
+    app.command("history length 2").unwrap();
+    app.command("campaign new Curse of Strahd").unwrap();
+
+    for name in ["Strahd", "Ireena", "Ismark"] {
+        app.command(&format!("npc named {}", name)).unwrap();
+    }
+
+    let output = app.command("history length").unwrap();
+    assert!(output.starts_with("Up to 100 changes "), "{}", output);
+
+    app.command("campaign switch default").unwrap();
+    let output = app.command("history length").unwrap();
+    assert!(output.starts_with("Up to 2 changes "), "{}", output);
+
+    // Switching back doesn't cut the other campaign's history down to this one's length.
+    app.command("campaign switch curse of strahd").unwrap();
+    let output = app.command("history").unwrap();
+    assert!(output.contains("\n* creating Strahd\n"), "{}", output);
+}
@@ -116,3 +116,59 @@ fn delete_works_with_unusable_data_store() {
         app.command("redo").unwrap(),
     );
 }
+
+#[test]
+fn deleting_an_employee_can_be_undone() {
+    let mut app = sync_app();
+
+    app.command("inn named The Prancing Pony").unwrap();
+    app.command("npc named Barliman").unwrap();
+    app.command("npc named Nob").unwrap();
+    app.command("Barliman owns The Prancing Pony").unwrap();
+    app.command("Barliman works at The Prancing Pony").unwrap();
+    app.command("Nob works at The Prancing Pony").unwrap();
+
+    assert_eq!(
+        "Barliman was successfully deleted. Use `undo` to reverse this.",
+        app.command("delete Barliman").unwrap(),
+    );
+
+    let output = app.command("The Prancing Pony").unwrap();
+    assert!(!output.contains("**Owner:**"), "{}", output);
+    assert!(
+        output.contains("**Staff:** `Nob`\\\n**Occupants:** "),
+        "{}",
+        output
+    );
+
+    assert!(app
+        .command("history")
+        .unwrap()
+        .contains("\n* deleting Barliman\n"));
+    assert_eq!(
+        "Successfully undid deleting Barliman. Use `redo` to reverse this.",
+        app.command("undo").unwrap(),
+    );
+
+    let output = app.command("The Prancing Pony").unwrap();
+    assert!(
+        output.contains("**Owner:** `Barliman`\\\n**Staff:** `Barliman`, `Nob`"),
+        "{}",
+        output,
+    );
+
+    assert_eq!(
+        "Successfully redid deleting Barliman. Use `undo` to reverse this.",
+        app.command("redo").unwrap(),
+    );
+    assert!(!app
+        .command("The Prancing Pony")
+        .unwrap()
+        .contains("**Owner:**"));
+
+    app.command("undo").unwrap();
+    assert!(app
+        .command("The Prancing Pony")
+        .unwrap()
+        .contains("**Owner:** `Barliman`"));
+}
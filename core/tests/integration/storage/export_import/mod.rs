@@ -1,5 +1,8 @@
 use crate::common::{sync_app, sync_app_with_dispatcher, SyncApp};
-use initiative_core::Event;
+use initiative_core::{BackupData, Event, ExportFile};
+use serde::Deserialize;
+use serde_json::Value;
+use std::cell::RefCell;
 
 static mut LAST_EVENT: Option<Event> = None;
 
@@ -36,13 +39,13 @@ fn inspect_journal(app: &mut SyncApp) -> String {
 #[test]
 fn export() {
     let mut app = sync_app_with_dispatcher(&event_dispatcher);
-    app.command("inn named Foo").unwrap();
+    app.command("temple named Foo").unwrap();
     app.command("npc named Blah").unwrap();
     app.command("+1d").unwrap();
     app.command("export").unwrap();
 
     let data = unsafe {
-        if let Some(Event::Export(data)) = &LAST_EVENT {
+        if let Some(Event::Export(ExportFile::Backup(data))) = &LAST_EVENT {
             Some(data)
         } else {
             None
@@ -55,7 +58,7 @@ fn export() {
     let data_json = serde_json::to_string(&data).unwrap();
 
     assert!(
-        data_json.starts_with(r#"{"_":"This document is exported from initiative.sh. Please note that this format is currently undocumented and no guarantees of forward compatibility are provided, although a reasonable effort will be made to ensure that older backups can be safely imported.","things":[{"#),
+        data_json.starts_with(r#"{"_":"This document is exported from initiative.sh. Its format is described by the JSON Schema published at https://github.com/initiative-sh/initiative.sh/blob/main/data/backup-schema.json. Backups produced by older versions of initiative.sh are upgraded automatically when imported.","version":3,"things":[{"#),
         "{}",
         data_json,
     );
@@ -76,7 +79,7 @@ fn import_event() {
     app.init();
 
     assert_eq!(
-        "The file upload popup should appear momentarily. Please select a compatible JSON file, such as that produced by the `export` command.",
+        "The file upload popup should appear momentarily. Please select a compatible JSON file, such as that produced by the `export` command, or a CSV spreadsheet of characters and places.",
         app.command("import").unwrap(),
     );
 
@@ -88,14 +91,14 @@ fn import_event() {
 fn export_and_import() {
     let (backup_data, journal_before) = {
         let mut app = sync_app_with_dispatcher(&event_dispatcher);
-        app.command("inn named Foo").unwrap();
+        app.command("temple named Foo").unwrap();
         app.command("npc named Blah").unwrap();
         app.command("+1d").unwrap();
         app.command("export").unwrap();
 
         (
             unsafe {
-                if let Some(Event::Export(data)) = LAST_EVENT.take() {
+                if let Some(Event::Export(ExportFile::Backup(data))) = LAST_EVENT.take() {
                     Some(data)
                 } else {
                     None
@@ -194,3 +197,415 @@ fn bulk_import_v2() {
         app.command("time").unwrap(),
     );
 }
+
+/// This is a backwards compatibility test. Do not update the source file.
+#[test]
+fn bulk_import_v3() {
+    let mut app = sync_app();
+    let backup_data = serde_json::from_str(include_str!("v3.json")).unwrap();
+
+    assert_eq!(
+        "Places: 5 created \\\nCharacters: 5 created \\\nKey/values: 1 created",
+        app.bulk_import(backup_data).unwrap(),
+    );
+
+    assert_eq!(
+        "# Journal
+
+## NPCs
+👨 `Faman Halin` (middle-aged human, he/him)\\
+👧 `Halynn Mardeka` (adolescent human, she/her)\\
+👴 `Losno Khayrysi` (elderly halfling, he/him)\\
+👩 `Myrcia Haskyr` (middle-aged human, she/her)\\
+👶 `Pino Nesgarth` (halfling infant, he/him)
+
+## Places
+🏨 `Book and Soldier` (inn)\\
+🏨 `Five Millers` (inn)\\
+🏨 `Raven and Fisherman` (inn)\\
+🏨 `Ten Ghosts` (inn)\\
+🏨 `The Moody Conjurer` (inn)
+
+*To export the contents of your journal, use `export`.*",
+        app.command("journal").unwrap(),
+    );
+
+    assert_eq!(
+        "It is currently day 2 at 8:00:00 am.",
+        app.command("time").unwrap(),
+    );
+}
+
+/// Backups of every version are upgraded to the current version on import, and survive being
+/// exported and imported again unchanged.
+#[test]
+fn round_trip() {
+    for (version, source) in [
+        (1, include_str!("v1.json")),
+        (2, include_str!("v2.json")),
+        (3, include_str!("v3.json")),
+    ] {
+        let backup_data: BackupData = serde_json::from_str(source).unwrap();
+        assert_eq!(3, backup_data.version, "v{}", version);
+
+        let exported = serde_json::to_value(&backup_data).unwrap();
+        let reimported = BackupData::deserialize(&exported).unwrap();
+        assert_eq!(backup_data.things, reimported.things, "v{}", version);
+        assert_eq!(
+            backup_data.key_value.time, reimported.key_value.time,
+            "v{}",
+            version,
+        );
+
+        if version == 3 {
+            assert_eq!(serde_json::from_str::<Value>(source).unwrap(), exported);
+        }
+    }
+}
+
+#[test]
+fn import_unsupported_version() {
+    assert_eq!(
+        "Backup version 4 is newer than the latest supported version (3).",
+        serde_json::from_str::<BackupData>(r#"{"version":4,"things":[],"keyValue":{}}"#)
+            .unwrap_err()
+            .to_string(),
+    );
+}
+
+/// The published schema must describe the current format. To regenerate it after changing any
+/// of the serialized types, run this test with `UPDATE_BACKUP_SCHEMA=1`.
+#[test]
+fn json_schema() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../data/backup-schema.json");
+    let schema = serde_json::to_string_pretty(&BackupData::json_schema()).unwrap() + "\n";
+
+    if std::env::var_os("UPDATE_BACKUP_SCHEMA").is_some() {
+        std::fs::write(path, &schema).unwrap();
+    }
+
+    assert_eq!(std::fs::read_to_string(path).unwrap(), schema);
+}
+
+#[test]
+fn import_merge_by_uuid() {
+    let mut app = sync_app();
+    app.command("npc named Faman Halin").unwrap();
+    app.bulk_import(v2_backup()).unwrap();
+
+    assert_eq!(
+        "Places: 5 updated \\\nCharacters: 4 updated, 1 skipped \\\nKey/values: 1 updated",
+        app.bulk_import(v2_backup()).unwrap(),
+    );
+
+    let mut app = sync_app();
+    app.bulk_import(v2_backup()).unwrap();
+    app.command("Pino Nesgarth is a dwarf").unwrap();
+
+    assert_eq!(
+        "Places: 5 updated \\\nCharacters: 5 updated \\\nKey/values: 1 updated",
+        app.command("import --merge-by-uuid")
+            .and_then(|_| app.bulk_import(v2_backup()))
+            .unwrap(),
+    );
+    assert!(app
+        .command("Pino Nesgarth")
+        .unwrap()
+        .contains("**Species:** halfling"),);
+}
+
+#[test]
+fn import_overwrite() {
+    let mut app = sync_app();
+    app.command("npc named Faman Halin").unwrap();
+    app.command("import --overwrite").unwrap();
+
+    assert_eq!(
+        "Places: 5 created \\\nCharacters: 4 created, 1 updated \\\nKey/values: 1 created",
+        app.bulk_import(v2_backup()).unwrap(),
+    );
+
+    let output = app.command("Faman Halin").unwrap();
+    assert!(output.contains("**Age:** 49 years"), "{}", output);
+    assert!(
+        output.contains("**Location:** 🏨 `The Moody Conjurer` (inn)"),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn import_rename() {
+    let mut app = sync_app();
+    app.bulk_import(v2_backup()).unwrap();
+    app.command("import --rename").unwrap();
+
+    assert_eq!(
+        "Places: 5 created \\\nCharacters: 5 created \\\nKey/values: 1 updated",
+        app.bulk_import(v2_backup()).unwrap(),
+    );
+
+    // References between renamed things point at the new copies.
+    let output = app.command("Faman Halin (2)").unwrap();
+    assert!(
+        output.contains("**Location:** 🏨 `The Moody Conjurer (2)` (inn)"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("Faman Halin").unwrap();
+    assert!(
+        output.contains("**Location:** 🏨 `The Moody Conjurer` (inn)"),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn import_rename_relatives() {
+    let backup = || -> BackupData {
+        serde_json::from_str(r#"{"version":3,"things":[{"type":"Npc","uuid":"6d2fc6b4-6b0a-4f3e-9d51-6a4b0c3f1e01","name":"Vance","gender":"masculine","age":"adult","age_years":44,"species":"human","ethnicity":"human","children":["0c8e3a57-2f0d-4a63-8a3e-2f6e0d9a7b02"]},{"type":"Npc","uuid":"0c8e3a57-2f0d-4a63-8a3e-2f6e0d9a7b02","name":"Rin","gender":"feminine","age":"young adult","age_years":19,"species":"human","ethnicity":"human","parents":["6d2fc6b4-6b0a-4f3e-9d51-6a4b0c3f1e01"]}],"keyValue":{"time":null}}"#).unwrap()
+    };
+
+    let mut app = sync_app();
+    app.bulk_import(backup()).unwrap();
+    app.command("import --rename").unwrap();
+
+    assert_eq!("Characters: 2 created", app.bulk_import(backup()).unwrap());
+
+    // Family links between renamed characters point at the new copies.
+    let output = app.command("Rin (2)").unwrap();
+    assert!(
+        output.contains("\\\n**Parents:** `Vance (2)`"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("Vance (2)").unwrap();
+    assert!(output.contains("\\\n**Children:** `Rin (2)`"), "{}", output,);
+
+    let output = app.command("Vance").unwrap();
+    assert!(output.contains("\\\n**Children:** `Rin`\n"), "{}", output,);
+}
+
+#[test]
+fn import_rename_employees() {
+    let backup = || -> BackupData {
+        serde_json::from_str(r#"{"version":3,"things":[{"type":"Place","uuid":"3f0c9d2e-5b7a-4c1e-8f6d-1a2b3c4d5e01","name":"The Prancing Pony","subtype":"inn","owner":"8a1d4e6f-2c3b-4d5e-9f7a-1b2c3d4e5f02","staff":["8a1d4e6f-2c3b-4d5e-9f7a-1b2c3d4e5f02","8a1d4e6f-2c3b-4d5e-9f7a-1b2c3d4e5f03"]},{"type":"Npc","uuid":"8a1d4e6f-2c3b-4d5e-9f7a-1b2c3d4e5f02","name":"Barliman","gender":"masculine","age":"adult","age_years":50,"species":"human","ethnicity":"human"},{"type":"Npc","uuid":"8a1d4e6f-2c3b-4d5e-9f7a-1b2c3d4e5f03","name":"Nob","gender":"masculine","age":"adult","age_years":30,"species":"halfling","ethnicity":"halfling"}],"keyValue":{"time":null}}"#).unwrap()
+    };
+
+    let mut app = sync_app();
+    app.bulk_import(backup()).unwrap();
+    app.command("import --rename").unwrap();
+
+    assert_eq!(
+        "Places: 1 created \\\nCharacters: 2 created",
+        app.bulk_import(backup()).unwrap(),
+    );
+
+    // The renamed place is run by the renamed characters.
+    let output = app.command("The Prancing Pony (2)").unwrap();
+    assert!(
+        output.contains("**Owner:** `Barliman (2)`\\\n**Staff:** `Barliman (2)`, `Nob (2)`"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("The Prancing Pony").unwrap();
+    assert!(
+        output.contains("**Owner:** `Barliman`\\\n**Staff:** `Barliman`, `Nob`"),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn import_skip() {
+    let mut app = sync_app();
+    app.command("npc named Faman Halin").unwrap();
+    app.command("import --skip").unwrap();
+
+    assert_eq!(
+        "Places: 5 created \\\nCharacters: 4 created, 1 skipped \\\nKey/values: 1 created",
+        app.bulk_import(v2_backup()).unwrap(),
+    );
+
+    app.command("import --skip").unwrap();
+
+    assert_eq!(
+        "Places: 5 skipped \\\nCharacters: 5 skipped \\\nKey/values: 1 skipped",
+        app.bulk_import(v2_backup()).unwrap(),
+    );
+}
+
+#[test]
+fn import_dry_run() {
+    let mut app = sync_app();
+    app.command("npc named Faman Halin").unwrap();
+    let journal_before = app.command("journal").unwrap();
+    app.command("import --rename --dry-run").unwrap();
+
+    assert_eq!(
+        "Places: 5 created \\\nCharacters: 5 created \\\nKey/values: 1 created\n\n*This was a dry run, so your journal has not been changed. To import for real, use `import` without `--dry-run`.*",
+        app.bulk_import(v2_backup()).unwrap(),
+    );
+    assert_eq!(journal_before, app.command("journal").unwrap());
+    assert_eq!(
+        "It is currently day 1 at 8:00:00 am.",
+        app.command("time").unwrap(),
+    );
+
+    // The options only apply to a single import.
+    assert_eq!(
+        "Places: 5 created \\\nCharacters: 4 created, 1 skipped \\\nKey/values: 1 created",
+        app.bulk_import(v2_backup()).unwrap(),
+    );
+}
+
+#[test]
+fn import_csv() {
+    let mut app = sync_app();
+    app.command("inn named Prancing Pony").unwrap();
+    app.command("import --skip --dry-run").unwrap();
+
+    let csv = "name,gender,species,location\nEllis Vance,female,human,prancing pony\nMira Vance,,elf,Ironhold";
+
+    assert_eq!(
+        "Characters: 1 created, 1 failed\n\n* Row 3: there is no place named \"Ironhold\".\n\n*This was a dry run, so your journal has not been changed. To import for real, use `import` without `--dry-run`.*",
+        app.bulk_import_csv(csv).unwrap(),
+    );
+    assert!(app.command("load Ellis Vance").is_err());
+
+    assert_eq!(
+        "Characters: 1 created, 1 failed\n\n* Row 3: there is no place named \"Ironhold\".",
+        app.bulk_import_csv(csv).unwrap(),
+    );
+
+    let output = app.command("Ellis Vance").unwrap();
+    assert!(
+        output.contains("**Location:** 🏨 `Prancing Pony` (inn)"),
+        "{}",
+        output
+    );
+
+    assert_eq!(
+        Err("The CSV file must have a `name` column.".to_string()),
+        app.bulk_import_csv("gender\nfemale"),
+    );
+}
+
+fn v2_backup() -> BackupData {
+    serde_json::from_str(include_str!("v2.json")).unwrap()
+}
+
+#[test]
+fn export_filtered() {
+    thread_local! {
+        static EXPORTED: RefCell<Option<BackupData>> = const { RefCell::new(None) };
+    }
+
+    fn export_dispatcher(event: Event) {
+        if let Event::Export(ExportFile::Backup(data)) = event {
+            EXPORTED.with(|exported| exported.replace(Some(data)));
+        }
+    }
+
+    let mut app = sync_app_with_dispatcher(&export_dispatcher);
+    app.bulk_import(v2_backup()).unwrap();
+
+    let mut exported_names = |command: &str| -> Result<Vec<String>, String> {
+        app.command(command)?;
+
+        let data = EXPORTED.with(|exported| exported.take()).unwrap();
+        assert_eq!(None, data.key_value.time);
+
+        let mut names: Vec<String> = data
+            .things
+            .iter()
+            .map(|thing| thing.name().to_string())
+            .collect();
+        names.sort();
+        Ok(names)
+    };
+
+    assert_eq!(
+        Ok(vec![
+            "Five Millers".to_string(),
+            "Pino Nesgarth".to_string()
+        ]),
+        exported_names("export everything in Five Millers"),
+    );
+
+    assert_eq!(
+        Ok(vec![
+            "Book and Soldier".to_string(),
+            "Halynn Mardeka".to_string(),
+            "Myrcia Haskyr".to_string(),
+            "Raven and Fisherman".to_string(),
+        ]),
+        exported_names("export npcs named *y*a*"),
+    );
+
+    assert_eq!(
+        Err("There is no place named \"Thornwick\" in your journal.".to_string()),
+        exported_names("export everything in Thornwick"),
+    );
+
+    assert_eq!(
+        Err("Nothing in your journal matches `places named *Vance`.".to_string()),
+        exported_names("export places named *Vance"),
+    );
+}
+
+#[test]
+fn export_handbook() {
+    thread_local! {
+        static EXPORTED: RefCell<Option<ExportFile>> = const { RefCell::new(None) };
+    }
+
+    fn export_dispatcher(event: Event) {
+        if let Event::Export(file) = event {
+            EXPORTED.with(|exported| exported.replace(Some(file)));
+        }
+    }
+
+    let mut app = sync_app_with_dispatcher(&export_dispatcher);
+    app.bulk_import(v2_backup()).unwrap();
+
+    assert_eq!(
+        "The handbook is exporting. Your download should begin shortly.",
+        app.command("export markdown").unwrap(),
+    );
+
+    let file = EXPORTED.with(|exported| exported.take()).unwrap();
+    assert_eq!("initiative_handbook.md", file.file_name());
+
+    let markdown = file.contents();
+    assert!(markdown.starts_with("# Campaign Handbook\n\n## Contents\n"));
+    assert!(
+        markdown.contains(
+            "\n* [Five Millers](#five-millers) (inn)\n  * [Pino Nesgarth](#pino-nesgarth) ("
+        ),
+        "{}",
+        markdown,
+    );
+    assert!(
+        markdown.contains("[Five Millers](#five-millers)")
+            && markdown.contains("<a id=\"pino-nesgarth\"></a>"),
+        "{}",
+        markdown,
+    );
+
+    app.command("export html").unwrap();
+
+    let file = EXPORTED.with(|exported| exported.take()).unwrap();
+    assert_eq!("text/html", file.mime_type());
+
+    let html = file.contents();
+    assert!(html.starts_with("<!DOCTYPE html>"), "{}", html);
+    assert!(
+        html.contains("<title>Campaign Handbook</title>"),
+        "{}",
+        html
+    );
+}
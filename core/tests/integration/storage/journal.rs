@@ -48,7 +48,7 @@ fn it_shows_alphabetized_results() {
     assert_eq!(10, npcs.len());
     npcs.sort_by(|a, b| a[6..].cmp(&b[6..]));
 
-    app.command("inn").unwrap();
+    app.command("temple").unwrap();
 
     let inn_list = app.command("more").unwrap();
     println!("{}", inn_list);
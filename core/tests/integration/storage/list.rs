@@ -0,0 +1,63 @@
+use crate::common::{sync_app, SyncApp};
+
+#[test]
+fn it_lists_matching_npcs() {
+    let mut app = app_with_journal();
+
+    assert_eq!(
+        "# Journal: elves in Thornwick\n\n## NPCs\n🧑 `Mira Vance` (elf)",
+        app.command("list all elves in Thornwick").unwrap(),
+    );
+    assert_eq!(
+        "# Journal: dwarvish npcs in Ironhold\n\n## NPCs\n🧑 `Borin Stonefist` (dwarf)",
+        app.command("list dwarvish characters in Ironhold").unwrap(),
+    );
+    assert_eq!(
+        "# Journal: npcs\n\n## NPCs\n🧑 `Borin Stonefist` (dwarf)\\\n🧑 `Ellis Vance` (dwarf)\\\n🧑 `Mira Vance` (elf)",
+        app.command("journal npcs").unwrap(),
+    );
+}
+
+#[test]
+fn it_lists_matching_places() {
+    let mut app = app_with_journal();
+
+    assert_eq!(
+        "# Journal: inns\n\n## Places\n🏨 `Prancing Pony` (inn)",
+        app.command("journal inns").unwrap(),
+    );
+    assert_eq!(
+        "# Journal: places in the Duchy of Vell\n\n## Places\n🏨 `Prancing Pony` (inn)\\\n🏘 `Thornwick` (town)",
+        app.command("list places in the Duchy of Vell").unwrap(),
+    );
+}
+
+#[test]
+fn it_shows_an_error_without_results() {
+    let mut app = app_with_journal();
+
+    assert_eq!(
+        Err("Nothing in your journal matches `halflings`.".to_string()),
+        app.command("list halflings"),
+    );
+    assert_eq!(
+        Err("There is no place named \"Waterdeep\" in your journal.".to_string()),
+        app.command("list npcs in Waterdeep"),
+    );
+}
+
+fn app_with_journal() -> SyncApp {
+    let mut app = sync_app();
+    app.bulk_import_csv(
+        "type,name,species,ethnicity,location,subtype
+place,Duchy of Vell,,,,duchy
+place,Thornwick,,,Duchy of Vell,town
+place,Prancing Pony,,,Thornwick,inn
+place,Ironhold,,,,town
+npc,Ellis Vance,dwarf,human,Thornwick,
+npc,Mira Vance,elf,elvish,Prancing Pony,
+npc,Borin Stonefist,dwarf,dwarvish,Ironhold,",
+    )
+    .unwrap();
+    app
+}
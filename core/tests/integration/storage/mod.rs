@@ -1,7 +1,10 @@
+mod campaign;
 mod change;
 mod export_import;
 mod journal;
+mod list;
 mod load;
+mod search;
 mod undo_redo;
 
 use crate::common::SyncApp;
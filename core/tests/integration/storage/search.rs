@@ -0,0 +1,50 @@
+use crate::common::{sync_app, SyncApp};
+
+#[test]
+fn it_ranks_results() {
+    let mut app = app_with_journal();
+
+    assert_eq!(
+        "# Search results for \"vance\"\n\n🧑 `Ellis Vance` (dwarf)\\\n🧑 `Mira Vance` (human)",
+        app.command("search vance").unwrap(),
+    );
+    assert_eq!(
+        "# Search results for \"pony\"\n\n🏨 `Prancing Pony` (inn)\\\n🧑 `Mira Vance` (human)",
+        app.command("search pony").unwrap(),
+    );
+}
+
+#[test]
+fn it_searches_related_fields() {
+    let mut app = app_with_journal();
+
+    assert_eq!(
+        "# Search results for \"dwarf thornwick\"\n\n🧑 `Ellis Vance` (dwarf)",
+        app.command("search dwarf thornwick").unwrap(),
+    );
+    assert_eq!(
+        "# Search results for \"QUIET\"\n\n🏘 `Thornwick` (town)",
+        app.command("search QUIET").unwrap(),
+    );
+}
+
+#[test]
+fn it_shows_an_error_without_results() {
+    assert_eq!(
+        Err("Nothing in your journal matches \"potato\".".to_string()),
+        app_with_journal().command("search potato"),
+    );
+}
+
+fn app_with_journal() -> SyncApp {
+    let mut app = sync_app();
+    app.bulk_import_csv(
+        "type,name,species,location,subtype,description
+place,Thornwick,,,town,A quiet town
+place,Prancing Pony,,Thornwick,inn,
+npc,Ellis Vance,dwarf,Thornwick,,
+npc,Mira Vance,human,Prancing Pony,,",
+    )
+    .unwrap();
+    app
+}
@@ -1,5 +1,6 @@
-use crate::common::{sync_app, SyncApp};
+use crate::common::{sync_app, sync_app_with_data_store, SyncApp};
 use initiative_core::app::AutocompleteSuggestion;
+use initiative_core::MemoryDataStore;
 
 fn undo_redo_test(
     app: &mut SyncApp,
@@ -141,3 +142,55 @@ fn set_key_value() {
     assert_eq!(a, c);
     assert_eq!(b, d);
 }
+
+#[test]
+fn survives_restart() {
+    let data_store = MemoryDataStore::default();
+
+    {
+        let mut app = sync_app_with_data_store(data_store.clone());
+        app.command("human named Potato Johnson").unwrap();
+        app.command("Potato Johnson is an elf").unwrap();
+        app.command("+1d").unwrap();
+        app.command("undo").unwrap();
+    }
+
+    let mut app = sync_app_with_data_store(data_store);
+
+    assert_eq!(
+        "# History
+
+## Changes
+
+* editing Potato Johnson
+* creating Potato Johnson
+
+## Undone changes
+
+* changing the time
+
+*Most recent changes are listed first. Use `undo` and `redo` to step backward and forward through your history.*",
+        app.command("history").unwrap(),
+    );
+
+    assert_eq!(
+        "Successfully redid changing the time. Use `undo` to reverse this.",
+        app.command("redo").unwrap(),
+    );
+
+    let output = app.command("undo").unwrap();
+    assert_eq!(
+        "Successfully undid changing the time. Use `redo` to reverse this.",
+        output,
+    );
+
+    let output = app.command("undo").unwrap();
+    assert!(output.contains("**Species:** human"), "{}", output);
+
+    let output = app.command("undo").unwrap();
+    assert_eq!(
+        "Successfully undid creating Potato Johnson. Use `redo` to reverse this.",
+        output,
+    );
+    assert!(app.command("Potato Johnson").is_err());
+}
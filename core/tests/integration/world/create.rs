@@ -49,9 +49,9 @@ fn create_npc_with_custom_attributes() {
     let mut app = sync_app();
 
     {
-        let output = app.command("Sue, a young enby dwarvish elf").unwrap();
+        let output = app.command("Sue, a young enby dwarvish elf bard").unwrap();
         assert!(
-            output.contains("# Sue\n*young adult elf, they/them*"),
+            output.contains("# Sue\n*young adult elf bard, they/them*"),
             "{}",
             output,
         );
@@ -72,7 +72,7 @@ fn create_npc_with_custom_attributes() {
     {
         let output = app.command("a boy named sue").unwrap_err();
         assert_eq!(
-            "That name is already in use by 🧑 `Sue` (young adult elf, they/them).",
+            "That name is already in use by 🧑 `Sue` (young adult elf bard, they/them).",
             output,
         );
     }
@@ -109,7 +109,8 @@ fn create_place_with_custom_attributes() {
 
     {
         let output = app.command("an inn called The Prancing Pony").unwrap();
-        assert!(output.contains("# The Prancing Pony\n*inn*"), "{}", output);
+        assert!(output.contains("# The Prancing Pony\n*"), "{}", output);
+        assert!(output.contains(" inn*\n"), "{}", output);
         assert!(
             output.contains("has been automatically added to your `journal`."),
             "{}",
@@ -159,13 +160,14 @@ fn create_with_unknown_words() {
 }
 
 #[test]
-fn generate_location_with_no_name_generator() {
+fn generate_region_with_name() {
     let mut app = sync_app();
 
-    assert_eq!(
-        "There is no name generator implemented for that type. You must specify your own name using `kingdom named [name]`.",
-        app.command("kingdom").unwrap_err(),
-    );
+    {
+        let output = app.command("kingdom").unwrap();
+        assert!(output.contains("\n# "), "{}", output);
+        assert!(output.contains("*kingdom*"), "{}", output);
+    }
 
     {
         let output = app.command("kingdom named Narnia").unwrap();
@@ -177,3 +179,25 @@ fn generate_location_with_no_name_generator() {
         assert!(output.contains("# Narnia"), "{}", output);
     }
 }
+
+#[test]
+fn npc_with_stat_block() {
+    let output = sync_app().command("a dwarf veteran").unwrap();
+
+    assert!(output.contains("**Occupation:** soldier"), "{}", output,);
+    assert!(
+        output.contains("\n\n**Stats:** veteran (CR 3, 700 XP)\\\n**Armor Class:** 17 (splint)\\\n**Hit Points:** 76 (`9d8 + 36`)\\\n**Speed:** 25 ft.\\\n"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.contains("\n| 16 (+3) | 13 (+1) | 16 (+3) | 10 (+0) | 12 (+1) | 10 (+0) |\n"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.contains("**Longsword:** +5 to hit, `1d8 + 3` slashing damage"),
+        "{}",
+        output,
+    );
+}
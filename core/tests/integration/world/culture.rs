@@ -0,0 +1,165 @@
+use crate::common::{sync_app, sync_app_with_data_store};
+use initiative_core::{DataStore, MemoryDataStore};
+use tokio_test::block_on;
+
+const CULTURES: &str = r#"[
+    {
+        "name": "vesk",
+        "species": "dragonborn",
+        "population": 1000000000,
+        "language": "giant",
+        "names": {
+            "first": { "start": ["Ka", "Vo"], "end": ["rix", "thrax"] },
+            "last": { "syllables": [3], "start": ["Dun"], "middle": ["ma"], "end": ["vek"] }
+        },
+        "genders": { "feminine": 0, "masculine": 0, "non_binary": 1 },
+        "ages": { "young_adult": 12, "adult": 15, "middle_aged": 20, "elderly": 25, "geriatric": 28, "lifespan": 30 },
+        "sizes": { "height": [84, 90], "weight": [300, 350] }
+    }
+]"#;
+
+#[test]
+fn custom_culture_is_generated() {
+    let mut app = sync_app();
+    assert_eq!(Ok(1), app.load_cultures(CULTURES));
+
+    let output = app.command("an adult vesk").unwrap();
+    assert!(
+        output.contains("**Species:** dragonborn (vesk)"),
+        "{}",
+        output
+    );
+    assert!(output.contains("Dunmavek"), "{}", output);
+    assert!(output.contains("**Gender:** non-binary"), "{}", output);
+    assert!(
+        output.contains("**Languages:** `srd language Common`, `srd language Giant`"),
+        "{}",
+        output
+    );
+
+    let output = app.command("an elderly vesk").unwrap();
+    assert!(output.contains("**Age:** 2"), "{}", output);
+    assert!(output.contains("elderly dragonborn"), "{}", output);
+}
+
+#[test]
+fn custom_culture_is_part_of_demographics() {
+    let mut app = sync_app();
+    app.load_cultures(CULTURES).unwrap();
+
+    let output = app.command("npc").unwrap();
+    assert!(output.contains("(vesk)"), "{}", output);
+}
+
+#[test]
+fn custom_culture_from_data_store() {
+    let mut data_store = MemoryDataStore::default();
+    block_on(data_store.set_value("cultures", CULTURES)).unwrap();
+    let mut app = sync_app_with_data_store(data_store);
+
+    let output = app.command("a vesk").unwrap();
+    assert!(
+        output.contains("**Species:** dragonborn (vesk)"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn custom_culture_autocomplete() {
+    let mut app = sync_app();
+    app.load_cultures(CULTURES).unwrap();
+
+    assert!(
+        app.autocomplete("ves")
+            .iter()
+            .any(|suggestion| suggestion.term == "vesk"),
+        "{:?}",
+        app.autocomplete("ves"),
+    );
+    assert!(
+        app.autocomplete("an elderly ves")
+            .iter()
+            .any(|suggestion| suggestion.term == "an elderly vesk"),
+        "{:?}",
+        app.autocomplete("an elderly ves"),
+    );
+}
+
+#[test]
+fn invalid_cultures_are_rejected() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        Err(
+            "Invalid culture \"vesk\": names must have at least one start and end syllable"
+                .to_string()
+        ),
+        app.load_cultures(r#"[{"name": "vesk", "names": {"first": {"start": [], "end": ["x"]}}}]"#),
+    );
+    assert!(app.load_cultures("potato").is_err());
+    assert!(app.command("a vesk").is_err());
+}
+
+#[test]
+fn custom_culture_from_backup() {
+    let mut app = sync_app();
+    let backup_data = serde_json::from_str(&format!(
+        r#"{{"version": 3, "things": [], "keyValue": {{"time": null, "cultures": {}}}}}"#,
+        CULTURES,
+    ))
+    .unwrap();
+
+    assert_eq!(
+        "Key/values: 1 created",
+        app.bulk_import(backup_data).unwrap(),
+    );
+
+    let output = app.command("a vesk").unwrap();
+    assert!(
+        output.contains("**Species:** dragonborn (vesk)"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn invalid_culture_from_backup() {
+    let mut app = sync_app();
+    let backup_data = serde_json::from_str(
+        r#"{"version": 3, "things": [], "keyValue": {"time": null, "cultures": [{"name": "vesk", "names": {"first": {"start": [], "end": ["x"]}}}]}}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        "Key/values: 1 failed\n\n* Invalid culture \"vesk\": names must have at least one start and end syllable",
+        app.bulk_import(backup_data).unwrap(),
+    );
+    assert!(app.command("a vesk").is_err());
+}
+
+#[test]
+fn custom_culture_from_backup_is_per_campaign() {
+    let mut app = sync_app();
+    let backup_data = serde_json::from_str(&format!(
+        r#"{{"version": 3, "things": [], "keyValue": {{"time": null, "cultures": {}}}}}"#,
+        CULTURES,
+    ))
+    .unwrap();
+    app.bulk_import(backup_data).unwrap();
+
+    app.command("campaign new Curse of Strahd").unwrap();
+    assert!(app.command("a vesk").is_err());
+
+    app.command("campaign switch default").unwrap();
+    assert!(app.command("a vesk").is_ok());
+}
+
+#[test]
+fn custom_culture_from_file_is_kept_across_campaigns() {
+    let mut app = sync_app();
+    app.load_cultures(CULTURES).unwrap();
+
+    app.command("campaign new Curse of Strahd").unwrap();
+    assert!(app.command("a vesk").is_ok());
+}
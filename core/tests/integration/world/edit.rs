@@ -1,4 +1,4 @@
-use crate::common::sync_app;
+use crate::common::{get_name, sync_app};
 
 #[test]
 fn edit_npc() {
@@ -46,6 +46,39 @@ fn edit_npc() {
     app.command("Joe").unwrap();
 }
 
+#[test]
+fn edit_npc_size() {
+    let mut app = sync_app();
+
+    app.command("Mira, an adult human").unwrap();
+
+    let output = app.command("Mira is 5'4\"").unwrap();
+    assert!(output.contains("**Size:** 5'4\", "), "{}", output);
+    assert!(!output.contains("didn't understand"), "{}", output);
+
+    let output = app.command("Mira is 60kg").unwrap();
+    assert!(output.contains("**Size:** 5'4\", 132 lbs"), "{}", output);
+
+    // The size category follows the height.
+    let output = app.command("Mira is 3'").unwrap();
+    assert!(
+        output.contains("**Size:** 3'0\", 132 lbs (small)"),
+        "{}",
+        output,
+    );
+
+    // Locked values survive regeneration, but everything else is consistent with them.
+    let output = app.command("create a 5'4\" dwarf named Tordek").unwrap();
+    assert!(output.contains("**Size:** 5'4\", "), "{}", output);
+
+    let output = app.command("create a dwarf child named Dain").unwrap();
+    assert!(
+        output.contains("**Size:** 2'") || output.contains("**Size:** 3'"),
+        "{}",
+        output
+    );
+}
+
 #[test]
 fn edit_place() {
     let mut app = sync_app();
@@ -168,3 +201,99 @@ fn edit_with_unknown_words() {
         output,
     );
 }
+
+#[test]
+fn edit_npc_languages() {
+    let mut app = sync_app();
+
+    let output = app.command("Mira, a dwarvish woman").unwrap();
+    assert!(
+        output.contains("\\\n**Languages:** `srd language Common`, `srd language Dwarvish`"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("Mira speaks Sylvan and deep speech").unwrap();
+    assert!(
+        output.contains("\\\n**Languages:** `srd language Common`, `srd language Dwarvish`")
+            && output.contains(", `srd language Sylvan`, `srd language Deep Speech`\n"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with("_Mira was successfully edited. Use `undo` to reverse this._"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("srd language Deep Speech").unwrap();
+    assert!(output.starts_with("# Deep Speech\n"), "{}", output);
+
+    assert_eq!(
+        Err(r#"There is no character named "Nobody"."#.to_string()),
+        app.command("Nobody speaks Elvish"),
+    );
+}
+
+#[test]
+fn edit_place_details() {
+    let mut app = sync_app();
+
+    let output = app
+        .command("a wealthy marble temple dedicated to Pelor called The Dawn Hall")
+        .unwrap();
+    assert!(
+        output.contains("*wealthy marble temple*")
+            && output.contains("**Quality:** wealthy")
+            && output.contains("**Worship:** Pelor"),
+        "{}",
+        output,
+    );
+
+    app.command("The Prancing Pony, a modest inn").unwrap();
+
+    let output = app.command("The Prancing Pony is expensive").unwrap();
+    assert!(output.contains("**Prices:** expensive"), "{}", output);
+    assert!(
+        output
+            .ends_with("_The Prancing Pony was successfully edited. Use `undo` to reverse this._"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("The Prancing Pony offers hot baths").unwrap();
+    assert!(output.contains("**Services:** hot baths"), "{}", output);
+
+    let output = app.command("undo").unwrap();
+    assert!(!output.contains("**Services:** hot baths"), "{}", output);
+}
+
+#[test]
+fn edit_place_staff() {
+    let mut app = sync_app();
+
+    app.command("The Prancing Pony, an inn").unwrap();
+    app.command("Mira, a human woman").unwrap();
+    app.command("Hilda, a dwarf woman").unwrap();
+
+    let output = app.command("Mira owns The Prancing Pony").unwrap();
+    assert!(output.contains("**Owner:** `Mira`"), "{}", output);
+
+    let output = app.command("Hilda works at The Prancing Pony").unwrap();
+    assert!(
+        output.contains("**Owner:** `Mira`") && output.contains("**Staff:** `Hilda`"),
+        "{}",
+        output,
+    );
+
+    let name = get_name(&app.command("npc").unwrap());
+    let error = app
+        .command(&format!("{} works at The Prancing Pony", name))
+        .unwrap_err();
+    assert!(
+        error.starts_with(&format!("{} must be in your journal before", name))
+            && error.ends_with(&format!("Use `save {}` first.", name)),
+        "{}",
+        error,
+    );
+}
@@ -0,0 +1,95 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn relatives_are_linked() {
+    let mut app = sync_app();
+    app.command("Vance, a human man").unwrap();
+
+    let daughter_output = app.command("Vance's daughter").unwrap();
+    let daughter = get_name(&daughter_output);
+    assert!(
+        daughter_output.contains("human") && daughter_output.contains("she/her"),
+        "{}",
+        daughter_output,
+    );
+    assert!(
+        daughter_output.contains("\\\n**Parents:** `Vance`"),
+        "{}",
+        daughter_output,
+    );
+
+    app.command(&format!("save {}", daughter)).unwrap();
+
+    let sibling_output = app
+        .command(&format!("generate a sibling for {}", daughter))
+        .unwrap();
+    let sibling = get_name(&sibling_output);
+    assert!(
+        sibling_output.contains("\\\n**Parents:** `Vance`\\\n**Siblings:** ")
+            && sibling_output.contains(&format!("`{}`", daughter)),
+        "{}",
+        sibling_output,
+    );
+
+    let vance_output = app.command("Vance").unwrap();
+    assert!(
+        vance_output.contains(&format!("\\\n**Children:** `{}`, `{}`", daughter, sibling)),
+        "{}",
+        vance_output,
+    );
+
+    let family_output = app.command("family of Vance").unwrap();
+    assert!(
+        family_output.starts_with("# Family of Vance\n\n## Children\n"),
+        "{}",
+        family_output,
+    );
+    assert!(family_output.contains(&daughter), "{}", family_output);
+    assert!(family_output.contains(&sibling), "{}", family_output);
+    assert_eq!(Ok(family_output), app.command("Vance's family"));
+}
+
+#[test]
+fn deleting_a_relative_removes_links() {
+    let mut app = sync_app();
+    app.command("Vance, a human man").unwrap();
+
+    let son = get_name(&app.command("Vance's son").unwrap());
+    app.command(&format!("save {}", son)).unwrap();
+
+    app.command("delete Vance").unwrap();
+    let son_output = app.command(&format!("load {}", son)).unwrap();
+    assert!(!son_output.contains("**Parents:**"), "{}", son_output);
+
+    app.command("undo").unwrap();
+    let son_output = app.command(&format!("load {}", son)).unwrap();
+    assert!(
+        son_output.contains("\\\n**Parents:** `Vance`"),
+        "{}",
+        son_output,
+    );
+}
+
+#[test]
+fn relatives_require_a_saved_adult() {
+    let mut app = sync_app();
+
+    let name = get_name(&app.command("human").unwrap());
+    assert_eq!(
+        Err(format!(
+            "{name} must be in your journal before you can add relatives. Use `save {name}` first.",
+            name = name,
+        )),
+        app.command(&format!("{}'s son", name)),
+    );
+
+    app.command("Timmy, a 5-year-old human").unwrap();
+    assert_eq!(
+        Err("Timmy is too young to have a son.".to_string()),
+        app.command("Timmy's son"),
+    );
+    assert_eq!(
+        Err("Timmy has no known relatives. You can create one with `Timmy's daughter` or `generate a sibling for Timmy`.".to_string()),
+        app.command("family of Timmy"),
+    );
+}
@@ -0,0 +1,233 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn inn_has_rooms_menu_and_rumors() {
+    let mut app = sync_app();
+
+    let output = app.command("The Prancing Pony, an inn").unwrap();
+    assert!(output.contains("\n**Rooms:** "), "{}", output);
+    assert!(output.contains("\n**Menu:** "), "{}", output);
+    assert!(output.contains("\n\n**Rumors:**\n* "), "{}", output);
+
+    let output = app.command("The Green Dragon, a bar").unwrap();
+    assert!(!output.contains("**Rooms:**"), "{}", output);
+    assert!(output.contains("**Menu:** "), "{}", output);
+    assert!(output.contains("**Rumors:**"), "{}", output);
+
+    let output = app.command("The Oven, a bakery").unwrap();
+    assert!(!output.contains("**Menu:**"), "{}", output);
+}
+
+#[test]
+fn edit_inn_menu() {
+    let mut app = sync_app();
+
+    let rooms = app
+        .command("The Prancing Pony, an inn")
+        .unwrap()
+        .lines()
+        .find(|line| line.starts_with("**Rooms:** "))
+        .unwrap()
+        .to_string();
+
+    let output = app
+        .command("The Prancing Pony serves roast boar and ale")
+        .unwrap();
+    assert!(
+        output.contains("\n**Menu:** roast boar and ale\n"),
+        "{}",
+        output,
+    );
+    assert!(output.contains(&rooms), "{}", output);
+
+    let output = app.command("undo").unwrap();
+    assert!(
+        !output.contains("**Menu:** roast boar and ale"),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn named_inn_comes_with_patrons() {
+    let mut app = sync_app();
+
+    let output = app.command("The Prancing Pony, an inn").unwrap();
+    let patrons = patron_names(&output);
+    assert!((2..=4).contains(&patrons.len()), "{}", output);
+    assert!(
+        output.ends_with("_Because you specified a name, The Prancing Pony has been automatically added to your `journal`. Use `undo` to remove it._"),
+        "{}",
+        output,
+    );
+
+    let patron_output = app.command(&format!("load {}", patrons[0])).unwrap();
+    assert!(
+        patron_output.contains("**Location:** 🏨 `The Prancing Pony`"),
+        "{}",
+        patron_output,
+    );
+
+    assert_eq!(
+        Ok(
+            "Successfully undid creating The Prancing Pony. Use `redo` to reverse this."
+                .to_string()
+        ),
+        app.command("undo"),
+    );
+    assert_eq!(
+        "# Journal\n\n*Your journal is currently empty.*",
+        app.command("journal").unwrap(),
+    );
+}
+
+#[test]
+fn saved_inn_comes_with_patrons() {
+    let mut app = sync_app();
+
+    let inn = get_name(&app.command("inn").unwrap());
+    let output = app.command(&format!("save {}", inn)).unwrap();
+    assert!(
+        output.starts_with(&format!(
+            "{} was successfully saved. Use `undo` to reverse this.\n\n## Patrons\n",
+            inn,
+        )),
+        "{}",
+        output,
+    );
+
+    let patrons = patron_names(&output);
+    assert!((2..=4).contains(&patrons.len()), "{}", output);
+
+    let inn_output = app.command(&inn).unwrap();
+    patrons.iter().for_each(|patron| {
+        assert!(
+            inn_output.contains(&format!("`{}`", patron)),
+            "{}",
+            inn_output
+        );
+    });
+
+    assert_eq!(
+        Ok(format!(
+            "Successfully undid saving {} to journal. Use `redo` to reverse this.",
+            inn,
+        )),
+        app.command("undo"),
+    );
+    assert_eq!(
+        "# Journal\n\n*Your journal is currently empty.*",
+        app.command("journal").unwrap(),
+    );
+    assert!(app.command(&inn).is_ok());
+
+    app.command("redo").unwrap();
+    let journal_output = app.command("journal").unwrap();
+    assert!(journal_output.contains(&inn), "{}", journal_output);
+    patrons.iter().for_each(|patron| {
+        assert!(journal_output.contains(patron), "{}", journal_output);
+    });
+}
+
+#[test]
+fn generate_patrons() {
+    let mut app = sync_app();
+    app.command("The Prancing Pony, an inn").unwrap();
+
+    let output = app
+        .command("generate patrons for the prancing pony")
+        .unwrap();
+    assert!(
+        output.starts_with("# Patrons of The Prancing Pony\n\n"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with(" patrons have been added to The Prancing Pony and your `journal`. Use `undo` to remove them._"),
+        "{}",
+        output,
+    );
+
+    let patrons: Vec<String> = output
+        .lines()
+        .skip(2)
+        .take_while(|line| !line.is_empty())
+        .map(|line| line.split('`').nth(1).unwrap().to_string())
+        .collect();
+    assert!((2..=4).contains(&patrons.len()), "{}", output);
+
+    let patron_output = app.command(&format!("load {}", patrons[0])).unwrap();
+    assert!(
+        patron_output.contains("**Location:** 🏨 `The Prancing Pony`"),
+        "{}",
+        patron_output,
+    );
+
+    assert_eq!(
+        Ok(
+            "Successfully undid adding patrons to The Prancing Pony. Use `redo` to reverse this."
+                .to_string()
+        ),
+        app.command("undo"),
+    );
+
+    let journal_output = app.command("journal").unwrap();
+    patrons.iter().for_each(|patron| {
+        assert!(!journal_output.contains(patron), "{}", journal_output);
+    });
+}
+
+#[test]
+fn generate_patrons_must_be_saved_inn() {
+    let mut app = sync_app();
+
+    let inn = get_name(&app.command("inn").unwrap());
+    assert_eq!(
+        Err(format!(
+            "{inn} must be in your journal before it can have patrons. Use `save {inn}` first.",
+            inn = inn,
+        )),
+        app.command(&format!("patrons at {}", inn)),
+    );
+
+    app.command("The Oven, a bakery").unwrap();
+    assert_eq!(
+        Err("Only inns and bars have patrons, and The Oven is neither.".to_string()),
+        app.command("generate patrons for The Oven"),
+    );
+}
+
+#[test]
+fn generate_patrons_autocomplete() {
+    let mut app = sync_app();
+    app.command("The Prancing Pony, an inn").unwrap();
+    app.command("The Pantry, a bakery").unwrap();
+
+    let suggestions = app.autocomplete("generate patrons for the p");
+    assert!(
+        suggestions.iter().any(|suggestion| suggestion.term
+            == "generate patrons for The Prancing Pony"
+            && suggestion.summary == "add customers"),
+        "{:?}",
+        suggestions,
+    );
+    assert!(
+        !suggestions
+            .iter()
+            .any(|suggestion| suggestion.term == "generate patrons for The Pantry"),
+        "{:?}",
+        suggestions,
+    );
+}
+
+fn patron_names(output: &str) -> Vec<String> {
+    output
+        .split("## Patrons\n")
+        .nth(1)
+        .unwrap()
+        .lines()
+        .skip(1)
+        .take_while(|line| !line.is_empty())
+        .map(|line| line.split('`').nth(1).unwrap().to_string())
+        .collect()
+}
@@ -0,0 +1,87 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn home_and_location() {
+    let mut app = sync_app();
+    app.command("Thornwick, a town").unwrap();
+    app.command("The Prancing Pony, an inn").unwrap();
+    app.command("Mira, a human woman").unwrap();
+
+    let output = app.command("Mira lives in Thornwick").unwrap();
+    assert!(
+        output.contains("\\\n**Home:** ") && output.contains("`Thornwick` (town)"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("mira is at the prancing pony").unwrap();
+    assert!(
+        output.contains("\\\n**Location:** ") && output.contains("`The Prancing Pony`"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("Thornwick").unwrap();
+    assert!(output.contains("\n\n**Residents:** `Mira`\n"), "{}", output);
+
+    // The inn was saved along with a few patrons, who are there too.
+    let output = app.command("The Prancing Pony").unwrap();
+    assert!(
+        output
+            .lines()
+            .any(|line| line.starts_with("**Occupants:** ") && line.contains("`Mira`")),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn home_must_be_saved() {
+    let mut app = sync_app();
+    let inn = get_name(&app.command("inn").unwrap());
+    app.command("Mira, a human woman").unwrap();
+
+    assert_eq!(
+        Err(format!(
+            "{inn} must be in your journal before anyone can be placed there. Use `save {inn}` first.",
+            inn = inn,
+        )),
+        app.command(&format!("Mira lives in {}", inn)),
+    );
+}
+
+#[test]
+fn occupation_suits_location() {
+    let mut app = sync_app();
+    app.command("The Forge, a smithy").unwrap();
+
+    for _ in 0..10 {
+        let output = app.command("adult human").unwrap();
+        let name = get_name(&output);
+        let occupation = output
+            .lines()
+            .find(|line| line.starts_with("**Occupation:**"))
+            .unwrap()
+            .to_string();
+
+        // Visiting a place doesn't change someone's line of work.
+        let output = app.command(&format!("{} is at The Forge", name)).unwrap();
+        assert!(output.contains(&occupation), "{}", output);
+
+        // Moving there does.
+        let output = app
+            .command(&format!("{} lives in The Forge", name))
+            .unwrap();
+        assert!(
+            ["blacksmith", "apprentice", "merchant"]
+                .iter()
+                .any(|occupation| output.contains(&format!("**Occupation:** {}", occupation))),
+            "{}",
+            output,
+        );
+    }
+
+    app.command("Mira, a human baker").unwrap();
+    let output = app.command("Mira lives in The Forge").unwrap();
+    assert!(output.contains("**Occupation:** baker"), "{}", output);
+}
@@ -1,20 +1,34 @@
 mod create;
 mod create_multiple;
+mod culture;
 mod edit;
+mod family;
+mod inn;
+mod location;
+mod populate;
+mod shop;
 
 use crate::common::{get_name, sync_app};
 
 #[test]
 fn generated_npcs_are_limited_by_species() {
     [
+        "aasimar",
         "dragonborn",
         "dwarf",
         "elf",
+        "firbolg",
+        "genasi",
         "gnome",
+        "goblin",
         "halfling",
         "half-elf",
         "half-orc",
         "human",
+        "kobold",
+        "lizardfolk",
+        "orc",
+        "tabaxi",
         "tiefling",
     ]
     .iter()
@@ -88,7 +102,15 @@ fn generated_content_is_persisted() {
     // **Species:** human\
     // **Gender:** feminine\
     // **Age:** 64 years\
-    // **Size:** 5'7", 112 lbs (medium)
+    // **Occupation:** farmer\
+    // **Size:** 5'7", 112 lbs (medium)\
+    // **Appearance:** short grey hair, brown eyes
+    //
+    // **Personality:** curious\
+    // **Ideal:** tradition\
+    // **Bond:** their family\
+    // **Flaw:** stubborn\
+    // **Motivation:** to pass on what they know
     //
     // _Sybil has not yet been saved. Use ~save~ to save her to your `journal`. For more
     // suggestions, type ~more~._
@@ -102,7 +124,12 @@ fn generated_content_is_persisted() {
         persisted_output.lines().nth(2).unwrap(),
     );
     assert_eq!(
-        12,
+        // Which details are present varies, eg. children don't have an occupation, but everything
+        // up to the "not yet saved" notice should be persisted.
+        generated_output
+            .lines()
+            .position(|line| line.starts_with('_'))
+            .unwrap(),
         generated_output
             .lines()
             .zip(persisted_output.lines())
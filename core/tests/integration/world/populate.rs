@@ -0,0 +1,169 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn populate_town() {
+    let mut app = sync_app();
+    app.command("Thornwick, a town").unwrap();
+
+    let output = app.command("populate Thornwick").unwrap();
+    assert!(
+        output.starts_with("# Thornwick\n\n## Places\n"),
+        "{}",
+        output,
+    );
+
+    // The mayor, innkeeper and priest, plus two to four patrons at the inn and at each bar.
+    let characters = output
+        .split("## Characters")
+        .nth(1)
+        .unwrap()
+        .lines()
+        .count()
+        - 3;
+    let bars = output.matches("(bar)").count();
+    assert!(
+        (5 + 2 * bars..=7 + 4 * bars).contains(&characters),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with(&format!("_Thornwick has been populated with 6 places and {} characters, all of which have been added to your `journal`. Use `undo` to remove them._", characters)),
+        "{}",
+        output,
+    );
+
+    let inn = find_name(&output, "(inn)");
+    let innkeeper = find_name(&output, " innkeeper, ");
+    let mayor = find_name(&output, " mayor, ");
+    find_name(&output, "(temple)");
+    find_name(&output, " priest, ");
+    find_name(&output, "(guardhouse)");
+
+    let innkeeper_output = app.command(&format!("load {}", innkeeper)).unwrap();
+    assert!(
+        innkeeper_output.contains(&format!("**Location:** 🏨 `{}`, 🏘 `Thornwick`", inn))
+            && innkeeper_output.contains("**Home:** 🏘 `Thornwick` (town)"),
+        "{}",
+        innkeeper_output,
+    );
+
+    let mayor_output = app.command(&format!("load {}", mayor)).unwrap();
+    assert!(
+        mayor_output.contains("**Location:** 🏘 `Thornwick` (town)"),
+        "{}",
+        mayor_output,
+    );
+
+    let journal_output = app.command("journal").unwrap();
+    assert!(journal_output.contains(&inn), "{}", journal_output);
+    assert!(journal_output.contains(&innkeeper), "{}", journal_output);
+
+    assert_eq!(
+        Ok("Successfully undid populating Thornwick. Use `redo` to reverse this.".to_string()),
+        app.command("undo"),
+    );
+
+    let journal_output = app.command("journal").unwrap();
+    assert!(journal_output.contains("`Thornwick`"), "{}", journal_output);
+    assert!(!journal_output.contains(&inn), "{}", journal_output);
+    assert!(!journal_output.contains(&innkeeper), "{}", journal_output);
+    assert!(app.command(&format!("load {}", mayor)).is_err());
+
+    app.command("redo").unwrap();
+    let innkeeper_output = app.command(&format!("load {}", innkeeper)).unwrap();
+    assert!(
+        innkeeper_output.contains(&format!("🏨 `{}`", inn)),
+        "{}",
+        innkeeper_output,
+    );
+}
+
+#[test]
+fn populate_capital_has_districts() {
+    let mut app = sync_app();
+    app.command("Lumen, a capital").unwrap();
+
+    let output = app.command("populate lumen").unwrap();
+    assert!(output.starts_with("# Lumen\n"), "{}", output);
+    assert_eq!(5, output.matches("(district)").count(), "{}", output);
+    assert_eq!(4, output.matches("(inn)").count(), "{}", output);
+    assert_eq!(4, output.matches(" innkeeper, ").count(), "{}", output);
+    assert_eq!(1, output.matches(" mayor, ").count(), "{}", output);
+    assert!(output.contains("(palace)"), "{}", output);
+}
+
+#[test]
+fn populate_camp_has_no_mayor() {
+    let mut app = sync_app();
+    app.command("Ashfall, a camp").unwrap();
+
+    let output = app.command("populate Ashfall").unwrap();
+    assert!(output.contains("(shrine)"), "{}", output);
+    assert!(!output.contains(" mayor, "), "{}", output);
+}
+
+#[test]
+fn populate_must_be_saved_settlement() {
+    let mut app = sync_app();
+
+    let town = get_name(&app.command("town").unwrap());
+    assert_eq!(
+        Err(format!(
+            "{town} must be in your journal before it can be populated. Use `save {town}` first.",
+            town = town,
+        )),
+        app.command(&format!("populate {}", town)),
+    );
+
+    app.command("The Prancing Pony, an inn").unwrap();
+    assert_eq!(
+        Err(
+            "Only settlements can be populated, and The Prancing Pony is not a settlement."
+                .to_string()
+        ),
+        app.command("populate The Prancing Pony"),
+    );
+}
+
+#[test]
+fn populate_autocomplete() {
+    let mut app = sync_app();
+    app.command("Thornwick, a town").unwrap();
+    app.command("Thistle, an inn").unwrap();
+    let unsaved = get_name(&app.command("town").unwrap());
+
+    let suggestions = app.autocomplete("populate th");
+    assert!(
+        suggestions
+            .iter()
+            .any(|suggestion| suggestion.term == "populate Thornwick"
+                && suggestion.summary == "add places and characters"),
+        "{:?}",
+        suggestions,
+    );
+    assert!(
+        !suggestions
+            .iter()
+            .any(|suggestion| suggestion.term == "populate Thistle"),
+        "{:?}",
+        suggestions,
+    );
+
+    let suggestions = app.autocomplete(&format!("populate {}", unsaved));
+    assert!(
+        !suggestions
+            .iter()
+            .any(|suggestion| suggestion.term.starts_with("populate ")),
+        "{:?}",
+        suggestions,
+    );
+}
+
+fn find_name(output: &str, description: &str) -> String {
+    output
+        .lines()
+        .find(|line| line.contains(description))
+        .and_then(|line| line.split('`').nth(1))
+        .unwrap_or_else(|| panic!("No {} in {}", description, output))
+        .to_string()
+}
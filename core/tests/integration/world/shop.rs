@@ -0,0 +1,108 @@
+use crate::common::sync_app;
+
+/// The name and quantity of the first item in a shop's stock table.
+fn first_stock(output: &str) -> (String, u16) {
+    let line = output
+        .lines()
+        .find(|line| line.starts_with("| `srd "))
+        .unwrap_or_else(|| panic!("{}", output));
+    let mut cells = line.split(" | ");
+
+    let name = cells
+        .next()
+        .unwrap()
+        .trim_start_matches("| `srd ")
+        .trim_start_matches("magic ")
+        .trim_start_matches("item ")
+        .trim_end_matches('`')
+        .to_string();
+    let quantity = cells
+        .nth(1)
+        .unwrap()
+        .trim_end_matches(" |")
+        .parse()
+        .unwrap();
+
+    (name, quantity)
+}
+
+#[test]
+fn shop_has_inventory() {
+    let mut app = sync_app();
+
+    let output = app.command("The Anvil, a weaponsmith").unwrap();
+    assert!(
+        output.contains("\n\n| Stock | Price | Quantity |\n|---|--:|--:|\n| `srd item "),
+        "{}",
+        output,
+    );
+
+    let (name, _) = first_stock(&output);
+    let output = app.command(&format!("srd item {}", name)).unwrap();
+    assert!(output.starts_with(&format!("# {}\n", name)), "{}", output);
+
+    let output = app.command("The Oven, a bakery").unwrap();
+    assert!(!output.contains("| Stock |"), "{}", output);
+}
+
+#[test]
+fn buy_from_shop() {
+    let mut app = sync_app();
+
+    let (name, quantity) = first_stock(&app.command("The Anvil, a weaponsmith").unwrap());
+
+    let output = app
+        .command(&format!("buy a {} from the anvil", name.to_lowercase()))
+        .unwrap();
+    assert!(
+        output.contains(&format!("_{} was bought from The Anvil for ", name))
+            && output.ends_with(". Use `undo` to reverse this._"),
+        "{}",
+        output,
+    );
+
+    if quantity > 1 {
+        assert_eq!((name.clone(), quantity - 1), first_stock(&output));
+    } else {
+        assert!(!output.contains(&format!(" {}`", name)), "{}", output);
+    }
+
+    let output = app.command("undo").unwrap();
+    assert_eq!((name, quantity), first_stock(&output));
+
+    assert_eq!(
+        Err("The Anvil doesn't have any Flux Capacitor in stock.".to_string()),
+        app.command("buy a Flux Capacitor from The Anvil"),
+    );
+
+    app.command("The Oven, a bakery").unwrap();
+    assert_eq!(
+        Err("The Oven doesn't have anything for sale.".to_string()),
+        app.command("buy a Longsword from The Oven"),
+    );
+}
+
+#[test]
+fn restock_shop() {
+    let mut app = sync_app();
+
+    app.command("The Anvil, a weaponsmith").unwrap();
+
+    let output = app.command("restock the anvil").unwrap();
+    assert!(
+        output.contains("| Stock | Price | Quantity |"),
+        "{}",
+        output
+    );
+    assert!(
+        output.ends_with("_The Anvil has been restocked. Use `undo` to reverse this._"),
+        "{}",
+        output,
+    );
+
+    app.command("The Oven, a bakery").unwrap();
+    assert_eq!(
+        Err("The Oven doesn't have an inventory to restock.".to_string()),
+        app.command("restock The Oven"),
+    );
+}
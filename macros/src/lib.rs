@@ -57,6 +57,7 @@ pub fn uuid(input: TokenStream) -> TokenStream {
 /// * `std::convert::TryFrom<&str>` (for use by Serde, I think)
 /// * `From<T> for &'static str`
 /// * `From<T> for String`
+/// * `schemars::JsonSchema` (an enum of all recognized words, for the backup schema)
 ///
 /// Recognized attributes on enum variants are:
 ///
@@ -7,6 +7,10 @@ struct Entry {
     name: String,
     aliases: Vec<String>,
     details: String,
+    category: Option<String>,
+    cost_cp: Option<u32>,
+    rarity: Option<String>,
+    species: Vec<String>,
 }
 #[derive(Default)]
 struct EntryBuilder {
@@ -14,6 +18,10 @@ struct EntryBuilder {
     name: Option<String>,
     aliases: Vec<String>,
     details: Option<String>,
+    category: Option<String>,
+    cost_cp: Option<u32>,
+    rarity: Option<String>,
+    species: Vec<String>,
 }
 
 impl EntryBuilder {
@@ -37,12 +45,36 @@ impl EntryBuilder {
         self
     }
 
+    fn with_category(mut self, category: Option<&str>) -> Self {
+        self.category = category.map(|s| s.to_string());
+        self
+    }
+
+    fn with_cost_cp(mut self, cost_cp: u32) -> Self {
+        self.cost_cp = Some(cost_cp);
+        self
+    }
+
+    fn with_rarity(mut self, rarity: Option<&str>) -> Self {
+        self.rarity = rarity.map(|s| s.to_string());
+        self
+    }
+
+    fn with_species(mut self, species: Vec<String>) -> Self {
+        self.species = species;
+        self
+    }
+
     fn into_entry(self) -> Result<Entry, ()> {
         Ok(Entry {
             ident: self.ident.ok_or(())?,
             name: self.name.ok_or(())?,
             aliases: self.aliases,
             details: self.details.ok_or(())?,
+            category: self.category,
+            cost_cp: self.cost_cp,
+            rarity: self.rarity,
+            species: self.species,
         })
     }
 }
@@ -70,6 +102,8 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
                     .with_name(item.name())
                     .with_aliases(item.alt_name().into_iter().collect())
                     .with_details(&item.display_details())
+                    .with_category(Some(item.category_index()))
+                    .with_cost_cp(item.cost_cp())
                     .into_entry()
                     .unwrap()
             })
@@ -132,6 +166,17 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
 
             result
         }
+        "Language" => srd_5e::languages()?
+            .iter()
+            .map(|language| {
+                EntryBuilder::default()
+                    .with_ident(&language.token())
+                    .with_name(language.name.to_owned())
+                    .with_details(&language.display_details())
+                    .into_entry()
+                    .unwrap()
+            })
+            .collect(),
         "MagicItem" => srd_5e::magic_items()?
             .iter()
             .map(|item| {
@@ -139,6 +184,8 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
                     .with_ident(&item.token())
                     .with_name(item.name.to_owned())
                     .with_details(&item.display_details())
+                    .with_category(item.category_index())
+                    .with_rarity(item.rarity())
                     .into_entry()
                     .unwrap()
             })
@@ -162,6 +209,7 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
                     .with_ident(&t.token())
                     .with_name(t.name.to_owned())
                     .with_details(&t.display_details())
+                    .with_species(t.species())
                     .into_entry()
                     .unwrap()
             })
@@ -217,6 +265,92 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
         quote! {}
     };
 
+    // Shops need to know what an item is and what it's worth in order to stock it.
+    let get_stock_details = match format!("{}", ident).as_str() {
+        "Item" => {
+            let get_category_cases = entries.iter().map(|entry| {
+                let variant = &entry.ident;
+                let category = entry.category.as_deref().unwrap_or_default();
+                quote! { #ident::#variant => #category }
+            });
+
+            let get_cost_cases = entries.iter().map(|entry| {
+                let variant = &entry.ident;
+                let cost_cp = entry.cost_cp.unwrap_or_default();
+                quote! { #ident::#variant => #cost_cp }
+            });
+
+            quote! {
+                pub fn get_category(&self) -> &'static str {
+                    match self {
+                        #(#get_category_cases),*
+                    }
+                }
+
+                pub fn get_cost_cp(&self) -> u32 {
+                    match self {
+                        #(#get_cost_cases),*
+                    }
+                }
+            }
+        }
+        "MagicItem" => {
+            let get_category_cases = entries.iter().map(|entry| {
+                let variant = &entry.ident;
+                match &entry.category {
+                    Some(category) => quote! { #ident::#variant => Some(#category) },
+                    None => quote! { #ident::#variant => None },
+                }
+            });
+
+            let get_rarity_cases = entries.iter().map(|entry| {
+                let variant = &entry.ident;
+                match &entry.rarity {
+                    Some(rarity) => quote! { #ident::#variant => Some(#rarity) },
+                    None => quote! { #ident::#variant => None },
+                }
+            });
+
+            quote! {
+                pub fn get_category(&self) -> Option<&'static str> {
+                    match self {
+                        #(#get_category_cases),*
+                    }
+                }
+
+                pub fn get_rarity(&self) -> Option<&'static str> {
+                    match self {
+                        #(#get_rarity_cases),*
+                    }
+                }
+            }
+        }
+        // Characters' stat blocks list the traits of their species.
+        "Trait" => {
+            let get_species_cases = entries.iter().map(|entry| {
+                let variant = &entry.ident;
+                let species = &entry.species;
+                quote! { #ident::#variant => &[#(#species),*] }
+            });
+
+            quote! {
+                /// The SRD indexes of the species and subspecies that have the trait, eg.
+                /// `"dwarf"` or `"hill-dwarf"`.
+                pub fn get_species(&self) -> &'static [&'static str] {
+                    match self {
+                        #(#get_species_cases),*
+                    }
+                }
+            }
+        }
+        _ => quote! {},
+    };
+
+    let all_variants = entries.iter().map(|entry| {
+        let variant = &entry.ident;
+        quote! { #ident::#variant }
+    });
+
     let words = entries.iter().flat_map(|entry| {
         let name = &entry.name;
         std::iter::once(quote! { #name, })
@@ -230,12 +364,16 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
         }
 
         impl #ident {
+            pub const ALL: &'static [#ident] = &[#(#all_variants),*];
+
             pub fn get_words() -> impl Iterator<Item = &'static str> {
                 [#(#words)*].into_iter()
             }
 
             #get_list
 
+            #get_stock_details
+
             pub fn get_name(&self) -> &'static str {
                 match self {
                     #(#get_name_cases),*
@@ -38,6 +38,20 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
                 uuid.0
             }
         }
+
+        impl schemars::JsonSchema for Uuid {
+            fn is_referenceable() -> bool {
+                false
+            }
+
+            fn schema_name() -> String {
+                <uuid::Uuid as schemars::JsonSchema>::schema_name()
+            }
+
+            fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                <uuid::Uuid as schemars::JsonSchema>::json_schema(gen)
+            }
+        }
     }
     .into())
 }
@@ -150,6 +150,21 @@ fn impl_word_list(ast: &syn::DeriveInput) -> Result<TokenStream, String> {
                     variant.as_str().to_string()
                 }
             }
+
+            impl schemars::JsonSchema for #name {
+                fn schema_name() -> String {
+                    stringify!(#name).to_string()
+                }
+
+                fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                    schemars::schema::SchemaObject {
+                        instance_type: Some(schemars::schema::InstanceType::String.into()),
+                        enum_values: Some(Self::get_words().map(Into::into).collect()),
+                        ..Default::default()
+                    }
+                    .into()
+                }
+            }
         };
 
         Ok(gen.into())
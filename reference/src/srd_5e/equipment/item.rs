@@ -117,6 +117,16 @@ impl Item {
         DetailsView(self)
     }
 
+    /// The item's cost in copper pieces.
+    pub fn cost_cp(&self) -> u32 {
+        self.cost.cp()
+    }
+
+    /// The index of the item's category, eg. `adventuring-gear`.
+    pub fn category_index(&self) -> &str {
+        &self.item_category.index
+    }
+
     pub fn get_category(&self) -> String {
         if self.name == "Weapon" {
             "Weapons".to_string()
@@ -324,6 +334,20 @@ impl<'a> fmt::Display for DetailsView<'a> {
     }
 }
 
+impl ValueWithUnit {
+    fn cp(&self) -> u32 {
+        let multiplier = match self.unit.as_str() {
+            "pp" => 1000.,
+            "gp" => 100.,
+            "ep" => 50.,
+            "sp" => 10.,
+            _ => 1.,
+        };
+
+        (self.quantity * multiplier).round() as u32
+    }
+}
+
 impl fmt::Display for ArmorClass {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.base)?;
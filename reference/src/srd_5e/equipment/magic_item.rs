@@ -12,6 +12,9 @@ pub struct MagicItem {
 
     #[serde(default)]
     variants: Vec<Reference>,
+
+    #[serde(rename = "equipment_category")]
+    item_category: Option<Reference>,
 }
 
 impl MagicItem {
@@ -30,6 +33,34 @@ impl MagicItem {
     pub fn has_variants(&self) -> bool {
         !self.variants.is_empty()
     }
+
+    /// The index of the item's category, eg. `wondrous-items`.
+    pub fn category_index(&self) -> Option<&str> {
+        self.item_category
+            .as_ref()
+            .map(|reference| reference.index.as_str())
+    }
+
+    /// The item's rarity as given in the first line of its description, eg. "very rare". Items
+    /// whose rarity varies have none.
+    pub fn rarity(&self) -> Option<&str> {
+        let line = self.desc.first()?;
+        let line = line
+            .split_once(" (requires")
+            .map_or(line.as_str(), |(s, _)| s);
+        let (_, rarity) = line.rsplit_once(", ")?;
+
+        [
+            "common",
+            "uncommon",
+            "rare",
+            "very rare",
+            "legendary",
+            "artifact",
+        ]
+        .into_iter()
+        .find(|&known| known == rarity)
+    }
 }
 
 pub struct SummaryView<'a>(&'a MagicItem);
@@ -0,0 +1,69 @@
+use serde::Deserialize;
+use std::fmt;
+
+#[derive(Debug, Deserialize)]
+pub struct Language {
+    index: String,
+    pub name: String,
+
+    #[serde(rename = "type")]
+    language_type: String,
+
+    typical_speakers: Vec<String>,
+
+    #[serde(default)]
+    script: Option<String>,
+}
+
+pub struct SummaryView<'a>(&'a Language);
+
+pub struct DetailsView<'a>(&'a Language);
+
+impl Language {
+    pub fn token(&self) -> String {
+        crate::to_camel_case(&self.index)
+    }
+
+    pub fn display_summary(&self) -> SummaryView<'_> {
+        SummaryView(self)
+    }
+
+    pub fn display_details(&self) -> DetailsView<'_> {
+        DetailsView(self)
+    }
+}
+
+impl<'a> fmt::Display for SummaryView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let language = self.0;
+        write!(f, "`{}`", language.name)
+    }
+}
+
+impl<'a> fmt::Display for DetailsView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let language = self.0;
+
+        write!(
+            f,
+            "# {}\n*{} language*",
+            language.name, language.language_type,
+        )?;
+
+        {
+            let mut speakers_iter = language.typical_speakers.iter();
+            if let Some(speakers) = speakers_iter.next() {
+                write!(f, "\n\n**Typical Speakers:** {}", speakers)?;
+            }
+            for speakers in speakers_iter {
+                write!(f, ", {}", speakers)?;
+            }
+        }
+
+        if let Some(script) = &language.script {
+            write!(f, "\\\n**Script:** {}", script)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,13 @@
 pub use conditions::Condition;
 pub use equipment::{Item, ItemCategory, MagicItem};
+pub use languages::Language;
 pub use spell::Spell;
 pub use std::fmt;
 pub use traits::Trait;
 
 mod conditions;
 mod equipment;
+mod languages;
 mod spell;
 mod traits;
 
@@ -32,6 +34,13 @@ pub fn item_categories() -> Result<Vec<ItemCategory>, String> {
     .map_err(|e| format!("{}", e))
 }
 
+pub fn languages() -> Result<Vec<Language>, String> {
+    serde_json::from_str(include_str!(
+        "../../../data/srd_5e/src/5e-SRD-Languages.json",
+    ))
+    .map_err(|e| format!("{}", e))
+}
+
 pub fn magic_items() -> Result<Vec<MagicItem>, String> {
     serde_json::from_str(include_str!(
         "../../../data/srd_5e/src/5e-SRD-Magic-Items.json",
@@ -36,6 +36,15 @@ impl Trait {
     pub fn has_parent(&self) -> bool {
         self.parent.is_some()
     }
+
+    /// The indexes of the species and subspecies that have the trait.
+    pub fn species(&self) -> Vec<String> {
+        self.races
+            .iter()
+            .chain(self.subraces.iter())
+            .map(|species| species.index.to_owned())
+            .collect()
+    }
 }
 
 impl<'a> fmt::Display for SummaryView<'a> {
@@ -44,6 +44,9 @@ fn leather_armor() {
 **Weight:** 10 lbs",
         format!("{}", item.display_details()),
     );
+
+    assert_eq!(1000, item.cost_cp());
+    assert_eq!("armor", item.category_index());
 }
 
 #[test]
@@ -13,6 +13,8 @@ fn deck_of_many_things() {
         format!("{}", magic_item.display_summary()),
     );
 
+    assert_eq!(Some("legendary"), magic_item.rarity());
+
     assert_eq!("\
 # Deck of Many Things
 
@@ -84,3 +86,15 @@ Once a card is drawn, it fades from existence. Unless the card is the Fool or th
         format!("{}", magic_item.display_details()),
     );
 }
+
+#[test]
+fn ring_of_protection() {
+    let magic_items = magic_items().unwrap();
+    let magic_item = magic_items
+        .iter()
+        .find(|i| i.name == "Ring of Protection")
+        .unwrap();
+
+    assert_eq!(Some("rare"), magic_item.rarity());
+    assert_eq!(Some("ring"), magic_item.category_index());
+}
@@ -30,6 +30,9 @@ function gen_where() {
     Dragonborn)
       echo -n "p.race IN('Dragonborn', 'Dragon')"
       ;;
+    Goblin)
+      echo -n "p.race IN('Goblin', 'Hobgoblin', 'Bugbear')"
+      ;;
     *)
       echo -n "p.race = '$race'"
   esac
@@ -53,10 +53,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                     println!(
                                         "{},{},{},{},{}",
                                         JsonValue::from(name.as_str()).dump(),
-                                        fields
-                                            .get("race")
-                                            .map_or(String::new(), |s| JsonValue::from(s.as_str())
-                                                .dump()),
+                                        fields.get("race").map_or(String::new(), |s| {
+                                            JsonValue::from(normalize_race(s)).dump()
+                                        }),
                                         fields
                                             .get("ethnicity")
                                             .map_or(String::new(), |s| JsonValue::from(s.as_str())
@@ -149,6 +148,34 @@ fn parse(text: &str) -> Option<(InfoboxType, HashMap<&str, String>)> {
     None
 }
 
+/// Reduce the free-form race field of an infobox to a single species name, so that subraces and
+/// alternate spellings are grouped together, eg. "Fire genasi" and "Lizard folk (Poison Dusk)"
+/// become "Genasi" and "Lizardfolk" respectively.
+fn normalize_race(race: &str) -> String {
+    let race = race
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .trim();
+
+    let lower = race.to_lowercase();
+
+    if lower.ends_with("genasi") {
+        String::from("Genasi")
+    } else if lower.ends_with("aasimar") {
+        String::from("Aasimar")
+    } else if ["lizardfolk", "lizard folk", "lizardman", "lizardmen"].contains(&lower.as_str()) {
+        String::from("Lizardfolk")
+    } else if lower.ends_with("kobold") {
+        String::from("Kobold")
+    } else {
+        race.to_string()
+    }
+}
+
 fn strip_tags(text: &str) -> String {
     let mut buf = Vec::new();
     let mut output = String::with_capacity(text.len());
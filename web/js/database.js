@@ -2,8 +2,42 @@ import Dexie from "dexie"
 import * as download from "downloadjs"
 import * as wasm from "initiative-web"
 
+const DEFAULT_CAMPAIGN = "Default"
+
 const dexie = new Dexie("initiative")
 
+let campaign = localStorage.getItem("campaign") || DEFAULT_CAMPAIGN
+
+dexie.version(10).stores({
+  campaignThings: "&[campaign+uuid], campaign, &[campaign+name], [campaign+location_uuid], [campaign+home_uuid]",
+})
+
+dexie.version(9).stores({
+  things: null,
+  keyValue: null,
+  campaignThings: "&[campaign+uuid], campaign, &[campaign+name]",
+  campaignKeyValues: "&[campaign+key], campaign",
+  campaigns: "&name",
+})
+
+dexie.version(8).stores({
+  things: "&uuid, &name, type",
+  keyValue: "&key",
+  campaignThings: "&[campaign+uuid], campaign, &[campaign+name]",
+  campaignKeyValues: "&[campaign+key], campaign",
+  campaigns: "&name",
+}).upgrade(async (tx) => {
+  await tx.table("campaigns").put({ name: DEFAULT_CAMPAIGN })
+
+  await tx.table("things").each((thing) => {
+    return tx.table("campaignThings").put({ ...thing, campaign: DEFAULT_CAMPAIGN })
+  })
+
+  await tx.table("keyValue").each(({ key, value }) => {
+    return tx.table("campaignKeyValues").put({ campaign: DEFAULT_CAMPAIGN, key, value })
+  })
+})
+
 dexie.version(7).stores({
   things: "&uuid, &name, type",
   keyValue: "&key",
@@ -120,56 +154,125 @@ export function health_check() {
   return !dexie.hasFailed()
 }
 
+function withoutCampaign(row) {
+  if (row) {
+    const { campaign, ...thing } = row
+    return thing
+  }
+}
+
+function campaignThings() {
+  return dexie.campaignThings.where("campaign").equals(campaign)
+}
+
 export async function delete_thing_by_uuid(uuid) {
-  return dexie.things.delete(uuid)
+  return dexie.campaignThings.delete([campaign, uuid])
 }
 
 export async function get_all_the_things() {
-  return dexie.things.toArray()
+  return campaignThings()
+    .toArray()
+    .then((things) => things.map(withoutCampaign))
 }
 
 export async function get_thing_by_uuid(uuid) {
-  return dexie.things.get({ uuid })
+  return dexie.campaignThings.get([campaign, uuid]).then(withoutCampaign)
 }
 
 export async function get_thing_by_name(name) {
-  return dexie.things
-    .where("name")
-    .equalsIgnoreCase(name)
+  const lowerName = name.toLowerCase()
+
+  return campaignThings()
+    .filter((thing) => thing.name.toLowerCase() === lowerName)
     .first()
+    .then(withoutCampaign)
 }
 
 export async function get_things_by_name_start(name, limit) {
-  return dexie.things
-    .where("name")
-    .startsWithIgnoreCase(name)
+  const lowerName = name.toLowerCase()
+
+  return campaignThings()
+    .filter((thing) => thing.name.toLowerCase().startsWith(lowerName))
     .limit(limit)
     .toArray()
+    .then((things) => things.map(withoutCampaign))
+}
+
+export async function get_npcs_by_place(placeUuid) {
+  const [occupants, residents] = await Promise.all([
+    dexie.campaignThings.where("[campaign+location_uuid]").equals([campaign, placeUuid]).toArray(),
+    dexie.campaignThings.where("[campaign+home_uuid]").equals([campaign, placeUuid]).toArray(),
+  ])
+
+  const npcs = new Map()
+  occupants.concat(residents)
+    .filter((thing) => thing.type === "Npc")
+    .forEach((thing) => npcs.set(thing.uuid, withoutCampaign(thing)))
+
+  return [...npcs.values()]
 }
 
 export async function save_thing(thing) {
-  return dexie.things.put(thing)
+  return dexie.campaignThings.put({ ...thing, campaign })
 }
 
 export async function set_value(key, value) {
-  return dexie.keyValue.put({key, value})
+  return dexie.campaignKeyValues.put({ campaign, key, value })
 }
 
 export async function get_value(key) {
-  return dexie.keyValue.get(key).then((v) => v?.value)
+  return dexie.campaignKeyValues.get([campaign, key]).then((v) => v?.value)
 }
 
 export async function delete_value(key) {
-  return dexie.keyValue.delete(key)
+  return dexie.campaignKeyValues.delete([campaign, key])
+}
+
+export function get_campaign() {
+  return campaign
+}
+
+export async function get_campaigns() {
+  const names = await dexie.campaigns.toCollection().primaryKeys()
+
+  if (!names.includes(campaign)) {
+    names.push(campaign)
+  }
+
+  return names
 }
 
-export async function export_database(data) {
-  download(JSON.stringify(data), "initiative_export.json", "application/json")
+export async function set_campaign(name) {
+  await dexie.campaigns.put({ name })
+  campaign = name
+  localStorage.setItem("campaign", name)
+}
+
+export async function delete_campaign(name) {
+  if (name === campaign || !await dexie.campaigns.get(name)) {
+    throw new Error(`Unable to delete campaign "${name}".`)
+  }
+
+  return dexie.transaction(
+    "rw",
+    dexie.campaigns,
+    dexie.campaignThings,
+    dexie.campaignKeyValues,
+    async () => {
+      await dexie.campaignThings.where("campaign").equals(name).delete()
+      await dexie.campaignKeyValues.where("campaign").equals(name).delete()
+      await dexie.campaigns.delete(name)
+    },
+  )
+}
+
+export async function export_file(file) {
+  download(file.contents, file.fileName, file.mimeType)
 }
 
 export async function import_database(successCallback, failureCallback) {
   const inputElement = document.createElement("input")
-  inputElement.accept = "application/json"
+  inputElement.accept = "application/json,text/csv,.csv"
   inputElement.style = "display: none"
   inputElement.type = "file"
 
@@ -181,8 +284,10 @@ export async function import_database(successCallback, failureCallback) {
 
     const file = event.target.files[0]
 
-    if (!/\.json$/.test(file.name)) {
-      failureCallback("The file you selected does not appear to be JSON.")
+    const isCsv = /\.csv$/i.test(file.name)
+
+    if (!isCsv && !/\.json$/.test(file.name)) {
+      failureCallback("The file you selected does not appear to be JSON or CSV.")
       return
     }
 
@@ -190,6 +295,11 @@ export async function import_database(successCallback, failureCallback) {
     reader.addEventListener("loadstart", (event) => console.log(event))
     reader.addEventListener("error", (event) => console.error(event))
     reader.addEventListener("load", (event) => {
+      if (isCsv) {
+        successCallback(event.target.result)
+        return
+      }
+
       try {
         const data = JSON.parse(event.target.result)
         successCallback(data)
@@ -1,5 +1,5 @@
 import * as wasm from "initiative-web"
-import { export_database, import_database } from "./database"
+import { export_file, import_database } from "./database"
 import terminal from "./terminal"
 
 const terminalElement = document.getElementById("terminal")
@@ -20,7 +20,7 @@ wasm.initialize("terminal")
 
 terminalElement.addEventListener(
   "initiative.export",
-  async (event) => await export_database(event.detail),
+  async (event) => await export_file(event.detail),
 )
 
 terminalElement.addEventListener(
@@ -28,7 +28,11 @@ terminalElement.addEventListener(
   async (event) => await import_database(
     async (data) => {
       try {
-        terminal.output(await wasm.bulk_import(data))
+        if (typeof data === "string") {
+          terminal.output(await wasm.bulk_import_csv(data))
+        } else {
+          terminal.output(await wasm.bulk_import(data))
+        }
       } catch (e) {
         terminal.output("! " + e)
       }
@@ -62,6 +62,14 @@ impl initiative_core::DataStore for DataStore {
             .map_err(|_| ())
     }
 
+    async fn get_npcs_by_place(&self, place_uuid: &Uuid) -> Result<Vec<Thing>, ()> {
+        get_npcs_by_place(place_uuid.to_string().into())
+            .await
+            .map_err(|_| ())?
+            .into_serde()
+            .map_err(|_| ())
+    }
+
     async fn save_thing(&mut self, thing: &Thing) -> Result<(), ()> {
         save_thing(JsValue::from_serde(thing).unwrap())
             .await
@@ -80,6 +88,26 @@ impl initiative_core::DataStore for DataStore {
     async fn delete_value(&mut self, key: &str) -> Result<(), ()> {
         delete_value(key).await.map(|_| ()).map_err(|_| ())
     }
+
+    async fn get_campaign(&self) -> Result<String, ()> {
+        get_campaign().as_string().ok_or(())
+    }
+
+    async fn get_campaigns(&self) -> Result<Vec<String>, ()> {
+        get_campaigns()
+            .await
+            .map_err(|_| ())?
+            .into_serde()
+            .map_err(|_| ())
+    }
+
+    async fn set_campaign(&mut self, name: &str) -> Result<(), ()> {
+        set_campaign(name).await.map(|_| ()).map_err(|_| ())
+    }
+
+    async fn delete_campaign(&mut self, name: &str) -> Result<(), ()> {
+        delete_campaign(name).await.map(|_| ()).map_err(|_| ())
+    }
 }
 
 #[wasm_bindgen(module = "/js/database.js")]
@@ -101,6 +129,9 @@ extern "C" {
     #[wasm_bindgen(catch)]
     async fn get_things_by_name_start(name: &str, limit: usize) -> Result<JsValue, JsValue>;
 
+    #[wasm_bindgen(catch)]
+    async fn get_npcs_by_place(place_uuid: JsValue) -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(catch)]
     async fn save_thing(thing: JsValue) -> Result<JsValue, JsValue>;
 
@@ -112,4 +143,15 @@ extern "C" {
 
     #[wasm_bindgen(catch)]
     async fn delete_value(key: &str) -> Result<JsValue, JsValue>;
+
+    fn get_campaign() -> JsValue;
+
+    #[wasm_bindgen(catch)]
+    async fn get_campaigns() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch)]
+    async fn set_campaign(name: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch)]
+    async fn delete_campaign(name: &str) -> Result<JsValue, JsValue>;
 }
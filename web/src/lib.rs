@@ -8,6 +8,7 @@ mod utils;
 
 use data_store::DataStore;
 use initiative_core as core;
+use js_sys::{Object, Reflect};
 use wasm_bindgen::prelude::*;
 use web_sys::{window, CustomEvent, CustomEventInit, Element};
 
@@ -52,11 +53,21 @@ pub async fn bulk_import(data: JsValue) -> Result<String, String> {
         .await
 }
 
+#[wasm_bindgen]
+pub async fn bulk_import_csv(data: String) -> Result<String, String> {
+    app().bulk_import_csv(&data).await
+}
+
 fn event_dispatcher(event: core::Event) {
     let js_event = match event {
-        core::Event::Export(data) => {
+        core::Event::Export(file) => {
+            let detail = Object::new();
+            Reflect::set(&detail, &"contents".into(), &file.contents().into()).unwrap();
+            Reflect::set(&detail, &"fileName".into(), &file.file_name().into()).unwrap();
+            Reflect::set(&detail, &"mimeType".into(), &file.mime_type().into()).unwrap();
+
             let mut init = CustomEventInit::new();
-            init.detail(&JsValue::from_serde(&data).unwrap());
+            init.detail(&detail);
             CustomEvent::new_with_event_init_dict("initiative.export", &init).unwrap()
         }
         core::Event::Import => CustomEvent::new("initiative.startImport").unwrap(),